@@ -0,0 +1,96 @@
+//! The `Cartridge` trait every game in the arcade implements, plus the
+//! `CartridgeScope` marker the launcher uses to clean up after one.
+//!
+//! `Cartridge` doesn't depend on `puri_platformer` at all — only the
+//! launcher binary (`main.rs`, in this same crate) does, wiring concrete
+//! games to it. That's also why `impl Cartridge for PlatformerPlugins`
+//! lives in `main.rs` rather than in `puri_platformer` itself: `main.rs`
+//! is the one place both this trait and `puri_platformer`'s types are in
+//! scope, so it's the only place the impl can be written without either
+//! crate depending on the other (a cartridge crate depending on the
+//! launcher, or the launcher depending on every cartridge, doesn't scale).
+
+use std::collections::HashSet;
+
+use bevy::prelude::*;
+
+/// Marks an entity as belonging to whichever cartridge is currently
+/// playing. The launcher doesn't ask cartridges to tag their own entities
+/// with this — most gameplay plugins were never written with a launcher in
+/// mind, `puri_platformer` included — so instead `enter_cartridge` (see
+/// `main.rs`) tags everything the cartridge's `build` created that wasn't
+/// already in the world before it ran. That's a one-time snapshot: anything
+/// a cartridge spawns mid-run after that (`puri_platformer`'s attack hitbox
+/// and shield sensor, both children of the player, are the two real
+/// examples today) never gets this marker itself, so [`exit_cartridge`]
+/// has to despawn recursively rather than by tag alone.
+#[derive(Component)]
+pub struct CartridgeScope;
+
+/// A single playable game hosted by the launcher.
+pub trait Cartridge: Send + Sync {
+    /// Shown in the carousel and used to key which cartridges have already
+    /// had `build` called (see the doc comment there).
+    fn name(&self) -> &'static str;
+
+    /// Registers this cartridge's plugins and spawns its initial entities.
+    ///
+    /// Called at most once per cartridge for the life of the launcher
+    /// process: Bevy panics if the same plugin type is added to an `App`
+    /// twice, so `build` can only safely run once (`enter_cartridge`
+    /// tracks this). Re-entering the same cartridge later re-runs its
+    /// `Startup` schedule instead of calling `build` again, which respawns
+    /// its entities fine as long as its `Startup` systems are safe to run
+    /// more than once. That's not guaranteed for every cartridge: a system
+    /// that assumes it's the only thing ever spawning a particular
+    /// singleton (`puri_platformer`'s camera spawn, for instance, expects
+    /// exactly one `Camera2d` to ever exist) will end up with two after a
+    /// second visit, and anything downstream doing `.single()` on it will
+    /// panic. Fully supporting repeated relaunches needs those systems
+    /// audited for idempotency; this crate's `tests/` run repeated
+    /// enter/exit cycles only with a stand-in cartridge whose `Startup` is
+    /// safe to re-run.
+    fn build(&self, app: &mut App);
+}
+
+/// Builds `cartridge` into `app` (unless `built` already contains its
+/// name, per the one-time-`build` contract on [`Cartridge`]), then runs
+/// `Startup` so its entities exist immediately rather than waiting for the
+/// app's own once-ever automatic pass — and tags every entity that wasn't
+/// already in the world beforehand with [`CartridgeScope`], so
+/// [`exit_cartridge`] can find them again.
+pub fn enter_cartridge(app: &mut App, cartridge: &dyn Cartridge, built: &mut HashSet<&'static str>) {
+    let before: HashSet<Entity> = app.world.iter_entities().map(|entity| entity.id()).collect();
+    if built.insert(cartridge.name()) {
+        cartridge.build(app);
+    }
+    app.world.run_schedule(Startup);
+    let after: Vec<Entity> = app.world.iter_entities().map(|entity| entity.id()).collect();
+    for entity in after {
+        if !before.contains(&entity) {
+            app.world.entity_mut(entity).insert(CartridgeScope);
+        }
+    }
+}
+
+/// Despawns every [`CartridgeScope`]-tagged entity and its children,
+/// leaving anything the launcher itself owns (its carousel UI, camera,
+/// etc.) untouched. Recursive for the same reason `despawn_recursive` is
+/// the crate-wide default everywhere else: a plain despawn would orphan a
+/// scoped entity's children instead of erroring loudly, and those children
+/// are never individually tagged `CartridgeScope` (see that type's own
+/// doc comment). Each entity is existence-checked first because a child
+/// of one scoped root can itself be a scoped root visited later in the
+/// same `scoped` list.
+pub fn exit_cartridge(app: &mut App) {
+    let scoped: Vec<Entity> = app
+        .world
+        .query_filtered::<Entity, With<CartridgeScope>>()
+        .iter(&app.world)
+        .collect();
+    for entity in scoped {
+        if app.world.get_entity(entity).is_some() {
+            despawn_with_children_recursive(&mut app.world, entity);
+        }
+    }
+}
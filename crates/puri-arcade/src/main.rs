@@ -0,0 +1,159 @@
+//! The arcade launcher: a game-select carousel that hands off to whichever
+//! cartridge the player picks, and a clean way back out.
+//!
+//! Cartridges can't be registered from inside a running system — adding a
+//! `Plugin` needs `&mut App`, which only exists in `main`, not in anything
+//! a `Query`/`Commands`-based system can reach. So carousel input just
+//! records *intent* (`PendingEnter`/`PendingExit` resources); `main`'s own
+//! loop reads that intent after each `app.update()` and does the actual
+//! `Cartridge::build` / teardown itself, outside the ECS.
+
+use std::collections::HashSet;
+
+use bevy::app::AppExit;
+use bevy::prelude::*;
+use puri_arcade::{enter_cartridge, exit_cartridge, Cartridge};
+
+/// `impl Cartridge for PlatformerPlugins` lives here (see the crate-level
+/// doc comment in `lib.rs` for why): this is the only place both the trait
+/// and `puri_platformer`'s types are in scope without a dependency cycle
+/// between this crate and that one.
+impl Cartridge for puri_platformer::PlatformerPlugins {
+    fn name(&self) -> &'static str {
+        "Puri Platformer"
+    }
+
+    fn build(&self, app: &mut App) {
+        app.add_plugins(puri_platformer::PlatformerPlugins);
+    }
+}
+
+#[derive(States, Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+enum LauncherState {
+    #[default]
+    Carousel,
+    Playing,
+}
+
+#[derive(Resource)]
+struct CartridgeNames(Vec<&'static str>);
+
+#[derive(Resource, Default)]
+struct Selected(usize);
+
+/// Set by `drive_carousel` on Enter; consumed by `main`'s loop, which is
+/// the only place that can actually call `Cartridge::build`.
+#[derive(Resource, Default)]
+struct PendingEnter(Option<usize>);
+
+/// Set by `handle_escape` while `LauncherState::Playing`; consumed the
+/// same way as `PendingEnter`.
+#[derive(Resource, Default)]
+struct PendingExit(bool);
+
+#[derive(Component)]
+struct CarouselEntry(usize);
+
+fn main() {
+    let cartridges: Vec<Box<dyn Cartridge>> = vec![Box::new(puri_platformer::PlatformerPlugins)];
+    let names = CartridgeNames(cartridges.iter().map(|cartridge| cartridge.name()).collect());
+
+    let mut app = App::new();
+    app.add_plugins(DefaultPlugins)
+        .init_state::<LauncherState>()
+        .insert_resource(names)
+        .init_resource::<Selected>()
+        .init_resource::<PendingEnter>()
+        .init_resource::<PendingExit>()
+        // Launcher chrome spawns in `PreStartup`, not `Startup`: `Startup`
+        // is reserved for cartridges, and gets re-run by hand (see below)
+        // every time one is entered, which would re-spawn anything of
+        // ours sitting in it too.
+        .add_systems(PreStartup, spawn_carousel)
+        .add_systems(
+            Update,
+            drive_carousel.run_if(in_state(LauncherState::Carousel)),
+        )
+        .add_systems(
+            Update,
+            handle_escape.run_if(in_state(LauncherState::Playing)),
+        );
+
+    let mut built: HashSet<&'static str> = HashSet::new();
+
+    loop {
+        app.update();
+
+        if !app.world.resource::<Events<AppExit>>().is_empty() {
+            break;
+        }
+
+        if let Some(index) = app.world.resource_mut::<PendingEnter>().0.take() {
+            enter_cartridge(&mut app, cartridges[index].as_ref(), &mut built);
+            app.world.resource_mut::<NextState<LauncherState>>().set(LauncherState::Playing);
+            set_carousel_visible(&mut app, false);
+        }
+
+        if app.world.resource_mut::<PendingExit>().0 {
+            app.world.resource_mut::<PendingExit>().0 = false;
+            exit_cartridge(&mut app);
+            app.world.resource_mut::<NextState<LauncherState>>().set(LauncherState::Carousel);
+            set_carousel_visible(&mut app, true);
+        }
+    }
+}
+
+fn set_carousel_visible(app: &mut App, visible: bool) {
+    let mut query = app.world.query_filtered::<&mut Visibility, With<CarouselEntry>>();
+    for mut entry_visibility in query.iter_mut(&mut app.world) {
+        *entry_visibility = if visible { Visibility::Inherited } else { Visibility::Hidden };
+    }
+}
+
+fn spawn_carousel(mut commands: Commands, names: Res<CartridgeNames>) {
+    commands.spawn(Camera2dBundle::default());
+    for (index, name) in names.0.iter().enumerate() {
+        commands.spawn((
+            CarouselEntry(index),
+            TextBundle {
+                text: Text::from_section(*name, TextStyle::default()),
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(16.0 + index as f32 * 24.0),
+                    left: Val::Px(16.0),
+                    ..default()
+                },
+                ..default()
+            },
+        ));
+    }
+}
+
+fn drive_carousel(
+    keys: Res<ButtonInput<KeyCode>>,
+    names: Res<CartridgeNames>,
+    mut selected: ResMut<Selected>,
+    mut pending_enter: ResMut<PendingEnter>,
+    mut entries: Query<(&CarouselEntry, &mut Text)>,
+) {
+    if keys.just_pressed(KeyCode::ArrowDown) {
+        selected.0 = (selected.0 + 1) % names.0.len();
+    }
+    if keys.just_pressed(KeyCode::ArrowUp) {
+        selected.0 = (selected.0 + names.0.len() - 1) % names.0.len();
+    }
+    if keys.just_pressed(KeyCode::Return) {
+        pending_enter.0 = Some(selected.0);
+    }
+
+    for (entry, mut text) in &mut entries {
+        let highlighted = entry.0 == selected.0;
+        text.sections[0].style.color = if highlighted { Color::YELLOW } else { Color::WHITE };
+    }
+}
+
+fn handle_escape(keys: Res<ButtonInput<KeyCode>>, mut pending_exit: ResMut<PendingExit>) {
+    if keys.just_pressed(KeyCode::Escape) {
+        pending_exit.0 = true;
+    }
+}
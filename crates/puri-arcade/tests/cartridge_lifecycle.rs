@@ -0,0 +1,121 @@
+use std::collections::HashSet;
+
+use bevy::prelude::*;
+use puri_arcade::{enter_cartridge, exit_cartridge, Cartridge, CartridgeScope};
+
+/// A minimal stand-in cartridge so this test doesn't need
+/// `puri_platformer`'s `DefaultPlugins`/renderer-dependent setup — just
+/// enough to prove the enter/exit contract `enter_cartridge`/
+/// `exit_cartridge` document.
+struct TestCartridge;
+
+impl Cartridge for TestCartridge {
+    fn name(&self) -> &'static str {
+        "test-cartridge"
+    }
+
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn_empty();
+            commands.spawn_empty();
+        });
+    }
+}
+
+#[test]
+fn a_single_enter_exit_cycle_leaves_no_entities_behind() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    let entities_before = app.world.entities().len();
+
+    let mut built = HashSet::new();
+    enter_cartridge(&mut app, &TestCartridge, &mut built);
+
+    let scoped = app
+        .world
+        .query_filtered::<Entity, With<CartridgeScope>>()
+        .iter(&app.world)
+        .count();
+    assert_eq!(scoped, 2, "both entities spawned by the cartridge should be tagged");
+
+    exit_cartridge(&mut app);
+
+    assert_eq!(
+        app.world.entities().len(),
+        entities_before,
+        "exiting should leave the world exactly as it was before entering"
+    );
+}
+
+#[test]
+fn relaunching_respawns_and_still_leaks_nothing() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    let entities_before = app.world.entities().len();
+
+    let mut built = HashSet::new();
+    enter_cartridge(&mut app, &TestCartridge, &mut built);
+    exit_cartridge(&mut app);
+    // `build` (which adds `TestCartridge`'s systems) must only ever run
+    // once — Bevy panics on a duplicate plugin registration — but
+    // `Startup` itself re-runs on every entry, so the second visit still
+    // gets fresh entities.
+    enter_cartridge(&mut app, &TestCartridge, &mut built);
+    assert_eq!(built.len(), 1);
+
+    let scoped = app
+        .world
+        .query_filtered::<Entity, With<CartridgeScope>>()
+        .iter(&app.world)
+        .count();
+    assert_eq!(scoped, 2);
+
+    exit_cartridge(&mut app);
+    assert_eq!(app.world.entities().len(), entities_before);
+}
+
+/// A cartridge whose `Startup` spawns one root with a child. Both exist by
+/// the time `enter_cartridge` takes its snapshot, so both get tagged; the
+/// test below then gives the root a second child after entry, standing in
+/// for `puri_platformer`'s attack hitbox/shield sensor (children of the
+/// player spawned mid-gameplay, after that snapshot), which is never
+/// tagged `CartridgeScope`.
+struct ParentingCartridge;
+
+impl Cartridge for ParentingCartridge {
+    fn name(&self) -> &'static str {
+        "parenting-cartridge"
+    }
+
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn_empty().with_children(|parent| {
+                parent.spawn_empty();
+            });
+        });
+    }
+}
+
+#[test]
+fn exiting_despawns_untagged_children_of_a_scoped_root() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    let entities_before = app.world.entities().len();
+
+    let mut built = HashSet::new();
+    enter_cartridge(&mut app, &ParentingCartridge, &mut built);
+    let root = app
+        .world
+        .query_filtered::<Entity, (With<CartridgeScope>, With<Children>)>()
+        .single(&app.world);
+    app.world.entity_mut(root).with_children(|parent| {
+        parent.spawn_empty();
+    });
+    exit_cartridge(&mut app);
+
+    assert_eq!(
+        app.world.entities().len(),
+        entities_before,
+        "the untagged child should be despawned along with its CartridgeScope parent"
+    );
+}
@@ -0,0 +1,77 @@
+//! Runs [`AnimationPlugin`] completely standalone — no `puri_platformer`
+//! types involved, just a hand-built texture atlas cycled by
+//! `SpriteAnimation`/`FrameTime`. Proves `puri_core` doesn't secretly
+//! depend on anything platformer-shaped.
+//!
+//! Run with `cargo run -p puri_core --example standalone_animation`.
+
+use bevy::prelude::*;
+use bevy::render::render_asset::RenderAssetUsages;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use puri_core::animation::{AnimationBundle, AnimationPlugin, FrameTime, SpriteAnimation};
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, AnimationPlugin))
+        .add_systems(Startup, spawn_animated_square)
+        .run();
+}
+
+/// Four solid-color 8x8 frames stacked into one atlas — no asset files
+/// needed, so this runs in a fresh checkout with nothing but `cargo run`.
+fn spawn_animated_square(
+    mut commands: Commands,
+    mut images: ResMut<Assets<Image>>,
+    mut layouts: ResMut<Assets<TextureAtlasLayout>>,
+) {
+    const FRAME: usize = 8;
+    let colors: [[u8; 4]; 4] = [
+        [255, 0, 0, 255],
+        [0, 255, 0, 255],
+        [0, 0, 255, 255],
+        [255, 255, 0, 255],
+    ];
+    let pixels: Vec<u8> = colors
+        .iter()
+        .flat_map(|color| std::iter::repeat(*color).take(FRAME * FRAME))
+        .flatten()
+        .collect();
+
+    let texture = images.add(Image::new(
+        Extent3d {
+            width: FRAME as u32,
+            height: (FRAME * colors.len()) as u32,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        pixels,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    ));
+    let layout = layouts.add(TextureAtlasLayout::from_grid(
+        Vec2::splat(FRAME as f32),
+        1,
+        colors.len() as u32,
+        None,
+        None,
+    ));
+
+    commands.spawn(Camera2dBundle::default());
+    commands.spawn((
+        SpriteSheetBundle {
+            texture,
+            atlas: TextureAtlas { layout, index: 0 },
+            transform: Transform::from_scale(Vec3::splat(20.0)),
+            ..default()
+        },
+        AnimationBundle {
+            animation: SpriteAnimation {
+                first: 0,
+                last: colors.len() - 1,
+                play_once: false,
+                ..default()
+            },
+            frame_time: FrameTime::default(),
+        },
+    ));
+}
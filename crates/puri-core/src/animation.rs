@@ -0,0 +1,157 @@
+//! Sprite-sheet animation: cycling a `TextureAtlas` index at a per-clip
+//! frame rate, with an atomic clip swap and a play-once mode. Nothing here reads
+//! `Time` directly except this module's own [`AnimationPlugin`] — the frame
+//! math itself ([`advance_frame`]) is a pure function so a cartridge with
+//! its own notion of scaled/paused time (see `puri_platformer::animation`)
+//! can drive it from whatever clock it likes.
+
+use bevy::prelude::*;
+
+#[derive(Component, Reflect, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component)]
+pub struct SpriteAnimation {
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub first: usize,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub last: usize,
+    /// Stops on the last frame and inserts `AnimationFinished` instead of
+    /// looping back to `first`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub play_once: bool,
+    /// Frames per second. `0.0` (the `Default` value, so existing data
+    /// doesn't need to name it) falls back to the 12fps this module always
+    /// used before clips could set their own rate.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub fps: f32,
+}
+
+/// Inserted once a `play_once` animation reaches its last frame.
+#[derive(Component)]
+pub struct AnimationFinished;
+
+#[derive(Component, Reflect, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component)]
+pub struct FrameTime {
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub seconds: f32,
+}
+
+/// The two components every animated entity needs, paired for spawning
+/// alongside whatever sprite bundle carries the actual `TextureAtlas`
+/// (this crate doesn't own texture loading, so it can't bundle that part).
+#[derive(Bundle, Default)]
+pub struct AnimationBundle {
+    pub animation: SpriteAnimation,
+    pub frame_time: FrameTime,
+}
+
+/// Atomically swaps to a different clip: layout handle, frame range, and a
+/// reset `FrameTime`/index, so nothing ever reads the old clip's index
+/// against the new clip's (possibly shorter) atlas.
+pub fn set_animation(
+    animation: &mut SpriteAnimation,
+    frame_time: &mut FrameTime,
+    atlas: &mut TextureAtlas,
+    layout: Handle<TextureAtlasLayout>,
+    clip: SpriteAnimation,
+) {
+    atlas.layout = layout;
+    atlas.index = clip.first;
+    frame_time.seconds = 0.0;
+    *animation = clip;
+}
+
+/// What a tick of [`advance_frame`] did, so callers only touch
+/// `TextureAtlas`/insert `AnimationFinished` when something actually
+/// changed rather than reassigning the same index every tick.
+pub enum FrameAdvance {
+    Unchanged,
+    Advanced(usize),
+    Finished,
+}
+
+/// The pure frame-advance logic behind `animate_sprite`, split out so it
+/// can be driven by any clock (see the module doc) and unit tested without
+/// spinning up an `App`.
+///
+/// A single-frame clip (`last <= first`) never has anything to advance to,
+/// so it's `Unchanged` unconditionally.
+pub fn advance_frame(
+    animation: &SpriteAnimation,
+    frame_time: &mut FrameTime,
+    current_index: usize,
+    dt: f32,
+) -> FrameAdvance {
+    if animation.last <= animation.first {
+        return FrameAdvance::Unchanged;
+    }
+
+    let fps = if animation.fps > 0.0 { animation.fps } else { 12.0 };
+    frame_time.seconds += dt;
+    if frame_time.seconds < 1.0 / fps {
+        return FrameAdvance::Unchanged;
+    }
+    frame_time.seconds = 0.0;
+
+    if current_index >= animation.last {
+        if animation.play_once {
+            FrameAdvance::Finished
+        } else {
+            FrameAdvance::Advanced(animation.first)
+        }
+    } else {
+        FrameAdvance::Advanced(current_index + 1)
+    }
+}
+
+/// Swapping `SpriteAnimation` to a clip with fewer frames than the last one
+/// (say, a 6-frame idle to a 3-frame land) can leave `atlas.index` pointing
+/// past the new clip's last frame — and past the atlas layout entirely if
+/// the new clip also swapped to a smaller texture. Catches that the instant
+/// `SpriteAnimation` changes, before a frame is ever rendered with it,
+/// rather than waiting for the tick-driven `advance_frame` check.
+pub fn clamp_stale_index(mut query: Query<(&SpriteAnimation, &mut TextureAtlas), Changed<SpriteAnimation>>) {
+    for (animation, mut atlas) in &mut query {
+        atlas.index = atlas.index.clamp(animation.first, animation.last);
+    }
+}
+
+/// Only writes `atlas.index` (and so only marks `TextureAtlas` `Changed`,
+/// which is what drives extraction to the render world) on ticks where the
+/// frame actually advances.
+fn animate_sprite(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<
+        (Entity, &SpriteAnimation, &mut FrameTime, &mut TextureAtlas),
+        Without<AnimationFinished>,
+    >,
+) {
+    let dt = time.delta_seconds();
+    for (entity, animation, mut frame_time, mut atlas) in &mut query {
+        match advance_frame(animation, &mut frame_time, atlas.index, dt) {
+            FrameAdvance::Unchanged => {}
+            FrameAdvance::Advanced(index) => atlas.index = index,
+            FrameAdvance::Finished => {
+                commands.entity(entity).insert(AnimationFinished);
+            }
+        }
+    }
+}
+
+/// Registers the animation types and drives them off the plain `Res<Time>`
+/// clock. A cartridge that needs its systems driven by its own scaled/
+/// paused clock (see `puri_platformer::animation::AnimationPlugin`) should
+/// call [`clamp_stale_index`]/[`advance_frame`] directly instead of adding
+/// this plugin.
+pub struct AnimationPlugin;
+
+impl Plugin for AnimationPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<SpriteAnimation>()
+            .register_type::<FrameTime>()
+            .add_systems(Update, (clamp_stale_index, animate_sprite).chain());
+    }
+}
@@ -0,0 +1,22 @@
+//! Game-agnostic building blocks shared across Puri Arcade cartridges:
+//! sprite-sheet animation and frame-rate-independent easing math today.
+//!
+//! Collision (`HitBox`, ground resolution) and camera follow are still
+//! `puri_platformer`-only: both are currently written against that crate's
+//! own `Player`/`Velocity`/`Grounded` types, and pulling them out cleanly
+//! needs a generic stand-in for "the thing being followed/resolved" first.
+//! That's follow-up work, not done here.
+//!
+//! See `examples/standalone_animation.rs` for [`animation::AnimationPlugin`]
+//! running on its own, with no other Puri Arcade crate involved.
+
+pub mod animation;
+pub mod math;
+
+pub mod prelude {
+    pub use crate::animation::{
+        set_animation, AnimationBundle, AnimationFinished, AnimationPlugin, FrameAdvance, FrameTime,
+        SpriteAnimation,
+    };
+    pub use crate::math::{exp_decay, move_toward, spring_damp};
+}
@@ -0,0 +1,46 @@
+//! Frame-rate independent smoothing helpers. Anything that eases toward a
+//! target over time should use one of these instead of hand-rolling a
+//! `lerp(current, target, speed * dt)`, which drifts depending on frame
+//! rate (it's really an Euler-integrated approximation of `exp_decay`).
+
+/// Exponentially decays `current` toward `target` at `rate` (higher is
+/// faster), independent of `dt`. This is the exact solution the naive
+/// `lerp(current, target, rate * dt)` only approximates.
+pub fn exp_decay(current: f32, target: f32, rate: f32, dt: f32) -> f32 {
+    target + (current - target) * (-rate * dt).exp()
+}
+
+/// Moves `current` toward `target` by at most `max_delta`, landing exactly
+/// on `target` instead of overshooting once within range.
+pub fn move_toward(current: f32, target: f32, max_delta: f32) -> f32 {
+    let delta = target - current;
+    if delta.abs() <= max_delta {
+        target
+    } else {
+        current + delta.signum() * max_delta
+    }
+}
+
+/// One semi-implicit step of a damped spring toward `target`. `velocity` is
+/// updated in place so the caller can carry it to the next call. Stable at
+/// any `dt`, unlike an explicit-Euler spring which blows up for large steps.
+/// `angular_frequency` controls speed (roughly `2*pi / settle_time`) and
+/// `damping` the bounciness (1.0 = critically damped, no overshoot).
+pub fn spring_damp(
+    current: f32,
+    velocity: &mut f32,
+    target: f32,
+    angular_frequency: f32,
+    damping: f32,
+    dt: f32,
+) -> f32 {
+    let f = 1.0 + 2.0 * dt * damping * angular_frequency;
+    let oo = angular_frequency * angular_frequency;
+    let hoo = dt * oo;
+    let hhoo = dt * hoo;
+    let det_inv = 1.0 / (f + hhoo);
+    let new_x = (f * current + dt * *velocity + hhoo * target) * det_inv;
+    let new_v = (*velocity + hoo * (target - current)) * det_inv;
+    *velocity = new_v;
+    new_x
+}
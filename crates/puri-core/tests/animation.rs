@@ -0,0 +1,85 @@
+use bevy::prelude::*;
+use puri_core::animation::{advance_frame, FrameAdvance, FrameTime, SpriteAnimation};
+
+#[test]
+fn single_frame_clip_never_advances() {
+    let animation = SpriteAnimation {
+        first: 2,
+        last: 2,
+        play_once: false,
+        ..default()
+    };
+    let mut frame_time = FrameTime::default();
+
+    for _ in 0..10 {
+        assert!(matches!(
+            advance_frame(&animation, &mut frame_time, 2, 1.0),
+            FrameAdvance::Unchanged
+        ));
+    }
+}
+
+#[test]
+fn a_tick_short_of_the_frame_boundary_does_not_advance() {
+    let animation = SpriteAnimation {
+        first: 0,
+        last: 3,
+        play_once: false,
+        ..default()
+    };
+    let mut frame_time = FrameTime::default();
+
+    assert!(matches!(
+        advance_frame(&animation, &mut frame_time, 0, 1.0 / 24.0),
+        FrameAdvance::Unchanged
+    ));
+}
+
+#[test]
+fn looping_clip_wraps_back_to_first() {
+    let animation = SpriteAnimation {
+        first: 0,
+        last: 3,
+        play_once: false,
+        ..default()
+    };
+    let mut frame_time = FrameTime::default();
+
+    match advance_frame(&animation, &mut frame_time, 3, 1.0) {
+        FrameAdvance::Advanced(index) => assert_eq!(index, 0),
+        _ => panic!("expected the clip to wrap"),
+    }
+}
+
+#[test]
+fn play_once_clip_finishes_instead_of_wrapping() {
+    let animation = SpriteAnimation {
+        first: 0,
+        last: 3,
+        play_once: true,
+        ..default()
+    };
+    let mut frame_time = FrameTime::default();
+
+    assert!(matches!(
+        advance_frame(&animation, &mut frame_time, 3, 1.0),
+        FrameAdvance::Finished
+    ));
+}
+
+#[test]
+fn a_faster_clip_advances_sooner_than_the_default_fps() {
+    let animation = SpriteAnimation {
+        first: 0,
+        last: 3,
+        fps: 24.0,
+        ..default()
+    };
+    let mut frame_time = FrameTime::default();
+
+    // Short of the default 12fps boundary, but past this clip's own 24fps one.
+    match advance_frame(&animation, &mut frame_time, 0, 1.0 / 16.0) {
+        FrameAdvance::Advanced(index) => assert_eq!(index, 1),
+        _ => panic!("expected the faster clip to have already advanced"),
+    }
+}
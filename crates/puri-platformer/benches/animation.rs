@@ -0,0 +1,62 @@
+//! `AnimationPlugin`'s per-frame update budget with a crowd of animated
+//! entities. `animate_sprite` reads `Query`/`Commands`, so unlike the
+//! collision benches this still needs a real (headless) `App` — but the
+//! library-crate split means that `App` is exactly the same
+//! `MinimalPlugins` + `AnimationPlugin` combination `TestWorld` uses, with
+//! no window or asset loading involved.
+//!
+//! Budget: one `Update` tick over 2k animated entities should stay under
+//! 500us on a reference machine.
+
+use bevy::prelude::*;
+use criterion::{criterion_group, criterion_main, Criterion};
+use puri_platformer::animation::{Animations, AnimationPlugin, FrameTime, SpriteAnimation};
+
+const ENTITY_COUNT: usize = 2_000;
+
+fn build_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .init_resource::<Assets<Image>>()
+        .init_resource::<Assets<TextureAtlasLayout>>()
+        .insert_resource(Animations::stub())
+        .add_plugins(AnimationPlugin);
+
+    let layout = app
+        .world
+        .resource_mut::<Assets<TextureAtlasLayout>>()
+        .add(TextureAtlasLayout::from_grid(Vec2::new(24.0, 32.0), 4, 1, None, None));
+
+    for _ in 0..ENTITY_COUNT {
+        app.world.spawn((
+            SpriteAnimation {
+                first: 0,
+                last: 3,
+                play_once: false,
+                ..default()
+            },
+            FrameTime { seconds: 0.0 },
+            TextureAtlas {
+                layout: layout.clone(),
+                index: 0,
+            },
+        ));
+    }
+
+    // Run Startup so `Animations`/plugin setup settle before timing ticks.
+    app.update();
+    app
+}
+
+fn bench_animate_sprite(c: &mut Criterion) {
+    c.bench_function("animate_sprite_2k", |b| {
+        b.iter_batched(
+            build_app,
+            |mut app| app.update(),
+            criterion::BatchSize::LargeInput,
+        )
+    });
+}
+
+criterion_group!(benches, bench_animate_sprite);
+criterion_main!(benches);
@@ -0,0 +1,74 @@
+//! 1,000 projectile-sized spawn/despawn lifecycles, pooled
+//! (`pool::EntityPool`) vs. unpooled (`commands.spawn`/`despawn`) — the
+//! comparison `pool`'s own doc comment on avoiding archetype moves exists
+//! to justify. Drives `Commands` directly against a bare `World` rather
+//! than a full `App`, the same shortcut `collision.rs`'s bench takes for
+//! plain-data work: nothing here needs a schedule to tick, just the
+//! spawn/insert/despawn cost itself.
+//!
+//! Budget: 1k pooled lifecycles should be well under 1k unpooled ones —
+//! every pooled acquire/release only moves the entity across the `Pooled`
+//! archetype boundary, never `ProjectileBundle`'s own components.
+
+use bevy::ecs::system::CommandQueue;
+use bevy::prelude::*;
+use criterion::{criterion_group, criterion_main, Criterion};
+use puri_platformer::prelude::{EntityPool, HitBox, Projectile, ProjectileBundle, Sensor};
+
+const LIFECYCLE_COUNT: usize = 1_000;
+const POOL_CAPACITY: usize = 16;
+
+fn demo_bundle() -> ProjectileBundle {
+    ProjectileBundle {
+        projectile: Projectile {
+            velocity: Vec2::new(200.0, 0.0),
+            damage: 1,
+        },
+        hitbox: HitBox { size: Vec2::new(8.0, 8.0) },
+        sensor: Sensor,
+        sprite: SpriteBundle::default(),
+    }
+}
+
+fn bench_pooled(c: &mut Criterion) {
+    c.bench_function("projectile_lifecycles_pooled_1k", |b| {
+        b.iter(|| {
+            let mut world = World::new();
+            let mut queue = CommandQueue::default();
+
+            let pool = {
+                let mut commands = Commands::new(&mut queue, &world);
+                let pool = EntityPool::new(&mut commands, POOL_CAPACITY, demo_bundle());
+                queue.apply(&mut world);
+                pool
+            };
+            let mut pool = pool;
+
+            for _ in 0..LIFECYCLE_COUNT {
+                let mut commands = Commands::new(&mut queue, &world);
+                let entity = pool.acquire(&mut commands, demo_bundle());
+                pool.release(&mut commands, entity);
+                queue.apply(&mut world);
+            }
+        })
+    });
+}
+
+fn bench_unpooled(c: &mut Criterion) {
+    c.bench_function("projectile_lifecycles_unpooled_1k", |b| {
+        b.iter(|| {
+            let mut world = World::new();
+            let mut queue = CommandQueue::default();
+
+            for _ in 0..LIFECYCLE_COUNT {
+                let mut commands = Commands::new(&mut queue, &world);
+                let entity = commands.spawn(demo_bundle()).id();
+                commands.entity(entity).despawn();
+                queue.apply(&mut world);
+            }
+        })
+    });
+}
+
+criterion_group!(benches, bench_pooled, bench_unpooled);
+criterion_main!(benches);
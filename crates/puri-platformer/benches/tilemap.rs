@@ -0,0 +1,71 @@
+//! Chunked tile mesh building vs. sprite-per-tile spawning, at the 200×30
+//! (6k tile) level size called out in the tilemap request.
+//!
+//! This doesn't measure real render-world extraction — that needs a
+//! running renderer, which the other benches in this crate also avoid (see
+//! `collision.rs`'s and `animation.rs`'s own notes). It measures the two
+//! approaches' actual per-frame cost instead: building every chunk's mesh
+//! (`tilemap`) vs. spawning one `SpriteBundle` entity per tile into a
+//! headless `App` (`sprite_per_tile`) — the same "each tile becomes visible
+//! renderer-side work" comparison, at the actual granularity this crate can
+//! benchmark without a GPU.
+//!
+//! Budget: chunked mesh building for 6k tiles should be well under the
+//! sprite-per-tile spawn cost at the same tile count.
+
+use bevy::prelude::*;
+use criterion::{criterion_group, criterion_main, Criterion};
+use puri_platformer::tilemap::{TileAnimations, TileLayer};
+
+const WIDTH: u32 = 200;
+const HEIGHT: u32 = 30;
+
+fn filled_layer() -> TileLayer {
+    let mut layer = TileLayer::new(WIDTH, HEIGHT, Vec2::new(16.0, 16.0), 4, 4);
+    for y in 0..HEIGHT {
+        for x in 0..WIDTH {
+            layer.set_tile(x, y, Some((x + y) % 16));
+        }
+    }
+    layer
+}
+
+fn bench_tilemap_full_rebuild(c: &mut Criterion) {
+    c.bench_function("tilemap_rebuild_6k_tiles", |b| {
+        b.iter_batched(
+            filled_layer,
+            |mut layer| {
+                let animations = TileAnimations::default();
+                let chunks = layer.take_dirty_chunks();
+                for chunk in chunks {
+                    let _ = layer.build_chunk_mesh(chunk, &animations, 0.0);
+                }
+            },
+            criterion::BatchSize::LargeInput,
+        )
+    });
+}
+
+fn spawn_sprite_per_tile(app: &mut App) {
+    for y in 0..HEIGHT {
+        for x in 0..WIDTH {
+            app.world.spawn(SpriteBundle {
+                transform: Transform::from_xyz(x as f32 * 16.0, y as f32 * 16.0, 0.0),
+                ..default()
+            });
+        }
+    }
+}
+
+fn bench_sprite_per_tile(c: &mut Criterion) {
+    c.bench_function("sprite_per_tile_6k_tiles", |b| {
+        b.iter_batched(
+            App::new,
+            |mut app| spawn_sprite_per_tile(&mut app),
+            criterion::BatchSize::LargeInput,
+        )
+    });
+}
+
+criterion_group!(benches, bench_tilemap_full_rebuild, bench_sprite_per_tile);
+criterion_main!(benches);
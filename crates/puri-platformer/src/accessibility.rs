@@ -0,0 +1,210 @@
+//! Accessibility toggles, persisted the same one-shot way `level_select`'s
+//! own unlock progress is (`assets/accessibility.ron`, `serde` feature).
+//!
+//! There's no Settings screen anywhere in this crate to add a section to —
+//! `window_config`'s own doc comment already covers there being no
+//! persisted `Settings` file at all — so each toggle gets its own debug key
+//! instead, the same stand-in `level_select`'s F7 uses for its own missing
+//! menu: F8 flips [`AccessibilityOptions::reduce_flashing`], F9 flips
+//! [`AccessibilityOptions::colorblind_palette`], F10 flips
+//! [`AccessibilityOptions::toggle_input_mode`]. Every reader below is a
+//! plain `Res<AccessibilityOptions>` re-checked every frame (or on change),
+//! so flipping a toggle takes effect immediately, mid-game, with no restart.
+//!
+//! What's real: [`reduce_flashing`](AccessibilityOptions::reduce_flashing)
+//! replaces `starman::animate_starman_tint`'s cycling rainbow with a steady
+//! outline tint — this crate has no separate "damage flash" system to
+//! disable (`death.rs`/`fall_damage.rs` never recolor the player sprite at
+//! all), so the starman rainbow is the only flashing effect there is to
+//! calm down. [`colorblind_palette`](AccessibilityOptions::colorblind_palette)
+//! swaps [`Palette`], a resource `map::spawn_map_entities` consults for
+//! `death::Checkpoint` and `objectives::ExitGate`'s colors — the only two
+//! rendered, gameplay-critical entities in the demo map with a color to
+//! swap (`prefab::Spike` is data-only with no sprite spawn at all, so
+//! there's no rendered hazard to swap instead; `ExitGate` stands in for one
+//! since it's the closest thing gating progress). [`sync_palette_colors`]
+//! also recolors whatever's already spawned the moment the palette changes,
+//! not just future spawns. [`toggle_input_mode`](AccessibilityOptions::toggle_input_mode)
+//! is read directly by `shield::raise_or_drop_shield`, changing it from
+//! "held" to "press to raise, press again to drop" — the only action in
+//! this crate driven by `keys.pressed` (a hold) at all; `player::dash`
+//! already fires on `just_pressed` (a single tap), so there's no hold
+//! behavior on dash for this to toggle away.
+//!
+//! What isn't: a reduce-screen-shake toggle. There's no `ScreenShake`
+//! anywhere in this crate — `camera::camera_follow` is a plain
+//! `exp_decay` follow with nothing that ever shakes the camera — so that
+//! part of the ask has nothing to wire into and is skipped rather than
+//! inventing a shake system solely to gate it.
+
+use bevy::prelude::*;
+
+use crate::death::Checkpoint;
+use crate::objectives::ExitGate;
+use crate::starman::Starman;
+
+/// Toggled by F8/F9/F10 respectively; see this module's own doc comment.
+#[derive(Resource, Reflect, Clone, Default)]
+#[reflect(Resource)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AccessibilityOptions {
+    pub reduce_flashing: bool,
+    pub colorblind_palette: bool,
+    pub toggle_input_mode: bool,
+}
+
+/// Colors for the demo map's two rendered, gameplay-critical spawns,
+/// consulted by `map::spawn_map_entities` at spawn time instead of the
+/// hard-coded `Color::rgb(...)` literals that used to sit there directly.
+#[derive(Resource, Clone, Copy)]
+pub struct Palette {
+    pub checkpoint: Color,
+    pub hazard: Color,
+}
+
+impl Palette {
+    fn standard() -> Self {
+        Self {
+            checkpoint: Color::rgb(0.9, 0.8, 0.3),
+            hazard: Color::rgb(0.7, 0.6, 0.2),
+        }
+    }
+
+    /// A blue/orange pair, chosen for staying distinguishable under the
+    /// common red-green color-vision deficiencies the standard palette's
+    /// yellow/olive pair doesn't.
+    fn colorblind() -> Self {
+        Self {
+            checkpoint: Color::rgb(0.0, 0.45, 0.7),
+            hazard: Color::rgb(0.9, 0.6, 0.0),
+        }
+    }
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+pub struct AccessibilityPlugin;
+
+impl Plugin for AccessibilityPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<AccessibilityOptions>()
+            .init_resource::<AccessibilityOptions>()
+            .init_resource::<Palette>()
+            .add_systems(
+                Update,
+                (
+                    toggle_accessibility_options,
+                    sync_palette_resource,
+                    sync_palette_colors,
+                    steady_tint_while_starman,
+                )
+                    .chain(),
+            );
+
+        #[cfg(feature = "serde")]
+        app.add_systems(Startup, persistence::load_from_disk);
+    }
+}
+
+fn toggle_accessibility_options(keys: Res<ButtonInput<KeyCode>>, mut options: ResMut<AccessibilityOptions>) {
+    let mut changed = false;
+    if keys.just_pressed(KeyCode::F8) {
+        options.reduce_flashing = !options.reduce_flashing;
+        changed = true;
+    }
+    if keys.just_pressed(KeyCode::F9) {
+        options.colorblind_palette = !options.colorblind_palette;
+        changed = true;
+    }
+    if keys.just_pressed(KeyCode::F10) {
+        options.toggle_input_mode = !options.toggle_input_mode;
+        changed = true;
+    }
+    if changed {
+        #[cfg(feature = "serde")]
+        persistence::save_to_disk(&options);
+    }
+}
+
+fn sync_palette_resource(options: Res<AccessibilityOptions>, mut palette: ResMut<Palette>) {
+    if !options.is_changed() {
+        return;
+    }
+    *palette = if options.colorblind_palette { Palette::colorblind() } else { Palette::standard() };
+}
+
+/// Recolors whatever's already spawned the moment [`Palette`] changes, so
+/// toggling mid-level doesn't wait for the next reload to take effect.
+fn sync_palette_colors(
+    palette: Res<Palette>,
+    mut checkpoints: Query<&mut Sprite, (With<Checkpoint>, Without<ExitGate>)>,
+    mut gates: Query<&mut Sprite, With<ExitGate>>,
+) {
+    if !palette.is_changed() {
+        return;
+    }
+    for mut sprite in &mut checkpoints {
+        sprite.color = palette.checkpoint;
+    }
+    for mut sprite in &mut gates {
+        sprite.color = palette.hazard;
+    }
+}
+
+/// Replaces `starman::animate_starman_tint`'s cycling rainbow with a
+/// steady outline-style tint while [`AccessibilityOptions::reduce_flashing`]
+/// is on. Ordered `.chain()`-after nothing in `starman`'s own set, so it
+/// runs in this module's own `Update` pass and simply overwrites whatever
+/// color that system already wrote this frame — the blink warning still
+/// alternates white/red either way, since that's a much slower, deliberate
+/// cue rather than the flashing this option is meant to calm.
+fn steady_tint_while_starman(options: Res<AccessibilityOptions>, mut player: Query<(&Starman, &mut Sprite)>) {
+    if !options.reduce_flashing {
+        return;
+    }
+    let Ok((starman, mut sprite)) = player.get_single_mut() else {
+        return;
+    };
+    if !starman.is_blinking() {
+        sprite.color = Color::rgb(0.6, 0.8, 1.0);
+    }
+}
+
+#[cfg(feature = "serde")]
+mod persistence {
+    use super::AccessibilityOptions;
+    use bevy::prelude::*;
+    use std::path::Path;
+
+    const SAVE_PATH: &str = "assets/accessibility.ron";
+
+    /// One-shot load of `assets/accessibility.ron` over the (all-off)
+    /// default, if present. Mirrors `high_scores::persistence::load_from_disk`.
+    pub fn load_from_disk(mut options: ResMut<AccessibilityOptions>) {
+        let Ok(contents) = std::fs::read_to_string(Path::new(SAVE_PATH)) else {
+            return;
+        };
+        match ron::from_str::<AccessibilityOptions>(&contents) {
+            Ok(loaded) => *options = loaded,
+            Err(err) => warn!("failed to parse {SAVE_PATH}: {err}"),
+        }
+    }
+
+    /// Writes the current toggles to `assets/accessibility.ron`. Called
+    /// right after any toggle changes, mirroring `high_scores::save_to_disk`'s
+    /// save-immediately timing.
+    pub fn save_to_disk(options: &AccessibilityOptions) {
+        match ron::to_string(options) {
+            Ok(serialized) => {
+                if let Err(err) = std::fs::write(SAVE_PATH, serialized) {
+                    warn!("failed to write {SAVE_PATH}: {err}");
+                }
+            }
+            Err(err) => warn!("failed to serialize accessibility options: {err}"),
+        }
+    }
+}
@@ -0,0 +1,388 @@
+//! Steamworks-style stats and achievements. Definitions are authored data
+//! ([`AchievementDefs`], loaded from `assets/achievements.ron` the same
+//! one-shot way `physics_config` hot-reloads its own config), progress is
+//! tallied off real gameplay events ([`AchievementProgress`]), and
+//! [`AchievementBackend`] is the seam a real platform SDK would implement
+//! instead of [`LocalBackend`] later — nothing in [`track_progress_and_unlock`]
+//! or the toast below it would need to change, only which type
+//! `AchievementsPlugin` boxes up as the active backend.
+//!
+//! Of the four conditions the ask names, two are wired to events that
+//! already exist in this crate and two aren't:
+//! - [`AchievementId::EnemiesStomped`] counts `combo::EnemyDefeated`.
+//! - [`AchievementId::GoldTime`] fires the moment a `challenge::ChallengeFinished`
+//!   carries `Some(Medal::Gold)`.
+//! - [`AchievementId::DeathlessClear`] is the fiddly one the ask calls out:
+//!   [`break_streak_on_death`] is the *only* system that ever clears
+//!   [`DeathlessStreak`], listening solely for `death::PlayerDied` — opening
+//!   `level_select`/`shop` or touching a `death::Checkpoint` fires neither,
+//!   so the streak survives both by construction, with nothing needed to
+//!   special-case them. [`track_deathless_clear`] both counts a clear
+//!   toward the achievement and resets the streak back to `true` for the
+//!   next attempt, on every `level_select::LevelCompleted` regardless of
+//!   whether that particular clear was deathless.
+//! - [`AchievementId::TotalCoins`] can't progress at all: there's no
+//!   `CollectedEvent` anywhere in this crate to count, the same gap
+//!   `objectives::ObjectiveKind::CollectCoins` and `results::LevelStats::coins_collected`
+//!   already document. The definition and its counter exist so the day a
+//!   pickup system lands, this only needs one more `EventReader`, not a
+//!   new module — until then nothing increments it and it can never unlock.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use crate::challenge::{ChallengeFinished, Medal};
+use crate::combo::EnemyDefeated;
+use crate::death::PlayerDied;
+use crate::level_reload::LevelReloadRequested;
+use crate::level_select::LevelCompleted;
+use crate::schedule::PlatformerSet;
+use crate::toast::ToastEvent;
+
+const TOAST_SECONDS: f32 = 3.0;
+
+#[derive(Reflect, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AchievementId {
+    TotalCoins,
+    EnemiesStomped,
+    DeathlessClear,
+    GoldTime,
+}
+
+/// One entry from `assets/achievements.ron`: what the achievement is
+/// called, its condition in player-facing words, and how high
+/// [`AchievementProgress`] has to count before it unlocks.
+#[derive(Reflect, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AchievementDef {
+    pub id: AchievementId,
+    pub name: String,
+    pub description: String,
+    pub target: u32,
+}
+
+/// Every achievement this game defines. Falls back to the four the ask
+/// describes if `assets/achievements.ron` is missing or fails to parse —
+/// mirrors `physics_config::PlayerPhysicsConfig`'s own default-then-overlay
+/// shape, not `prefab::PrefabRegistry`'s (there's exactly one file here,
+/// not a directory of them).
+#[derive(Resource, Reflect, Clone)]
+#[reflect(Resource)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AchievementDefs(pub Vec<AchievementDef>);
+
+impl Default for AchievementDefs {
+    fn default() -> Self {
+        Self(vec![
+            AchievementDef {
+                id: AchievementId::TotalCoins,
+                name: "Pocket Change".to_string(),
+                description: "Collect 100 coins in total.".to_string(),
+                target: 100,
+            },
+            AchievementDef {
+                id: AchievementId::EnemiesStomped,
+                name: "Boot Camp".to_string(),
+                description: "Stomp 20 enemies.".to_string(),
+                target: 20,
+            },
+            AchievementDef {
+                id: AchievementId::DeathlessClear,
+                name: "Untouchable".to_string(),
+                description: "Clear a level without dying.".to_string(),
+                target: 1,
+            },
+            AchievementDef {
+                id: AchievementId::GoldTime,
+                name: "Photo Finish".to_string(),
+                description: "Finish a challenge run with a gold medal.".to_string(),
+                target: 1,
+            },
+        ])
+    }
+}
+
+/// How far each [`AchievementId`] has progressed, persisted the same
+/// one-shot way `level_select::LevelCoinBest` is
+/// (`assets/achievement_progress.ron`, `serde` feature).
+#[derive(Resource, Reflect, Clone, Default)]
+#[reflect(Resource)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AchievementProgress(HashMap<AchievementId, u32>);
+
+impl AchievementProgress {
+    pub fn count(&self, id: AchievementId) -> u32 {
+        *self.0.get(&id).unwrap_or(&0)
+    }
+
+    /// Adds `by` to `id`'s count and persists immediately, mirroring
+    /// `LevelCoinBest::record_and_persist`'s own save-on-change shape.
+    fn increment(&mut self, id: AchievementId, by: u32) {
+        *self.0.entry(id).or_insert(0) += by;
+
+        #[cfg(feature = "serde")]
+        persistence::save_to_disk(self);
+    }
+}
+
+/// Whether the current level attempt has stayed deathless so far. Not
+/// persisted — this only needs to survive within one attempt, the same
+/// session-only lifetime as `challenge::ChallengeRun`.
+#[derive(Resource)]
+struct DeathlessStreak(bool);
+
+impl Default for DeathlessStreak {
+    fn default() -> Self {
+        Self(true)
+    }
+}
+
+/// The seam a platform SDK (Steamworks, a console's own achievement API)
+/// would implement in place of [`LocalBackend`] — everything above this
+/// trait only ever calls through it, never touches [`LocalBackend`]
+/// directly.
+pub trait AchievementBackend: Send + Sync {
+    fn is_unlocked(&self, id: AchievementId) -> bool;
+
+    /// Returns whether `id` was newly unlocked (`false` if it already was).
+    fn unlock(&mut self, id: AchievementId) -> bool;
+}
+
+/// The only [`AchievementBackend`] this crate ships: an in-memory unlocked
+/// set persisted to `assets/achievements_unlocked.ron`, loaded once at
+/// `Startup`.
+#[derive(Default)]
+struct LocalBackend {
+    unlocked: Vec<AchievementId>,
+}
+
+impl AchievementBackend for LocalBackend {
+    fn is_unlocked(&self, id: AchievementId) -> bool {
+        self.unlocked.contains(&id)
+    }
+
+    fn unlock(&mut self, id: AchievementId) -> bool {
+        if self.is_unlocked(id) {
+            return false;
+        }
+        self.unlocked.push(id);
+
+        #[cfg(feature = "serde")]
+        unlocked_persistence::save_to_disk(&self.unlocked);
+
+        true
+    }
+}
+
+/// Boxed so [`AchievementsPlugin`] can insert whichever [`AchievementBackend`]
+/// the platform wants without the systems below needing to know which one.
+#[derive(Resource)]
+pub struct AchievementBackendRes(Box<dyn AchievementBackend>);
+
+impl AchievementBackendRes {
+    /// `pub` (rather than the field itself) so `testing::TestWorld` can
+    /// read unlock state through the same [`AchievementBackend`] trait
+    /// every real caller goes through, without reaching into which backend
+    /// is actually installed.
+    pub fn is_unlocked(&self, id: AchievementId) -> bool {
+        self.0.is_unlocked(id)
+    }
+}
+
+/// Fired the moment [`track_progress_and_unlock`] unlocks an achievement,
+/// for the HUD toast.
+#[derive(Event)]
+pub struct AchievementUnlocked {
+    pub name: String,
+}
+
+pub struct AchievementsPlugin;
+
+impl Plugin for AchievementsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<AchievementDefs>()
+            .register_type::<AchievementProgress>()
+            .init_resource::<AchievementDefs>()
+            .init_resource::<AchievementProgress>()
+            .init_resource::<DeathlessStreak>()
+            .insert_resource(AchievementBackendRes(Box::new(LocalBackend::default())))
+            .add_event::<AchievementUnlocked>()
+            .add_systems(
+                Update,
+                (
+                    track_enemies_stomped,
+                    track_gold_time,
+                    break_streak_on_death,
+                    track_deathless_clear,
+                    reset_streak_on_reload,
+                    track_progress_and_unlock,
+                    fire_toast_on_unlock,
+                )
+                    .chain()
+                    .in_set(PlatformerSet::PostPhysics),
+            );
+
+        #[cfg(feature = "serde")]
+        app.add_systems(Startup, (persistence::load_from_disk, unlocked_persistence::load_from_disk));
+    }
+}
+
+fn track_enemies_stomped(mut defeated: EventReader<EnemyDefeated>, mut progress: ResMut<AchievementProgress>) {
+    let count = defeated.read().count() as u32;
+    if count > 0 {
+        progress.increment(AchievementId::EnemiesStomped, count);
+    }
+}
+
+fn track_gold_time(mut finished: EventReader<ChallengeFinished>, mut progress: ResMut<AchievementProgress>) {
+    for event in finished.read() {
+        if event.medal == Some(Medal::Gold) {
+            progress.increment(AchievementId::GoldTime, 1);
+        }
+    }
+}
+
+fn break_streak_on_death(mut died: EventReader<PlayerDied>, mut streak: ResMut<DeathlessStreak>) {
+    if died.read().next().is_some() {
+        streak.0 = false;
+    }
+}
+
+/// Counts a clear toward [`AchievementId::DeathlessClear`] only if
+/// [`DeathlessStreak`] is still `true`, then resets it for the next
+/// attempt either way — see this module's own doc comment on why nothing
+/// else needs to touch the streak.
+fn track_deathless_clear(
+    mut completed: EventReader<LevelCompleted>,
+    mut streak: ResMut<DeathlessStreak>,
+    mut progress: ResMut<AchievementProgress>,
+) {
+    for _ in completed.read() {
+        if streak.0 {
+            progress.increment(AchievementId::DeathlessClear, 1);
+        }
+        streak.0 = true;
+    }
+}
+
+fn reset_streak_on_reload(mut events: EventReader<LevelReloadRequested>, mut streak: ResMut<DeathlessStreak>) {
+    if events.read().next().is_some() {
+        streak.0 = true;
+    }
+}
+
+fn track_progress_and_unlock(
+    defs: Res<AchievementDefs>,
+    progress: Res<AchievementProgress>,
+    mut backend: ResMut<AchievementBackendRes>,
+    mut unlocked: EventWriter<AchievementUnlocked>,
+) {
+    for def in &defs.0 {
+        if backend.0.is_unlocked(def.id) {
+            continue;
+        }
+        if progress.count(def.id) >= def.target && backend.0.unlock(def.id) {
+            unlocked.send(AchievementUnlocked { name: def.name.clone() });
+        }
+    }
+}
+
+fn fire_toast_on_unlock(mut events: EventReader<AchievementUnlocked>, mut toasts: EventWriter<ToastEvent>) {
+    for event in events.read() {
+        toasts.send(ToastEvent {
+            text: format!("Achievement unlocked: {}", event.name),
+            icon: None,
+            duration: TOAST_SECONDS,
+        });
+    }
+}
+
+#[cfg(feature = "serde")]
+mod persistence {
+    use std::path::Path;
+
+    use bevy::prelude::*;
+
+    use super::{AchievementDefs, AchievementProgress};
+
+    /// One-shot overlay of `assets/achievements.ron` onto the built-in
+    /// [`AchievementDefs`] default, if present. Mirrors
+    /// `physics_config::hot_reload::load_from_disk`.
+    pub fn load_from_disk(mut defs: ResMut<AchievementDefs>, mut progress: ResMut<AchievementProgress>) {
+        let defs_path = Path::new("assets/achievements.ron");
+        if let Ok(contents) = std::fs::read_to_string(defs_path) {
+            match ron::from_str::<AchievementDefs>(&contents) {
+                Ok(loaded) => *defs = loaded,
+                Err(err) => warn!("failed to parse {}: {err}", defs_path.display()),
+            }
+        }
+
+        let progress_path = Path::new("assets/achievement_progress.ron");
+        let Ok(contents) = std::fs::read_to_string(progress_path) else {
+            return;
+        };
+        match ron::from_str::<AchievementProgress>(&contents) {
+            Ok(loaded) => *progress = loaded,
+            Err(err) => warn!("failed to parse {}: {err}", progress_path.display()),
+        }
+    }
+
+    /// Called by `AchievementProgress::increment` right after it changes,
+    /// mirroring `level_select::coin_persistence::save_to_disk`'s own
+    /// save-immediately shape.
+    pub fn save_to_disk(progress: &AchievementProgress) {
+        match ron::to_string(progress) {
+            Ok(serialized) => {
+                if let Err(err) = std::fs::write("assets/achievement_progress.ron", serialized) {
+                    warn!("failed to save assets/achievement_progress.ron: {err}");
+                }
+            }
+            Err(err) => warn!("failed to serialize achievement progress: {err}"),
+        }
+    }
+}
+
+/// Same one-shot load/save-immediately shape as [`persistence`], for the
+/// unlocked set [`LocalBackend`] owns instead — kept as its own module
+/// rather than folded into `persistence` since the two save to different
+/// files, mirroring `level_select::coin_persistence`'s own note on the
+/// same split.
+#[cfg(feature = "serde")]
+mod unlocked_persistence {
+    use bevy::prelude::*;
+
+    use super::{AchievementBackendRes, AchievementId};
+
+    const PATH: &str = "assets/achievements_unlocked.ron";
+
+    /// One-shot load of `assets/achievements_unlocked.ron` into whichever
+    /// backend `AchievementsPlugin` installed, unlocking each id straight
+    /// through [`super::AchievementBackend::unlock`] rather than reaching
+    /// into its private state directly.
+    pub fn load_from_disk(mut backend: ResMut<AchievementBackendRes>) {
+        let Ok(contents) = std::fs::read_to_string(PATH) else {
+            return;
+        };
+        match ron::from_str::<Vec<AchievementId>>(&contents) {
+            Ok(loaded) => {
+                for id in loaded {
+                    backend.0.unlock(id);
+                }
+            }
+            Err(err) => warn!("failed to parse {PATH}: {err}"),
+        }
+    }
+
+    pub fn save_to_disk(unlocked: &[AchievementId]) {
+        match ron::to_string(&unlocked) {
+            Ok(serialized) => {
+                if let Err(err) = std::fs::write(PATH, serialized) {
+                    warn!("failed to save {PATH}: {err}");
+                }
+            }
+            Err(err) => warn!("failed to serialize unlocked achievements: {err}"),
+        }
+    }
+}
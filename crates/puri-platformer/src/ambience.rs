@@ -0,0 +1,136 @@
+//! Per-level ambient tint/darkness, rendered as a fullscreen UI overlay
+//! rather than by recoloring every sprite — the same trick `death`'s fade
+//! overlay uses ([`DeathFade`](crate::death)), which also excludes the HUD
+//! from tinting for free: UI renders after the 2D world in its own pass,
+//! and [`spawn_overlay`] gives this overlay a lower `ZIndex` than every HUD
+//! element (the debug overlay text, the dialogue box, both left at the
+//! default `ZIndex::Global(0)`), so those still draw on top, untinted.
+//!
+//! `map::spawn_map_entities` is the closest thing this crate has to a level
+//! loader (see its own note on that); it sets [`LevelAmbience`] on every
+//! spawn, today always to the same neutral default since there's no
+//! per-level data format yet to read a tint from. Whichever system ends up
+//! loading real level files later just needs to insert a different
+//! [`LevelAmbience`]; [`apply_ambience`] doesn't change.
+//!
+//! [`apply_ambience`] never snaps the overlay straight to
+//! [`LevelAmbience`]'s target — it chases it with `math::exp_decay`, the
+//! same frame-rate-independent approach `player::CoyoteBuffer`'s neighbors
+//! and `grapple`'s swing already use, so a level transition fades rather
+//! than pops.
+//!
+//! [`DayNightCycle`], if present, drives [`LevelAmbience`] on its own from
+//! a sine wave over `period` seconds of wall-clock `Time`. There's no level
+//! timer or endless mode in this crate yet to drive it from instead, so
+//! wall-clock elapsed time stands in until one exists.
+
+use bevy::prelude::*;
+
+use crate::math::exp_decay;
+
+/// The tint/darkness a level wants right now, e.g. a cool blue overlay at
+/// `darkness: 0.4` for a cave. [`apply_ambience`] smoothly interpolates the
+/// displayed overlay toward this every frame rather than snapping to it.
+#[derive(Resource, Clone, Copy)]
+pub struct LevelAmbience {
+    pub tint: Color,
+    pub darkness: f32,
+}
+
+impl Default for LevelAmbience {
+    fn default() -> Self {
+        Self {
+            tint: Color::WHITE,
+            darkness: 0.0,
+        }
+    }
+}
+
+/// How fast the displayed overlay chases [`LevelAmbience`]'s target, in
+/// `exp_decay`'s rate units (higher settles faster).
+const TRANSITION_RATE: f32 = 3.0;
+
+/// Optional slow day/night cycle. While this resource is present,
+/// [`drive_day_night_cycle`] overwrites whatever [`LevelAmbience`] the
+/// level set, each frame, with one computed from a sine wave over `period`
+/// seconds — darkest at the trough, clear at the peak. Remove the resource
+/// to hand control back to the level.
+#[derive(Resource, Clone, Copy)]
+pub struct DayNightCycle {
+    pub period: f32,
+    pub max_darkness: f32,
+    pub night_tint: Color,
+}
+
+/// Tags the fullscreen overlay entity [`apply_ambience`] recolors every
+/// frame. `pub(crate)` so `testing::TestWorld` can query the overlay's
+/// displayed color directly.
+#[derive(Component)]
+pub(crate) struct AmbienceOverlay;
+
+/// The overlay's currently-displayed tint/darkness, lagging behind
+/// [`LevelAmbience`]'s target by [`apply_ambience`]'s interpolation.
+#[derive(Resource, Clone, Copy, Default)]
+struct DisplayedAmbience {
+    tint: Vec3,
+    darkness: f32,
+}
+
+pub struct AmbiencePlugin;
+
+impl Plugin for AmbiencePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LevelAmbience>()
+            .init_resource::<DisplayedAmbience>()
+            .add_systems(Startup, spawn_overlay)
+            .add_systems(Update, (drive_day_night_cycle, apply_ambience).chain());
+    }
+}
+
+fn spawn_overlay(mut commands: Commands) {
+    commands.spawn((
+        AmbienceOverlay,
+        NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                ..default()
+            },
+            background_color: Color::NONE.into(),
+            z_index: ZIndex::Global(-100),
+            ..default()
+        },
+    ));
+}
+
+fn drive_day_night_cycle(time: Res<Time>, cycle: Option<Res<DayNightCycle>>, mut ambience: ResMut<LevelAmbience>) {
+    let Some(cycle) = cycle else {
+        return;
+    };
+    if cycle.period <= 0.0 {
+        return;
+    }
+    // `phase` is 1.0 at noon, -1.0 at midnight; remap to [0, max_darkness].
+    let phase = (time.elapsed_seconds() / cycle.period * std::f32::consts::TAU).sin();
+    ambience.darkness = cycle.max_darkness * (1.0 - phase) / 2.0;
+    ambience.tint = cycle.night_tint;
+}
+
+fn apply_ambience(
+    time: Res<Time>,
+    ambience: Res<LevelAmbience>,
+    mut displayed: ResMut<DisplayedAmbience>,
+    mut overlay: Query<&mut BackgroundColor, With<AmbienceOverlay>>,
+) {
+    let dt = time.delta_seconds();
+    let target_tint = Vec3::new(ambience.tint.r(), ambience.tint.g(), ambience.tint.b());
+    displayed.tint.x = exp_decay(displayed.tint.x, target_tint.x, TRANSITION_RATE, dt);
+    displayed.tint.y = exp_decay(displayed.tint.y, target_tint.y, TRANSITION_RATE, dt);
+    displayed.tint.z = exp_decay(displayed.tint.z, target_tint.z, TRANSITION_RATE, dt);
+    displayed.darkness = exp_decay(displayed.darkness, ambience.darkness, TRANSITION_RATE, dt);
+
+    let Ok(mut background) = overlay.get_single_mut() else {
+        return;
+    };
+    background.0 = Color::rgba(displayed.tint.x, displayed.tint.y, displayed.tint.z, displayed.darkness);
+}
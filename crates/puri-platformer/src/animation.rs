@@ -0,0 +1,221 @@
+//! Sprite animation. The component types and frame-advance logic now live
+//! in `puri_core` so other cartridges get them for free (see `puri-core`'s
+//! `animation` module); this module keeps only what's platformer-specific:
+//! the [`Animations`] resource (named clips, and per-character atlases that
+//! place them), and slotting `puri_core`'s frame-advance logic into
+//! `PlatformerSet::AnimationSet` driven by the game's `GameTime` instead
+//! of `puri_core::AnimationPlugin`'s own unscaled `Res<Time>`.
+
+use std::collections::HashMap;
+
+use bevy::diagnostic::{Diagnostic, Diagnostics, RegisterDiagnostic};
+use bevy::prelude::*;
+
+pub use puri_core::animation::{
+    set_animation, AnimationBundle, AnimationFinished, FrameAdvance, FrameTime, SpriteAnimation,
+};
+use puri_core::animation::advance_frame;
+
+use crate::debug_overlay::ACTIVE_ANIMATIONS;
+use crate::time_scale::GameTime;
+
+/// A named clip's timing, independent of any atlas — `"walk"` plays back
+/// identically whether it's the player's sheet or an enemy's, even though
+/// each lays those frames out at a different offset.
+#[derive(Clone, Copy)]
+struct ClipDef {
+    len: usize,
+    fps: f32,
+    play_once: bool,
+}
+
+/// One character's sprite sheet, plus where each clip name it supports
+/// starts within it.
+struct AtlasEntry {
+    texture: Handle<Image>,
+    layout: Handle<TextureAtlasLayout>,
+    clip_offsets: HashMap<&'static str, usize>,
+}
+
+/// Component carrying the offset [`Animations::resolve`] returned for an
+/// entity's clip, so `animate_sprite` can translate between the
+/// clip-relative index `advance_frame` operates on and the atlas-absolute
+/// `TextureAtlas::index` it renders from. Absent (equivalent to `0`) on
+/// anything whose clip starts at the top of its atlas, which today is
+/// everything but the shared `"walk"` clip's second occupant.
+#[derive(Component, Default)]
+pub struct ClipOffset(pub usize);
+
+/// Shared sprite sheet handles and clip definitions for animated entities.
+/// Built from the `AssetServer` in normal play via [`FromWorld`], but the
+/// headless test harness inserts a [`Animations::stub`] before this is
+/// initialized so no real assets are ever loaded in tests.
+#[derive(Resource)]
+pub struct Animations {
+    clips: HashMap<&'static str, ClipDef>,
+    atlases: HashMap<&'static str, AtlasEntry>,
+}
+
+impl Animations {
+    /// Looks up `clip` on `character`'s atlas, returning everything a
+    /// spawner needs to play it: the sheet, a clip-relative
+    /// [`SpriteAnimation`] (so the same clip plays identically regardless
+    /// of where the atlas actually lays it out), and the offset to carry
+    /// alongside it as a [`ClipOffset`].
+    ///
+    /// Panics if `character` or `clip` isn't registered — both are
+    /// hard-coded call sites today (`player::PlayerBundle::new`,
+    /// `map::spawn_map_entities`'s NPC), not player-authored level data, so
+    /// a typo here is a programming error, same as an out-of-range `Vec`
+    /// index.
+    pub fn resolve(&self, character: &str, clip: &str) -> (Handle<Image>, Handle<TextureAtlasLayout>, SpriteAnimation, usize) {
+        let atlas = self
+            .atlases
+            .get(character)
+            .unwrap_or_else(|| panic!("no atlas registered for character {character:?}"));
+        let def = self
+            .clips
+            .get(clip)
+            .unwrap_or_else(|| panic!("no clip registered named {clip:?}"));
+        let offset = *atlas
+            .clip_offsets
+            .get(clip)
+            .unwrap_or_else(|| panic!("character {character:?} has no {clip:?} clip"));
+        let animation = SpriteAnimation {
+            first: 0,
+            last: def.len.saturating_sub(1),
+            play_once: def.play_once,
+            fps: def.fps,
+        };
+        (atlas.texture.clone(), atlas.layout.clone(), animation, offset)
+    }
+
+    /// Builds the same clip/atlas names [`FromWorld`] registers, with every
+    /// handle defaulted, so the headless test harness never touches the
+    /// `AssetServer`.
+    pub fn stub() -> Self {
+        let mut clips = HashMap::new();
+        clips.insert("walk", ClipDef { len: 4, fps: 12.0, play_once: false });
+
+        let mut atlases = HashMap::new();
+        atlases.insert(
+            "player",
+            AtlasEntry {
+                texture: Handle::default(),
+                layout: Handle::default(),
+                clip_offsets: HashMap::from([("walk", 0)]),
+            },
+        );
+        atlases.insert(
+            "npc",
+            AtlasEntry {
+                texture: Handle::default(),
+                layout: Handle::default(),
+                clip_offsets: HashMap::from([("walk", 4)]),
+            },
+        );
+
+        Self { clips, atlases }
+    }
+}
+
+impl FromWorld for Animations {
+    fn from_world(world: &mut World) -> Self {
+        let asset_server = world.resource::<AssetServer>().clone();
+
+        let mut clips = HashMap::new();
+        clips.insert("walk", ClipDef { len: 4, fps: 12.0, play_once: false });
+
+        let mut atlases = HashMap::new();
+        {
+            let mut layouts = world.resource_mut::<Assets<TextureAtlasLayout>>();
+            let player_layout = layouts.add(TextureAtlasLayout::from_grid(Vec2::new(24.0, 32.0), 4, 1, None, None));
+            atlases.insert(
+                "player",
+                AtlasEntry {
+                    texture: asset_server.load("sprites/player.png"),
+                    layout: player_layout,
+                    clip_offsets: HashMap::from([("walk", 0)]),
+                },
+            );
+
+            // The NPC's sheet lays its walk cycle out on the second row of
+            // a 4x2 grid (frames 4-7), sharing the exact same `ClipDef`
+            // timing as the player's despite the different sheet — proving
+            // `resolve` doesn't need a separate clip entry per character.
+            let npc_layout = layouts.add(TextureAtlasLayout::from_grid(Vec2::new(24.0, 32.0), 4, 2, None, None));
+            atlases.insert(
+                "npc",
+                AtlasEntry {
+                    texture: asset_server.load("sprites/npc.png"),
+                    layout: npc_layout,
+                    clip_offsets: HashMap::from([("walk", 4)]),
+                },
+            );
+        }
+
+        Self { clips, atlases }
+    }
+}
+
+pub struct AnimationPlugin;
+
+impl Plugin for AnimationPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<SpriteAnimation>()
+            .register_type::<FrameTime>()
+            .init_resource::<Animations>()
+            // Registered here (rather than only by `debug_overlay`) so
+            // `animate_sprite` can report it even when this plugin is added
+            // on its own, e.g. `benches/animation.rs`.
+            .register_diagnostic(Diagnostic::new(ACTIVE_ANIMATIONS))
+            .add_systems(
+                Update,
+                (clamp_stale_index, animate_sprite)
+                    .chain()
+                    .in_set(crate::schedule::PlatformerSet::AnimationSet),
+            );
+    }
+}
+
+/// Same reasoning as `puri_core::animation::clamp_stale_index`, but offset
+/// by [`ClipOffset`] first — without that, a `ClipOffset(4)` entity's
+/// already-valid `atlas.index` of `4` would get clamped straight back down
+/// to `0` the instant its `SpriteAnimation` is inserted.
+fn clamp_stale_index(
+    mut query: Query<(&SpriteAnimation, Option<&ClipOffset>, &mut TextureAtlas), Changed<SpriteAnimation>>,
+) {
+    for (animation, offset, mut atlas) in &mut query {
+        let offset = offset.map_or(0, |offset| offset.0);
+        atlas.index = atlas.index.clamp(offset + animation.first, offset + animation.last);
+    }
+}
+
+/// Only writes `atlas.index` (and so only marks `TextureAtlas` `Changed`,
+/// which is what drives extraction to the render world) on ticks where the
+/// frame actually advances (see `puri_core::animation::advance_frame`).
+fn animate_sprite(
+    mut commands: Commands,
+    time: GameTime,
+    mut diagnostics: Diagnostics,
+    mut query: Query<
+        (Entity, &SpriteAnimation, Option<&ClipOffset>, &mut FrameTime, &mut TextureAtlas),
+        Without<AnimationFinished>,
+    >,
+) {
+    let dt = time.delta_seconds();
+    // Every entity reaching this query still has a running clip —
+    // `Without<AnimationFinished>` already filtered out the ones that don't.
+    diagnostics.add_measurement(&ACTIVE_ANIMATIONS, || query.iter().count() as f64);
+    for (entity, animation, offset, mut frame_time, mut atlas) in &mut query {
+        let offset = offset.map_or(0, |offset| offset.0);
+        let relative_index = atlas.index - offset;
+        match advance_frame(animation, &mut frame_time, relative_index, dt) {
+            FrameAdvance::Unchanged => {}
+            FrameAdvance::Advanced(index) => atlas.index = offset + index,
+            FrameAdvance::Finished => {
+                commands.entity(entity).insert(AnimationFinished);
+            }
+        }
+    }
+}
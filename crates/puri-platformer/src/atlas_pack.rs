@@ -0,0 +1,147 @@
+//! Offline sprite-atlas packer: turns loose per-frame PNGs under
+//! `assets/raw/<character>/` into one packed atlas image plus a RON layout
+//! (frame name -> pixel rect), so a character's frames stop costing a
+//! draw call and a texture binding each. Frames are packed in
+//! sorted-by-filename order, so running the packer twice over the same
+//! inputs produces byte-identical output and adding one new frame only
+//! perturbs the diff from that frame's entry on.
+//!
+//! The actual `--pack-atlases` entry point is the `pack_atlases` bin
+//! target (`src/bin/pack_atlases.rs`) — this module is the part unit tests
+//! exercise directly and a real `AtlasLibrary` entry would call once one
+//! exists. Not wired into `animation::Animations` yet: there's no
+//! `assets/raw` character data in this repo to point it at, only the
+//! hand-built grids `Animations::from_world` constructs.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+
+use image::{GenericImage, RgbaImage};
+use serde::{Deserialize, Serialize};
+
+/// [`pack`] rejects any frame larger than this on either axis and wraps
+/// rows at this width — most GPUs guarantee at least this much. The
+/// packed atlas's height is not checked against it, so enough rows can
+/// still produce an atlas taller than this.
+pub const MAX_ATLAS_SIZE: u32 = 2048;
+
+/// Pixel rect of one packed frame within the atlas image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FrameRect {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// Frame name (the input PNG's file stem) -> its rect in the packed atlas.
+/// A `BTreeMap` rather than the packer's internal placement order, so a
+/// layout re-saved after a frame is added or removed only diffs the
+/// frames that actually changed rather than reshuffling every entry after
+/// them the way a `Vec` in placement order would.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AtlasLayout {
+    pub frames: BTreeMap<String, FrameRect>,
+}
+
+#[derive(Debug)]
+pub enum PackError {
+    Io(std::io::Error),
+    Decode(image::ImageError),
+    Ron(ron::Error),
+    FrameTooLarge { name: String, width: u32, height: u32 },
+}
+
+impl fmt::Display for PackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PackError::Io(err) => write!(f, "i/o error: {err}"),
+            PackError::Decode(err) => write!(f, "failed to decode frame: {err}"),
+            PackError::Ron(err) => write!(f, "failed to (de)serialize layout: {err}"),
+            PackError::FrameTooLarge { name, width, height } => write!(
+                f,
+                "frame {name:?} is {width}x{height}, larger than the {MAX_ATLAS_SIZE}x{MAX_ATLAS_SIZE} max atlas size"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PackError {}
+
+impl From<std::io::Error> for PackError {
+    fn from(err: std::io::Error) -> Self {
+        PackError::Io(err)
+    }
+}
+
+/// Packs every `*.png` directly inside `dir` into one atlas image, placed
+/// left-to-right in a row (sorted by filename, so the same input directory
+/// always packs the same way) and wrapping to a new row once the next
+/// frame would push it past [`MAX_ATLAS_SIZE`] — simple shelf packing, not
+/// the tightest possible, but deterministic and easy to read out of a
+/// diff.
+pub fn pack(dir: &Path) -> Result<(RgbaImage, AtlasLayout), PackError> {
+    let mut entries: Vec<_> = std::fs::read_dir(dir)?
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "png"))
+        .collect();
+    entries.sort_by_key(|entry| entry.file_name());
+
+    let mut frames = Vec::new();
+    for entry in &entries {
+        let path = entry.path();
+        let name = path.file_stem().unwrap_or_default().to_string_lossy().into_owned();
+        let image = image::open(&path).map_err(PackError::Decode)?.to_rgba8();
+        if image.width() > MAX_ATLAS_SIZE || image.height() > MAX_ATLAS_SIZE {
+            return Err(PackError::FrameTooLarge { name, width: image.width(), height: image.height() });
+        }
+        frames.push((name, image));
+    }
+
+    let mut layout = AtlasLayout::default();
+    let mut cursor_x = 0u32;
+    let mut cursor_y = 0u32;
+    let mut row_height = 0u32;
+    let mut atlas_width = 0u32;
+    let mut placements = Vec::with_capacity(frames.len());
+    for (name, frame) in &frames {
+        if cursor_x != 0 && cursor_x + frame.width() > MAX_ATLAS_SIZE {
+            cursor_x = 0;
+            cursor_y += row_height;
+            row_height = 0;
+        }
+        let rect = FrameRect { x: cursor_x, y: cursor_y, width: frame.width(), height: frame.height() };
+        layout.frames.insert(name.clone(), rect);
+        placements.push(rect);
+        cursor_x += frame.width();
+        row_height = row_height.max(frame.height());
+        atlas_width = atlas_width.max(cursor_x);
+    }
+    let atlas_height = cursor_y + row_height;
+
+    let mut atlas = RgbaImage::new(atlas_width, atlas_height);
+    for ((_, frame), rect) in frames.iter().zip(&placements) {
+        atlas
+            .copy_from(frame, rect.x, rect.y)
+            .expect("frame was placed inside the atlas bounds it just grew to fit");
+    }
+
+    Ok((atlas, layout))
+}
+
+/// Writes `atlas` as `<name>.png` and `layout` as `<name>.ron` inside
+/// `out_dir`.
+pub fn write_packed(out_dir: &Path, name: &str, atlas: &RgbaImage, layout: &AtlasLayout) -> Result<(), PackError> {
+    std::fs::create_dir_all(out_dir)?;
+    atlas.save(out_dir.join(format!("{name}.png"))).map_err(PackError::Decode)?;
+    let serialized = ron::to_string(layout).map_err(PackError::Ron)?;
+    std::fs::write(out_dir.join(format!("{name}.ron")), serialized)?;
+    Ok(())
+}
+
+/// Reads a layout previously written by [`write_packed`].
+pub fn load_layout(path: &Path) -> Result<AtlasLayout, PackError> {
+    let contents = std::fs::read_to_string(path)?;
+    ron::from_str(&contents).map_err(|err| PackError::Ron(err.code))
+}
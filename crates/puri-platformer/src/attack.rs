@@ -0,0 +1,364 @@
+//! Melee attack: [`ATTACK_KEY`] starts a 3-phase swing (windup, active,
+//! recovery), each phase a real [`AttackPhase`] tracked on [`Attacking`],
+//! with a real damaging [`MeleeHitbox`] child spawned only for the active
+//! phase's duration and a real second swing if the key is pressed again
+//! during recovery.
+//!
+//! What's real: horizontal input is genuinely locked (see
+//! [`lock_movement_during_attack`]) for as long as `Attacking::phase` is
+//! `Windup` or `Active` — a press during that window does nothing, which is
+//! what "committed" means here, rather than a cooldown that merely ignores
+//! extra presses. A press during `Recovery` sets [`Attacking::buffered_next`],
+//! consumed by [`advance_attack`] the instant recovery ends to chain
+//! straight into a second `Windup` (`combo_step` 1) instead of clearing
+//! `Attacking`. An attack started airborne cancels its remaining windup or
+//! active frames into `Recovery` early the instant `player::Grounded`
+//! reports landing (see [`cancel_air_attack_on_landing`]) — "cancels into
+//! fall recovery" is read as "recovery starts right away", since there's no
+//! separate landing-recovery animation state anywhere in this crate to
+//! distinguish it from the swing's own recovery.
+//!
+//! What doesn't have anywhere real to attach to: there's no dedicated attack
+//! spritesheet, or in fact any gameplay code that has ever swapped
+//! `SpriteAnimation` clips at all — `PlayerBundle::new`'s single four-frame
+//! walk cycle is the only clip that's ever existed, and `set_animation`
+//! (`puri_core::animation`) had no caller outside `testing::TestWorld`'s
+//! test-only helper before this module. [`advance_attack`] is that first
+//! real caller: it swaps to a held single frame from the same four-frame
+//! sheet per phase ([`WINDUP_CLIP`]/[`ACTIVE_CLIP`]/[`RECOVERY_CLIP`]) and
+//! restores the default walk clip once `Attacking` is removed, so the state
+//! machine and the animation system genuinely compose the way the request
+//! asked, even though there's no dedicated attack art to show for it yet.
+//! "Per-frame hitboxes" is implemented as "a hitbox for the active phase's
+//! frame", the only phase with one held frame today; a clip with several
+//! active frames, each with its own hitbox shape, needs real per-frame
+//! authoring data this crate's `SpriteAnimation` doesn't carry.
+//!
+//! Damage is a direct [`Health`] write, not `fall_damage::DamageEvent` —
+//! that event is read only by `death::apply_damage`'s `With<Player>` query,
+//! so it can only ever hurt the player. [`Hurtbox`] plus a manual AABB
+//! overlap check (the same inline approach `combo::stomp_enemies` uses,
+//! since `collision::overlap_boxes` only exists under the non-`rapier`
+//! backend) is this crate's first system that ever damages something other
+//! than the player — `boss::Boss`'s own `Health(20)` has never had anything
+//! decrement it either. `map::spawn_map_entities`'s new training dummy is
+//! the only [`Hurtbox`] in the demo level today.
+
+use bevy::prelude::*;
+
+use crate::animation::{Animations, FrameTime, SpriteAnimation};
+use crate::collision::{HitBox, Sensor};
+use crate::player::{Facing, Grounded, Health, Player, Velocity};
+use crate::schedule::PlatformerSet;
+use crate::time_scale::GameTime;
+
+pub const ATTACK_KEY: KeyCode = KeyCode::KeyX;
+
+const WINDUP_SECONDS: f32 = 0.15;
+const ACTIVE_SECONDS: f32 = 0.12;
+const RECOVERY_SECONDS: f32 = 0.25;
+const ATTACK_DAMAGE: u32 = 1;
+const HITBOX_SIZE: Vec2 = Vec2::new(28.0, 20.0);
+/// How far in front of the player's center the active-phase hitbox sits,
+/// mirroring `shield::SHIELD_OFFSET`.
+const HITBOX_OFFSET: f32 = 20.0;
+
+/// Held single-frame "clips" swapped in per phase; see this module's own
+/// doc comment on why there's no dedicated attack sheet to draw real
+/// multi-frame clips from.
+const WINDUP_CLIP: SpriteAnimation = SpriteAnimation { first: 1, last: 1, play_once: false, fps: 12.0 };
+const ACTIVE_CLIP: SpriteAnimation = SpriteAnimation { first: 2, last: 2, play_once: false, fps: 12.0 };
+const RECOVERY_CLIP: SpriteAnimation = SpriteAnimation { first: 3, last: 3, play_once: false, fps: 12.0 };
+/// Matches `PlayerBundle::new`'s spawn-time clip, restored once `Attacking`
+/// is removed.
+const DEFAULT_CLIP: SpriteAnimation = SpriteAnimation { first: 0, last: 3, play_once: false, fps: 12.0 };
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttackPhase {
+    Windup,
+    Active,
+    Recovery,
+}
+
+/// A player mid-swing. `combo_step` is 0 for the first swing of a pair and 1
+/// for the buffered follow-up; `was_grounded` records whether this swing
+/// started on the ground, so [`cancel_air_attack_on_landing`] only cuts short the
+/// swings that actually started in the air.
+#[derive(Component)]
+pub struct Attacking {
+    pub phase: AttackPhase,
+    timer: Timer,
+    combo_step: u8,
+    buffered_next: bool,
+    was_grounded: bool,
+}
+
+impl Attacking {
+    fn windup(combo_step: u8, was_grounded: bool) -> Self {
+        Self {
+            phase: AttackPhase::Windup,
+            timer: Timer::from_seconds(WINDUP_SECONDS, TimerMode::Once),
+            combo_step,
+            buffered_next: false,
+            was_grounded,
+        }
+    }
+}
+
+/// The active phase's damaging hitbox, spawned as a child of the attacking
+/// player and despawned the instant its phase ends. `already_hit` stops one
+/// swing from damaging the same target on every frame its box overlaps it.
+#[derive(Component, Default)]
+struct MeleeHitbox {
+    already_hit: Vec<Entity>,
+}
+
+/// Anything a melee swing can damage — `map::spawn_map_entities`'s training
+/// dummy is the only one in the demo level.
+#[derive(Component)]
+pub struct Hurtbox;
+
+pub struct AttackPlugin;
+
+impl Plugin for AttackPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (start_or_buffer_attack, lock_movement_during_attack)
+                .chain()
+                .in_set(PlatformerSet::Intent)
+                // Locks out `player::dash`'s own write to `Velocity.x`, the
+                // same ordering reason `shield::hold_still_while_shielding`
+                // is `.after(crate::player::dash)`.
+                .after(crate::player::dash)
+                .run_if(crate::dialogue::playing_and_not_talking)
+                .run_if(crate::death::player_not_dying)
+                .run_if(crate::cutscene::not_playing),
+        )
+        .add_systems(
+            Update,
+            (cancel_air_attack_on_landing, advance_attack, apply_melee_damage)
+                .chain()
+                .in_set(PlatformerSet::PostPhysics)
+                .run_if(crate::death::player_not_dying),
+        );
+        #[cfg(feature = "serde")]
+        app.add_systems(Update, record_attack_usage);
+    }
+}
+
+/// Starts a swing on [`ATTACK_KEY`] if the player isn't already mid-swing;
+/// buffers a follow-up if it's pressed again during `Recovery`. A press
+/// during `Windup`/`Active` is silently dropped — that's the "committed"
+/// part, not a special case this system needs to handle.
+fn start_or_buffer_attack(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut commands: Commands,
+    mut player: Query<(Entity, Option<&mut Attacking>, Option<&Grounded>), With<Player>>,
+) {
+    if !keys.just_pressed(ATTACK_KEY) {
+        return;
+    }
+    let Ok((entity, attacking, grounded)) = player.get_single_mut() else {
+        return;
+    };
+    match attacking {
+        None => {
+            commands.entity(entity).insert(Attacking::windup(0, grounded.is_some()));
+        }
+        Some(mut attacking) => {
+            if attacking.phase == AttackPhase::Recovery {
+                attacking.buffered_next = true;
+            }
+        }
+    }
+}
+
+/// Zeroes horizontal velocity for a player committed to `Windup`/`Active`,
+/// overriding whatever `player_input`/`player::dash` set it to this frame —
+/// mirrors `shield::hold_still_while_shielding`'s same-set override.
+fn lock_movement_during_attack(mut player: Query<(&mut Velocity, &Attacking), With<Player>>) {
+    let Ok((mut velocity, attacking)) = player.get_single_mut() else {
+        return;
+    };
+    if attacking.phase != AttackPhase::Recovery {
+        velocity.0.x = 0.0;
+    }
+}
+
+/// If a swing that started airborne is still `Windup`/`Active` the instant
+/// `Grounded` is (re)added, cuts it straight to `Recovery` instead of
+/// letting it finish — see this module's own doc comment on reading
+/// "cancels into fall recovery" this way.
+fn cancel_air_attack_on_landing(
+    mut commands: Commands,
+    mut player: Query<(&mut Attacking, Option<&Children>), (With<Player>, Added<Grounded>)>,
+    hitboxes: Query<Entity, With<MeleeHitbox>>,
+) {
+    for (mut attacking, children) in &mut player {
+        if attacking.was_grounded || attacking.phase == AttackPhase::Recovery {
+            continue;
+        }
+        despawn_melee_hitbox(&mut commands, children, &hitboxes);
+        attacking.phase = AttackPhase::Recovery;
+        attacking.timer = Timer::from_seconds(RECOVERY_SECONDS, TimerMode::Once);
+    }
+}
+
+/// Despawns the attacking player's `MeleeHitbox` child, if it currently has
+/// one, without touching any other children it may have (e.g. a held
+/// `Shield`).
+fn despawn_melee_hitbox(
+    commands: &mut Commands,
+    children: Option<&Children>,
+    hitboxes: &Query<Entity, With<MeleeHitbox>>,
+) {
+    for &child in children.into_iter().flatten() {
+        if hitboxes.get(child).is_ok() {
+            commands.entity(child).despawn_recursive();
+        }
+    }
+}
+
+/// The phase state machine: ticks `Attacking::timer`, and on finishing
+/// transitions `Windup` -> `Active` (spawning the swing's [`MeleeHitbox`]),
+/// `Active` -> `Recovery` (despawning it), and `Recovery` -> either a second
+/// `Windup` (if [`Attacking::buffered_next`]) or removing `Attacking`
+/// entirely. Also does the phase's animation clip swap — see this module's
+/// own doc comment on this being real gameplay code's first call into
+/// `set_animation`.
+fn advance_attack(
+    time: GameTime,
+    mut commands: Commands,
+    layouts: Res<Animations>,
+    mut player: Query<
+        (
+            Entity,
+            &mut Attacking,
+            &Facing,
+            Option<&Children>,
+            &mut SpriteAnimation,
+            &mut FrameTime,
+            &mut TextureAtlas,
+        ),
+        With<Player>,
+    >,
+    hitboxes: Query<Entity, With<MeleeHitbox>>,
+) {
+    let Ok((entity, mut attacking, facing, children, mut animation, mut frame_time, mut atlas)) =
+        player.get_single_mut()
+    else {
+        return;
+    };
+    attacking.timer.tick(time.delta());
+    if !attacking.timer.finished() {
+        return;
+    }
+    // Every held "clip" below is a single frame off the player's own walk
+    // sheet, not a registered `Animations` clip — see this module's own doc
+    // comment on why there's no dedicated attack sheet. `resolve`'s layout
+    // handle is the same regardless of which clip name it's asked for, so
+    // any registered player clip works here.
+    let (_, layout, _, _) = layouts.resolve("player", "walk");
+
+    match attacking.phase {
+        AttackPhase::Windup => {
+            attacking.phase = AttackPhase::Active;
+            attacking.timer = Timer::from_seconds(ACTIVE_SECONDS, TimerMode::Once);
+            crate::animation::set_animation(&mut animation, &mut frame_time, &mut atlas, layout.clone(), ACTIVE_CLIP);
+            let offset = match facing {
+                Facing::Right => HITBOX_OFFSET,
+                Facing::Left => -HITBOX_OFFSET,
+            };
+            commands.entity(entity).with_children(|parent| {
+                parent.spawn((
+                    MeleeHitbox::default(),
+                    Sensor,
+                    HitBox { size: HITBOX_SIZE },
+                    SpriteBundle {
+                        sprite: Sprite {
+                            color: Color::rgba(1.0, 0.3, 0.2, 0.6),
+                            custom_size: Some(HITBOX_SIZE),
+                            ..default()
+                        },
+                        transform: Transform::from_xyz(offset, 0.0, 0.1),
+                        ..default()
+                    },
+                ));
+            });
+        }
+        AttackPhase::Active => {
+            despawn_melee_hitbox(&mut commands, children, &hitboxes);
+            attacking.phase = AttackPhase::Recovery;
+            attacking.timer = Timer::from_seconds(RECOVERY_SECONDS, TimerMode::Once);
+            crate::animation::set_animation(&mut animation, &mut frame_time, &mut atlas, layout.clone(), RECOVERY_CLIP);
+        }
+        AttackPhase::Recovery => {
+            if attacking.buffered_next && attacking.combo_step == 0 {
+                let was_grounded = attacking.was_grounded;
+                *attacking = Attacking::windup(1, was_grounded);
+                crate::animation::set_animation(&mut animation, &mut frame_time, &mut atlas, layout.clone(), WINDUP_CLIP);
+            } else {
+                commands.entity(entity).remove::<Attacking>();
+                crate::animation::set_animation(&mut animation, &mut frame_time, &mut atlas, layout.clone(), DEFAULT_CLIP);
+            }
+        }
+    }
+}
+
+/// Damages every [`Hurtbox`] the active phase's [`MeleeHitbox`] overlaps,
+/// once per target per swing — mirrors `combo::stomp_enemies`'s own inline
+/// AABB overlap check. Works out the hitbox's world position from the
+/// player's own `Transform` and `Facing` rather than reading the child's
+/// `GlobalTransform`, which wouldn't be caught up to a hitbox spawned this
+/// same frame until the next `PostUpdate` transform propagation runs.
+fn apply_melee_damage(
+    player: Query<(&Transform, &Facing, Option<&Children>), With<Player>>,
+    mut hitboxes: Query<&mut MeleeHitbox>,
+    mut targets: Query<(Entity, &Transform, &HitBox, &mut Health), With<Hurtbox>>,
+) {
+    let Ok((player_transform, facing, children)) = player.get_single() else {
+        return;
+    };
+    let Some(&hitbox_entity) = children.into_iter().flatten().find(|&&child| hitboxes.get(child).is_ok()) else {
+        return;
+    };
+    let offset = match facing {
+        Facing::Right => HITBOX_OFFSET,
+        Facing::Left => -HITBOX_OFFSET,
+    };
+    let hitbox_pos = player_transform.translation.truncate() + Vec2::new(offset, 0.0);
+
+    for (target_entity, target_transform, target_hitbox, mut health) in &mut targets {
+        let touch_distance = (HITBOX_SIZE + target_hitbox.size) / 2.0;
+        let overlapping = (hitbox_pos - target_transform.translation.truncate()).abs().cmplt(touch_distance).all();
+        if !overlapping {
+            continue;
+        }
+        let Ok(mut hit) = hitboxes.get_mut(hitbox_entity) else {
+            continue;
+        };
+        if hit.already_hit.contains(&target_entity) {
+            continue;
+        }
+        hit.already_hit.push(target_entity);
+        health.0 = health.0.saturating_sub(ATTACK_DAMAGE);
+    }
+}
+
+/// Mirrors `speedrun_overlay`'s own citation: an independent consumer of
+/// [`Attacking`] rather than [`start_or_buffer_attack`] needing to know
+/// session recording exists.
+#[cfg(feature = "serde")]
+fn record_attack_usage(
+    started: Query<(), Added<Attacking>>,
+    mut session: EventWriter<crate::session_recorder::RecordSessionEvent>,
+) {
+    for () in &started {
+        session.send(crate::session_recorder::RecordSessionEvent(
+            crate::session_recorder::SessionEvent::AbilityUsed {
+                ability: "attack".to_string(),
+            },
+        ));
+    }
+}
@@ -0,0 +1,172 @@
+//! Positional sound effects, built on `bevy_audio`'s `SpatialAudioSink`
+//! rather than this crate hand-rolling stereo panning — `bevy = "0.13"`
+//! (no `default-features = false` in `Cargo.toml`) already pulls in
+//! `bevy_audio`, it's just never been used anywhere in this crate before
+//! now (`shop`'s own doc comment covers the "no audio anywhere" gap this
+//! module closes for anything that opts into it).
+//!
+//! [`PlaySfx`] and [`PlaySfxAt`] mirror `toast::ToastEvent`'s
+//! fire-and-forget convention: any gameplay system sends one,
+//! [`play_one_shots`] spawns a short-lived, `PlaybackMode::Despawn` audio
+//! entity for it. `PlaySfxAt`'s emitter position feeds `bevy_audio`'s
+//! spatial engine (via `PlaybackSettings::with_spatial`) the same
+//! position the camera's own `SpatialListener` (added onto the `Camera2d`
+//! entity in `camera::spawn_camera`) uses, so pan falls out of the two
+//! positions bevy already knows how to turn into stereo balance. Distance
+//! attenuation and the max-radius cutoff are this module's own, since
+//! bevy's spatial sink has no notion of "too far to hear" — beyond
+//! [`MAX_AUDIBLE_DISTANCE`] a `PlaySfxAt` is dropped before it ever spawns
+//! a sink.
+//!
+//! [`LoopingEmitter`] is the waterfall/torch case: tag an entity with one
+//! and an [`SfxId`], and [`start_looping_emitters`] gives it a
+//! `PlaybackMode::Loop` spatial sink once. [`sync_looping_emitters`] then
+//! re-points that same, already-playing `SpatialAudioSink` at the
+//! emitter's and camera's current positions every frame with
+//! `set_emitter_position`/`set_listener_position`, and re-clamps its
+//! volume by distance — never recreating the sink, so a loop never pops
+//! or restarts as the camera pans past it.
+//!
+//! No `assets/sfx` directory exists in this crate yet, so [`SfxLibrary`]
+//! starts empty; [`SfxLibrary::register`] is there for whatever adds the
+//! first sound.
+
+use std::collections::HashMap;
+
+use bevy::audio::{AudioBundle, AudioSinkPlayback, AudioSource, PlaybackSettings, SpatialAudioSink, Volume};
+use bevy::prelude::*;
+
+use crate::camera::spatial_listener_gap;
+
+/// Beyond this world-space distance from the camera a sound is inaudible;
+/// `PlaySfxAt` skips spawning entirely and a `LoopingEmitter`'s sink is
+/// held at zero volume rather than silenced by any bevy-side falloff.
+pub const MAX_AUDIBLE_DISTANCE: f32 = 800.0;
+
+/// A named sound effect, resolved through `SfxLibrary` — the string-keyed
+/// convention `script::LevelId` already uses for "human-authored name,
+/// not a save-stable index", since a sound effect has the same "unique
+/// enough for one level/one call site" lifetime a `LevelId` does.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct SfxId(pub String);
+
+/// Every loaded sound effect, keyed by [`SfxId`]. Empty by default — see
+/// this module's own doc comment on why nothing populates it yet.
+#[derive(Resource, Default)]
+pub struct SfxLibrary {
+    sounds: HashMap<SfxId, Handle<AudioSource>>,
+}
+
+impl SfxLibrary {
+    pub fn register(&mut self, id: SfxId, handle: Handle<AudioSource>) {
+        self.sounds.insert(id, handle);
+    }
+
+    /// `pub(crate)` so `music::spawn_stems` can resolve stem `SfxId`s
+    /// through the same registry one-shot/looping sfx use, instead of
+    /// `music` keeping a second, redundant name-to-handle map.
+    pub(crate) fn get(&self, id: &SfxId) -> Option<Handle<AudioSource>> {
+        self.sounds.get(id).cloned()
+    }
+}
+
+/// Plays `0` at full volume, non-positional — a UI click, a menu
+/// confirm, anything with no world position to pan from.
+#[derive(Event, Clone)]
+pub struct PlaySfx(pub SfxId);
+
+/// Plays `0` positioned at world-space `1`, panned and attenuated against
+/// the camera. Dropped silently if `1` is beyond `MAX_AUDIBLE_DISTANCE`
+/// or `0` isn't registered in `SfxLibrary`.
+#[derive(Event, Clone)]
+pub struct PlaySfxAt(pub SfxId, pub Vec2);
+
+/// Tags an entity (its `Transform` is the emitter position) as a
+/// continuous sound source — a waterfall, a torch. `start_looping_emitters`
+/// gives it a sink once; nothing in this crate spawns one yet (there's no
+/// waterfall/torch prefab), so this is the same "the hookup exists, no
+/// caller uses it today" gap `player::PartSlot`'s own doc comment leaves
+/// for its state-machine caller.
+#[derive(Component, Clone)]
+pub struct LoopingEmitter(pub SfxId);
+
+pub struct AudioPlugin;
+
+impl Plugin for AudioPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SfxLibrary>()
+            .add_event::<PlaySfx>()
+            .add_event::<PlaySfxAt>()
+            .add_systems(Update, (play_one_shots, play_positional_one_shots, start_looping_emitters, sync_looping_emitters));
+    }
+}
+
+/// `distance` in world units; `1.0` at zero distance, linearly down to
+/// `0.0` at `MAX_AUDIBLE_DISTANCE` and beyond.
+fn attenuate(distance: f32) -> f32 {
+    (1.0 - distance / MAX_AUDIBLE_DISTANCE).clamp(0.0, 1.0)
+}
+
+fn play_one_shots(mut commands: Commands, mut events: EventReader<PlaySfx>, library: Res<SfxLibrary>) {
+    for PlaySfx(id) in events.read() {
+        let Some(source) = library.get(id) else { continue };
+        commands.spawn(AudioBundle {
+            source,
+            settings: PlaybackSettings::DESPAWN,
+        });
+    }
+}
+
+fn play_positional_one_shots(
+    mut commands: Commands,
+    mut events: EventReader<PlaySfxAt>,
+    library: Res<SfxLibrary>,
+    camera: Query<&Transform, With<Camera2d>>,
+) {
+    let Ok(camera_transform) = camera.get_single() else {
+        return;
+    };
+    for PlaySfxAt(id, position) in events.read() {
+        let Some(source) = library.get(id) else { continue };
+        let distance = camera_transform.translation.truncate().distance(*position);
+        if distance > MAX_AUDIBLE_DISTANCE {
+            continue;
+        }
+        commands.spawn((
+            AudioBundle {
+                source,
+                settings: PlaybackSettings::DESPAWN.with_spatial(true).with_volume(Volume::new(attenuate(distance))),
+            },
+            TransformBundle::from_transform(Transform::from_translation(position.extend(0.0))),
+        ));
+    }
+}
+
+fn start_looping_emitters(
+    mut commands: Commands,
+    library: Res<SfxLibrary>,
+    emitters: Query<(Entity, &LoopingEmitter), Added<LoopingEmitter>>,
+) {
+    for (entity, emitter) in &emitters {
+        let Some(source) = library.get(&emitter.0) else { continue };
+        commands.entity(entity).insert(AudioBundle {
+            source,
+            settings: PlaybackSettings::LOOP.with_spatial(true),
+        });
+    }
+}
+
+fn sync_looping_emitters(
+    camera: Query<&Transform, With<Camera2d>>,
+    emitters: Query<(&Transform, &SpatialAudioSink), (With<LoopingEmitter>, Without<Camera2d>)>,
+) {
+    let Ok(camera_transform) = camera.get_single() else {
+        return;
+    };
+    for (transform, sink) in &emitters {
+        let distance = camera_transform.translation.truncate().distance(transform.translation.truncate());
+        sink.set_volume(attenuate(distance));
+        sink.set_emitter_position(transform.translation);
+        sink.set_listener_position(*camera_transform, spatial_listener_gap());
+    }
+}
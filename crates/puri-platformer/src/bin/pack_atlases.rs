@@ -0,0 +1,40 @@
+//! Atlas packing's entry point — a standalone binary rather than a
+//! `--pack-atlases` flag on this crate's `main` (its `LaunchOptions` has no
+//! such flag), so it can be scripted from CI or a Makefile without
+//! spinning up a window: `cargo run -p puri_platformer --bin
+//! pack_atlases --features serde -- assets/raw/player`.
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use puri_platformer::atlas_pack;
+
+fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1);
+    let Some(raw_dir) = args.next() else {
+        eprintln!("usage: pack_atlases <assets/raw/CHARACTER>");
+        return ExitCode::FAILURE;
+    };
+    let raw_dir = PathBuf::from(raw_dir);
+    let name = raw_dir.file_name().and_then(|name| name.to_str()).unwrap_or("atlas").to_string();
+
+    let (atlas, layout) = match atlas_pack::pack(&raw_dir) {
+        Ok(packed) => packed,
+        Err(err) => {
+            eprintln!("pack_atlases: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    // Packed output sits alongside the raw frames' parent, e.g.
+    // `assets/raw/player/*.png` -> `assets/raw/player.png`/`.ron`, so the
+    // loose frames and their packed form are easy to tell apart on disk.
+    let out_dir = raw_dir.parent().unwrap_or(&raw_dir);
+    if let Err(err) = atlas_pack::write_packed(out_dir, &name, &atlas, &layout) {
+        eprintln!("pack_atlases: {err}");
+        return ExitCode::FAILURE;
+    }
+
+    println!("packed {} frame(s) from {} into {name}.png/{name}.ron", layout.frames.len(), raw_dir.display());
+    ExitCode::SUCCESS
+}
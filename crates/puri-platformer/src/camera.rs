@@ -0,0 +1,78 @@
+use bevy::audio::SpatialListener;
+use bevy::prelude::*;
+
+use crate::camera_rail::rail_inactive;
+use crate::math::exp_decay;
+use crate::photo::photo_mode_inactive;
+use crate::player::Player;
+use crate::schedule::PlatformerSet;
+
+const FOLLOW_SPEED: f32 = 6.0;
+/// Distance between the `SpatialListener`'s two "ears", in world units.
+/// `audio::sync_looping_emitters` re-derives the same value each frame
+/// (through [`spatial_listener_gap`]) rather than this crate having two
+/// numbers that need to stay in sync.
+const SPATIAL_LISTENER_GAP: f32 = 32.0;
+
+/// The gap `spawn_camera` gives the `Camera2d`'s `SpatialListener` — see
+/// that constant's own doc comment.
+pub fn spatial_listener_gap() -> f32 {
+    SPATIAL_LISTENER_GAP
+}
+
+/// Attach to the player for one frame to make the camera jump straight to
+/// its position instead of lerping, e.g. right after a teleport.
+#[derive(Component)]
+pub struct CameraSnap;
+
+pub struct CameraPlugin;
+
+impl Plugin for CameraPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, spawn_camera).add_systems(
+            // Paused while a cutscene has its own hand on the camera, while
+            // `photo::pan_zoom_camera` has taken it over instead, or while a
+            // `camera_rail::CameraRail` is scrolling the camera through an
+            // autoscroll section, so none of the four ever fight over
+            // `Camera2d`'s `Transform` the same frame.
+            PostUpdate,
+            camera_follow
+                .in_set(PlatformerSet::CameraSet)
+                .run_if(crate::cutscene::not_playing)
+                .run_if(photo_mode_inactive)
+                .run_if(rail_inactive),
+        );
+    }
+}
+
+fn spawn_camera(mut commands: Commands) {
+    // `SpatialListener` is what `audio::play_positional_one_shots`/
+    // `sync_looping_emitters` pan and attenuate every positional sound
+    // against — see that module's own doc comment.
+    commands.spawn((Camera2dBundle::default(), SpatialListener::new(SPATIAL_LISTENER_GAP)));
+}
+
+fn camera_follow(
+    mut commands: Commands,
+    time: Res<Time>,
+    player: Query<(Entity, &Transform, Option<&CameraSnap>), (With<Player>, Without<Camera2d>)>,
+    mut camera: Query<&mut Transform, With<Camera2d>>,
+) {
+    let Ok((entity, player_transform, snap)) = player.get_single() else {
+        return;
+    };
+    let Ok(mut camera_transform) = camera.get_single_mut() else {
+        return;
+    };
+    let target = player_transform.translation.truncate();
+
+    if snap.is_some() {
+        camera_transform.translation.x = target.x;
+        camera_transform.translation.y = target.y;
+        commands.entity(entity).remove::<CameraSnap>();
+    } else {
+        let dt = time.delta_seconds();
+        camera_transform.translation.x = exp_decay(camera_transform.translation.x, target.x, FOLLOW_SPEED, dt);
+        camera_transform.translation.y = exp_decay(camera_transform.translation.y, target.y, FOLLOW_SPEED, dt);
+    }
+}
@@ -0,0 +1,287 @@
+//! In-world camera rails for autoscroller sections: a [`CameraRail`] whose
+//! `Sensor`/`HitBox` doubles as its own activation zone, spanning the whole
+//! corridor it scrolls through rather than a small trigger strip at the
+//! entrance — see [`RailActive`]'s own doc comment on why that shape is
+//! what makes "exiting the rail's end hands control back" fall out for
+//! free instead of needing a separate "reached the end" case.
+//!
+//! [`advance_rail_camera`] writes `Camera2d`'s `Transform` directly while a
+//! rail is active, the same precedent `cutscene::cutscene_runner` already
+//! set for another module taking the camera over from `camera::camera_follow`
+//! (paused via `run_if(rail_inactive)` the same way it's already paused via
+//! `run_if(cutscene::not_playing)`); handing back is just letting
+//! [`RailActive`] disappear, since `camera_follow`'s own `exp_decay` chase
+//! re-syncs from wherever the camera was left with no pop, the same reason
+//! `photo`'s own doc comment gives for not needing to reset the camera on
+//! exit either.
+//!
+//! [`constrain_to_rail`] doesn't reuse `map::advance_along_path`/`PathMode`
+//! for the rail's own travel — that traversal loops or ping-pongs forever
+//! and would need a new terminating mode (touching `MovingPlatform` and
+//! `enemy_ai::patrol`'s shared traversal to add it) just to stop at an end.
+//! [`position_along`] below is a self-contained forward-only stepping
+//! function instead, so `map.rs`'s existing consumers of `Path` are
+//! untouched.
+//!
+//! Getting crushed against a wall is a straight [`fall_damage::DamageEvent`]
+//! with [`player::DamageKind::Crush`] — the exact sourceless variant
+//! `player.rs`'s own doc comment already set aside for "a future one
+//! doesn't need a breaking enum change to plug in". [`CrushedEvent`] is
+//! sent at the same call site (`boss::advance_phase` firing both
+//! `GroundPoundLanded` and `RumbleEvent::slam()` together is the precedent),
+//! so a future VFX/audio reader has a specific event to key off instead of
+//! guessing at a `DamageEvent`'s `kind`.
+
+use bevy::prelude::*;
+
+use crate::collision::{GroundedBody, HitBox, Sensor};
+use crate::fall_damage::DamageEvent;
+use crate::map::Path;
+use crate::player::{DamageKind, Player};
+use crate::render_layer::{z_for, GameLayer};
+use crate::schedule::PlatformerSet;
+use crate::time_scale::GameTime;
+
+/// `path.points[0]` is where the camera starts; `speed` is world units per
+/// second the camera advances along `path` once active. The entity also
+/// carries the `Sensor`+`HitBox` that both spawn functions below attach —
+/// see this module's own doc comment on why that box is the whole
+/// activation zone, not just an entrance strip.
+#[derive(Component)]
+pub struct CameraRail {
+    pub path: Path,
+    pub speed: f32,
+}
+
+/// Present on a [`CameraRail`] while the player overlaps its zone;
+/// `traveled` is world units advanced along `path` so far, clamped at the
+/// path's own length. Removed the instant the player stops overlapping —
+/// whether that's backing out the way they came, or simply having walked
+/// far enough past the (now-stopped) trailing edge once the rail's
+/// traversal is finished — so there's no separate "reached the end" flag
+/// to keep in sync with this one.
+/// `pub(crate)` rather than private: `camera::CameraPlugin` needs to name it
+/// (through `rail_inactive`'s `Query<(), With<RailActive>>` parameter) to
+/// gate `camera_follow` on the same rail state this module tracks.
+#[derive(Component)]
+pub(crate) struct RailActive {
+    traveled: f32,
+}
+
+/// Fired the instant [`constrain_to_rail`] finds the player's pushed-forward
+/// position already overlapping a solid — see this module's own doc
+/// comment on why this is sent alongside, not instead of, the
+/// `fall_damage::DamageEvent` that actually kills the player.
+#[derive(Event)]
+pub struct CrushedEvent {
+    pub position: Vec2,
+}
+
+pub struct CameraRailPlugin;
+
+impl Plugin for CameraRailPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<CrushedEvent>()
+            .add_systems(
+                Update,
+                (activate_rail, advance_rail_camera)
+                    .chain()
+                    .in_set(PlatformerSet::Intent)
+                    // Never really simultaneous (no level cues a cutscene
+                    // during an autoscroll segment), but both write
+                    // `Camera2d`'s `Transform` in the same set, so the
+                    // ambiguity checker still needs a static order between
+                    // them.
+                    .after(crate::cutscene::cutscene_runner),
+            )
+            .add_systems(
+                Update,
+                constrain_to_rail
+                    .in_set(PlatformerSet::PostPhysics)
+                    .after(crate::level_reload::reposition_after_reload),
+            );
+    }
+}
+
+/// `camera::CameraPlugin`'s own `run_if(rail_inactive)` gate for
+/// `camera_follow`, mirroring the `run_if(cutscene::not_playing)` it
+/// already has. `pub(crate)` rather than `pub` since its `Query`'s
+/// `RailActive` filter is itself only `pub(crate)`.
+pub(crate) fn rail_inactive(active: Query<(), With<RailActive>>) -> bool {
+    active.is_empty()
+}
+
+fn activate_rail(
+    mut commands: Commands,
+    player: Query<(&Transform, &HitBox), With<Player>>,
+    rails: Query<(Entity, &Transform, &HitBox, Option<&RailActive>), With<CameraRail>>,
+) {
+    let Ok((player_transform, player_box)) = player.get_single() else {
+        return;
+    };
+    for (entity, rail_transform, rail_box, active) in &rails {
+        let overlapping = player_box.intersects(
+            player_transform.translation.truncate(),
+            rail_box,
+            rail_transform.translation.truncate(),
+        );
+        match (overlapping, active) {
+            (true, None) => {
+                commands.entity(entity).insert(RailActive { traveled: 0.0 });
+            }
+            (false, Some(_)) => {
+                commands.entity(entity).remove::<RailActive>();
+            }
+            _ => {}
+        }
+    }
+}
+
+/// There's only ever one rail active at a time (a player can only overlap
+/// one autoscroll corridor at once in practice), the same "unambiguous
+/// without its own id" reasoning `boss.rs`'s own doc comment gives for why
+/// a live `Boss` doesn't need one either.
+fn advance_rail_camera(
+    time: GameTime,
+    mut rails: Query<(&CameraRail, &mut RailActive)>,
+    mut camera: Query<&mut Transform, With<Camera2d>>,
+) {
+    let Some((rail, mut active)) = rails.iter_mut().next() else {
+        return;
+    };
+    let Ok(mut camera_transform) = camera.get_single_mut() else {
+        return;
+    };
+    let total_length = path_length(&rail.path);
+    active.traveled = (active.traveled + rail.speed * time.delta_seconds()).min(total_length);
+    let position = position_along(&rail.path, active.traveled);
+    camera_transform.translation.x = position.x;
+    camera_transform.translation.y = position.y;
+}
+
+/// Keeps the player's `Transform` inside the current visible rect once a
+/// rail is active — the trailing edge catching up to them reads as "pushed
+/// forward", the leading edge reads as "can't outrun the camera", and
+/// clamping into `HitBox` on a solid the same frame reads as "crushed
+/// against a wall", all from the one clamp. Post-hoc, after
+/// `collision::aabb::move_bodies` and `level_reload::reposition_after_reload`
+/// have already had their say for the frame, the same
+/// `crumbling`/`enemy_ai::bounce_on_landing` "correct `Transform` directly
+/// rather than fight for `Velocity`" idiom — so a knockback arc or a
+/// moving-platform carry from earlier in the frame just gets clamped back
+/// on-screen instead of being fought over.
+fn constrain_to_rail(
+    active_rails: Query<(), With<RailActive>>,
+    camera: Query<(&Transform, &OrthographicProjection), With<Camera2d>>,
+    mut player: Query<(&mut Transform, &HitBox), (With<Player>, Without<Camera2d>)>,
+    solids: Query<(&Transform, &HitBox), (Without<GroundedBody>, Without<Sensor>)>,
+    mut crushed_events: EventWriter<CrushedEvent>,
+    mut damage_events: EventWriter<DamageEvent>,
+) {
+    if active_rails.is_empty() {
+        return;
+    }
+    let Ok((camera_transform, projection)) = camera.get_single() else {
+        return;
+    };
+    let Ok((mut player_transform, player_box)) = player.get_single_mut() else {
+        return;
+    };
+
+    let camera_pos = camera_transform.translation.truncate();
+    let visible_min = camera_pos + projection.area.min;
+    let visible_max = camera_pos + projection.area.max;
+    let half_size = player_box.size / 2.0;
+    let clamped_min = visible_min + half_size;
+    let clamped_max = visible_max - half_size;
+
+    let position = player_transform.translation.truncate();
+    let clamped = position.clamp(clamped_min.min(clamped_max), clamped_min.max(clamped_max));
+    if clamped == position {
+        return;
+    }
+    player_transform.translation.x = clamped.x;
+    player_transform.translation.y = clamped.y;
+
+    let crushed = solids
+        .iter()
+        .any(|(solid_transform, solid_box)| player_box.intersects(clamped, solid_box, solid_transform.translation.truncate()));
+    if crushed {
+        crushed_events.send(CrushedEvent { position: clamped });
+        damage_events.send(DamageEvent {
+            amount: u32::MAX,
+            kind: DamageKind::Crush,
+        });
+    }
+}
+
+fn path_length(path: &Path) -> f32 {
+    path.points.windows(2).map(|segment| segment[0].distance(segment[1])).sum()
+}
+
+/// Forward-only position `distance` along `path` from its first point,
+/// clamped at the last point once `distance` exceeds the path's own
+/// length — see this module's own doc comment on why this doesn't reuse
+/// `map::advance_along_path`.
+fn position_along(path: &Path, distance: f32) -> Vec2 {
+    let Some(&first) = path.points.first() else {
+        return Vec2::ZERO;
+    };
+    if path.points.len() < 2 {
+        return first;
+    }
+    let mut remaining = distance;
+    for segment in path.points.windows(2) {
+        let (from, to) = (segment[0], segment[1]);
+        let segment_len = from.distance(to);
+        if remaining <= segment_len {
+            let t = if segment_len > 0.0 { remaining / segment_len } else { 0.0 };
+            return from.lerp(to, t);
+        }
+        remaining -= segment_len;
+    }
+    *path.points.last().unwrap()
+}
+
+/// A short rightward autoscroll corridor: a wall waits at the far end, so
+/// dawdling at the trailing edge all the way to it demonstrates the crush
+/// death rather than just the push.
+pub fn spawn_demo_rail(commands: &mut Commands) {
+    // `mode` is irrelevant here — `position_along` steps forward-only and
+    // never consults it — but `Path` doesn't have a constructor, so it's
+    // just left at its `Default`.
+    let path = Path {
+        points: vec![Vec2::new(1000.0, -80.0), Vec2::new(1300.0, -80.0)],
+        ..default()
+    };
+    let zone_size = Vec2::new(360.0, 200.0);
+    let zone_center = Vec2::new(1150.0, -80.0);
+
+    commands.spawn((
+        crate::map::LevelEntity,
+        CameraRail { path, speed: 60.0 },
+        Sensor,
+        HitBox { size: zone_size },
+        SpriteBundle {
+            transform: Transform::from_translation(zone_center.extend(z_for(GameLayer::Entities, zone_center.y, true))),
+            ..default()
+        },
+    ));
+
+    let wall_pos = Vec2::new(1340.0, -80.0);
+    commands.spawn((
+        crate::map::LevelEntity,
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::rgb(0.3, 0.3, 0.35),
+                custom_size: Some(Vec2::new(32.0, 200.0)),
+                ..default()
+            },
+            transform: Transform::from_translation(wall_pos.extend(z_for(GameLayer::TilesBack, wall_pos.y, false))),
+            ..default()
+        },
+        HitBox {
+            size: Vec2::new(32.0, 200.0),
+        },
+    ));
+}
@@ -0,0 +1,348 @@
+//! Timed challenge mode: race the level clock against gold/silver/bronze
+//! [`MedalThresholds`], with a translucent ghost of the best run racing
+//! alongside.
+//!
+//! There's no level-select screen, results screen, or `SaveData` disk
+//! persistence anywhere in this crate to hang the rest of the original ask
+//! on — `high_scores`'s own doc comment already covers the same gap for a
+//! running score. [`ChallengeBest`] keeps the best time/medal/ghost in
+//! memory for the session; a future cartridge with an actual save system
+//! would be where that gets written to disk. [`start_challenge`] is the
+//! entry point a level-select screen would call; nothing calls it today,
+//! the same way `prefab::Coin` is authored data with no spawner wired up
+//! yet — see that module's own note on the pattern.
+//!
+//! "Checkpoints are disabled" is [`checkpoints_enabled`] gating
+//! `death::touch_checkpoints` off; with `LastCheckpoint` pinned at the
+//! run's start position and never advancing, `death`'s existing
+//! fade-out/hold/fade-in respawn (unchanged — a death always goes through
+//! it; the R-press restart below is separate) always lands the player
+//! back at the run's start, which is what "restarts immediately" means
+//! here: no checkpoint progress survives a death, not a shorter animation.
+//!
+//! This crate has no `FixedUpdate` schedule (every gameplay system, timers
+//! included, runs once per variable-length `Update` frame — see
+//! `schedule`'s own doc comment), so "sampled per fixed tick" is
+//! implemented as "sampled once per `Update` frame", the same granularity
+//! every other per-frame timer in this crate already uses.
+//!
+//! [`start_challenge`] takes a `snapshot::snapshot` of the run's starting
+//! state the moment it starts, kept as [`RetrySnapshot`]; while a run is
+//! active, [`press_r_to_retry`] restores it the instant R is pressed —
+//! `snapshot`'s own doc comment covers exactly what "restores" does and
+//! doesn't cover. This is a second, faster restart path alongside
+//! `death`'s fade-out/respawn one, added here rather than in `death` since
+//! it's specific to challenge mode.
+//!
+//! [`finish_on_exit`] reports no medal at all once
+//! `difficulty_assist::DifficultyAssist::accepted` is set, no matter how
+//! fast the run — see that module's own doc comment for why an
+//! assist-aided run shouldn't count toward one.
+
+use std::collections::VecDeque;
+
+use bevy::ecs::system::Command;
+use bevy::prelude::*;
+use bevy::time::Stopwatch;
+
+use crate::collision::HitBox;
+use crate::death::{LastCheckpoint, PlayerDied};
+use crate::difficulty_assist::DifficultyAssist;
+use crate::objectives::ExitGate;
+use crate::player::Player;
+use crate::render_layer::{z_for, GameLayer};
+use crate::schedule::PlatformerSet;
+use crate::snapshot::{self, GameSnapshot};
+use crate::time_scale::GameTime;
+
+/// Caps the in-progress recorder's position track, so an unusually long
+/// (or stuck) run's memory use stays bounded instead of growing for as
+/// long as the attempt lasts.
+const MAX_TRACK_SAMPLES: usize = 36_000;
+const GHOST_SIZE: Vec2 = Vec2::new(24.0, 32.0);
+const GHOST_COLOR: Color = Color::rgba(1.0, 1.0, 1.0, 0.35);
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum Medal {
+    Bronze,
+    Silver,
+    Gold,
+}
+
+/// Per-level medal cutoffs, in seconds. A finish at or under `gold` earns
+/// gold, at or under `silver` earns silver, at or under `bronze` earns
+/// bronze; slower than `bronze` earns none. A level sets its own instance
+/// via `insert_resource` the same way a cartridge overrides
+/// `PlayerPhysicsConfig` rather than editing this module's defaults.
+#[derive(Resource, Clone, Copy)]
+pub struct MedalThresholds {
+    pub gold: f32,
+    pub silver: f32,
+    pub bronze: f32,
+}
+
+impl MedalThresholds {
+    fn medal_for(&self, seconds: f32) -> Option<Medal> {
+        if seconds <= self.gold {
+            Some(Medal::Gold)
+        } else if seconds <= self.silver {
+            Some(Medal::Silver)
+        } else if seconds <= self.bronze {
+            Some(Medal::Bronze)
+        } else {
+            None
+        }
+    }
+}
+
+impl Default for MedalThresholds {
+    fn default() -> Self {
+        Self {
+            gold: 30.0,
+            silver: 45.0,
+            bronze: 60.0,
+        }
+    }
+}
+
+/// The in-progress attempt. Its presence as a resource *is* "a challenge
+/// run is active" — nothing else needs a separate on/off flag, the same
+/// way `Sensor`'s absence means solid elsewhere in this crate.
+#[derive(Resource)]
+pub struct ChallengeRun {
+    elapsed: Stopwatch,
+    track: VecDeque<Vec2>,
+    ghost_frame: usize,
+}
+
+impl ChallengeRun {
+    /// `pub(crate)` so `testing::TestWorld::start_challenge` can build one
+    /// directly instead of round-tripping through a `Commands` a test
+    /// harness has no ordinary way to obtain outside a real system.
+    pub(crate) fn new() -> Self {
+        Self {
+            elapsed: Stopwatch::new(),
+            track: VecDeque::new(),
+            ghost_frame: 0,
+        }
+    }
+
+    pub fn elapsed_seconds(&self) -> f32 {
+        self.elapsed.elapsed_secs()
+    }
+}
+
+/// The best completed run this session: its medal, time, and the position
+/// track [`animate_ghost`] races the next attempt against. Nothing
+/// persists this across a relaunch — see this module's own doc comment.
+#[derive(Resource, Default)]
+pub struct ChallengeBest {
+    pub medal: Option<Medal>,
+    pub time: Option<f32>,
+    ghost: Vec<Vec2>,
+}
+
+/// Fired the moment a run reaches the level's `ExitGate`. `delta_vs_best`
+/// is negative when this run beat the previous best; `None` on the very
+/// first completed run, when there's nothing yet to compare against.
+#[derive(Event)]
+pub struct ChallengeFinished {
+    pub medal: Option<Medal>,
+    pub time: f32,
+    pub delta_vs_best: Option<f32>,
+}
+
+/// `pub(crate)` (rather than private) purely so `testing::TestWorld` can
+/// count how many ghost sprites are on screen, instead of exposing raw ECS
+/// access to tests.
+#[derive(Component)]
+pub(crate) struct GhostSprite;
+
+/// The level-start state [`press_r_to_retry`] restores. `pub(crate)` so
+/// `testing::TestWorld::start_challenge` can insert one directly, the same
+/// reason [`ChallengeRun::new`] is `pub(crate)`.
+#[derive(Resource)]
+pub(crate) struct RetrySnapshot(pub(crate) GameSnapshot);
+
+/// A [`Command`] rather than an ordinary system parameter so capturing the
+/// snapshot doesn't need a `Query`/`Res` over "every whitelisted component
+/// on every entity" — `snapshot`'s own doc comment covers what it walks.
+/// Queued from [`start_challenge`], the same way `level_load`'s deferred
+/// spawns are queued through `Commands` rather than run inline.
+struct CaptureRetrySnapshot;
+
+impl Command for CaptureRetrySnapshot {
+    fn apply(self, world: &mut World) {
+        let snap = snapshot::snapshot(world);
+        world.insert_resource(RetrySnapshot(snap));
+    }
+}
+
+/// The other half of [`CaptureRetrySnapshot`]: restores the level-start
+/// snapshot and puts it straight back, so a run can be retried more than
+/// once without re-capturing it each time.
+struct RestoreRetrySnapshot;
+
+impl Command for RestoreRetrySnapshot {
+    fn apply(self, world: &mut World) {
+        let Some(RetrySnapshot(snap)) = world.remove_resource::<RetrySnapshot>() else {
+            return;
+        };
+        snapshot::restore(world, &snap);
+        world.insert_resource(RetrySnapshot(snap));
+    }
+}
+
+pub struct ChallengePlugin;
+
+impl Plugin for ChallengePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MedalThresholds>()
+            .init_resource::<ChallengeBest>()
+            .add_event::<ChallengeFinished>()
+            .add_systems(
+                Update,
+                (tick_run, record_track, restart_on_death, press_r_to_retry, finish_on_exit, animate_ghost)
+                    .chain()
+                    .in_set(PlatformerSet::PostPhysics),
+            );
+    }
+}
+
+/// Starts (or restarts) a challenge run at the player's current position,
+/// and queues a deferred [`CaptureRetrySnapshot`] so [`press_r_to_retry`]
+/// has something to restore to from the very first frame the run is active.
+/// Pins `checkpoint` there so a mid-run death has nowhere else to send the
+/// player back to — see this module's own doc comment on why that's what
+/// "restarts immediately" means here.
+pub fn start_challenge(commands: &mut Commands, checkpoint: &mut LastCheckpoint, player_position: Vec2) {
+    checkpoint.id = None;
+    checkpoint.position = player_position;
+    commands.insert_resource(ChallengeRun::new());
+    commands.add(CaptureRetrySnapshot);
+}
+
+/// Instantly restores the level-start [`RetrySnapshot`] on an R press while
+/// a run is active — no fade, no despawn/respawn, just the whitelisted
+/// component and `WorldFlags` values `snapshot::restore` writes back in
+/// place. `checkpoints_enabled` already keeps `LastCheckpoint` pinned at
+/// the run's start for this same reason, so nothing else needs resetting.
+fn press_r_to_retry(mut commands: Commands, keys: Res<ButtonInput<KeyCode>>, run: Option<Res<ChallengeRun>>) {
+    if run.is_none() || !keys.just_pressed(KeyCode::KeyR) {
+        return;
+    }
+    commands.add(RestoreRetrySnapshot);
+}
+
+/// Run condition gating `death::touch_checkpoints` off for a run's
+/// duration.
+pub fn checkpoints_enabled(run: Option<Res<ChallengeRun>>) -> bool {
+    run.is_none()
+}
+
+fn tick_run(time: GameTime, run: Option<ResMut<ChallengeRun>>) {
+    let Some(mut run) = run else { return };
+    run.elapsed.tick(time.delta());
+}
+
+fn record_track(run: Option<ResMut<ChallengeRun>>, player: Query<&Transform, With<Player>>) {
+    let Some(mut run) = run else { return };
+    let Ok(transform) = player.get_single() else { return };
+    if run.track.len() == MAX_TRACK_SAMPLES {
+        run.track.pop_front();
+    }
+    run.track.push_back(transform.translation.truncate());
+}
+
+fn restart_on_death(mut died: EventReader<PlayerDied>, run: Option<ResMut<ChallengeRun>>) {
+    if died.read().next().is_none() {
+        return;
+    }
+    let Some(mut run) = run else { return };
+    run.elapsed = Stopwatch::new();
+    run.track.clear();
+    run.ghost_frame = 0;
+}
+
+/// A run ends the moment the player reaches an unlocked `ExitGate` — its
+/// `HitBox` gone is what "unlocked" means in `objectives`, so that's the
+/// same check used here rather than importing its private "already
+/// processed" marker.
+fn finish_on_exit(
+    mut commands: Commands,
+    run: Option<ResMut<ChallengeRun>>,
+    thresholds: Res<MedalThresholds>,
+    assist: Res<DifficultyAssist>,
+    mut best: ResMut<ChallengeBest>,
+    mut finished: EventWriter<ChallengeFinished>,
+    player: Query<&Transform, With<Player>>,
+    gates: Query<&Transform, (With<ExitGate>, Without<HitBox>)>,
+) {
+    let Some(run) = run else { return };
+    let Ok(player_transform) = player.get_single() else { return };
+    let reached_exit = gates
+        .iter()
+        .any(|gate| (player_transform.translation.truncate() - gate.translation.truncate()).length() < 24.0);
+    if !reached_exit {
+        return;
+    }
+
+    let time = run.elapsed_seconds();
+    // An assist-aided run still finishes and still records a time, just
+    // never a medal — `difficulty_assist`'s own doc comment covers why.
+    let medal = if assist.accepted { None } else { thresholds.medal_for(time) };
+    let delta_vs_best = best.time.map(|previous| time - previous);
+    let is_new_best = match best.time {
+        Some(previous) => time < previous,
+        None => true,
+    };
+    if is_new_best {
+        best.time = Some(time);
+        best.medal = medal;
+        best.ghost = run.track.iter().copied().collect();
+    }
+    finished.send(ChallengeFinished { medal, time, delta_vs_best });
+    commands.remove_resource::<ChallengeRun>();
+}
+
+/// Keeps a translucent [`GhostSprite`] racing along `ChallengeBest`'s
+/// recorded track for as long as a run is active, one recorded position
+/// per frame the same way `record_track` laid them down.
+fn animate_ghost(
+    mut commands: Commands,
+    run: Option<ResMut<ChallengeRun>>,
+    best: Res<ChallengeBest>,
+    mut ghost: Query<&mut Transform, With<GhostSprite>>,
+    existing: Query<Entity, With<GhostSprite>>,
+) {
+    let Some(mut run) = run else {
+        for entity in &existing {
+            commands.entity(entity).despawn_recursive();
+        }
+        return;
+    };
+    if best.ghost.is_empty() {
+        return;
+    }
+    if existing.is_empty() {
+        commands.spawn((
+            GhostSprite,
+            SpriteBundle {
+                sprite: Sprite {
+                    color: GHOST_COLOR,
+                    custom_size: Some(GHOST_SIZE),
+                    ..default()
+                },
+                ..default()
+            },
+        ));
+        return;
+    }
+
+    let index = run.ghost_frame.min(best.ghost.len() - 1);
+    run.ghost_frame += 1;
+    if let Ok(mut transform) = ghost.get_single_mut() {
+        let position = best.ghost[index];
+        transform.translation = position.extend(z_for(GameLayer::Entities, position.y, false));
+    }
+}
@@ -0,0 +1,136 @@
+use bevy::diagnostic::{Diagnostic, Diagnostics, RegisterDiagnostic};
+use bevy::prelude::*;
+use tracing::info_span;
+
+use super::{CollisionEvent, GroundedBody, HitBox, MovementMode, PlatformTop, Sensor, SurfaceMaterial};
+use crate::debug_overlay::COLLISION_PAIRS_TESTED;
+use crate::player::{Grounded, Jump, Velocity};
+use crate::schedule::PlatformerSet;
+use crate::spatial_grid::SpatialGrid;
+use crate::time_scale::GameTime;
+
+/// Bigger than the biggest solid in the demo map (the 800-wide floor still
+/// only touches ~7 cells), small enough that a normal-sized moving body
+/// only ever shares a cell with the handful of solids actually near it.
+const GRID_CELL_SIZE: f32 = 128.0;
+
+pub struct CollisionPlugin;
+
+impl Plugin for CollisionPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<HitBox>()
+            .register_type::<Sensor>()
+            .register_type::<GroundedBody>()
+            .register_type::<MovementMode>()
+            .register_type::<SurfaceMaterial>()
+            .register_type::<PlatformTop>()
+            .add_event::<CollisionEvent>()
+            // Registered here (rather than only by `debug_overlay`) so
+            // `move_bodies` can report it even if this plugin is ever added
+            // on its own.
+            .register_diagnostic(Diagnostic::new(COLLISION_PAIRS_TESTED))
+            .add_systems(Update, move_bodies.in_set(PlatformerSet::CollisionResolve));
+    }
+}
+
+/// Moves every `GroundedBody` one axis at a time, resolving overlap against
+/// solids on each axis independently so a wall stops horizontal movement
+/// without affecting the vertical (grounded) resolution, and vice versa.
+/// Iterating rather than `single_mut()`-ing the player means a despawn
+/// mid-frame (death, level transition) just skips that entity instead of
+/// panicking, and any future enemy with the same marker gets ground
+/// detection for free.
+///
+/// Solids are bucketed into a [`SpatialGrid`] rebuilt fresh each frame
+/// before the move loop, so a body only narrow-phases against nearby
+/// solids instead of every solid in the level (see `benches/collision.rs`
+/// for the brute-force-vs-grid comparison this replaced).
+///
+/// Takes [`GameTime`] rather than `Res<Time>` — `player::player_input`
+/// and `player::apply_gravity` already stop changing `Velocity` once
+/// `TimeScale` hits `0.0`, but a leftover nonzero `Velocity` would still
+/// have kept sliding a body across `Transform` every frame at the
+/// unscaled rate if this system didn't honor the same scale.
+fn move_bodies(
+    time: GameTime,
+    mut commands: Commands,
+    mut diagnostics: Diagnostics,
+    mut bodies: Query<(Entity, &mut Transform, &Velocity, &HitBox, Option<&MovementMode>), With<GroundedBody>>,
+    solids: Query<(Entity, &Transform, &HitBox), (Without<GroundedBody>, Without<Sensor>)>,
+) {
+    let _span = info_span!("move_bodies").entered();
+    let collider_entities: Vec<Entity> = solids.iter().map(|(entity, ..)| entity).collect();
+    let colliders: Vec<(Vec2, Vec2)> = solids
+        .iter()
+        .map(|(_, transform, hitbox)| (transform.translation.truncate(), hitbox.size))
+        .collect();
+    let grid = SpatialGrid::build(&colliders, GRID_CELL_SIZE);
+    let mut pairs_tested: u32 = 0;
+
+    for (entity, mut transform, velocity, hitbox, mode) in &mut bodies {
+        transform.translation.x += velocity.0.x * time.delta_seconds();
+        for index in grid.query(transform.translation.truncate(), hitbox.size) {
+            let (solid_pos, solid_size) = colliders[index];
+            pairs_tested += 1;
+            let solid = HitBox { size: solid_size };
+            if let Some(contact) = hitbox.intersect(transform.translation.truncate(), &solid, solid_pos) {
+                if velocity.0.x > 0.0 {
+                    transform.translation.x += contact.depth.x;
+                } else if velocity.0.x < 0.0 {
+                    transform.translation.x += contact.depth.x;
+                }
+            }
+        }
+
+        transform.translation.y += velocity.0.y * time.delta_seconds();
+        // A body straddling two solids (e.g. the seam between adjacent
+        // platforms) gets a grounding contact against each; keep the one
+        // with the larger horizontal overlap as the entity it's "standing
+        // on", since that's the one more of the body's footprint rests on.
+        let mut standing_on: Option<(Entity, f32)> = None;
+        for index in grid.query(transform.translation.truncate(), hitbox.size) {
+            let (solid_pos, solid_size) = colliders[index];
+            pairs_tested += 1;
+            let solid = HitBox { size: solid_size };
+            if let Some(contact) = hitbox.intersect(transform.translation.truncate(), &solid, solid_pos) {
+                if velocity.0.y <= 0.0 {
+                    transform.translation.y += contact.depth.y;
+                    let overlap_x = contact.depth.x.abs();
+                    if !standing_on.is_some_and(|(_, best)| best >= overlap_x) {
+                        standing_on = Some((collider_entities[index], overlap_x));
+                    }
+                } else {
+                    transform.translation.y += contact.depth.y;
+                }
+            }
+        }
+
+        // A `MovementMode::Flying` body still resolved out of solids above
+        // (a diving `prefab::Flyer` stops at the floor), it just never
+        // reads as "standing on" it — see `MovementMode`'s own doc comment.
+        if mode != Some(&MovementMode::Flying) {
+            if let Some((support, _)) = standing_on {
+                commands.entity(entity).insert(Grounded(Some(support))).remove::<Jump>();
+            } else {
+                commands.entity(entity).remove::<Grounded>();
+            }
+        }
+    }
+
+    diagnostics.add_measurement(&COLLISION_PAIRS_TESTED, || pairs_tested as f64);
+}
+
+/// Overlap on both axes if the two boxes intersect, `None` otherwise. Each
+/// component is signed to point from box `b` toward box `a`, so adding it
+/// to `pos_a` pushes `a` out along that axis. Takes plain `Vec2`s rather than `Transform`/`HitBox` so it's
+/// usable from benchmarks without spinning up an `App`.
+pub fn overlap_boxes(pos_a: Vec2, size_a: Vec2, pos_b: Vec2, size_b: Vec2) -> Option<Vec2> {
+    let delta = pos_a - pos_b;
+    let overlap_x = (size_a.x + size_b.x) / 2.0 - delta.x.abs();
+    let overlap_y = (size_a.y + size_b.y) / 2.0 - delta.y.abs();
+    if overlap_x > 0.0 && overlap_y > 0.0 {
+        Some(Vec2::new(overlap_x * delta.x.signum(), overlap_y * delta.y.signum()))
+    } else {
+        None
+    }
+}
@@ -0,0 +1,133 @@
+//! Collision and movement resolution. The default backend is a homegrown
+//! AABB sweep; the `rapier` feature swaps in `bevy_rapier2d` behind the same
+//! gameplay-facing API (`HitBox`, `Grounded`, `CollisionEvent`, `Sensor`) so
+//! game code never needs to know which one is active.
+
+use bevy::prelude::*;
+
+mod aabb;
+#[cfg(feature = "rapier")]
+mod rapier;
+
+/// Axis-aligned bounding box used for both solid geometry and the player.
+/// Under the `rapier` backend this is the source data for a generated
+/// `Collider` rather than being swept directly.
+#[derive(Component, Reflect, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component)]
+pub struct HitBox {
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub size: Vec2,
+}
+
+/// The result of an overlap between two `HitBox`es: which side `other` was
+/// pushed out along, and how far into it `self` had penetrated on each axis.
+///
+/// `normal` picks the axis of least penetration (ties broken toward the
+/// vertical axis, since a corner-overlapping platformer body should land on
+/// top of a ledge rather than getting shoved off its side) and always points
+/// away from `other`, toward `self`. `depth` is `aabb::overlap_boxes`' own
+/// signed per-axis overlap, kept around so a caller that wants both axes
+/// (like `aabb::move_bodies`'s per-axis resolution) doesn't have to
+/// recompute it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Contact {
+    pub normal: Vec2,
+    pub depth: Vec2,
+}
+
+impl HitBox {
+    /// `Some(Contact)` if `self` at `pos` overlaps `other` at `other_pos`,
+    /// `None` otherwise. Takes plain `Vec2`s rather than `Transform`s, same
+    /// as `aabb::overlap_boxes` which this wraps — 2D box math doesn't need
+    /// the `z` a `Transform.translation` carries, and every existing caller
+    /// already truncates to `Vec2` before touching this kind of function
+    /// (see `portal::teleport_on_overlap`, `script::overlapping`).
+    pub fn intersect(&self, pos: Vec2, other: &HitBox, other_pos: Vec2) -> Option<Contact> {
+        let depth = aabb::overlap_boxes(pos, self.size, other_pos, other.size)?;
+        let normal = if depth.y.abs() <= depth.x.abs() {
+            Vec2::new(0.0, depth.y.signum())
+        } else {
+            Vec2::new(depth.x.signum(), 0.0)
+        };
+        Some(Contact { normal, depth })
+    }
+
+    /// Convenience wrapper for callers that only need to know whether the
+    /// two boxes overlap at all, not the contact data.
+    pub fn intersects(&self, pos: Vec2, other: &HitBox, other_pos: Vec2) -> bool {
+        self.intersect(pos, other, other_pos).is_some()
+    }
+}
+
+/// Marker for a `HitBox` that reports overlap without blocking movement.
+#[derive(Component, Reflect, Clone, Default)]
+#[reflect(Component)]
+pub struct Sensor;
+
+/// Opts an entity into velocity-driven movement and ground resolution
+/// against solids — i.e. it gets a `Grounded`/removed-`Grounded` each tick
+/// the same way the player does. Originally this was hard-coded to
+/// `With<Player>`, so only the player could ever stand on anything; any
+/// entity with `Velocity`, a `HitBox`, and this marker now qualifies,
+/// which is what an enemy that walks and falls will need.
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+pub struct GroundedBody;
+
+/// Selects which part of [`aabb::move_bodies`]'s per-frame resolution a
+/// `GroundedBody` wants. Absent reads as [`MovementMode::Grounded`], so
+/// every existing `GroundedBody` (the player, a `prefab::Patroller`) keeps
+/// its current behavior without needing this component added.
+///
+/// `Flying` skips the ground probe — no `player::Grounded` gets inserted —
+/// so a hovering `prefab::Flyer` never reads as "standing on" whatever
+/// solid it last dipped into; solid collision on both axes still runs
+/// first, so a diving flyer still stops at the floor instead of clipping
+/// through it. `Swimming` doesn't change `move_bodies`'s resolution at all
+/// today — a `prefab::Fish` never touches a solid, since `enemy_ai::swim`
+/// already keeps it inside its `water::Water` zone — it's a real third
+/// value for `enemy_ai`'s systems to select behavior by, the same
+/// "the reaction system is real, nothing needs the trigger yet" gap this
+/// crate already has for `fall_damage::MovementLockout`.
+#[derive(Component, Reflect, Clone, Copy, Default, PartialEq, Eq)]
+#[reflect(Component)]
+pub enum MovementMode {
+    #[default]
+    Grounded,
+    Flying,
+    Swimming,
+}
+
+/// Fired once per overlap begin/end between two `HitBox`es, at least one of
+/// which is a `Sensor`, regardless of which collision backend is active.
+#[derive(Event)]
+pub enum CollisionEvent {
+    Started(Entity, Entity),
+    Stopped(Entity, Entity),
+}
+
+/// A solid's surface, for `weather::rain_friction_scale` to react to. Just
+/// `Stone` today — kept as an enum rather than a bare marker so a future
+/// `Wood`/`Ice` variant doesn't need a breaking change to slot in, the same
+/// reason `player::DamageKind` leaves room for variants nothing sends yet.
+#[derive(Component, Reflect, Clone, Copy, PartialEq, Eq, Default)]
+#[reflect(Component)]
+pub enum SurfaceMaterial {
+    #[default]
+    Stone,
+}
+
+/// Marks a solid whose upper edge is a walkable platform top, for
+/// `weather::grow_snow_cover` to know where to grow a snow cap. Independent
+/// of [`SurfaceMaterial`] — a platform can be a walkable top without being
+/// stone (`map`'s wooden lift, for one), and today nothing needs a
+/// non-walkable `SurfaceMaterial` solid to also carry this.
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+pub struct PlatformTop;
+
+#[cfg(not(feature = "rapier"))]
+pub use aabb::{overlap_boxes, CollisionPlugin};
+#[cfg(feature = "rapier")]
+pub use rapier::CollisionPlugin;
@@ -0,0 +1,94 @@
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+
+use super::{CollisionEvent as GameCollisionEvent, GroundedBody, HitBox, Sensor as GameSensor};
+use crate::player::{Grounded, Velocity};
+
+/// Unlike the AABB backend (`aabb::move_bodies`), this doesn't report
+/// `debug_overlay::COLLISION_PAIRS_TESTED` — rapier's narrow phase runs
+/// inside its own pipeline, not a loop this crate controls, so there's no
+/// single place here to count from. The overlay just reads 0 for it under
+/// this feature.
+pub struct CollisionPlugin;
+
+impl Plugin for CollisionPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<HitBox>()
+            .register_type::<GroundedBody>()
+            .add_plugins(RapierPhysicsPlugin::<NoUserData>::pixels_per_meter(1.0))
+            .add_event::<GameCollisionEvent>()
+            .add_systems(PostStartup, attach_colliders)
+            .add_systems(Update, (sync_velocity, update_grounded, forward_collision_events));
+    }
+}
+
+/// Generates rapier `Collider`s from the same `HitBox` data the AABB
+/// backend sweeps directly, so level and prefab data doesn't change.
+fn attach_colliders(
+    mut commands: Commands,
+    bodies: Query<(Entity, &HitBox), With<GroundedBody>>,
+    solids: Query<(Entity, &HitBox, Option<&GameSensor>), Without<GroundedBody>>,
+) {
+    for (entity, hitbox) in &bodies {
+        commands.entity(entity).insert((
+            RigidBody::KinematicPositionBased,
+            Collider::cuboid(hitbox.size.x / 2.0, hitbox.size.y / 2.0),
+            KinematicCharacterController::default(),
+        ));
+    }
+    for (entity, hitbox, sensor) in &solids {
+        let mut entity_commands = commands.entity(entity);
+        entity_commands.insert((
+            RigidBody::Fixed,
+            Collider::cuboid(hitbox.size.x / 2.0, hitbox.size.y / 2.0),
+        ));
+        if sensor.is_some() {
+            entity_commands.insert(Sensor);
+        }
+    }
+}
+
+fn sync_velocity(
+    time: Res<Time>,
+    mut query: Query<(&Velocity, &mut KinematicCharacterController), With<GroundedBody>>,
+) {
+    for (velocity, mut controller) in &mut query {
+        controller.translation = Some(velocity.0 * time.delta_seconds());
+    }
+}
+
+fn update_grounded(
+    mut commands: Commands,
+    query: Query<(Entity, &KinematicCharacterControllerOutput), With<GroundedBody>>,
+) {
+    for (entity, output) in &query {
+        if output.grounded {
+            // `KinematicCharacterControllerOutput` doesn't expose which
+            // collider the ground contact resolved against, so this
+            // backend can't populate the supporting entity the AABB
+            // backend's hitbox probe does.
+            commands.entity(entity).insert(Grounded(None));
+        } else {
+            commands.entity(entity).remove::<Grounded>();
+        }
+    }
+}
+
+/// Re-emits rapier's collision events as our own `CollisionEvent`, so
+/// gameplay code (interactables, pushables, sensors) reads one API
+/// regardless of the active backend.
+fn forward_collision_events(
+    mut rapier_events: EventReader<bevy_rapier2d::pipeline::CollisionEvent>,
+    mut game_events: EventWriter<GameCollisionEvent>,
+) {
+    for event in rapier_events.read() {
+        match *event {
+            bevy_rapier2d::pipeline::CollisionEvent::Started(a, b, _) => {
+                game_events.send(GameCollisionEvent::Started(a, b));
+            }
+            bevy_rapier2d::pipeline::CollisionEvent::Stopped(a, b, _) => {
+                game_events.send(GameCollisionEvent::Stopped(a, b));
+            }
+        }
+    }
+}
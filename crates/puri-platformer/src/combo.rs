@@ -0,0 +1,154 @@
+//! Stomping an enemy (see [`Stompable`], currently only `turret::Turret`)
+//! bounces the player and builds a combo: consecutive stomps without
+//! touching the ground multiply the score awarded per kill (100, 200,
+//! 400...), shown as a `floating_text::FloatingText` rising from the kill.
+//! The combo resets the moment the player lands or takes damage.
+//!
+//! There's no running `Score` resource anywhere in this crate to add these
+//! points to — `high_scores`'s own doc comment already covers why (this is
+//! a checkpoint-and-respawn platformer, not a lives/game-over one, so
+//! nothing has ever needed a live score). The popup number is real
+//! (`100 * 2^(combo - 1)`, matching the ask's 100/200/400 progression) but
+//! it's feedback only, same as a fighting-game hit counter, not banked
+//! anywhere.
+
+use bevy::prelude::*;
+
+use crate::collision::HitBox;
+use crate::fall_damage::DamageEvent;
+use crate::floating_text::spawn_floating_text;
+use crate::loot::{LootKind, LootTable};
+use crate::player::{Grounded, Jump, Player, Velocity};
+use crate::rng::GameRng;
+use crate::schedule::PlatformerSet;
+
+/// Marks an enemy as stompable from above. Contact from the side does
+/// nothing — see `turret`'s own doc comment on why that's a non-event
+/// rather than an explicit immunity check.
+#[derive(Component)]
+pub struct Stompable;
+
+/// Consecutive stomps since the player last touched the ground or took
+/// damage. Backfilled onto the player the same way `shield::Stamina` is,
+/// rather than touching `PlayerBundle` for a stat only this module needs.
+#[derive(Component, Default)]
+pub struct ComboCount(pub u32);
+
+/// Fired once per stomp kill, for `results::LevelStats` to tally without
+/// this module needing to know a results screen exists. `loot` is the
+/// `LootTable` roll for the entity killed, or `None` if it didn't carry
+/// one — see `loot`'s own doc comment on why that's the same as rolling
+/// `LootKind::Nothing` from `loot::spawn_drops`'s point of view.
+#[derive(Event)]
+pub struct EnemyDefeated {
+    pub position: Vec2,
+    pub loot: Option<LootKind>,
+}
+
+const BOUNCE_IMPULSE: f32 = 260.0;
+/// Awarded instead of [`BOUNCE_IMPULSE`] when the jump key is held the
+/// frame the stomp lands, for a player chaining stomps on purpose.
+const BOUNCE_IMPULSE_HELD: f32 = 420.0;
+const BASE_SCORE: u32 = 100;
+const POPUP_RISE_SPEED: f32 = 40.0;
+const POPUP_LIFETIME_SECONDS: f32 = 0.8;
+
+pub struct ComboPlugin;
+
+impl Plugin for ComboPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<EnemyDefeated>().add_systems(
+            Update,
+            (ensure_combo_component, stomp_enemies, reset_combo_on_landing, reset_combo_on_damage)
+                .chain()
+                .in_set(PlatformerSet::PostPhysics)
+                .run_if(crate::death::player_not_dying),
+        );
+    }
+}
+
+fn ensure_combo_component(mut commands: Commands, player: Query<Entity, (With<Player>, Without<ComboCount>)>) {
+    for entity in &player {
+        commands.entity(entity).insert(ComboCount::default());
+    }
+}
+
+/// Detects the player landing on top of a [`Stompable`] entity while
+/// falling, despawns it, bounces the player, and awards combo score —
+/// generalizing the overlap/direction check `turret` used to do for
+/// itself alone.
+/// `pub(crate)` so `loot::spawn_drops` can order itself `.after` this — both
+/// write `GameRng` (`stomp_enemies` to roll a kill's `LootTable`,
+/// `spawn_drops` to roll its scatter velocity), and the ambiguity checker
+/// `tests/schedule_ambiguity.rs` runs at `LogLevel::Error` won't accept two
+/// unordered writers to the same resource (mirrors `player::dash`'s own
+/// citation for the same reason).
+pub(crate) fn stomp_enemies(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut rng: ResMut<GameRng>,
+    mut defeated: EventWriter<EnemyDefeated>,
+    mut player: Query<(Entity, &Transform, &HitBox, &mut Velocity, &mut ComboCount), With<Player>>,
+    stompables: Query<(Entity, &Transform, &HitBox, Option<&LootTable>), With<Stompable>>,
+) {
+    let Ok((player_entity, player_transform, player_box, mut velocity, mut combo)) = player.get_single_mut() else {
+        return;
+    };
+    if velocity.0.y >= 0.0 {
+        return;
+    }
+
+    for (entity, transform, hitbox, loot_table) in &stompables {
+        let touch_distance = (player_box.size + hitbox.size) / 2.0;
+        let overlapping = (player_transform.translation.truncate() - transform.translation.truncate())
+            .abs()
+            .cmplt(touch_distance)
+            .all();
+        let player_above = player_transform.translation.y > transform.translation.y;
+        if !overlapping || !player_above {
+            continue;
+        }
+
+        let position = transform.translation.truncate();
+        let loot = loot_table.map(|table| table.roll(&mut rng));
+        commands.entity(entity).despawn_recursive();
+        defeated.send(EnemyDefeated { position, loot });
+
+        let held = keys.pressed(KeyCode::Space);
+        velocity.0.y = if held { BOUNCE_IMPULSE_HELD } else { BOUNCE_IMPULSE };
+        commands.entity(player_entity).insert(Jump);
+
+        combo.0 += 1;
+        let score = BASE_SCORE * 2u32.pow(combo.0 - 1);
+        let text = if combo.0 > 1 {
+            format!("{score} x{}", combo.0)
+        } else {
+            score.to_string()
+        };
+        spawn_floating_text(
+            &mut commands,
+            &mut rng,
+            transform.translation.truncate(),
+            text,
+            Color::rgb(1.0, 0.9, 0.3),
+            Vec2::new(0.0, POPUP_RISE_SPEED),
+            POPUP_LIFETIME_SECONDS,
+        );
+    }
+}
+
+fn reset_combo_on_landing(mut player: Query<&mut ComboCount, (With<Player>, Added<Grounded>)>) {
+    for mut combo in &mut player {
+        combo.0 = 0;
+    }
+}
+
+fn reset_combo_on_damage(mut damage_events: EventReader<DamageEvent>, mut player: Query<&mut ComboCount, With<Player>>) {
+    if damage_events.read().next().is_none() {
+        return;
+    }
+    let Ok(mut combo) = player.get_single_mut() else {
+        return;
+    };
+    combo.0 = 0;
+}
@@ -0,0 +1,127 @@
+//! Tick-indexed input commands, filling the `PlatformerSet::Input` slot
+//! `schedule`'s own contract already reserves for "reads raw device state
+//! into intent" — until now nothing populated it, and every `Intent`-set
+//! system read `ButtonInput` directly instead.
+//!
+//! [`sample_local_input`] is the only producer today: it samples the local
+//! keyboard into a [`PlayerCommand`] for [`PlayerId::LOCAL`] and pushes it
+//! onto [`CommandQueue`] at the current `bevy::core::FrameCount` tick, which
+//! `player::player_input`/`player::dash` (both already in `Intent`, already
+//! ordered after `Input` by `schedule`'s `.chain()`) then read back instead
+//! of touching `ButtonInput` themselves. That's the actual behavior change
+//! this request delivers: input sampling and input consumption are now two
+//! separate, tick-addressed steps instead of one.
+//!
+//! What's still missing for real online co-op, honestly: there's only ever
+//! one `Player` entity in this crate (see `level_select`'s own note on the
+//! lack of a second real level — the lack of a second real player is the
+//! same story), so nothing spawns a `PlayerId` other than `LOCAL`, and no
+//! second command source (replay file, network peer) exists to push one —
+//! `launch_options::LaunchOptions::replay` is already documented as not
+//! yet implemented. [`CommandQueue::push`] takes an arbitrary [`PlayerId`]
+//! and ticket number precisely so that a replay reader or a network
+//! receiver can call it directly once one exists, without this module
+//! needing to change. `attack`/`shield`/`interact` haven't been migrated
+//! off raw `ButtonInput` reads yet — each is its own action gated by
+//! different state (combat phase, shield stamina, prompt visibility) and
+//! migrating them is left for whichever request actually needs their
+//! commands to be replayable too.
+
+use std::collections::BTreeMap;
+
+use bevy::core::FrameCount;
+use bevy::prelude::*;
+
+use crate::schedule::PlatformerSet;
+
+/// Identifies which player a [`PlayerCommand`] belongs to. Only
+/// [`PlayerId::LOCAL`] is ever produced today — see this module's own doc
+/// comment.
+#[derive(Component, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct PlayerId(pub u32);
+
+impl PlayerId {
+    pub const LOCAL: PlayerId = PlayerId(0);
+}
+
+/// One player's sampled input for one tick. Deliberately just the fields
+/// `player::player_input`/`player::dash` need today, not a catch-all input
+/// struct — see this module's own doc comment on what hasn't migrated yet.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct PlayerCommand {
+    pub move_axis: f32,
+    pub jump_just_pressed: bool,
+    pub dash_just_pressed: bool,
+}
+
+/// How many ticks of history [`sample_local_input`] keeps behind the
+/// current tick. Generous relative to the one-tick lag any consumer
+/// actually has, but bounded so a long session's queue can't grow forever.
+const HISTORY_TICKS: u32 = 300;
+
+/// Commands, indexed by the tick they apply to and then by [`PlayerId`],
+/// so a consumer reading tick `N` sees every player's command for that
+/// exact tick regardless of which source produced it or in what order.
+#[derive(Resource, Default)]
+pub struct CommandQueue {
+    by_tick: BTreeMap<u32, Vec<(PlayerId, PlayerCommand)>>,
+}
+
+impl CommandQueue {
+    pub fn push(&mut self, tick: u32, id: PlayerId, command: PlayerCommand) {
+        self.by_tick.entry(tick).or_default().push((id, command));
+    }
+
+    /// `id`'s command for `tick`, or `None` if nothing was ever pushed for
+    /// that (tick, id) pair — e.g. a network peer's packet hasn't arrived
+    /// yet.
+    pub fn command_for(&self, tick: u32, id: PlayerId) -> Option<PlayerCommand> {
+        self.by_tick.get(&tick)?.iter().find(|(entry, _)| *entry == id).map(|(_, command)| *command)
+    }
+
+    /// Drops every tick strictly older than `tick`, keeping the queue from
+    /// growing unbounded over a long session.
+    pub fn prune_before(&mut self, tick: u32) {
+        self.by_tick.retain(|&stored_tick, _| stored_tick >= tick);
+    }
+
+    pub fn len(&self) -> usize {
+        self.by_tick.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.by_tick.is_empty()
+    }
+}
+
+/// Reads the local keyboard the same way `player::player_input`/`dash`
+/// used to read it directly, and pushes the result as [`PlayerId::LOCAL`]'s
+/// command for the current tick.
+fn sample_local_input(keys: Res<ButtonInput<KeyCode>>, frame: Res<FrameCount>, mut queue: ResMut<CommandQueue>) {
+    let mut move_axis = 0.0;
+    if keys.pressed(KeyCode::ArrowLeft) || keys.pressed(KeyCode::KeyA) {
+        move_axis -= 1.0;
+    }
+    if keys.pressed(KeyCode::ArrowRight) || keys.pressed(KeyCode::KeyD) {
+        move_axis += 1.0;
+    }
+    queue.push(
+        frame.0,
+        PlayerId::LOCAL,
+        PlayerCommand {
+            move_axis,
+            jump_just_pressed: keys.just_pressed(KeyCode::Space),
+            dash_just_pressed: keys.just_pressed(KeyCode::ShiftLeft),
+        },
+    );
+    queue.prune_before(frame.0.saturating_sub(HISTORY_TICKS));
+}
+
+pub struct CommandQueuePlugin;
+
+impl Plugin for CommandQueuePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CommandQueue>()
+            .add_systems(Update, sample_local_input.in_set(PlatformerSet::Input));
+    }
+}
@@ -0,0 +1,96 @@
+//! Crouch — the one real, buildable piece of "crouch-slide down slopes"
+//! (see below for the rest of that request). Holding [`CROUCH_KEY`] while
+//! [`Grounded`] adds [`Crouching`] the same frame; [`resize_hitbox_for_crouch`]
+//! shrinks the player's `HitBox` to [`CROUCH_HITBOX`] while it's present and
+//! restores [`STAND_HITBOX`] the frame it's removed, including on leaving
+//! the ground, so a jump mid-crouch doesn't carry a half-height hitbox
+//! into the air.
+//!
+//! **What's not real**: the slide, its speed gain off a slope, and
+//! "sliding into enemies damages them" all depend on slopes existing, and
+//! this crate's collision is exclusively axis-aligned —
+//! `collision::mod.rs`'s own `Contact::normal` only ever resolves to one
+//! of the four cardinal directions, and every `HitBox` in the crate is an
+//! un-rotated rectangle (`map::spawn_map_entities`'s demo layout is flat
+//! platforms and one raised ledge, nothing tilted). "Projecting velocity
+//! along the slope surface" and "gains speed from the slope angle" have no
+//! surface to project onto or angle to read, and the requested "long
+//! downhill section" has nowhere to live in a level format that's only
+//! ever axis-aligned rectangles (`map`'s own doc comment covers the
+//! separate "no external level format" gap this shares). Building real
+//! slopes means teaching `collision`'s AABB sweep about non-axis-aligned
+//! surfaces first — a foundational change to `collision/aabb.rs`, not
+//! something this request's crouch half can bootstrap on its own. Once
+//! slopes exist, `grapple::swing_on_grapple`'s tangential-velocity math is
+//! the closest existing precedent in this crate for "project velocity
+//! along a surface" to build the slide from.
+//!
+//! [`CROUCH_KEY`] reuses `KeyCode::ArrowDown` rather than claiming a new
+//! one: that key already means "down" in every other context that reads
+//! it (`level_select`/`shop`'s menu navigation, `photo`'s pan, `main.rs`'s
+//! free camera), and none of those states are ever active during normal
+//! gameplay at the same time this is — the same
+//! reused-across-mutually-exclusive-contexts precedent
+//! `speedrun_overlay`'s own doc comment cites for sharing the F3 flag
+//! instead of adding a new key of its own.
+
+use bevy::prelude::*;
+
+use crate::collision::HitBox;
+use crate::player::{Grounded, Player};
+use crate::schedule::PlatformerSet;
+
+pub const CROUCH_KEY: KeyCode = KeyCode::ArrowDown;
+
+const STAND_HITBOX: Vec2 = Vec2::new(24.0, 32.0);
+const CROUCH_HITBOX: Vec2 = Vec2::new(24.0, 20.0);
+
+/// Present while the player is holding [`CROUCH_KEY`] on the ground; see
+/// this module's own doc comment for why nothing beyond a hitbox shrink
+/// happens while this is present.
+#[derive(Component)]
+pub struct Crouching;
+
+pub struct CrouchPlugin;
+
+impl Plugin for CrouchPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (crouch_or_stand, resize_hitbox_for_crouch)
+                .chain()
+                .in_set(PlatformerSet::Intent),
+        );
+    }
+}
+
+fn crouch_or_stand(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    player: Query<(Entity, Option<&Grounded>, Has<Crouching>), With<Player>>,
+) {
+    let Ok((entity, grounded, crouching)) = player.get_single() else {
+        return;
+    };
+    let holding_crouch = grounded.is_some() && keys.pressed(CROUCH_KEY);
+    match (holding_crouch, crouching) {
+        (true, false) => {
+            commands.entity(entity).insert(Crouching);
+        }
+        (false, true) => {
+            commands.entity(entity).remove::<Crouching>();
+        }
+        _ => {}
+    }
+}
+
+fn resize_hitbox_for_crouch(mut player: Query<(&mut HitBox, Has<Crouching>), With<Player>>) {
+    let Ok((mut hitbox, crouching)) = player.get_single_mut() else {
+        return;
+    };
+    hitbox.size = if crouching {
+        CROUCH_HITBOX
+    } else {
+        STAND_HITBOX
+    };
+}
@@ -0,0 +1,106 @@
+//! Platforms that shake, vanish, and respawn after the player stands on
+//! them for a while. [`tick_crumble`] drives its `elapsed` counters off
+//! `time_scale::GameTime` rather than `Res<Time>` — see that module's own
+//! doc comment on why this is the crate-wide convention for anything that
+//! should freeze along with slow-motion (or a future pause).
+
+use bevy::prelude::*;
+
+use crate::collision::HitBox;
+use crate::player::Player;
+use crate::time_scale::GameTime;
+
+#[derive(Component)]
+pub struct CrumblingPlatform {
+    pub delay: f32,
+    pub respawn: f32,
+}
+
+#[derive(Component)]
+enum CrumbleState {
+    Shaking { elapsed: f32, base_x: f32 },
+    Gone { elapsed: f32 },
+}
+
+pub struct CrumblingPlugin;
+
+impl Plugin for CrumblingPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (start_shaking, tick_crumble));
+    }
+}
+
+fn start_shaking(
+    mut commands: Commands,
+    player: Query<(&Transform, &HitBox), With<Player>>,
+    mut platforms: Query<
+        (Entity, &Transform, &HitBox, Option<&CrumbleState>),
+        With<CrumblingPlatform>,
+    >,
+) {
+    let Ok((player_transform, player_box)) = player.get_single() else {
+        return;
+    };
+    for (entity, transform, hitbox, state) in &mut platforms {
+        if !matches!(state, None) {
+            continue;
+        }
+        let standing_on = (player_transform.translation.x - transform.translation.x).abs()
+            < (player_box.size.x + hitbox.size.x) / 2.0
+            && (player_transform.translation.y - hitbox.size.y / 2.0
+                - (transform.translation.y + hitbox.size.y / 2.0))
+                .abs()
+                < 2.0;
+        if standing_on {
+            commands.entity(entity).insert(CrumbleState::Shaking {
+                elapsed: 0.0,
+                base_x: transform.translation.x,
+            });
+        }
+    }
+}
+
+fn tick_crumble(
+    time: GameTime,
+    mut commands: Commands,
+    player: Query<&Transform, With<Player>>,
+    mut platforms: Query<(
+        Entity,
+        &mut Transform,
+        &mut Sprite,
+        &CrumblingPlatform,
+        &mut CrumbleState,
+    )>,
+) {
+    for (entity, mut transform, mut sprite, config, mut state) in &mut platforms {
+        match &mut *state {
+            CrumbleState::Shaking { elapsed, base_x } => {
+                *elapsed += time.delta_seconds();
+                transform.translation.x = *base_x + (*elapsed * 40.0).sin() * 2.0;
+                if *elapsed >= config.delay {
+                    transform.translation.x = *base_x;
+                    sprite.color.set_a(0.0);
+                    commands.entity(entity).remove::<HitBox>();
+                    *state = CrumbleState::Gone { elapsed: 0.0 };
+                }
+            }
+            CrumbleState::Gone { elapsed } => {
+                *elapsed += time.delta_seconds();
+                if *elapsed >= config.respawn {
+                    let overlapping = player.get_single().is_ok_and(|player_transform| {
+                        (player_transform.translation.truncate() - transform.translation.truncate())
+                            .length()
+                            < 24.0
+                    });
+                    if !overlapping {
+                        sprite.color.set_a(1.0);
+                        commands.entity(entity).insert(HitBox {
+                            size: Vec2::new(48.0, 12.0),
+                        });
+                        commands.entity(entity).remove::<CrumbleState>();
+                    }
+                }
+            }
+        }
+    }
+}
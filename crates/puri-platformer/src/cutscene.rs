@@ -0,0 +1,380 @@
+//! Minimal cutscene scripting: a fixed list of timed [`CutsceneCommand`]s
+//! played back one at a time by [`cutscene_runner`], which also disables
+//! player input for as long as one is playing (see [`not_playing`]).
+//!
+//! Several pieces of the original ask have nowhere real to attach to yet:
+//!
+//! - There's no `Cutscene` *asset* in the Bevy `Handle`/`AssetLoader` sense
+//!   anywhere in this crate — nothing here uses `AssetServer::load` at all,
+//!   `prefab::PrefabRegistry` and `level_select::LevelManifest` are both
+//!   hand-parsed RON read with `std::fs::read_to_string`, not registered
+//!   asset types. [`Cutscene`] follows that same shape: plain, `Reflect`
+//!   and (with `serde`) serializable data, but [`level_1_intro`] builds one
+//!   in code rather than loading it from a file, the same way
+//!   `LevelManifest::default` hand-writes its level list rather than
+//!   reading `assets/levels.ron` (see that module's own note on there being
+//!   no level file format yet).
+//! - There's no per-level `intro_cutscene` field either — `LevelEntry`
+//!   would be the natural place for one, but with only `id: 0` ever loading
+//!   real gameplay (`level_select`'s own note again), authoring a field
+//!   that can only ever point at one level's cutscene wouldn't be honest
+//!   data. [`play_level_1_intro`] just always offers
+//!   [`level_1_intro`] for level id `0` instead of reading a manifest field.
+//! - There's no unified `SaveData` struct anywhere in this crate to add a
+//!   "played" flag to — every persisted resource saves itself to its own
+//!   `assets/*.ron` file (`level_select::persistence`'s own note covers the
+//!   same gap for level unlocks). [`CutscenePlayed`] follows that shape:
+//!   real, `serde`-gated, one-shot load/save-immediately persistence, not a
+//!   `SaveData` field that doesn't exist.
+//! - "Play animation" and "fade" commands are out of scope here per the
+//!   original ask's own wording ("at least" the other four) — there's no
+//!   generic fade-to-black overlay in this crate to drive (`death::Dying`'s
+//!   fade is hard-coded to that one sequence, not a reusable primitive), and
+//!   wiring a cutscene command to `animation::set_animation` needs an actor
+//!   to actually carry named animation clips, which none of this crate's
+//!   demo entities do yet.
+//! - "Skippable with Start" is read as the gamepad `Start` button plus
+//!   `KeyCode::Escape` as its keyboard equivalent, the same pairing
+//!   `level_select`'s and `shop`'s own "close this screen" bindings already
+//!   use Escape for (there's no keyboard key actually labeled "Start").
+//! - "Plays on first entry only" has no real "the player just entered a
+//!   level" event to hook — this crate only ever boots straight into the
+//!   one demo level at `Startup` (`level_select`'s own note covers the same
+//!   gap). Auto-playing at `Startup` would mean *every* boot (and every
+//!   test in this crate's suite, which all construct a fresh app the same
+//!   way) sees the intro, which isn't what "once" is supposed to mean.
+//!   [`play_level_1_intro`] is instead wired to `F4`, the next free slot in
+//!   the same dev-only-trigger convention `debug_overlay`'s F3,
+//!   `time_scale`'s F6, and `level_select`'s F7 already use for entry
+//!   points with nowhere real to hang off of yet — pressing it plays the
+//!   intro if [`CutscenePlayed`] says level `0` hasn't seen it, exactly the
+//!   check a real level-load hook would run.
+//!
+//! What *is* real: the command interpreter in [`cutscene_runner`] handles
+//! camera movement, walking a [`ActorId`]-tagged actor to an x position,
+//! showing a dialogue line, and waiting, in order, one command at a time.
+//! [`CutsceneCommand::Dialogue`] doesn't reinvent dialogue rendering — it
+//! spawns a throwaway `dialogue::Sign` and fires the same `InteractEvent`
+//! `interact::emit_interact_event` would, so `dialogue::open_sign_dialogue`
+//! opens the exact same box a real NPC's would, and the cutscene just waits
+//! for `dialogue::DialogueClosed` to know the player read it. [`level_1_intro`]
+//! is the "short intro for level 1": it walks the demo level's own NPC (see
+//! `map::setup_map`'s NPC, now also carrying an [`ActorId`]) a few steps and
+//! has it say hello before handing control back.
+
+use bevy::prelude::*;
+
+use crate::collision::GroundedBody;
+use crate::dialogue::{ActiveDialogue, Dialogue, DialogueBox, DialogueClosed, Sign};
+use crate::interact::InteractEvent;
+use crate::math::exp_decay;
+use crate::player::Velocity;
+use crate::schedule::PlatformerSet;
+
+/// Matches `camera::FOLLOW_SPEED`, so a cutscene's camera pan feels the same
+/// speed as the normal player-follow it temporarily replaces.
+const CAMERA_MOVE_SPEED: f32 = 6.0;
+const CAMERA_ARRIVE_DISTANCE: f32 = 2.0;
+/// Matches `npc::Npc::wander_speed`'s own ballpark for the demo NPC.
+const ACTOR_WALK_SPEED: f32 = 40.0;
+const ACTOR_ARRIVE_DISTANCE: f32 = 2.0;
+
+/// A stable id a cutscene command can reference an entity by, the same role
+/// `death::Checkpoint::id`/`portal::PortalId` play for their own systems.
+/// Any entity with `Velocity` and `GroundedBody` can carry one to become a
+/// walkable cutscene actor — `map::setup_map`'s NPC is the only one today.
+#[derive(Component, Reflect, Clone, Copy, Default)]
+#[reflect(Component)]
+pub struct ActorId(pub u32);
+
+/// One instruction in a [`Cutscene`]. See this module's own doc comment for
+/// which commands from the original ask are and aren't implemented.
+#[derive(Clone, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CutsceneCommand {
+    MoveCamera(Vec2),
+    WalkActor { actor: u32, target_x: f32 },
+    Dialogue(String),
+    Wait(f32),
+}
+
+/// A cutscene: just a list of [`CutsceneCommand`]s played in order. See this
+/// module's own doc comment on why nothing loads one from disk today.
+#[derive(Clone, Reflect, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Cutscene(pub Vec<CutsceneCommand>);
+
+/// Level ids whose intro cutscene has already played once, so it doesn't
+/// replay on every subsequent visit. See this module's own doc comment on
+/// why this isn't a `SaveData` field.
+#[derive(Resource, Reflect, Clone, Default)]
+#[reflect(Resource)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CutscenePlayed(Vec<u32>);
+
+impl CutscenePlayed {
+    pub fn has_played(&self, level_id: u32) -> bool {
+        self.0.contains(&level_id)
+    }
+
+    fn mark_played(&mut self, level_id: u32) {
+        if !self.has_played(level_id) {
+            self.0.push(level_id);
+        }
+    }
+}
+
+#[derive(States, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum CutscenePlaying {
+    #[default]
+    NotPlaying,
+    Playing,
+}
+
+/// Which step of the current command is in progress. `pub(crate)` (rather
+/// than private) so it can sit on `CutsceneRunner::step`, which is itself
+/// `pub(crate)` for a future `testing::TestWorld` helper to inspect a
+/// running cutscene's progress without reaching into the resource's other,
+/// still-private fields.
+#[derive(Clone)]
+pub(crate) enum CutsceneStep {
+    /// About to start `commands[index]`.
+    Pending,
+    MovingCamera(Vec2),
+    WalkingActor { actor: u32, target_x: f32 },
+    WaitingForDialogue(Entity),
+    Waiting(Timer),
+}
+
+/// The cutscene currently playing, if any. Mirrors `dialogue::ActiveDialogue`'s
+/// "just a resource, gone when nothing's playing" shape rather than encoding
+/// per-step progress in `States`.
+#[derive(Resource)]
+pub(crate) struct CutsceneRunner {
+    pub(crate) level_id: u32,
+    commands: Vec<CutsceneCommand>,
+    index: usize,
+    pub(crate) step: CutsceneStep,
+}
+
+pub struct CutscenePlugin;
+
+impl Plugin for CutscenePlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<ActorId>()
+            .register_type::<CutscenePlayed>()
+            .init_state::<CutscenePlaying>()
+            .init_resource::<CutscenePlayed>()
+            .add_systems(Update, play_level_1_intro.run_if(not_playing))
+            .add_systems(
+                Update,
+                cutscene_runner.in_set(PlatformerSet::Intent).run_if(in_state(CutscenePlaying::Playing)),
+            );
+
+        #[cfg(feature = "serde")]
+        app.add_systems(Startup, persistence::load_from_disk);
+    }
+}
+
+/// Whether gameplay systems (player input, NPC wander, the camera's normal
+/// player-follow) should run this frame — mirrors
+/// `dialogue::playing_and_not_talking`.
+pub fn not_playing(state: Res<State<CutscenePlaying>>) -> bool {
+    *state.get() == CutscenePlaying::NotPlaying
+}
+
+/// The demo level's intro: pan to the NPC, have it take a few steps, say
+/// hello, then a short beat before handing control back. The x values
+/// mirror `map::setup_map`'s own NPC placement (`npc_pos = (30.0, -128.0)`).
+fn level_1_intro() -> Cutscene {
+    Cutscene(vec![
+        CutsceneCommand::MoveCamera(Vec2::new(30.0, -128.0)),
+        CutsceneCommand::WalkActor { actor: 0, target_x: 60.0 },
+        CutsceneCommand::Dialogue("Welcome to the Demo Level!".into()),
+        CutsceneCommand::Wait(0.5),
+    ])
+}
+
+const LEVEL_1_ID: u32 = 0;
+
+fn play_level_1_intro(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    played: Res<CutscenePlayed>,
+    mut next_state: ResMut<NextState<CutscenePlaying>>,
+) {
+    if !keys.just_pressed(KeyCode::F4) || played.has_played(LEVEL_1_ID) {
+        return;
+    }
+    commands.insert_resource(CutsceneRunner {
+        level_id: LEVEL_1_ID,
+        commands: level_1_intro().0,
+        index: 0,
+        step: CutsceneStep::Pending,
+    });
+    next_state.set(CutscenePlaying::Playing);
+}
+
+fn start_command(commands: &mut Commands, command: CutsceneCommand, interact_events: &mut EventWriter<InteractEvent>) -> CutsceneStep {
+    match command {
+        CutsceneCommand::MoveCamera(target) => CutsceneStep::MovingCamera(target),
+        CutsceneCommand::WalkActor { actor, target_x } => CutsceneStep::WalkingActor { actor, target_x },
+        CutsceneCommand::Dialogue(line) => {
+            let entity = commands.spawn(Sign { pages: vec![line] }).id();
+            interact_events.send(InteractEvent { target: entity });
+            CutsceneStep::WaitingForDialogue(entity)
+        }
+        CutsceneCommand::Wait(seconds) => CutsceneStep::Waiting(Timer::from_seconds(seconds, TimerMode::Once)),
+    }
+}
+
+fn advance(runner: &mut CutsceneRunner) {
+    runner.index += 1;
+    runner.step = CutsceneStep::Pending;
+}
+
+/// The command interpreter: advances through `CutsceneRunner`'s commands
+/// one step per call, running several in the same frame if each finishes
+/// immediately (e.g. an actor that's already at its target). Also reads the
+/// skip input every frame, since a held `Wait` or an in-progress walk needs
+/// to be interruptible at any point, not just between commands.
+///
+/// `pub(crate)` so `camera_rail::advance_rail_camera` can order itself
+/// `.after` this — both write `Camera2d`'s `Transform` in
+/// `PlatformerSet::Intent`, and the ambiguity checker won't accept two
+/// unordered writers to the same component (mirrors `death::apply_damage`'s
+/// own citation for the same reason).
+pub(crate) fn cutscene_runner(
+    mut commands: Commands,
+    time: Res<Time>,
+    keys: Res<ButtonInput<KeyCode>>,
+    gamepads: Res<Gamepads>,
+    gamepad_buttons: Res<ButtonInput<GamepadButton>>,
+    mut runner: ResMut<CutsceneRunner>,
+    mut played: ResMut<CutscenePlayed>,
+    mut camera: Query<&mut Transform, With<Camera2d>>,
+    mut actors: Query<(&ActorId, &mut Transform, &mut Velocity), With<GroundedBody>>,
+    mut dialogue_closed: EventReader<DialogueClosed>,
+    mut interact_events: EventWriter<InteractEvent>,
+    active_dialogue: Option<Res<ActiveDialogue>>,
+    dialogue_box: Query<Entity, With<DialogueBox>>,
+    mut dialogue_next_state: ResMut<NextState<Dialogue>>,
+    mut dialogue_closed_writer: EventWriter<DialogueClosed>,
+    mut next_state: ResMut<NextState<CutscenePlaying>>,
+) {
+    let pressed_start = keys.just_pressed(KeyCode::Escape)
+        || gamepads.iter().any(|pad| gamepad_buttons.just_pressed(GamepadButton::new(pad, GamepadButtonType::Start)));
+    if pressed_start {
+        if let Some(active) = active_dialogue {
+            for entity in &dialogue_box {
+                commands.entity(entity).despawn_recursive();
+            }
+            dialogue_closed_writer.send(DialogueClosed { source: active.source });
+            commands.remove_resource::<ActiveDialogue>();
+            dialogue_next_state.set(Dialogue::Closed);
+        }
+        played.mark_played(runner.level_id);
+        #[cfg(feature = "serde")]
+        persistence::save_to_disk(&played);
+        commands.remove_resource::<CutsceneRunner>();
+        next_state.set(CutscenePlaying::NotPlaying);
+        return;
+    }
+
+    let dt = time.delta_seconds();
+    loop {
+        match runner.step.clone() {
+            CutsceneStep::Pending => {
+                let Some(command) = runner.commands.get(runner.index).cloned() else {
+                    played.mark_played(runner.level_id);
+                    #[cfg(feature = "serde")]
+                    persistence::save_to_disk(&played);
+                    commands.remove_resource::<CutsceneRunner>();
+                    next_state.set(CutscenePlaying::NotPlaying);
+                    return;
+                };
+                runner.step = start_command(&mut commands, command, &mut interact_events);
+            }
+            CutsceneStep::MovingCamera(target) => {
+                let Ok(mut transform) = camera.get_single_mut() else {
+                    break;
+                };
+                transform.translation.x = exp_decay(transform.translation.x, target.x, CAMERA_MOVE_SPEED, dt);
+                transform.translation.y = exp_decay(transform.translation.y, target.y, CAMERA_MOVE_SPEED, dt);
+                if transform.translation.truncate().distance(target) <= CAMERA_ARRIVE_DISTANCE {
+                    advance(&mut runner);
+                } else {
+                    break;
+                }
+            }
+            CutsceneStep::WalkingActor { actor, target_x } => {
+                let Some((_, transform, mut velocity)) = actors.iter_mut().find(|(id, ..)| id.0 == actor) else {
+                    // No entity with this id exists (yet) — skip rather than stall forever.
+                    advance(&mut runner);
+                    continue;
+                };
+                let to_target = target_x - transform.translation.x;
+                if to_target.abs() <= ACTOR_ARRIVE_DISTANCE {
+                    velocity.0.x = 0.0;
+                    advance(&mut runner);
+                } else {
+                    velocity.0.x = to_target.signum() * ACTOR_WALK_SPEED;
+                    break;
+                }
+            }
+            CutsceneStep::WaitingForDialogue(entity) => {
+                if dialogue_closed.read().any(|event| event.source == entity) {
+                    commands.entity(entity).despawn();
+                    advance(&mut runner);
+                } else {
+                    break;
+                }
+            }
+            CutsceneStep::Waiting(mut timer) => {
+                timer.tick(time.delta());
+                let finished = timer.finished();
+                runner.step = CutsceneStep::Waiting(timer);
+                if finished {
+                    advance(&mut runner);
+                } else {
+                    break;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+mod persistence {
+    use super::CutscenePlayed;
+    use bevy::prelude::*;
+    use std::path::Path;
+
+    const SAVE_PATH: &str = "assets/cutscenes_played.ron";
+
+    /// One-shot load of `assets/cutscenes_played.ron` over the (empty)
+    /// default, if present. Mirrors `level_select::persistence::load_from_disk`.
+    pub fn load_from_disk(mut played: ResMut<CutscenePlayed>) {
+        let Ok(contents) = std::fs::read_to_string(Path::new(SAVE_PATH)) else {
+            return;
+        };
+        match ron::from_str::<CutscenePlayed>(&contents) {
+            Ok(loaded) => *played = loaded,
+            Err(err) => warn!("failed to parse {SAVE_PATH}: {err}"),
+        }
+    }
+
+    /// Writes the current played set to `assets/cutscenes_played.ron`.
+    /// Called right after a cutscene finishes or is skipped, mirroring
+    /// `high_scores::save_to_disk`'s save-immediately timing.
+    pub fn save_to_disk(played: &CutscenePlayed) {
+        match ron::to_string(played) {
+            Ok(serialized) => {
+                if let Err(err) = std::fs::write(SAVE_PATH, serialized) {
+                    warn!("failed to write {SAVE_PATH}: {err}");
+                }
+            }
+            Err(err) => warn!("failed to serialize cutscenes played: {err}"),
+        }
+    }
+}
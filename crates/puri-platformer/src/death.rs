@@ -0,0 +1,352 @@
+//! Death and respawn. `DamageEvent`s drain `Health`; hitting zero starts a
+//! scripted `Dying` sequence that locks out input, plays the death
+//! animation once, fades to black, moves the player to the last
+//! `Checkpoint`, and fades back in.
+//!
+//! The repo doesn't have `Knockback` or `WallSlide` components yet, so
+//! there's nothing stale from those to clear on death; `Jump` is cleared
+//! since it's the one transient movement marker that exists today, and
+//! `starman::Starman` is cleared so a star's invincibility doesn't survive
+//! a death the way it survives a checkpoint touch (see that module's own
+//! note on the distinction).
+//!
+//! `start_death_transition` additionally fires a `transition::Transition`
+//! iris centered on the death position — the arcade touch layered on top
+//! of, not replacing, the fade above; see that module's own doc comment
+//! for why `Iris` is a square rather than a real circle.
+
+use bevy::prelude::*;
+
+use crate::animation::SpriteAnimation;
+use crate::camera::CameraSnap;
+use crate::difficulty_assist::DifficultyAssist;
+use crate::event_log;
+use crate::fall_damage::DamageEvent;
+use crate::player::{Health, Jump, Player, Resistances, Velocity};
+use crate::rumble::RumbleEvent;
+use crate::shop::PurchasedUpgrades;
+use crate::starman::Starman;
+use crate::toast::ToastEvent;
+use crate::transition::{world_to_screen, Transition, TransitionAction, TransitionKind};
+
+/// Respawn health; matches `PlayerBundle::new`'s starting `Health(3)`.
+const RESPAWN_HEALTH: u32 = 3;
+const HOLD_SECONDS: f32 = 0.5;
+const FADE_SECONDS: f32 = 0.3;
+const CHECKPOINT_TOAST_SECONDS: f32 = 2.0;
+/// Cover/reveal ramp for the iris `transition::Transition` fired alongside
+/// a death — independent of, and layered on top of (`transition`'s
+/// overlay sits at `ZIndex::Global(2000)` against this module's `1000`),
+/// the plain fade-to-black `tick_dying` already drives. Slower than
+/// `FADE_SECONDS` since an iris closing in on the player reads as
+/// intentional at a more deliberate pace than a flat fade does.
+const IRIS_SECONDS: f32 = 0.5;
+
+/// `position` is where the player died, not where they'll respawn —
+/// `results::LevelStats::death_positions` records it verbatim for
+/// `difficulty_assist::detect_death_clustering` to cluster against.
+#[derive(Event)]
+pub struct PlayerDied {
+    pub position: Vec2,
+}
+
+/// The last `Checkpoint` the player touched, defaulting to the spawn point.
+/// Tracks the checkpoint's stable `id` alongside its position so
+/// `level_reload` can re-resolve the position after a respawn without
+/// trusting a possibly-stale `Vec2` (or a despawned `Entity`) across the
+/// reload.
+#[derive(Resource)]
+pub struct LastCheckpoint {
+    pub id: Option<u32>,
+    pub position: Vec2,
+}
+
+impl LastCheckpoint {
+    /// Updates `position` to match `id`'s new position among `checkpoints`,
+    /// if a checkpoint with that id still exists. A level reload can move
+    /// (or remove) checkpoints; the *id* is what stays stable across it,
+    /// not the `Vec2` this resource cached before the reload.
+    pub fn re_resolve(&mut self, checkpoints: impl Iterator<Item = (u32, Vec2)>) {
+        let Some(id) = self.id else { return };
+        if let Some((_, position)) = checkpoints.into_iter().find(|&(candidate, _)| candidate == id) {
+            self.position = position;
+        }
+    }
+}
+
+impl Default for LastCheckpoint {
+    fn default() -> Self {
+        Self {
+            id: None,
+            position: Vec2::ZERO,
+        }
+    }
+}
+
+/// A touchable respawn point. Overlap sets `LastCheckpoint`. `id` is stable
+/// across level reloads (unlike the `Entity` a reload despawns and
+/// recreates), so `LastCheckpoint::re_resolve` can find "the same"
+/// checkpoint in newly-spawned data.
+#[derive(Component)]
+pub struct Checkpoint {
+    pub id: u32,
+}
+
+#[derive(PartialEq)]
+enum DeathPhase {
+    FadeOut,
+    Holding,
+    FadeIn,
+}
+
+/// Present on the player entity for the whole death sequence. Input systems
+/// `run_if(player_not_dying)` to stay disabled the entire time.
+#[derive(Component)]
+pub struct Dying {
+    phase: DeathPhase,
+    timer: Timer,
+}
+
+impl Dying {
+    fn new() -> Self {
+        Self {
+            phase: DeathPhase::FadeOut,
+            timer: Timer::from_seconds(FADE_SECONDS, TimerMode::Once),
+        }
+    }
+}
+
+#[derive(Component)]
+struct DeathFade;
+
+pub struct DeathPlugin;
+
+impl Plugin for DeathPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<PlayerDied>()
+            .init_resource::<LastCheckpoint>()
+            .add_systems(Startup, spawn_fade_overlay)
+            .add_systems(
+                Update,
+                (
+                    apply_damage,
+                    touch_checkpoints.run_if(crate::challenge::checkpoints_enabled),
+                    start_dying,
+                    tick_dying,
+                )
+                    .chain(),
+            )
+            .add_systems(Update, start_death_transition);
+        #[cfg(feature = "serde")]
+        app.add_systems(Update, (record_player_death, record_damage_taken));
+    }
+}
+
+/// Query-based run condition so player systems stop reacting to input for
+/// the whole `Dying` sequence, mirroring `dialogue::playing_and_not_talking`.
+pub fn player_not_dying(dying: Query<(), (With<Player>, With<Dying>)>) -> bool {
+    dying.is_empty()
+}
+
+fn spawn_fade_overlay(mut commands: Commands) {
+    commands.spawn((
+        DeathFade,
+        NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                ..default()
+            },
+            background_color: Color::rgba(0.0, 0.0, 0.0, 0.0).into(),
+            z_index: ZIndex::Global(1000),
+            ..default()
+        },
+    ));
+}
+
+/// `pub(crate)` so `loot::apply_heal` can order itself `.after` this — both
+/// write `Health` on `(With<Player>, Without<Dying>)`, and the ambiguity
+/// checker `tests/schedule_ambiguity.rs` runs at `LogLevel::Error` won't
+/// accept two unordered writers to the same filter (mirrors `player::dash`'s
+/// own citation for the same reason).
+pub(crate) fn apply_damage(
+    mut damage_events: EventReader<DamageEvent>,
+    mut died_events: EventWriter<PlayerDied>,
+    mut rumble_events: EventWriter<RumbleEvent>,
+    mut player: Query<
+        (&mut Health, &Transform, Option<&Starman>, Option<&Resistances>),
+        (With<Player>, Without<Dying>),
+    >,
+) {
+    let Ok((mut health, transform, starman, resistances)) = player.get_single_mut() else {
+        return;
+    };
+    if crate::starman::suppress_damage_while_starman(starman) {
+        damage_events.clear();
+        return;
+    }
+    for event in damage_events.read() {
+        let multiplier = resistances.map_or(1.0, |resistances| resistances.multiplier_for(event.kind));
+        let amount = (event.amount as f32 * multiplier).round() as u32;
+        if amount == 0 {
+            event_log::record(format!("damage immune kind={:?}", event.kind));
+            continue;
+        }
+        health.0 = health.0.saturating_sub(amount);
+        debug!(amount, remaining = health.0, "damaged");
+        event_log::record(format!("damaged amount={amount} remaining={}", health.0));
+        rumble_events.send(RumbleEvent::damage());
+        if health.0 == 0 {
+            died_events.send(PlayerDied {
+                position: transform.translation.truncate(),
+            });
+        }
+    }
+}
+
+fn touch_checkpoints(
+    mut checkpoint: ResMut<LastCheckpoint>,
+    mut toasts: EventWriter<ToastEvent>,
+    player: Query<&Transform, (With<Player>, Without<Checkpoint>)>,
+    checkpoints: Query<(&Checkpoint, &Transform)>,
+) {
+    let Ok(player_transform) = player.get_single() else {
+        return;
+    };
+    for (marker, checkpoint_transform) in &checkpoints {
+        let position = checkpoint_transform.translation.truncate();
+        let touching = (player_transform.translation.truncate() - position).length() < 24.0;
+        if touching && checkpoint.id != Some(marker.id) {
+            checkpoint.id = Some(marker.id);
+            checkpoint.position = position;
+            debug!(id = marker.id, ?position, "checkpoint");
+            event_log::record(format!("checkpoint id={} position={position:?}", marker.id));
+            toasts.send(ToastEvent {
+                text: "Checkpoint reached".to_string(),
+                icon: None,
+                duration: CHECKPOINT_TOAST_SECONDS,
+            });
+        }
+    }
+}
+
+fn start_dying(
+    mut commands: Commands,
+    mut died_events: EventReader<PlayerDied>,
+    mut player: Query<(Entity, &mut Velocity, &mut SpriteAnimation), With<Player>>,
+) {
+    for _ in died_events.read() {
+        let Ok((entity, mut velocity, mut animation)) = player.get_single_mut() else {
+            continue;
+        };
+        // Overlapping a moving platform or sensor when death starts must not
+        // leave the player stuck to it or re-triggering it later; zeroing
+        // velocity and removing Jump is all there is to clear today. Starman
+        // is removed too, per this module's own doc comment.
+        velocity.0 = Vec2::ZERO;
+        animation.play_once = true;
+        commands.entity(entity).remove::<Jump>();
+        commands.entity(entity).remove::<Starman>();
+        commands.entity(entity).insert(Dying::new());
+    }
+}
+
+/// The classic arcade iris-out, centered on where the player died. `None`
+/// action: `tick_dying`'s own respawn-and-checkpoint sequence already
+/// runs on its own timer regardless of this transition, so there's
+/// nothing this needs to trigger — it's purely the visual layered on top.
+fn start_death_transition(
+    mut died_events: EventReader<PlayerDied>,
+    mut transition: ResMut<Transition>,
+    player: Query<&Transform, With<Player>>,
+    camera: Query<(&Camera, &GlobalTransform)>,
+) {
+    for _ in died_events.read() {
+        let Ok(player_transform) = player.get_single() else {
+            continue;
+        };
+        let Ok((camera, camera_transform)) = camera.get_single() else {
+            continue;
+        };
+        let center = world_to_screen(camera, camera_transform, player_transform.translation.truncate())
+            .unwrap_or(Vec2::ZERO);
+        transition.start(TransitionKind::Iris { center }, IRIS_SECONDS, TransitionAction::None);
+    }
+}
+
+fn tick_dying(
+    time: Res<Time>,
+    mut commands: Commands,
+    checkpoint: Res<LastCheckpoint>,
+    owned: Res<PurchasedUpgrades>,
+    assist: Res<DifficultyAssist>,
+    mut fade: Query<&mut BackgroundColor, With<DeathFade>>,
+    mut player: Query<(Entity, &mut Transform, &mut Health, &mut SpriteAnimation, &mut Dying), With<Player>>,
+) {
+    let Ok((entity, mut transform, mut health, mut animation, mut dying)) = player.get_single_mut() else {
+        return;
+    };
+    dying.timer.tick(time.delta());
+    let Ok(mut fade_color) = fade.get_single_mut() else {
+        return;
+    };
+
+    match dying.phase {
+        DeathPhase::FadeOut => {
+            let alpha = dying.timer.fraction();
+            fade_color.0.set_a(alpha);
+            if dying.timer.finished() {
+                transform.translation = checkpoint.position.extend(transform.translation.z);
+                health.0 = RESPAWN_HEALTH + owned.extra_hearts() + assist.extra_hearts();
+                animation.play_once = false;
+                commands.entity(entity).insert(CameraSnap);
+                dying.phase = DeathPhase::Holding;
+                dying.timer = Timer::from_seconds(HOLD_SECONDS, TimerMode::Once);
+            }
+        }
+        DeathPhase::Holding => {
+            if dying.timer.finished() {
+                dying.phase = DeathPhase::FadeIn;
+                dying.timer = Timer::from_seconds(FADE_SECONDS, TimerMode::Once);
+            }
+        }
+        DeathPhase::FadeIn => {
+            let alpha = 1.0 - dying.timer.fraction();
+            fade_color.0.set_a(alpha);
+            if dying.timer.finished() {
+                fade_color.0.set_a(0.0);
+                commands.entity(entity).remove::<Dying>();
+            }
+        }
+    }
+}
+
+/// `cause` is always `"unknown"`: [`PlayerDied`] carries only a position,
+/// not the `DamageKind` of the hit that caused the death.
+#[cfg(feature = "serde")]
+fn record_player_death(
+    mut died_events: EventReader<PlayerDied>,
+    mut session: EventWriter<crate::session_recorder::RecordSessionEvent>,
+) {
+    for event in died_events.read() {
+        session.send(crate::session_recorder::RecordSessionEvent(
+            crate::session_recorder::SessionEvent::Death {
+                x: event.position.x,
+                y: event.position.y,
+                cause: "unknown".to_string(),
+            },
+        ));
+    }
+}
+
+#[cfg(feature = "serde")]
+fn record_damage_taken(
+    mut damage_events: EventReader<DamageEvent>,
+    mut session: EventWriter<crate::session_recorder::RecordSessionEvent>,
+) {
+    for event in damage_events.read() {
+        session.send(crate::session_recorder::RecordSessionEvent(
+            crate::session_recorder::SessionEvent::DamageTaken { amount: event.amount },
+        ));
+    }
+}
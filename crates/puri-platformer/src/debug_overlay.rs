@@ -0,0 +1,251 @@
+//! F3 debug overlay: FPS, a frame-time sparkline, entity count, and three
+//! custom diagnostics instrumented at the source (`collision`'s pairs
+//! tested per frame, `animation`'s currently-active clip count,
+//! `projectile`'s pool-exhaustion counter) plus the player's
+//! transform/velocity/grounded state, plus (when non-empty) the current
+//! locale's `localization::Localization::missing_keys` list — this is the
+//! "missing-key warning list" that module's own doc comment promises a
+//! diagnostics overlay — plus the player's final `equipment::Equipment`-scaled
+//! move speed and fall-damage-per-unit, for tuning gear without doing the
+//! multiplication by hand — plus a metronome grid of the current
+//! `rhythm::MusicClock` beat/bar, the "beat grid" that module's own doc
+//! comment defers here instead of drawing it on the hazards themselves.
+//!
+//! F3 is the single toggle for both this overlay and the hitbox gizmos
+//! `main.rs` draws (see [`DebugOverlayVisible`]) — one flag, one key,
+//! rather than the gizmos having their own separate on/off state.
+//!
+//! The overlay is one `Text` entity with a fixed number of sections,
+//! spawned once at `Startup`. Every frame `update_overlay_text` clears and
+//! rewrites each section's `String` in place with `write!`, reusing its
+//! existing allocation instead of rebuilding the section list (or the
+//! `String`s in it) from scratch.
+
+use std::collections::VecDeque;
+use std::fmt::Write;
+
+use bevy::diagnostic::{
+    Diagnostic, DiagnosticPath, DiagnosticsStore, EntityCountDiagnosticsPlugin, FrameTimeDiagnosticsPlugin,
+};
+use bevy::prelude::*;
+
+use crate::equipment::{Equipment, Stat};
+use crate::fall_damage::FallDamageConfig;
+use crate::localization::Localization;
+use crate::physics_config::PlayerPhysicsConfig;
+use crate::player::{Grounded, Player, Velocity};
+use crate::rhythm::{MusicClock, BEATS_PER_BAR};
+
+/// Reported by `collision::aabb::move_bodies`: how many `overlap_boxes`
+/// narrow-phase tests ran this frame, across every grid-bucketed body.
+pub const COLLISION_PAIRS_TESTED: DiagnosticPath = DiagnosticPath::const_new("collision/pairs_tested");
+
+/// Reported by `animation::animate_sprite`: how many entities currently
+/// have a running (not `AnimationFinished`) clip.
+pub const ACTIVE_ANIMATIONS: DiagnosticPath = DiagnosticPath::const_new("animation/active");
+
+/// Reported by `projectile::report_pool_exhaustion`: the running total from
+/// `pool::EntityPool::exhausted_count` — how many times `acquire` has had
+/// to fall back to an unpooled spawn because the projectile pool was empty.
+/// A running total rather than a per-frame count, so a spike that's already
+/// over by the time someone checks the overlay still shows up as having
+/// happened.
+pub const PROJECTILE_POOL_EXHAUSTED: DiagnosticPath = DiagnosticPath::const_new("pool/projectile_exhausted");
+
+const HISTORY_LEN: usize = 60;
+const SPARKLINE_LEVELS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+/// Frame times at or above this are pegged to the tallest sparkline bar;
+/// past a dropped frame or two there's nothing more useful to show.
+const SPARKLINE_CEILING_MS: f32 = 33.3;
+
+/// Single source of truth for whether the overlay text and hitbox gizmos
+/// are showing. `main.rs`'s `draw_hitbox_gizmos` run condition and this
+/// module's own systems both read it; F3 flips it.
+#[derive(Resource, Default)]
+pub struct DebugOverlayVisible(pub bool);
+
+#[derive(Resource, Default)]
+struct FrameTimeHistory(VecDeque<f32>);
+
+#[derive(Component)]
+pub(crate) struct OverlayText;
+
+pub struct DebugOverlayPlugin;
+
+impl Plugin for DebugOverlayPlugin {
+    fn build(&self, app: &mut App) {
+        // `collision`/`animation` register their own diagnostics (see
+        // `COLLISION_PAIRS_TESTED`/`ACTIVE_ANIMATIONS`) so they still get
+        // reported if either plugin is ever added without this one.
+        app.add_plugins((FrameTimeDiagnosticsPlugin, EntityCountDiagnosticsPlugin))
+            .init_resource::<DebugOverlayVisible>()
+            .init_resource::<FrameTimeHistory>()
+            .add_systems(Startup, spawn_overlay)
+            .add_systems(Update, toggle_overlay)
+            .add_systems(PostUpdate, update_overlay_text.run_if(overlay_visible));
+    }
+}
+
+pub fn overlay_visible(visible: Res<DebugOverlayVisible>) -> bool {
+    visible.0
+}
+
+fn toggle_overlay(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut visible: ResMut<DebugOverlayVisible>,
+    mut text: Query<&mut Visibility, With<OverlayText>>,
+) {
+    if !keys.just_pressed(KeyCode::F3) {
+        return;
+    }
+    visible.0 = !visible.0;
+    for mut node_visibility in &mut text {
+        *node_visibility = if visible.0 { Visibility::Inherited } else { Visibility::Hidden };
+    }
+}
+
+fn spawn_overlay(mut commands: Commands) {
+    commands.spawn((
+        OverlayText,
+        Visibility::Hidden,
+        TextBundle {
+            text: Text::from_sections([
+                TextSection::from_style(TextStyle::default()),
+                TextSection::from_style(TextStyle::default()),
+                TextSection::from_style(TextStyle::default()),
+                TextSection::from_style(TextStyle::default()),
+                TextSection::from_style(TextStyle::default()),
+            ]),
+            style: Style {
+                position_type: PositionType::Absolute,
+                top: Val::Px(8.0),
+                right: Val::Px(8.0),
+                ..default()
+            },
+            ..default()
+        },
+    ));
+}
+
+fn update_overlay_text(
+    diagnostics: Res<DiagnosticsStore>,
+    mut history: ResMut<FrameTimeHistory>,
+    localization: Res<Localization>,
+    physics_config: Res<PlayerPhysicsConfig>,
+    fall_damage_config: Res<FallDamageConfig>,
+    mut overlay: Query<&mut Text, With<OverlayText>>,
+    player: Query<(&Transform, &Velocity, Option<&Grounded>), With<Player>>,
+    player_equipment: Query<&Equipment, With<Player>>,
+    music_clock: Res<MusicClock>,
+) {
+    let Ok(mut text) = overlay.get_single_mut() else {
+        return;
+    };
+
+    let fps = diagnostics
+        .get(&FrameTimeDiagnosticsPlugin::FPS)
+        .and_then(Diagnostic::smoothed)
+        .unwrap_or(0.0);
+    let frame_time_ms = diagnostics
+        .get(&FrameTimeDiagnosticsPlugin::FRAME_TIME)
+        .and_then(Diagnostic::smoothed)
+        .unwrap_or(0.0);
+    let entity_count = diagnostics
+        .get(&EntityCountDiagnosticsPlugin::ENTITY_COUNT)
+        .and_then(Diagnostic::value)
+        .unwrap_or(0.0);
+    let collision_pairs = diagnostics
+        .get(&COLLISION_PAIRS_TESTED)
+        .and_then(Diagnostic::value)
+        .unwrap_or(0.0);
+    let active_animations = diagnostics
+        .get(&ACTIVE_ANIMATIONS)
+        .and_then(Diagnostic::value)
+        .unwrap_or(0.0);
+    let pool_exhausted = diagnostics
+        .get(&PROJECTILE_POOL_EXHAUSTED)
+        .and_then(Diagnostic::value)
+        .unwrap_or(0.0);
+
+    history.0.push_back(frame_time_ms as f32);
+    if history.0.len() > HISTORY_LEN {
+        history.0.pop_front();
+    }
+
+    let sparkline: String = history
+        .0
+        .iter()
+        .map(|&ms| {
+            let level = (ms / SPARKLINE_CEILING_MS).clamp(0.0, 1.0) * (SPARKLINE_LEVELS.len() - 1) as f32;
+            SPARKLINE_LEVELS[level.round() as usize]
+        })
+        .collect();
+
+    let summary = &mut text.sections[0].value;
+    summary.clear();
+    let _ = write!(
+        summary,
+        "FPS: {fps:.0}  frame: {frame_time_ms:.2}ms  entities: {entity_count:.0}\n\
+         collision pairs: {collision_pairs:.0}  active anims: {active_animations:.0}  pool exhausted: {pool_exhausted:.0}\n\
+         {sparkline}",
+    );
+
+    let player_line = &mut text.sections[1].value;
+    player_line.clear();
+    match player.get_single() {
+        Ok((transform, velocity, grounded)) => {
+            let position = transform.translation.truncate();
+            let _ = write!(
+                player_line,
+                "\nplayer pos: ({:.1}, {:.1})  vel: ({:.1}, {:.1})  grounded: {}",
+                position.x,
+                position.y,
+                velocity.0.x,
+                velocity.0.y,
+                grounded.is_some(),
+            );
+        }
+        Err(_) => {
+            let _ = write!(player_line, "\nplayer: none");
+        }
+    }
+
+    let missing = localization.missing_keys();
+    let missing_line = &mut text.sections[2].value;
+    missing_line.clear();
+    if !missing.is_empty() {
+        let _ = write!(
+            missing_line,
+            "\nlocalization ({}) missing keys: {}",
+            localization.locale(),
+            missing.join(", "),
+        );
+    }
+
+    let equipment_line = &mut text.sections[3].value;
+    equipment_line.clear();
+    match player_equipment.get_single() {
+        Ok(equipment) => {
+            let _ = write!(
+                equipment_line,
+                "\nmove speed: {:.1}  fall dmg/unit: {:.3}",
+                equipment.scale(Stat::MoveSpeed, physics_config.move_speed),
+                equipment.scale(Stat::FallDamage, fall_damage_config.damage_per_unit),
+            );
+        }
+        Err(_) => {
+            let _ = write!(equipment_line, "\nequipment: none");
+        }
+    }
+
+    let beat_in_bar = music_clock.beat() % BEATS_PER_BAR;
+    let grid: String = (0..BEATS_PER_BAR).map(|i| if i == beat_in_bar { '●' } else { '○' }).collect();
+    let metronome_line = &mut text.sections[4].value;
+    metronome_line.clear();
+    let _ = write!(
+        metronome_line,
+        "\nbeat: {}  bar: {}  {grid}",
+        music_clock.beat(),
+        music_clock.bar(),
+    );
+}
@@ -0,0 +1,188 @@
+//! Sign/NPC dialogue text boxes, typed out character by character.
+//!
+//! `Sign::pages` entries double as `localization::Localization` keys:
+//! [`advance_typewriter`] and [`handle_dialogue_input`] resolve each page
+//! through it before measuring how long it is, so a translated page's
+//! typewriter timing is driven by the *resolved* string's `.chars().count()`
+//! — Unicode scalar values, not UTF-8 byte length, so accented and other
+//! multi-byte characters each still count as one "typed" character — the
+//! same way it always counted the English literal. See that module's own
+//! doc comment for what `resolve` does when a page has no translation.
+
+use bevy::prelude::*;
+
+use crate::interact::InteractEvent;
+use crate::localization::Localization;
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+pub struct Sign {
+    pub pages: Vec<String>,
+}
+
+/// Characters-per-second for the typewriter effect, tunable per-game.
+#[derive(Resource)]
+pub struct DialogueSettings {
+    pub chars_per_second: f32,
+    pub box_width_chars: usize,
+}
+
+impl Default for DialogueSettings {
+    fn default() -> Self {
+        Self {
+            chars_per_second: 40.0,
+            box_width_chars: 42,
+        }
+    }
+}
+
+#[derive(States, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Dialogue {
+    #[default]
+    Closed,
+    Open,
+}
+
+/// The dialogue currently on screen, and which entity's `Sign` opened it.
+/// `pub(crate)` so `npc` can read [`ActiveDialogue::source`] to turn the
+/// speaking NPC toward the player, and so `testing::TestWorld` can drive it
+/// directly.
+#[derive(Resource)]
+pub(crate) struct ActiveDialogue {
+    pub(crate) source: Entity,
+    pages: Vec<String>,
+    page: usize,
+    shown_chars: f32,
+}
+
+/// `pub(crate)` (rather than private) so `cutscene::cutscene_runner` can
+/// despawn one when a cutscene's dialogue line is skipped mid-typewriter.
+#[derive(Component)]
+pub(crate) struct DialogueBox;
+
+/// Fired the instant a dialogue box closes (its last page was already fully
+/// shown and dismissed), naming the entity whose `Sign` it was. `npc`'s
+/// reward-granting NPCs use this to hand out their reward exactly once,
+/// after the player has actually read to the end rather than the moment
+/// the box opened.
+#[derive(Event)]
+pub struct DialogueClosed {
+    pub source: Entity,
+}
+
+pub struct DialoguePlugin;
+
+impl Plugin for DialoguePlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Sign>()
+            .init_state::<Dialogue>()
+            .init_resource::<DialogueSettings>()
+            .add_event::<DialogueClosed>()
+            .add_systems(Update, open_sign_dialogue)
+            .add_systems(
+                Update,
+                (advance_typewriter, handle_dialogue_input).run_if(in_state(Dialogue::Open)),
+            );
+    }
+}
+
+fn open_sign_dialogue(
+    mut commands: Commands,
+    mut events: EventReader<InteractEvent>,
+    signs: Query<&Sign>,
+    mut next_state: ResMut<NextState<Dialogue>>,
+) {
+    for event in events.read() {
+        let Ok(sign) = signs.get(event.target) else {
+            continue;
+        };
+        commands.insert_resource(ActiveDialogue {
+            source: event.target,
+            pages: wrap_pages(&sign.pages, 42),
+            page: 0,
+            shown_chars: 0.0,
+        });
+        commands.spawn((DialogueBox, NodeBundle::default()));
+        next_state.set(Dialogue::Open);
+    }
+}
+
+fn wrap_pages(pages: &[String], width: usize) -> Vec<String> {
+    pages
+        .iter()
+        .map(|page| {
+            let mut wrapped = String::new();
+            let mut line_len = 0;
+            for word in page.split_whitespace() {
+                if line_len + word.len() + 1 > width {
+                    wrapped.push('\n');
+                    line_len = 0;
+                } else if line_len > 0 {
+                    wrapped.push(' ');
+                    line_len += 1;
+                }
+                wrapped.push_str(word);
+                line_len += word.len();
+            }
+            wrapped
+        })
+        .collect()
+}
+
+fn advance_typewriter(
+    time: Res<Time>,
+    settings: Res<DialogueSettings>,
+    localization: Res<Localization>,
+    mut dialogue: Option<ResMut<ActiveDialogue>>,
+) {
+    let Some(dialogue) = dialogue.as_mut() else {
+        return;
+    };
+    let resolved = localization.resolve(&dialogue.pages[dialogue.page]);
+    let page_len = resolved.chars().count() as f32;
+    dialogue.shown_chars = (dialogue.shown_chars + settings.chars_per_second * time.delta_seconds())
+        .min(page_len);
+}
+
+fn handle_dialogue_input(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    localization: Res<Localization>,
+    mut dialogue: Option<ResMut<ActiveDialogue>>,
+    dialogue_box: Query<Entity, With<DialogueBox>>,
+    mut next_state: ResMut<NextState<Dialogue>>,
+    mut closed: EventWriter<DialogueClosed>,
+) {
+    if !keys.just_pressed(KeyCode::KeyE) && !keys.just_pressed(KeyCode::Space) {
+        return;
+    }
+    let Some(mut dialogue) = dialogue else {
+        return;
+    };
+    let page_len = localization.resolve(&dialogue.pages[dialogue.page]).chars().count() as f32;
+
+    if dialogue.shown_chars < page_len {
+        // Skip straight to the full page on the first press.
+        dialogue.shown_chars = page_len;
+        return;
+    }
+
+    if dialogue.page + 1 < dialogue.pages.len() {
+        dialogue.page += 1;
+        dialogue.shown_chars = 0.0;
+        return;
+    }
+
+    for entity in &dialogue_box {
+        commands.entity(entity).despawn_recursive();
+    }
+    closed.send(DialogueClosed { source: dialogue.source });
+    commands.remove_resource::<ActiveDialogue>();
+    next_state.set(Dialogue::Closed);
+}
+
+/// Whether player-facing gameplay systems should run this frame; a `run_if`
+/// helper so `Playing` systems can pause while a sign is being read.
+pub fn playing_and_not_talking(state: Res<State<Dialogue>>) -> bool {
+    *state.get() == Dialogue::Closed
+}
@@ -0,0 +1,189 @@
+//! Dynamic difficulty assist, offered once the player is dying repeatedly
+//! in the same spot rather than making progress.
+//!
+//! [`detect_death_clustering`] watches `results::LevelStats::death_positions`
+//! (recorded there, not here, so `results` stays the one place that owns
+//! "everything this attempt counted" — see that module's own doc comment)
+//! and looks at the trailing run of deaths within [`CLUSTER_RADIUS`] of
+//! wherever `death::LastCheckpoint` will respawn the player next. Once
+//! [`DEATH_CLUSTER_THRESHOLD`] of those land in a row it fires a
+//! `toast::ToastEvent` offering an assist and never offers again this
+//! attempt (`offered` latches instead of re-checking every death, so
+//! accepting or ignoring it doesn't retrigger the same toast).
+//!
+//! There's no pause menu anywhere in this crate to hang a real checkbox on
+//! — `time_scale`'s own doc comment already covers that gap for bullet
+//! time's F6 toggle. [`TOGGLE_KEY`] is this module's version of the same
+//! one-debug-key fallback, except gated on `DifficultyAssist::offered` so
+//! it does nothing (and isn't advertised) until an assist has actually
+//! been offered.
+//!
+//! Accepting scales down [`Patroller`] walk speed
+//! (`DifficultyAssist::enemy_speed_multiplier`, read by `enemy_ai::patrol`)
+//! and adds a respawn heart (`DifficultyAssist::extra_hearts`, read by
+//! `death::tick_dying` next to `shop`'s own `PurchasedUpgrades::extra_hearts`
+//! for the same `Health` line). "Checkpoint-placed platform over the
+//! failing gap" has no real gap geometry to target — this crate's tilemap
+//! is hand-authored `Transform`+`HitBox` rectangles with no notion of
+//! "the gap the player keeps falling into" (`map`'s own doc comment covers
+//! the same lack of derived level metadata). Instead [`spawn_or_despawn_bridge`]
+//! places a solid platform at the centroid of the clustered death
+//! positions themselves — wherever the player is actually dying is a
+//! reasonable stand-in for "the failing gap" without inventing level
+//! metadata that doesn't exist. It's tagged `map::LevelEntity` so a full
+//! reload cleans it up the same as everything `spawn_map_entities` spawns.
+//!
+//! Accepting also disables medal eligibility: `challenge::finish_on_exit`
+//! reads `DifficultyAssist::accepted` and reports no medal for an
+//! assist-aided run, and `results`'s screen reads it too so a run with an
+//! assist active is flagged there rather than looking like an ordinary
+//! clean clear.
+
+use bevy::prelude::*;
+
+use crate::death::LastCheckpoint;
+use crate::level_reload::LevelReloadRequested;
+use crate::map::LevelEntity;
+use crate::collision::HitBox;
+use crate::render_layer::{z_for, GameLayer};
+use crate::results::LevelStats;
+use crate::schedule::PlatformerSet;
+use crate::toast::ToastEvent;
+
+/// How many of the trailing deaths near the same checkpoint trigger the
+/// offer.
+const DEATH_CLUSTER_THRESHOLD: usize = 3;
+/// How close a death has to land to the checkpoint's respawn position to
+/// count as part of the same cluster, rather than a death somewhere else
+/// entirely on the way back to it.
+const CLUSTER_RADIUS: f32 = 96.0;
+/// Once accepted, every `Patroller` in the level moves at 60% of its
+/// authored speed.
+const ENEMY_SPEED_MULTIPLIER: f32 = 0.6;
+const EXTRA_HEARTS: u32 = 1;
+const BRIDGE_SIZE: Vec2 = Vec2::new(96.0, 16.0);
+const TOAST_SECONDS: f32 = 5.0;
+const TOGGLE_KEY: KeyCode = KeyCode::KeyP;
+
+/// Modifiers the rest of the gameplay code consults, rather than each
+/// system re-deriving "should I go easier right now" from `LevelStats`
+/// itself — mirrors `shop::PurchasedUpgrades` exposing derived getters
+/// instead of callers reading raw purchase counts.
+#[derive(Resource, Default)]
+pub struct DifficultyAssist {
+    /// Latches once the death-cluster threshold is crossed; never clears
+    /// itself back to `false` (only a level reload resets it) so the
+    /// offer toast fires exactly once per struggling spot.
+    pub offered: bool,
+    /// Toggled by `TOGGLE_KEY` once `offered` is `true`.
+    pub accepted: bool,
+    bridge_position: Vec2,
+}
+
+impl DifficultyAssist {
+    pub fn enemy_speed_multiplier(&self) -> f32 {
+        if self.accepted {
+            ENEMY_SPEED_MULTIPLIER
+        } else {
+            1.0
+        }
+    }
+
+    pub fn extra_hearts(&self) -> u32 {
+        if self.accepted {
+            EXTRA_HEARTS
+        } else {
+            0
+        }
+    }
+}
+
+/// The assist platform `spawn_or_despawn_bridge` maintains. `pub(crate)`
+/// rather than private since `testing::TestWorld::assist_bridge_count`
+/// queries for it directly instead of exposing raw ECS access to tests.
+#[derive(Component)]
+pub(crate) struct AssistBridge;
+
+pub struct DifficultyAssistPlugin;
+
+impl Plugin for DifficultyAssistPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DifficultyAssist>().add_systems(
+            Update,
+            (detect_death_clustering, toggle_assist, spawn_or_despawn_bridge, reset_on_reload)
+                .chain()
+                .in_set(PlatformerSet::PostPhysics),
+        );
+    }
+}
+
+fn detect_death_clustering(
+    checkpoint: Res<LastCheckpoint>,
+    stats: Res<LevelStats>,
+    mut assist: ResMut<DifficultyAssist>,
+    mut toasts: EventWriter<ToastEvent>,
+) {
+    if assist.offered {
+        return;
+    }
+
+    let clustered: Vec<Vec2> = stats
+        .death_positions
+        .iter()
+        .rev()
+        .take_while(|position| position.distance(checkpoint.position) < CLUSTER_RADIUS)
+        .copied()
+        .collect();
+    if clustered.len() < DEATH_CLUSTER_THRESHOLD {
+        return;
+    }
+
+    assist.offered = true;
+    assist.bridge_position = clustered.iter().fold(Vec2::ZERO, |sum, position| sum + *position) / clustered.len() as f32;
+    toasts.send(ToastEvent {
+        text: "Struggling here? Press P for an assist.".to_string(),
+        icon: None,
+        duration: TOAST_SECONDS,
+    });
+}
+
+fn toggle_assist(keys: Res<ButtonInput<KeyCode>>, mut assist: ResMut<DifficultyAssist>) {
+    if assist.offered && keys.just_pressed(TOGGLE_KEY) {
+        assist.accepted = !assist.accepted;
+    }
+}
+
+/// Spawns exactly one [`AssistBridge`] while accepted, despawning it the
+/// moment `accepted` goes back to `false` (toggled off, or a reload reset
+/// it) — mirrors `challenge::animate_ghost`'s own spawn-on-presence,
+/// despawn-on-absence handling of `GhostSprite`.
+fn spawn_or_despawn_bridge(mut commands: Commands, assist: Res<DifficultyAssist>, bridge: Query<Entity, With<AssistBridge>>) {
+    if assist.accepted && bridge.is_empty() {
+        commands.spawn((
+            LevelEntity,
+            AssistBridge,
+            SpriteBundle {
+                sprite: Sprite {
+                    color: Color::rgba(0.6, 0.9, 0.6, 0.9),
+                    custom_size: Some(BRIDGE_SIZE),
+                    ..default()
+                },
+                transform: Transform::from_translation(
+                    assist.bridge_position.extend(z_for(GameLayer::Entities, assist.bridge_position.y, true)),
+                ),
+                ..default()
+            },
+            HitBox { size: BRIDGE_SIZE },
+        ));
+    } else if !assist.accepted {
+        for entity in &bridge {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+fn reset_on_reload(mut events: EventReader<LevelReloadRequested>, mut assist: ResMut<DifficultyAssist>) {
+    if events.read().next().is_some() {
+        *assist = DifficultyAssist::default();
+    }
+}
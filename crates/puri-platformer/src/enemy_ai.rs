@@ -0,0 +1,498 @@
+//! Hitstun and launch physics for [`prefab::Patroller`], the crate's one
+//! enemy-shaped prefab (see that type's own doc comment — "No patrol-AI
+//! system reads this yet" — this module is that system, plus what happens
+//! when one gets hit). Gated on `feature = "serde"` the same as `prefab`
+//! itself, since `Patroller` only exists under that feature.
+//!
+//! [`patrol`] walks a `Patroller` back and forth over `range` around the
+//! position it was spawned at (tracked by the lazily-inserted
+//! [`PatrolState`], the same `ensure_*`-on-first-tick pattern
+//! `combo::ensure_combo_component` uses for `ComboCount`, so `Patroller`
+//! itself stays the plain RON-deserializable data component `prefab.rs`
+//! wants it to be) — riding `collision::GroundedBody`'s shared movement
+//! path, the same way `npc::wander` does. [`patrol`]'s walk speed is
+//! scaled by `difficulty_assist::DifficultyAssist::enemy_speed_multiplier`,
+//! the one modifier that module applies directly here rather than through
+//! `Patroller`'s own authored `speed` (see that module's own doc comment
+//! for the rest of what accepting an assist changes).
+//!
+//! [`HitEvent`] carries the damage, launch velocity, and `player::DamageKind`
+//! of a hit; [`apply_hit_events`] scales the damage by the target's
+//! `player::Resistances` first ([`scaled_damage`], shared with
+//! [`spawn_damage_numbers`] so the popup and the actual health change never
+//! disagree), and a hit that scales to zero inserts no [`Hitstun`] at all —
+//! fully resisted, not just quieter. A hit that does land inserts
+//! [`Hitstun`] (scaled to the damage actually taken) and sets `Velocity` to
+//! the launch, both of which [`patrol`] and [`apply_hitstun_gravity`]
+//! respect: a stunned `Patroller` stops walking and falls under this
+//! module's own gravity pull instead of drifting forever, since
+//! `player::apply_gravity` is `With<Player>`-only (see `npc.rs`'s own note
+//! on that same gap) and generalizing it is a separate change from wiring
+//! up hitstun. [`bounce_on_landing`] gives it exactly one damped bounce off
+//! the ground per hit (tracked by [`Bounced`], cleared the next time it's
+//! hit again) before [`tick_hitstun`] lets `patrol` take back over.
+//! [`deal_contact_damage`] lets a still-stunned, still-moving `Patroller`
+//! that lands on or drifts into another one hurt it in turn — a direct
+//! `Health` write, same as `attack.rs`'s training-dummy hit, so neither
+//! carries a `DamageKind` today; the request asks for one on `HitEvent`/
+//! `DamageEvent` specifically, not every `Health` write in the crate.
+//!
+//! [`spawn_damage_numbers`] pops a `floating_text::FloatingText` for every
+//! [`HitEvent`] applied, the same popup `combo::stomp_enemies` shows for a
+//! stomp kill, so a `Patroller` that survives a hit still gets on-screen
+//! feedback for the damage it took.
+//!
+//! [`fly`] and [`swim`] are `Patroller`'s siblings for the other two
+//! `collision::MovementMode`s: [`prefab::Flyer`] patrols back and forth
+//! like a `Patroller` while sine-wave bobbing vertically, diving straight
+//! down once the player is within `dive_range` on the x axis; both ride
+//! `Velocity` through the same `GroundedBody` + `collision::aabb::move_bodies`
+//! sweep `patrol` does, so a diving flyer still stops at a floor tile
+//! instead of clipping through it, it just never gets marked `Grounded`
+//! for it (see `MovementMode`'s own doc comment). [`prefab::Fish`] patrols
+//! the same way but turns around at the edges of whatever `water::Water`
+//! zone it's inside instead of a fixed range, since it isn't meant to
+//! leave the water it was placed in. Neither takes or deals `HitEvent`
+//! damage — that's still `Patroller`-only, the same as `Hitstun`/`Bounced`
+//! and every other component in this module above this point.
+//!
+//! Nothing in this crate fires [`HitEvent`] for a real enemy touch yet —
+//! `attack.rs`'s melee swing only ever hits its own, non-`serde`-gated
+//! training dummy directly via `Health`, and there's no ranged/contact
+//! damage source that touches a `Patroller` today. That's the same
+//! "the reaction system is real, nothing fires the trigger yet" gap this
+//! crate already has for `fall_damage::MovementLockout` and
+//! `boss::Hurtbox`; `tests/enemy_ai.rs` drives it by sending `HitEvent`
+//! directly, same as a real damage source eventually would.
+
+use bevy::prelude::*;
+
+use crate::collision::{GroundedBody, HitBox, MovementMode};
+use crate::difficulty_assist::DifficultyAssist;
+use crate::floating_text::spawn_floating_text;
+use crate::map::{path_direction, Path, PathProgress};
+use crate::physics_config::PlayerPhysicsConfig;
+use crate::player::{DamageKind, Grounded, Health, Player, Resistances, Velocity};
+use crate::prefab::{Fish, Flyer, Patroller};
+use crate::rng::GameRng;
+use crate::schedule::PlatformerSet;
+use crate::time_scale::GameTime;
+use crate::water::Water;
+
+const HITSTUN_SECONDS_PER_DAMAGE: f32 = 0.3;
+const BOUNCE_DAMPING: f32 = 0.5;
+/// Below this speed a "bounce" would just be jitter, so it settles instead.
+const BOUNCE_MIN_SPEED: f32 = 20.0;
+const CONTACT_DAMAGE: u32 = 1;
+const DAMAGE_POPUP_RISE_SPEED: f32 = 40.0;
+const DAMAGE_POPUP_LIFETIME_SECONDS: f32 = 0.6;
+
+#[derive(Event)]
+pub struct HitEvent {
+    pub target: Entity,
+    pub damage: u32,
+    pub launch_velocity: Vec2,
+    pub kind: DamageKind,
+}
+
+/// Disables [`patrol`] for as long as it's running. [`apply_hit_events`]
+/// inserts it scaled to [`HitEvent::damage`]; [`tick_hitstun`] removes it
+/// (and [`Bounced`]) once it finishes.
+#[derive(Component)]
+pub struct Hitstun(pub Timer);
+
+/// Marks a stunned `Patroller` that has already had its one damped bounce
+/// off the ground this hit, so [`bounce_on_landing`] doesn't bounce it
+/// again on every later ground contact before [`Hitstun`] runs out.
+#[derive(Component)]
+struct Bounced;
+
+/// Runtime patrol state a bare `Patroller` gets on its first tick via
+/// [`ensure_patrol_state`] — see this module's own doc comment on why this
+/// isn't just baked into `Patroller` itself.
+#[derive(Component)]
+struct PatrolState {
+    origin: Vec2,
+    direction: f32,
+}
+
+/// [`Flyer`]'s equivalent of [`PatrolState`] — same origin/direction
+/// bookkeeping for the horizontal patrol, plus `elapsed_seconds` for
+/// [`fly`]'s sine-wave bob, which needs a running clock `PatrolState`
+/// never did.
+#[derive(Component)]
+struct FlyState {
+    origin: Vec2,
+    direction: f32,
+    elapsed_seconds: f32,
+}
+
+/// [`Fish`]'s equivalent of [`PatrolState`] — just the direction, since
+/// [`swim`] turns around at its `water::Water` zone's edges rather than a
+/// fixed range around an origin.
+#[derive(Component)]
+struct SwimState {
+    direction: f32,
+}
+
+pub struct EnemyAiPlugin;
+
+impl Plugin for EnemyAiPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<HitEvent>()
+            .add_systems(
+                Update,
+                (
+                    ensure_patrol_state,
+                    ensure_fly_state,
+                    ensure_swim_state,
+                    apply_hit_events,
+                    tick_hitstun,
+                    patrol,
+                    fly,
+                    swim,
+                )
+                    .chain()
+                    .in_set(PlatformerSet::Intent),
+            )
+            .add_systems(Update, apply_hitstun_gravity.in_set(PlatformerSet::Physics))
+            .add_systems(
+                Update,
+                (bounce_on_landing, deal_contact_damage)
+                    .chain()
+                    .in_set(PlatformerSet::PostPhysics),
+            )
+            .add_systems(
+                Update,
+                spawn_damage_numbers
+                    .in_set(PlatformerSet::PostPhysics)
+                    .after(crate::loot::collect_drops),
+            );
+    }
+}
+
+fn ensure_patrol_state(
+    mut commands: Commands,
+    patrollers: Query<(Entity, &Transform), (With<Patroller>, Without<PatrolState>)>,
+) {
+    for (entity, transform) in &patrollers {
+        commands.entity(entity).insert(PatrolState {
+            origin: transform.translation.truncate(),
+            direction: 1.0,
+        });
+    }
+}
+
+/// [`ensure_patrol_state`]'s equivalent for [`Flyer`], also inserting
+/// [`MovementMode::Flying`] on the same first tick so `assets/prefabs/flyer.ron`
+/// doesn't need to author it — see `MovementMode`'s own doc comment on why
+/// that's a safe default to add after the fact.
+fn ensure_fly_state(
+    mut commands: Commands,
+    flyers: Query<(Entity, &Transform), (With<Flyer>, Without<FlyState>)>,
+) {
+    for (entity, transform) in &flyers {
+        commands.entity(entity).insert((
+            FlyState {
+                origin: transform.translation.truncate(),
+                direction: 1.0,
+                elapsed_seconds: 0.0,
+            },
+            MovementMode::Flying,
+        ));
+    }
+}
+
+/// [`ensure_patrol_state`]'s equivalent for [`Fish`], also inserting
+/// [`MovementMode::Swimming`] the same way [`ensure_fly_state`] does for
+/// [`Flyer`].
+fn ensure_swim_state(mut commands: Commands, fish: Query<Entity, (With<Fish>, Without<SwimState>)>) {
+    for entity in &fish {
+        commands
+            .entity(entity)
+            .insert((SwimState { direction: 1.0 }, MovementMode::Swimming));
+    }
+}
+
+/// Damage this `HitEvent` actually deals to `target`, after scaling by its
+/// `Resistances` (absent means full damage, the same `Option<&Resistances>`
+/// default `death::apply_damage` uses for the player side).
+fn scaled_damage(event: &HitEvent, resistances: Option<&Resistances>) -> u32 {
+    let multiplier = resistances.map_or(1.0, |resistances| resistances.multiplier_for(event.kind));
+    (event.damage as f32 * multiplier).round() as u32
+}
+
+/// Applies every queued [`HitEvent`]: damages `target`'s `Health`, sets its
+/// `Velocity` to the launch, and stuns it for [`HITSTUN_SECONDS_PER_DAMAGE`]
+/// per point of damage. Clears a stale [`Bounced`] so this fresh launch
+/// gets its own bounce rather than inheriting a spent one from a previous
+/// hit. `target` excludes `Player` (mirroring `grapple`/`pushable`/`turret`'s
+/// own `Without<Player>` queries) so this query's `Velocity`/`Health` access
+/// stays provably disjoint from `player`'s own `With<Player>`-filtered
+/// writers to the same components, for the ambiguity checker
+/// `tests/schedule_ambiguity.rs` runs.
+fn apply_hit_events(
+    mut commands: Commands,
+    mut events: EventReader<HitEvent>,
+    mut targets: Query<(&mut Velocity, &mut Health, Option<&Resistances>), (With<Patroller>, Without<Player>)>,
+) {
+    for event in events.read() {
+        let Ok((mut velocity, mut health, resistances)) = targets.get_mut(event.target) else {
+            continue;
+        };
+        let damage = scaled_damage(event, resistances);
+        if damage == 0 {
+            continue;
+        }
+        health.0 = health.0.saturating_sub(damage);
+        velocity.0 = event.launch_velocity;
+        commands
+            .entity(event.target)
+            .insert(Hitstun(Timer::from_seconds(
+                HITSTUN_SECONDS_PER_DAMAGE * damage as f32,
+                TimerMode::Once,
+            )))
+            .remove::<Bounced>();
+    }
+}
+
+/// Pops a `floating_text::FloatingText` at `target`'s position for every
+/// [`HitEvent`] applied this frame. Reads its own independent `EventReader`
+/// rather than sharing [`apply_hit_events`]'s (Bevy events support any
+/// number of readers), in the same [`PlatformerSet::PostPhysics`] frame the
+/// event was sent in, so a hit and its popup always land together. Shows
+/// "IMMUNE" instead of a `0` when [`scaled_damage`] zeroes the hit out, the
+/// same way a numeric popup would otherwise read as a dud hit rather than a
+/// resisted one.
+fn spawn_damage_numbers(
+    mut commands: Commands,
+    mut rng: ResMut<GameRng>,
+    mut events: EventReader<HitEvent>,
+    targets: Query<(&Transform, Option<&Resistances>), With<Patroller>>,
+) {
+    for event in events.read() {
+        let Ok((transform, resistances)) = targets.get(event.target) else {
+            continue;
+        };
+        let damage = scaled_damage(event, resistances);
+        let text = if damage == 0 { "IMMUNE".to_string() } else { damage.to_string() };
+        spawn_floating_text(
+            &mut commands,
+            &mut rng,
+            transform.translation.truncate(),
+            text,
+            Color::rgb(1.0, 0.3, 0.2),
+            Vec2::new(0.0, DAMAGE_POPUP_RISE_SPEED),
+            DAMAGE_POPUP_LIFETIME_SECONDS,
+        );
+    }
+}
+
+fn tick_hitstun(
+    time: GameTime,
+    mut commands: Commands,
+    mut stunned: Query<(Entity, &mut Hitstun)>,
+) {
+    for (entity, mut hitstun) in &mut stunned {
+        hitstun.0.tick(time.delta());
+        if hitstun.0.finished() {
+            commands
+                .entity(entity)
+                .remove::<Hitstun>()
+                .remove::<Bounced>();
+        }
+    }
+}
+
+/// Walks a `Patroller` back and forth over `range` around [`PatrolState`]'s
+/// origin, flipping direction at each edge — mirrors `map::MovingPlatform`'s
+/// origin/range bookkeeping, but as a real `Velocity` a `Patroller` with
+/// `GroundedBody` rides through `collision::aabb`'s shared movement sweep,
+/// the same way `npc::wander` does, rather than `MovingPlatform`'s own
+/// direct `Transform` write. A `Patroller` with a `map::Path` attached
+/// (one frame after it's attached — see `map::ensure_path_progress`'s own
+/// doc comment) walks toward `map::path_direction`'s waypoint instead of
+/// bouncing between `range`'s edges, ping-ponging or looping the same way
+/// `move_platform` does; `PatrolState`'s origin/range bookkeeping just
+/// goes unused for as long as `Path` stays attached. Does nothing while
+/// [`Hitstun`]'d.
+fn patrol(
+    assist: Res<DifficultyAssist>,
+    mut patrollers: Query<
+        (
+            &Patroller,
+            &mut PatrolState,
+            &mut Velocity,
+            &Transform,
+            Option<&Path>,
+            Option<&mut PathProgress>,
+        ),
+        (With<GroundedBody>, Without<Hitstun>, Without<Player>),
+    >,
+) {
+    for (patroller, mut state, mut velocity, transform, path, progress) in &mut patrollers {
+        let direction = match (path, progress) {
+            (Some(path), Some(mut progress)) if path.points.len() >= 2 => {
+                path_direction(path, &mut progress, transform.translation.truncate())
+            }
+            _ => {
+                let offset = transform.translation.x - state.origin.x;
+                if offset >= patroller.range {
+                    state.direction = -1.0;
+                } else if offset <= -patroller.range {
+                    state.direction = 1.0;
+                }
+                state.direction
+            }
+        };
+        velocity.0.x = direction * patroller.speed * assist.enemy_speed_multiplier();
+    }
+}
+
+/// Patrols a [`Flyer`] back and forth over `range` the same way [`patrol`]
+/// does for a `Patroller`, sine-wave bobbing its `Velocity.y` around
+/// [`FlyState::origin`] at `bob_amplitude`/`bob_speed` (the wave's own
+/// velocity, `amplitude * bob_speed * cos(phase)`, so `move_bodies`'
+/// ordinary Euler integration approximates the sine position without this
+/// system ever touching `Transform` directly — the same "only ever writes
+/// `Velocity`" shape `patrol`/`npc::wander` already use). Once the player
+/// comes within `dive_range` on the x axis it drops straight down at
+/// `dive_speed` instead, until `move_bodies`' own solid collision (not
+/// this system) stops it at a floor tile — see `MovementMode`'s own doc
+/// comment on why that still applies without a `Grounded` insertion. Does
+/// not read `Path`/`map::MovingPlatform`-style waypoints the way `patrol`
+/// does — nothing has asked a `Flyer` to follow one yet.
+fn fly(
+    time: GameTime,
+    player: Query<&Transform, With<Player>>,
+    mut flyers: Query<(&Flyer, &mut FlyState, &mut Velocity, &Transform)>,
+) {
+    let player_x = player.get_single().ok().map(|transform| transform.translation.x);
+
+    for (flyer, mut state, mut velocity, transform) in &mut flyers {
+        state.elapsed_seconds += time.delta_seconds();
+        let position = transform.translation.truncate();
+
+        if player_x.is_some_and(|player_x| (player_x - position.x).abs() <= flyer.dive_range) {
+            velocity.0 = Vec2::new(0.0, -flyer.dive_speed);
+            continue;
+        }
+
+        let offset = position.x - state.origin.x;
+        if offset >= flyer.range {
+            state.direction = -1.0;
+        } else if offset <= -flyer.range {
+            state.direction = 1.0;
+        }
+
+        let phase = state.elapsed_seconds * flyer.bob_speed;
+        velocity.0 = Vec2::new(
+            state.direction * flyer.speed,
+            flyer.bob_amplitude * flyer.bob_speed * phase.cos(),
+        );
+    }
+}
+
+/// Patrols a [`Fish`] back and forth at `speed`, turning around at the
+/// edges of whichever `water::Water` zone it's currently inside instead of
+/// a fixed range — a `Fish` outside every zone (shouldn't happen for one
+/// placed by `assets/prefabs/fish.ron` inside a pool, but nothing enforces
+/// it) just keeps going in [`SwimState::direction`] until it finds one.
+fn swim(water: Query<(&Transform, &Water)>, mut fish: Query<(&Fish, &mut SwimState, &mut Velocity, &Transform)>) {
+    for (fish, mut state, mut velocity, transform) in &mut fish {
+        let position = transform.translation.truncate();
+        for (water_transform, zone) in &water {
+            // `y_bounds`, not `contains` — an overshooting horizontal step
+            // that lands just past `x_bounds` is exactly the case that
+            // needs to still match this zone so the turn-around below runs.
+            let (bottom, top) = zone.y_bounds(water_transform);
+            if position.y < bottom || position.y > top {
+                continue;
+            }
+            let (left, right) = zone.x_bounds(water_transform);
+            if position.x >= right {
+                state.direction = -1.0;
+            } else if position.x <= left {
+                state.direction = 1.0;
+            }
+            break;
+        }
+        velocity.0.x = state.direction * fish.speed;
+    }
+}
+
+/// This module's own gravity pull for a stunned, airborne `Patroller` — see
+/// this module's own doc comment on why it doesn't ride
+/// `player::apply_gravity` instead.
+fn apply_hitstun_gravity(
+    time: GameTime,
+    config: Res<PlayerPhysicsConfig>,
+    mut stunned: Query<&mut Velocity, (With<Hitstun>, With<GroundedBody>, Without<Player>)>,
+) {
+    for mut velocity in &mut stunned {
+        velocity.0.y =
+            (velocity.0.y - config.gravity * time.delta_seconds()).max(-config.terminal_velocity);
+    }
+}
+
+/// Gives a stunned `Patroller` exactly one damped bounce the instant it
+/// lands, instead of settling immediately or bouncing forever.
+fn bounce_on_landing(
+    mut commands: Commands,
+    mut landed: Query<
+        (Entity, &mut Velocity),
+        (
+            With<Hitstun>,
+            Without<Bounced>,
+            Without<Player>,
+            Added<Grounded>,
+        ),
+    >,
+) {
+    for (entity, mut velocity) in &mut landed {
+        if velocity.0.y.abs() > BOUNCE_MIN_SPEED {
+            velocity.0.y = -velocity.0.y * BOUNCE_DAMPING;
+        } else {
+            velocity.0.y = 0.0;
+        }
+        commands.entity(entity).insert(Bounced);
+    }
+}
+
+/// A still-launched (`Hitstun`'d and actually moving) `Patroller` deals
+/// contact damage to any other, not-currently-stunned `Patroller` its
+/// `HitBox` overlaps — manual AABB overlap, the same inline approach
+/// `combo::stomp_enemies` and `attack::apply_melee_damage` use. Excluding
+/// `Hitstun`'d targets keeps the two queries disjoint (an entity can't be
+/// both `With<Hitstun>` and `Without<Hitstun>`) and, as a side effect,
+/// stops two already-launched `Patroller`s from chain-damaging each other.
+fn deal_contact_damage(
+    launched: Query<
+        (Entity, &Transform, &HitBox, &Velocity),
+        (With<Patroller>, With<Hitstun>, Without<Player>),
+    >,
+    mut targets: Query<
+        (Entity, &Transform, &HitBox, &mut Health),
+        (With<Patroller>, Without<Hitstun>, Without<Player>),
+    >,
+) {
+    for (launched_entity, launched_transform, launched_box, velocity) in &launched {
+        if velocity.0.length_squared() < BOUNCE_MIN_SPEED * BOUNCE_MIN_SPEED {
+            continue;
+        }
+        for (target_entity, target_transform, target_box, mut health) in &mut targets {
+            if target_entity == launched_entity {
+                continue;
+            }
+            let touch_distance = (launched_box.size + target_box.size) / 2.0;
+            let overlapping = (launched_transform.translation.truncate()
+                - target_transform.translation.truncate())
+            .abs()
+            .cmplt(touch_distance)
+            .all();
+            if overlapping {
+                health.0 = health.0.saturating_sub(CONTACT_DAMAGE);
+            }
+        }
+    }
+}
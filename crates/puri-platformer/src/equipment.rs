@@ -0,0 +1,391 @@
+//! Equipment slots: gear bought in the shop fills a [`EquipmentSlot`] on the
+//! player's [`Equipment`] component and modifies a `PlayerPhysicsConfig`- or
+//! `fall_damage::FallDamageConfig`-derived stat through [`Equipment::scale`]
+//! rather than mutating either config directly — the same "modifier
+//! computed at the read site, base resource never mutated" approach
+//! `difficulty_assist::DifficultyAssist::enemy_speed_multiplier` already
+//! uses for `enemy_ai::patrol`'s `Patroller::speed`. `player::player_input`
+//! calls it for [`Stat::MoveSpeed`], `fall_damage::apply_landing_damage`
+//! for [`Stat::FallDamage`].
+//!
+//! There's no pickup entity or prefab for equipment in this crate (no
+//! standalone "gear drop", unlike `loot::LootDrop`'s coin/heal pickups) —
+//! like `shop::CATALOG`'s own `FasterAttack` gap, "filled by pickups" has
+//! nowhere real to plug in yet, so the only source wired up is a shop
+//! purchase, reusing `shop::UpgradeId`/`PurchasedUpgrades` exactly the way
+//! `shop::UpgradeId::SpikeBoots` did for `player::Resistances`. Buying gear
+//! equips it instantly ([`equip_purchased_gear`], the same idempotent
+//! every-frame sync shape as `shop::sync_abilities_with_upgrades`) unless
+//! the slot's already been emptied by [`unequip_on_key`] — a sync-from-owned
+//! system can only ever add, so the "must be removable" half of this
+//! request needs its own explicit un-equip action instead.
+//!
+//! Persistence mirrors `shop::persistence`'s own note: there's no unified
+//! `SaveData` struct in this crate, so the save file is its own
+//! `equipment_save.ron`, restored onto the player entity directly when one
+//! already exists, or queued via `persistence::PendingEquipmentLoad` for
+//! the frame it appears if the load raced `Startup` ahead of
+//! `player::spawn_player` — the same `Added<Player>` catch-up
+//! `shop::sync_health_with_upgrades` uses for `PurchasedUpgrades::extra_hearts`.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use crate::player::Player;
+use crate::schedule::PlatformerSet;
+use crate::shop::{PurchasedUpgrades, UpgradeId};
+
+/// A `PlayerPhysicsConfig`/`fall_damage::FallDamageConfig` value equipment
+/// can scale. Not every config field has a slot to modify it yet — these
+/// are the two the request named (move speed, fall damage).
+#[derive(Reflect, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Stat {
+    MoveSpeed,
+    FallDamage,
+}
+
+/// One modifier a piece of equipment applies to a [`Stat`]. See
+/// [`Equipment::scale`] for how several of these compose.
+#[derive(Clone, Copy, Debug)]
+pub enum ModifierOp {
+    Add(f32),
+    Mul(f32),
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct StatModifier {
+    pub stat: Stat,
+    pub op: ModifierOp,
+}
+
+/// Which body slot a piece of gear occupies. Only one [`EquipmentId`] can be
+/// worn per slot at a time.
+#[derive(Reflect, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EquipmentSlot {
+    Boots,
+    Charm,
+}
+
+#[derive(Reflect, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EquipmentId {
+    SpeedBoots,
+    FallGuardCharm,
+}
+
+pub struct EquipmentInfo {
+    pub id: EquipmentId,
+    pub slot: EquipmentSlot,
+    pub name: &'static str,
+    pub modifiers: &'static [StatModifier],
+    /// Tint for the overlay sprite [`sync_equipment_overlay`] spawns as a
+    /// player child — this crate has no real art for equipment (or
+    /// anything else; see the crate-wide `custom_size: Some(...)`
+    /// placeholder convention `shield::Shield`'s sensor also follows), so a
+    /// colored rectangle is the visual feedback.
+    pub overlay_color: Color,
+}
+
+/// The shop's equipment offering, alongside `shop::CATALOG`'s permanent
+/// upgrades — see this module's own doc comment on why buying is the only
+/// wired-up source.
+pub const CATALOG: &[EquipmentInfo] = &[
+    EquipmentInfo {
+        id: EquipmentId::SpeedBoots,
+        slot: EquipmentSlot::Boots,
+        name: "Speed Boots",
+        modifiers: &[StatModifier {
+            stat: Stat::MoveSpeed,
+            op: ModifierOp::Mul(1.15),
+        }],
+        overlay_color: Color::rgb(0.9, 0.7, 0.2),
+    },
+    EquipmentInfo {
+        id: EquipmentId::FallGuardCharm,
+        slot: EquipmentSlot::Charm,
+        name: "Fall Guard Charm",
+        modifiers: &[StatModifier {
+            stat: Stat::FallDamage,
+            op: ModifierOp::Mul(0.5),
+        }],
+        overlay_color: Color::rgb(0.5, 0.3, 0.8),
+    },
+];
+
+impl EquipmentInfo {
+    fn get(id: EquipmentId) -> &'static EquipmentInfo {
+        CATALOG.iter().find(|info| info.id == id).expect("every EquipmentId is in CATALOG")
+    }
+}
+
+/// What's currently worn, keyed by slot so equipping a second boots item
+/// (there's only one today, but `scale` doesn't assume that) replaces
+/// rather than stacks with the first.
+#[derive(Component, Reflect, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component)]
+pub struct Equipment(HashMap<EquipmentSlot, EquipmentId>);
+
+impl Equipment {
+    pub fn equip(&mut self, id: EquipmentId) {
+        let info = EquipmentInfo::get(id);
+        self.0.insert(info.slot, id);
+    }
+
+    pub fn unequip(&mut self, slot: EquipmentSlot) {
+        self.0.remove(&slot);
+    }
+
+    pub fn equipped(&self, slot: EquipmentSlot) -> Option<EquipmentId> {
+        self.0.get(&slot).copied()
+    }
+
+    /// Scales `base` by every equipped item's modifiers for `stat`:
+    /// additive deltas sum onto `base` first, then every multiplicative
+    /// factor multiplies that sum in turn — additive-then-multiplicative,
+    /// not the other order, so e.g. a future flat `+20 move speed` trinket
+    /// and this module's `+15%` boots both land on top of the same base
+    /// before either scales the other.
+    pub fn scale(&self, stat: Stat, base: f32) -> f32 {
+        let modifiers = self
+            .0
+            .values()
+            .flat_map(|&id| EquipmentInfo::get(id).modifiers.iter())
+            .filter(|modifier| modifier.stat == stat);
+
+        let mut sum = base;
+        let mut multiplier = 1.0;
+        for modifier in modifiers {
+            match modifier.op {
+                ModifierOp::Add(delta) => sum += delta,
+                ModifierOp::Mul(factor) => multiplier *= factor,
+            }
+        }
+        sum * multiplier
+    }
+}
+
+/// The overlay sprite for one worn slot, spawned as a player child by
+/// [`sync_equipment_overlay`]. Mirrors `shield::Shield`'s own child-sensor
+/// shape, minus the sensor/hitbox — this is visual only. The slot is
+/// `pub(crate)` because `testing::TestWorld::equipment_overlay_present`
+/// needs to filter by which slot a given overlay renders.
+#[derive(Component)]
+pub(crate) struct EquipmentOverlay(pub(crate) EquipmentSlot);
+
+const OVERLAY_SIZE: Vec2 = Vec2::new(10.0, 6.0);
+/// Boots sit near the player's feet, the charm near the chest — offsets
+/// against `PlayerBundle::new`'s 24x32 hitbox.
+fn overlay_offset(slot: EquipmentSlot) -> f32 {
+    match slot {
+        EquipmentSlot::Boots => -13.0,
+        EquipmentSlot::Charm => 6.0,
+    }
+}
+
+const BOOTS_UNEQUIP_KEY: KeyCode = KeyCode::Digit1;
+const CHARM_UNEQUIP_KEY: KeyCode = KeyCode::Digit2;
+
+pub struct EquipmentPlugin;
+
+impl Plugin for EquipmentPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Equipment>().add_systems(
+            Update,
+            (equip_purchased_gear, unequip_on_key, sync_equipment_overlay)
+                .chain()
+                .in_set(PlatformerSet::PostPhysics),
+        );
+
+        #[cfg(feature = "serde")]
+        app.add_systems(Startup, persistence::load_from_disk)
+            .add_systems(Update, persistence::apply_pending_load);
+    }
+}
+
+/// Equips gear the instant it's bought, checked every frame like
+/// `shop::sync_abilities_with_upgrades` — but each owned item is equipped
+/// at most once per player entity (remembered in `auto_equipped`), which
+/// is what lets [`unequip_on_key`] actually keep a slot empty instead of
+/// this system refilling it the very next frame. A slot that's already
+/// filled is left as it is.
+fn equip_purchased_gear(
+    owned: Res<PurchasedUpgrades>,
+    mut auto_equipped_for: Local<Option<Entity>>,
+    mut auto_equipped: Local<Vec<UpgradeId>>,
+    mut player: Query<(Entity, &mut Equipment), With<Player>>,
+) {
+    let Ok((entity, mut equipment)) = player.get_single_mut() else {
+        return;
+    };
+    if *auto_equipped_for != Some(entity) {
+        *auto_equipped_for = Some(entity);
+        auto_equipped.clear();
+    }
+    let mut changed = false;
+    if owned.owns(UpgradeId::SpeedBoots) && !auto_equipped.contains(&UpgradeId::SpeedBoots) {
+        auto_equipped.push(UpgradeId::SpeedBoots);
+        if equipment.equipped(EquipmentSlot::Boots).is_none() {
+            equipment.equip(EquipmentId::SpeedBoots);
+            changed = true;
+        }
+    }
+    if owned.owns(UpgradeId::FallGuardCharm) && !auto_equipped.contains(&UpgradeId::FallGuardCharm) {
+        auto_equipped.push(UpgradeId::FallGuardCharm);
+        if equipment.equipped(EquipmentSlot::Charm).is_none() {
+            equipment.equip(EquipmentId::FallGuardCharm);
+            changed = true;
+        }
+    }
+    if changed {
+        #[cfg(feature = "serde")]
+        persistence::save_to_disk(&equipment);
+    }
+}
+
+/// The only way a slot empties again once [`equip_purchased_gear`] has
+/// filled it: pressing the slot's key drops whatever's worn there.
+/// Re-pressing (or buying nothing new) leaves it empty — neither key
+/// re-equips anything.
+fn unequip_on_key(keys: Res<ButtonInput<KeyCode>>, mut player: Query<&mut Equipment, With<Player>>) {
+    let Ok(mut equipment) = player.get_single_mut() else {
+        return;
+    };
+    let mut changed = false;
+    if keys.just_pressed(BOOTS_UNEQUIP_KEY) && equipment.equipped(EquipmentSlot::Boots).is_some() {
+        equipment.unequip(EquipmentSlot::Boots);
+        changed = true;
+    }
+    if keys.just_pressed(CHARM_UNEQUIP_KEY) && equipment.equipped(EquipmentSlot::Charm).is_some() {
+        equipment.unequip(EquipmentSlot::Charm);
+        changed = true;
+    }
+    if changed {
+        #[cfg(feature = "serde")]
+        persistence::save_to_disk(&equipment);
+    }
+}
+
+/// Reconciles each slot's actual child sprite against what [`Equipment`]
+/// says is worn — mirrors `shield::raise_or_drop_shield`'s own
+/// find-existing-marked-child-then-spawn-or-despawn shape, just driven by
+/// `Changed<Equipment>` instead of a held key.
+fn sync_equipment_overlay(
+    mut commands: Commands,
+    player: Query<(Entity, &Equipment, Option<&Children>), (With<Player>, Changed<Equipment>)>,
+    overlays: Query<&EquipmentOverlay>,
+) {
+    for (entity, equipment, children) in &player {
+        let existing: Vec<(Entity, EquipmentSlot)> = children
+            .into_iter()
+            .flatten()
+            .filter_map(|&child| overlays.get(child).ok().map(|overlay| (child, overlay.0)))
+            .collect();
+
+        for slot in [EquipmentSlot::Boots, EquipmentSlot::Charm] {
+            let worn = equipment.equipped(slot);
+            let current = existing.iter().find(|&&(_, s)| s == slot).map(|&(child, _)| child);
+            match (worn, current) {
+                (Some(id), None) => {
+                    let info = EquipmentInfo::get(id);
+                    commands.entity(entity).with_children(|parent| {
+                        parent.spawn((
+                            EquipmentOverlay(slot),
+                            SpriteBundle {
+                                sprite: Sprite {
+                                    color: info.overlay_color,
+                                    custom_size: Some(OVERLAY_SIZE),
+                                    ..default()
+                                },
+                                transform: Transform::from_xyz(0.0, overlay_offset(slot), 0.1),
+                                ..default()
+                            },
+                        ));
+                    });
+                }
+                (None, Some(child)) => {
+                    commands.entity(child).despawn_recursive();
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+mod persistence {
+    use super::Equipment;
+    use crate::player::Player;
+    use bevy::prelude::*;
+    use serde::{Deserialize, Serialize};
+    use std::path::Path;
+
+    const SAVE_PATH: &str = "assets/equipment_save.ron";
+
+    #[derive(Serialize, Deserialize, Default)]
+    struct EquipmentSave {
+        equipped: Equipment,
+    }
+
+    /// One-shot load of `assets/equipment_save.ron`, applied straight onto
+    /// the player entity if one already exists (a level load spawns it in
+    /// `Startup` too, but plugin registration order isn't guaranteed
+    /// against this), or queued via [`PendingEquipmentLoad`] for the next
+    /// frame the player exists.
+    pub fn load_from_disk(mut commands: Commands, mut player: Query<&mut Equipment, With<Player>>) {
+        let Ok(contents) = std::fs::read_to_string(Path::new(SAVE_PATH)) else {
+            return;
+        };
+        match ron::from_str::<EquipmentSave>(&contents) {
+            Ok(loaded) => {
+                if let Ok(mut equipment) = player.get_single_mut() {
+                    *equipment = loaded.equipped;
+                } else {
+                    commands.insert_resource(PendingEquipmentLoad(loaded.equipped));
+                }
+            }
+            Err(err) => warn!("failed to parse {SAVE_PATH}: {err}"),
+        }
+    }
+
+    /// Writes the current loadout to `assets/equipment_save.ron`. Called
+    /// right after `equip`/`unequip` changes it, mirroring
+    /// `shop::persistence::save_to_disk`'s "save immediately on the change"
+    /// timing.
+    pub fn save_to_disk(equipment: &Equipment) {
+        let save = EquipmentSave {
+            equipped: equipment.clone(),
+        };
+        match ron::to_string(&save) {
+            Ok(serialized) => {
+                if let Err(err) = std::fs::write(SAVE_PATH, serialized) {
+                    warn!("failed to write {SAVE_PATH}: {err}");
+                }
+            }
+            Err(err) => warn!("failed to serialize equipment save: {err}"),
+        }
+    }
+
+    /// Holds a load that raced `Startup` ahead of `player::spawn_player` —
+    /// applied onto the player the frame it appears, next to
+    /// `shop::sync_health_with_upgrades`'s own `Added<Player>` catch-up.
+    #[derive(Resource)]
+    pub(crate) struct PendingEquipmentLoad(pub(crate) Equipment);
+
+    pub(crate) fn apply_pending_load(
+        mut commands: Commands,
+        pending: Option<Res<PendingEquipmentLoad>>,
+        mut player: Query<&mut Equipment, Added<Player>>,
+    ) {
+        let Some(pending) = pending else {
+            return;
+        };
+        if let Ok(mut equipment) = player.get_single_mut() {
+            *equipment = pending.0.clone();
+            commands.remove_resource::<PendingEquipmentLoad>();
+        }
+    }
+}
@@ -0,0 +1,63 @@
+//! Ring buffer of the last [`CAPACITY`] gameplay events, dumped to disk by
+//! a panic hook. Reconstructing "what led up to this crash" from a stack
+//! trace alone is guesswork once move/jump/fall/ground-detection ordering
+//! is involved; this keeps a plain-text trail of it around for free.
+//!
+//! Not a Bevy resource: `std::panic::set_hook` runs outside any `App` (it
+//! can fire before `App::run` starts, or on a thread with no `World` at
+//! all), so the buffer is a process-global behind a `Mutex` rather than
+//! something only systems can reach. Gameplay systems call [`record`]
+//! alongside their usual `debug!`/`info!` calls — the two log different
+//! things: `RUST_LOG` filters which events are worth watching live, while
+//! this buffer always keeps the last [`CAPACITY`] regardless of filter, in
+//! case the ones right before a crash weren't being watched.
+
+use std::collections::VecDeque;
+use std::io::Write;
+use std::sync::{Mutex, OnceLock};
+
+const CAPACITY: usize = 200;
+const DUMP_PATH: &str = "event_log_dump.txt";
+
+fn buffer() -> &'static Mutex<VecDeque<String>> {
+    static BUFFER: OnceLock<Mutex<VecDeque<String>>> = OnceLock::new();
+    BUFFER.get_or_init(|| Mutex::new(VecDeque::with_capacity(CAPACITY)))
+}
+
+/// Appends a structured event line, evicting the oldest once full.
+pub fn record(event: impl Into<String>) {
+    let mut buffer = buffer().lock().unwrap();
+    if buffer.len() == CAPACITY {
+        buffer.pop_front();
+    }
+    buffer.push_back(event.into());
+}
+
+/// The events currently held, oldest first. Exposed alongside `record` so
+/// a future debug screen (or a test) can inspect the trail without going
+/// through disk.
+pub fn snapshot() -> Vec<String> {
+    buffer().lock().unwrap().iter().cloned().collect()
+}
+
+/// Replaces the process panic hook with one that runs the previous hook
+/// first (so the usual panic message and backtrace still print), then
+/// dumps the buffer to [`DUMP_PATH`] in the current directory.
+pub fn install_panic_hook() {
+    let previous = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        previous(info);
+        if let Err(err) = dump_to_disk() {
+            eprintln!("event_log: failed to write {DUMP_PATH}: {err}");
+        }
+    }));
+}
+
+fn dump_to_disk() -> std::io::Result<()> {
+    let buffer = buffer().lock().unwrap();
+    let mut file = std::fs::File::create(DUMP_PATH)?;
+    for line in buffer.iter() {
+        writeln!(file, "{line}")?;
+    }
+    Ok(())
+}
@@ -0,0 +1,158 @@
+//! Damage from landing after a long fall.
+//!
+//! There's no ground pound or glide ability on the player yet (only the
+//! boss has a ground-pound move, in `boss`), so this only implements the
+//! exemption we can actually check today: landing inside `Water`.
+//! `prefab::Spring` exists as data but no bounce system reads it, so there
+//! is no spring exemption here either.
+
+use bevy::prelude::*;
+use tracing::info_span;
+
+use crate::collision::HitBox;
+use crate::equipment::{Equipment, Stat};
+use crate::event_log;
+use crate::player::{DamageKind, Grounded, Player};
+use crate::rumble::RumbleEvent;
+use crate::schedule::PlatformerSet;
+use crate::water::Water;
+
+/// Tunable in the editor: how far a fall has to be before it hurts, and how
+/// much damage each unit past that threshold deals.
+#[derive(Resource)]
+pub struct FallDamageConfig {
+    pub threshold: f32,
+    pub damage_per_unit: f32,
+}
+
+impl Default for FallDamageConfig {
+    fn default() -> Self {
+        Self {
+            threshold: 220.0,
+            damage_per_unit: 0.05,
+        }
+    }
+}
+
+#[derive(Event)]
+pub struct DamageEvent {
+    pub amount: u32,
+    /// A hard landing is the player's body meeting the ground, so `Contact`
+    /// is the closest fit of the `DamageKind` variants on offer — there's
+    /// no dedicated `Fall` variant, since nothing asked for fall damage to
+    /// be independently resistible.
+    pub kind: DamageKind,
+}
+
+/// Blocks player input systems for a short window after a heavy landing.
+#[derive(Component)]
+pub struct MovementLockout(pub Timer);
+
+/// Tracks the highest point reached since the player was last grounded, so
+/// fall distance survives multi-bounce sequences (jump, fall, bounce off a
+/// spring-like slope, fall again) rather than resetting every frame.
+#[derive(Component, Default)]
+pub struct FallTracker {
+    peak_y: f32,
+    was_grounded: bool,
+}
+
+pub struct FallDamagePlugin;
+
+impl Plugin for FallDamagePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<FallDamageConfig>()
+            .add_event::<DamageEvent>()
+            .add_systems(
+                Update,
+                (ensure_fall_tracker, track_peak, apply_landing_damage, tick_lockout)
+                    .chain()
+                    .in_set(PlatformerSet::PostPhysics),
+            );
+    }
+}
+
+fn ensure_fall_tracker(
+    mut commands: Commands,
+    player: Query<Entity, (With<Player>, Without<FallTracker>)>,
+) {
+    for entity in &player {
+        commands.entity(entity).insert(FallTracker::default());
+    }
+}
+
+fn track_peak(mut player: Query<(&Transform, &mut FallTracker, Option<&Grounded>), With<Player>>) {
+    for (transform, mut tracker, grounded) in &mut player {
+        if grounded.is_some() {
+            tracker.was_grounded = true;
+            tracker.peak_y = transform.translation.y;
+        } else {
+            tracker.peak_y = tracker.peak_y.max(transform.translation.y);
+        }
+    }
+}
+
+fn apply_landing_damage(
+    mut commands: Commands,
+    config: Res<FallDamageConfig>,
+    water: Query<(&Transform, &HitBox), With<Water>>,
+    mut damage_events: EventWriter<DamageEvent>,
+    mut rumble_events: EventWriter<RumbleEvent>,
+    mut player: Query<
+        (Entity, &Transform, &mut FallTracker, Option<&Grounded>, Option<&Equipment>),
+        With<Player>,
+    >,
+) {
+    let _span = info_span!("apply_landing_damage").entered();
+    for (entity, transform, mut tracker, grounded, equipment) in &mut player {
+        let just_landed = grounded.is_some() && tracker.was_grounded && tracker.peak_y > transform.translation.y;
+        if !just_landed {
+            continue;
+        }
+        let fall_distance = tracker.peak_y - transform.translation.y;
+        tracker.peak_y = transform.translation.y;
+        debug!(?entity, fall_distance, "landed");
+        event_log::record(format!("landed entity={entity:?} fall_distance={fall_distance:.1}"));
+        if fall_distance <= config.threshold {
+            continue;
+        }
+
+        let landed_in_water = water.iter().any(|(water_transform, hitbox)| {
+            (transform.translation.truncate() - water_transform.translation.truncate())
+                .abs()
+                .cmplt(hitbox.size / 2.0)
+                .all()
+        });
+        if landed_in_water {
+            continue;
+        }
+
+        let excess = fall_distance - config.threshold;
+        let amount = (excess * config.damage_per_unit).ceil() as u32;
+        let amount = equipment.map_or(amount, |equipment| equipment.scale(Stat::FallDamage, amount as f32).round() as u32);
+        if amount == 0 {
+            continue;
+        }
+        damage_events.send(DamageEvent {
+            amount,
+            kind: DamageKind::Contact,
+        });
+        rumble_events.send(RumbleEvent::landing());
+        commands
+            .entity(entity)
+            .insert(MovementLockout(Timer::from_seconds(0.4, TimerMode::Once)));
+    }
+}
+
+fn tick_lockout(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut locked: Query<(Entity, &mut MovementLockout)>,
+) {
+    for (entity, mut lockout) in &mut locked {
+        lockout.0.tick(time.delta());
+        if lockout.0.finished() {
+            commands.entity(entity).remove::<MovementLockout>();
+        }
+    }
+}
@@ -0,0 +1,117 @@
+//! Small world-space text that rises and fades, then despawns itself.
+//! [`spawn_floating_text`] takes plain values (no dependency on `combo` or
+//! any other feature module) so any system with a `Commands` and the shared
+//! `rng::GameRng` can spawn one the same way: `combo::stomp_enemies` for
+//! score popups (with an "xN" suffix once a combo is actually multiplying
+//! the score), `loot::collect_drops` for coin values, and
+//! `enemy_ai::spawn_damage_numbers` for `HitEvent` damage taken.
+//!
+//! There's no notion of an extra life anywhere in this crate for a "+1 UP"
+//! popup to attach to — `combo`'s own doc comment already covers why
+//! (checkpoint-and-respawn, not lives/game-over) — so that one popup from
+//! the ask has nothing to wire up to.
+//!
+//! [`enforce_simultaneous_cap`] recycles the oldest [`FloatingText`] (the
+//! one with the least `lifetime` remaining, since every popup starts at a
+//! similar duration) once more than [`MAX_SIMULTANEOUS`] are alive, so a
+//! burst of rapid hits can't accumulate an unbounded pile of text entities.
+//! [`spawn_floating_text`] jitters each spawn's `x` position by a small
+//! seeded-RNG offset for the same reason `loot::spawn_drops` scatters its
+//! velocity: several popups landing on the exact same point read as one
+//! unreadable blob rather than a stack.
+
+use bevy::prelude::*;
+
+use crate::render_layer::{z_for, GameLayer};
+use crate::rng::GameRng;
+use crate::schedule::PlatformerSet;
+use crate::time_scale::GameTime;
+
+/// Highest number of [`FloatingText`] entities alive at once. Comfortably
+/// above what a single combo chain or hit flurry produces in practice, but
+/// still a real ceiling against runaway spawning.
+const MAX_SIMULTANEOUS: usize = 24;
+/// Horizontal jitter range applied by [`spawn_floating_text`], in pixels.
+const JITTER_X: f32 = 6.0;
+
+/// Rises at `velocity` for `lifetime`, fading out linearly over it.
+/// Despawned by [`animate_floating_text`] once `lifetime` finishes, or
+/// earlier by [`enforce_simultaneous_cap`] if it's the oldest past the cap.
+#[derive(Component)]
+pub struct FloatingText {
+    velocity: Vec2,
+    lifetime: Timer,
+}
+
+pub struct FloatingTextPlugin;
+
+impl Plugin for FloatingTextPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (enforce_simultaneous_cap, animate_floating_text)
+                .chain()
+                .in_set(PlatformerSet::PostPhysics),
+        );
+    }
+}
+
+/// Spawns `text` in `color` at `position` (jittered horizontally by
+/// [`JITTER_X`] via `rng` so stacked popups don't overlap), rising at
+/// `velocity` and despawning after `lifetime_seconds`.
+pub fn spawn_floating_text(
+    commands: &mut Commands,
+    rng: &mut GameRng,
+    position: Vec2,
+    text: String,
+    color: Color,
+    velocity: Vec2,
+    lifetime_seconds: f32,
+) -> Entity {
+    let position = position + Vec2::new(rng.range_f32(-JITTER_X, JITTER_X), 0.0);
+    commands
+        .spawn((
+            FloatingText {
+                velocity,
+                lifetime: Timer::from_seconds(lifetime_seconds, TimerMode::Once),
+            },
+            Text2dBundle {
+                text: Text::from_section(text, TextStyle { color, ..default() }),
+                transform: Transform::from_translation(position.extend(z_for(GameLayer::Ui, position.y, false))),
+                ..default()
+            },
+        ))
+        .id()
+}
+
+/// Despawns the oldest [`FloatingText`] entities once more than
+/// [`MAX_SIMULTANEOUS`] are alive — see this module's own doc comment on
+/// why "oldest" is approximated by least `lifetime` remaining.
+fn enforce_simultaneous_cap(mut commands: Commands, texts: Query<(Entity, &FloatingText)>) {
+    let overflow = texts.iter().count().saturating_sub(MAX_SIMULTANEOUS);
+    if overflow == 0 {
+        return;
+    }
+    let mut by_age: Vec<_> = texts.iter().map(|(entity, text)| (entity, text.lifetime.fraction_remaining())).collect();
+    by_age.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+    for (entity, _) in by_age.into_iter().take(overflow) {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+fn animate_floating_text(
+    time: GameTime,
+    mut commands: Commands,
+    mut texts: Query<(Entity, &mut Transform, &mut Text, &mut FloatingText)>,
+) {
+    let dt = time.delta_seconds();
+    for (entity, mut transform, mut text, mut floating) in &mut texts {
+        floating.lifetime.tick(time.delta());
+        transform.translation += (floating.velocity * dt).extend(0.0);
+        text.sections[0].style.color.set_a(floating.lifetime.fraction_remaining());
+
+        if floating.lifetime.finished() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
@@ -0,0 +1,178 @@
+//! Grappling hook: raycast to a `GrapplePoint`, then swing on a
+//! fixed-length constraint until released.
+
+use bevy::prelude::*;
+
+use crate::collision::HitBox;
+use crate::player::{Facing, Player, Velocity};
+
+#[derive(Component)]
+pub struct GrapplePoint;
+
+#[derive(Component)]
+pub struct Grappled {
+    pub anchor: Entity,
+    pub length: f32,
+    pub angle: f32,
+    pub angular_velocity: f32,
+}
+
+const GRAPPLE_RANGE: f32 = 220.0;
+const GRAVITY: f32 = 420.0;
+
+pub struct GrapplePlugin;
+
+impl Plugin for GrapplePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (fire_grapple, swing_on_grapple, release_grapple, draw_rope).chain(),
+        );
+        #[cfg(feature = "serde")]
+        app.add_systems(Update, record_grapple_usage);
+    }
+}
+
+fn fire_grapple(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    player: Query<(Entity, &Transform, &Facing), (With<Player>, Without<Grappled>)>,
+    points: Query<(Entity, &Transform), With<GrapplePoint>>,
+    solids: Query<(&Transform, &HitBox), Without<Player>>,
+) {
+    if !keys.just_pressed(KeyCode::KeyF) {
+        return;
+    }
+    let Ok((entity, transform, facing)) = player.get_single() else {
+        return;
+    };
+    let dir = match facing {
+        Facing::Right => 1.0,
+        Facing::Left => -1.0,
+    };
+    let player_pos = transform.translation.truncate();
+
+    let Some((anchor, anchor_pos)) = points
+        .iter()
+        .filter(|(_, anchor_transform)| {
+            let to_anchor = anchor_transform.translation.truncate() - player_pos;
+            to_anchor.length() <= GRAPPLE_RANGE && to_anchor.x.signum() == dir
+        })
+        .min_by(|(_, a), (_, b)| {
+            a.translation
+                .truncate()
+                .distance(player_pos)
+                .partial_cmp(&b.translation.truncate().distance(player_pos))
+                .unwrap()
+        })
+        .map(|(entity, transform)| (entity, transform.translation.truncate()))
+    else {
+        return;
+    };
+
+    if segment_blocked(player_pos, anchor_pos, &solids) {
+        return;
+    }
+
+    let to_anchor = anchor_pos - player_pos;
+    commands.entity(entity).insert(Grappled {
+        anchor,
+        length: to_anchor.length(),
+        angle: to_anchor.y.atan2(to_anchor.x),
+        angular_velocity: 0.0,
+    });
+}
+
+fn segment_blocked(
+    from: Vec2,
+    to: Vec2,
+    solids: &Query<(&Transform, &HitBox), Without<Player>>,
+) -> bool {
+    // Coarse sampling along the segment rather than a proper raycast, since
+    // we don't have a dedicated physics backend to query for the default build.
+    let steps = 16;
+    for i in 1..steps {
+        let t = i as f32 / steps as f32;
+        let point = from.lerp(to, t);
+        for (transform, hitbox) in solids {
+            let half = hitbox.size / 2.0;
+            let local = point - transform.translation.truncate();
+            if local.x.abs() < half.x && local.y.abs() < half.y {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+fn swing_on_grapple(
+    time: Res<Time>,
+    mut player: Query<(&mut Transform, &mut Velocity, &mut Grappled), With<Player>>,
+    anchors: Query<&Transform, (With<GrapplePoint>, Without<Player>)>,
+) {
+    for (mut transform, mut velocity, mut grapple) in &mut player {
+        let Ok(anchor_transform) = anchors.get(grapple.anchor) else {
+            continue;
+        };
+        let anchor = anchor_transform.translation.truncate();
+
+        // Pendulum: tangential acceleration from gravity's component along
+        // the arc, integrated into angular velocity and then position.
+        let angular_accel = -GRAVITY * grapple.angle.cos() / grapple.length;
+        grapple.angular_velocity += angular_accel * time.delta_seconds();
+        grapple.angle += grapple.angular_velocity * time.delta_seconds();
+
+        let new_pos = anchor + Vec2::new(grapple.angle.cos(), grapple.angle.sin()) * grapple.length;
+        let old_pos = transform.translation.truncate();
+        velocity.0 = (new_pos - old_pos) / time.delta_seconds().max(1e-6);
+        transform.translation = new_pos.extend(transform.translation.z);
+    }
+}
+
+fn release_grapple(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    player: Query<(Entity, Ref<Grappled>), With<Player>>,
+) {
+    if !keys.just_pressed(KeyCode::KeyF) {
+        return;
+    }
+    for (entity, grapple) in &player {
+        // The same press that just attached this grapple is not a release.
+        if grapple.is_added() {
+            continue;
+        }
+        // Releasing preserves the swing's current velocity as launch speed;
+        // Velocity was already updated to the tangential speed this frame.
+        commands.entity(entity).remove::<Grappled>();
+    }
+}
+
+fn draw_rope(mut gizmos: Gizmos, player: Query<(&Transform, &Grappled)>, anchors: Query<&Transform, With<GrapplePoint>>) {
+    for (transform, grapple) in &player {
+        if let Ok(anchor_transform) = anchors.get(grapple.anchor) {
+            gizmos.line_2d(
+                transform.translation.truncate(),
+                anchor_transform.translation.truncate(),
+                Color::WHITE,
+            );
+        }
+    }
+}
+
+/// Mirrors `speedrun_overlay`'s own citation: an independent consumer of
+/// [`Grappled`] rather than [`fire_grapple`] needing to know session
+/// recording exists.
+#[cfg(feature = "serde")]
+fn record_grapple_usage(
+    grappled: Query<(), Added<Grappled>>,
+    mut session: EventWriter<crate::session_recorder::RecordSessionEvent>,
+) {
+    for () in &grappled {
+        session.send(crate::session_recorder::RecordSessionEvent(
+            crate::session_recorder::SessionEvent::AbilityUsed {
+                ability: "grapple".to_string(),
+            },
+        ));
+    }
+}
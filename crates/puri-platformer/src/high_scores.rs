@@ -0,0 +1,111 @@
+//! Persisted high score table.
+//!
+//! This only covers the data side — the table itself, its ordering/
+//! qualification rules, and (with the `serde` feature) loading and saving
+//! it to `assets/high_scores.ron`, the same one-shot ron pattern
+//! `physics_config` uses for hot-reloadable config. There's no `Score`
+//! resource, `GameOver` state, or name-entry screen in this game: the
+//! platformer is checkpoint-and-respawn (see `death.rs`), not lives-and-
+//! game-over, so there's nothing today that would ever call
+//! [`HighScores::try_insert`]. Wiring a scored mode up to this — the entry
+//! screen, main-menu table display, and gamepad navigation the original
+//! ask describes — is UI/gameplay work for whichever cartridge actually
+//! has a score to submit, and is deferred rather than built against a
+//! game loop that doesn't exist.
+
+use bevy::prelude::*;
+
+const MAX_ENTRIES: usize = 10;
+
+#[derive(Reflect, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScoreEntry {
+    pub name: String,
+    pub score: u32,
+    pub date: String,
+}
+
+/// Top [`MAX_ENTRIES`] scores, highest first. Persisted to the one fixed
+/// path `assets/high_scores.ron`; neither this resource nor
+/// [`HighScoresPlugin`] keeps a separate table per cartridge.
+#[derive(Resource, Reflect, Clone, Default)]
+#[reflect(Resource)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HighScores(Vec<ScoreEntry>);
+
+impl HighScores {
+    pub fn entries(&self) -> &[ScoreEntry] {
+        &self.0
+    }
+
+    /// Whether `score` would make the table, i.e. there's a free slot or it
+    /// beats the current lowest entry.
+    pub fn qualifies(&self, score: u32) -> bool {
+        self.0.len() < MAX_ENTRIES || self.0.last().is_some_and(|lowest| score > lowest.score)
+    }
+
+    /// Inserts `entry` if it [`qualifies`](Self::qualifies), keeping the
+    /// table sorted highest-first and capped at [`MAX_ENTRIES`]. Ties keep
+    /// the existing (older) entry above the new one — `entry` is placed
+    /// after every entry with an equal or higher score, never before one.
+    /// Returns whether it was inserted.
+    pub fn try_insert(&mut self, entry: ScoreEntry) -> bool {
+        if !self.qualifies(entry.score) {
+            return false;
+        }
+        let position = self.0.iter().position(|existing| existing.score < entry.score).unwrap_or(self.0.len());
+        self.0.insert(position, entry);
+        self.0.truncate(MAX_ENTRIES);
+        true
+    }
+}
+
+pub struct HighScoresPlugin;
+
+impl Plugin for HighScoresPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<HighScores>().init_resource::<HighScores>();
+
+        #[cfg(feature = "serde")]
+        app.add_systems(Startup, persistence::load_from_disk);
+    }
+}
+
+#[cfg(feature = "serde")]
+mod persistence {
+    use super::HighScores;
+    use bevy::prelude::*;
+    use std::path::Path;
+
+    const SAVE_PATH: &str = "assets/high_scores.ron";
+
+    /// One-shot load of `assets/high_scores.ron` over the (empty) default
+    /// table, if present. Mirrors `physics_config::hot_reload::load_from_disk`.
+    pub fn load_from_disk(mut scores: ResMut<HighScores>) {
+        let Ok(contents) = std::fs::read_to_string(Path::new(SAVE_PATH)) else {
+            return;
+        };
+        match ron::from_str::<HighScores>(&contents) {
+            Ok(loaded) => *scores = loaded,
+            Err(err) => warn!("failed to parse {SAVE_PATH}: {err}"),
+        }
+    }
+
+    /// Writes the current table to `assets/high_scores.ron`. Call after
+    /// [`HighScores::try_insert`] returns `true` so a new qualifying score
+    /// survives the cartridge teardown/relaunch cycle in the launcher (and
+    /// the process exiting entirely).
+    pub fn save_to_disk(scores: &HighScores) {
+        match ron::to_string(scores) {
+            Ok(serialized) => {
+                if let Err(err) = std::fs::write(SAVE_PATH, serialized) {
+                    warn!("failed to write {SAVE_PATH}: {err}");
+                }
+            }
+            Err(err) => warn!("failed to serialize high scores: {err}"),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+pub use persistence::save_to_disk;
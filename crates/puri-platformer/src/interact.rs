@@ -0,0 +1,110 @@
+//! Generic interaction: sensors carrying a prompt that the player can
+//! trigger with the `Interact` action, regardless of what consumes it
+//! (NPCs, levers, signs, doors, ...).
+
+use bevy::prelude::*;
+
+use crate::collision::HitBox;
+use crate::player::Player;
+use crate::prompt::{InputIcons, LastUsedDevice, PromptAction, PromptText};
+use crate::schedule::PlatformerSet;
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+pub struct Interactable {
+    pub prompt: String,
+}
+
+#[derive(Event)]
+pub struct InteractEvent {
+    pub target: Entity,
+}
+
+/// `pub(crate)` (rather than private) purely so `testing::TestWorld` can
+/// query the prompt's rendered `Text` directly instead of exposing raw ECS
+/// access to tests.
+#[derive(Component)]
+pub(crate) struct InteractPrompt;
+
+pub struct InteractPlugin;
+
+impl Plugin for InteractPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Interactable>()
+            .add_event::<InteractEvent>()
+            .add_systems(
+                Update,
+                (update_prompt, emit_interact_event).in_set(PlatformerSet::Intent),
+            );
+    }
+}
+
+const INTERACT_RANGE: f32 = 40.0;
+
+fn nearest_interactable(
+    player_pos: Vec2,
+    interactables: &Query<(Entity, &Transform, &HitBox), With<Interactable>>,
+) -> Option<Entity> {
+    interactables
+        .iter()
+        .map(|(entity, transform, _)| (entity, transform.translation.truncate().distance(player_pos)))
+        .filter(|(_, distance)| *distance <= INTERACT_RANGE)
+        .min_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap())
+        .map(|(entity, _)| entity)
+}
+
+fn update_prompt(
+    mut commands: Commands,
+    icons: Res<InputIcons>,
+    device: Res<LastUsedDevice>,
+    player: Query<&Transform, With<Player>>,
+    interactables: Query<(Entity, &Transform, &HitBox), With<Interactable>>,
+    existing_prompt: Query<Entity, With<InteractPrompt>>,
+) {
+    let Ok(player_transform) = player.get_single() else {
+        return;
+    };
+    let nearest = nearest_interactable(player_transform.translation.truncate(), &interactables);
+
+    for entity in &existing_prompt {
+        commands.entity(entity).despawn_recursive();
+    }
+
+    if let Some(target) = nearest {
+        if let Ok((_, transform, hitbox)) = interactables.get(target) {
+            commands.spawn((
+                InteractPrompt,
+                PromptText { action: PromptAction::Interact, verb: None },
+                Text2dBundle {
+                    text: Text::from_section(icons.label(PromptAction::Interact, device.0), TextStyle::default()),
+                    transform: Transform::from_translation(
+                        transform.translation + Vec3::new(0.0, hitbox.size.y / 2.0 + 12.0, 2.0),
+                    ),
+                    ..default()
+                },
+            ));
+        }
+    }
+}
+
+fn emit_interact_event(
+    keys: Res<ButtonInput<KeyCode>>,
+    gamepads: Res<Gamepads>,
+    gamepad_buttons: Res<ButtonInput<GamepadButton>>,
+    player: Query<&Transform, With<Player>>,
+    interactables: Query<(Entity, &Transform, &HitBox), With<Interactable>>,
+    mut events: EventWriter<InteractEvent>,
+) {
+    let pressed_gamepad_y = gamepads.iter().any(|pad| {
+        gamepad_buttons.just_pressed(GamepadButton::new(pad, GamepadButtonType::North))
+    });
+    if !keys.just_pressed(KeyCode::KeyE) && !pressed_gamepad_y {
+        return;
+    }
+    let Ok(player_transform) = player.get_single() else {
+        return;
+    };
+    if let Some(target) = nearest_interactable(player_transform.translation.truncate(), &interactables) {
+        events.send(InteractEvent { target });
+    }
+}
@@ -0,0 +1,90 @@
+//! Command-line options, parsed once in `main` and inserted as a resource
+//! so any plugin can read the player's launch-time choices. Parsing is a
+//! pure function over an argument iterator (rather than reading
+//! `std::env::args()` itself) so `main` can print usage and exit nonzero on
+//! a bad flag instead of this module doing process control.
+
+use std::path::PathBuf;
+
+use bevy::prelude::Resource;
+
+#[derive(Resource, Clone, Debug, Default, PartialEq)]
+pub struct LaunchOptions {
+    /// Loads this level file instead of `map`'s built-in demo layout.
+    /// Nothing reads this yet — there's no external level file format in
+    /// this codebase, only the hard-coded `setup_map` — so for now it's
+    /// just threaded through for the next request that adds one.
+    pub level: Option<PathBuf>,
+    /// Seeds `GameRng` instead of the wall clock.
+    pub seed: Option<u64>,
+    /// Intended to drive an input recorder once one exists; unused today.
+    pub record: Option<PathBuf>,
+    /// Intended to drive an input player once one exists; unused today.
+    pub replay: Option<PathBuf>,
+    /// Run `MinimalPlugins` instead of `DefaultPlugins` — no window, no
+    /// renderer, no audio — for CI smoke tests.
+    pub headless: bool,
+    /// Draws hitbox gizmos even outside the `editor` feature.
+    pub debug_draw: bool,
+    /// Writes a chrome://tracing-compatible trace of the run to this file.
+    /// Only takes effect when built with the `trace` feature — without it
+    /// the flag is still accepted (so scripts don't have to know which
+    /// build they're running) but `main` just warns and ignores it.
+    pub trace: Option<PathBuf>,
+    /// Appends `session_recorder::SessionEvent`s as JSON lines to this file
+    /// for the length of the run. Only takes effect with the `serde`
+    /// feature — same accept-but-warn fallback as `trace` above.
+    pub analytics: Option<PathBuf>,
+    /// Prints aggregate stats from a file `analytics` previously wrote,
+    /// then exits without launching the game. Same `serde`-feature
+    /// fallback as `analytics` above.
+    pub analyze: Option<PathBuf>,
+}
+
+pub const USAGE: &str = "\
+Usage: puri_platformer [OPTIONS]
+
+Options:
+  --level <PATH>    Load a level file instead of the built-in demo map
+  --seed <N>        Seed the deterministic RNG with N instead of the wall clock
+  --record <PATH>   Record input to PATH (not yet implemented)
+  --replay <PATH>   Replay input from PATH (not yet implemented)
+  --headless        Run without a window or renderer, for CI smoke tests
+  --debug-draw      Draw hitbox gizmos outside the editor build
+  --trace <PATH>    Write a chrome://tracing trace of the run to PATH (needs the `trace` feature)
+  --analytics <PATH>  Append session analytics as JSON lines to PATH (needs the `serde` feature)
+  --analyze <PATH>    Print aggregate stats from a PATH written by --analytics, then exit (needs the `serde` feature)
+  -h, --help        Print this message";
+
+impl LaunchOptions {
+    /// Parses `args` (excluding argv[0]). Returns the offending flag or
+    /// value on failure so the caller can print [`USAGE`] and exit nonzero
+    /// rather than this panicking mid-parse.
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut options = Self::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--level" => options.level = Some(PathBuf::from(next_value(&mut args, "--level")?)),
+                "--seed" => {
+                    let value = next_value(&mut args, "--seed")?;
+                    options.seed = Some(value.parse().map_err(|_| format!("--seed: not a valid u64: {value}"))?);
+                }
+                "--record" => options.record = Some(PathBuf::from(next_value(&mut args, "--record")?)),
+                "--replay" => options.replay = Some(PathBuf::from(next_value(&mut args, "--replay")?)),
+                "--headless" => options.headless = true,
+                "--debug-draw" => options.debug_draw = true,
+                "--trace" => options.trace = Some(PathBuf::from(next_value(&mut args, "--trace")?)),
+                "--analytics" => options.analytics = Some(PathBuf::from(next_value(&mut args, "--analytics")?)),
+                "--analyze" => options.analyze = Some(PathBuf::from(next_value(&mut args, "--analyze")?)),
+                "-h" | "--help" => return Err(USAGE.to_string()),
+                other => return Err(format!("unrecognized argument: {other}")),
+            }
+        }
+        Ok(options)
+    }
+}
+
+fn next_value(args: &mut impl Iterator<Item = String>, flag: &str) -> Result<String, String> {
+    args.next().ok_or_else(|| format!("{flag} requires a value"))
+}
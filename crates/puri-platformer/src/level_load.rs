@@ -0,0 +1,287 @@
+//! Async, staged loading for the one real level swap this crate has:
+//! choosing an unlocked entry in `level_select`. [`LevelLoadState::Loading`]
+//! runs a four-stage pipeline (parse, textures, colliders, spawn) driven by
+//! an `AsyncComputeTaskPool` task and polled every frame by
+//! [`poll_parse_task`], showing [`LoadProgress`] as text until
+//! [`spawn_level`] hands back to [`LevelLoadState::Idle`] (this crate's
+//! stand-in for "Playing" — see this module's own note below on why
+//! there's no separate state for that).
+//!
+//! Two of the four stages are honest pass-throughs rather than real
+//! background work, advanced the instant [`poll_parse_task`] sees them:
+//! - **Textures**: nothing in this crate loads a per-level texture atlas —
+//!   the player/NPC sheets `animation::Animations` resolves are whole-game
+//!   assets loaded once at `Startup`, not per-level, and every other sprite
+//!   `map::spawn_map_entities` spawns is a plain `Color`.
+//! - **Colliders**: `spawn_map_entities` gives each solid its own `HitBox`
+//!   already; there's no per-tile mesh to merge the way `tilemap`'s chunk
+//!   builder merges *visual* geometry (see that module's own doc comment),
+//!   and the real per-frame broad-phase cost is
+//!   `collision::spatial_grid::SpatialGrid` rebuilding at query time, already
+//!   covered by its own bench.
+//!
+//! **Parse** is real: [`start_level_load`] reads `level_reload::LEVEL_PATH`
+//! off the main thread via `AsyncComputeTaskPool`, the same file that module
+//! already names but (per its own note) never actually parses into
+//! entities — this just proves out the off-thread I/O shape for whenever a
+//! real format lands.
+//!
+//! **Spawn** is real too, at the one granularity this crate can offer:
+//! [`spawn_level`] calls `map::spawn_map_entities` directly, a single
+//! budget-of-one "batch" — there's no way to split it into `&mut Commands`
+//! closures without also capturing `Res<Palette>`/`Res<WorldFlags>` by
+//! value, which would just duplicate the read [`spawn_level`] already does
+//! directly. The generic, budgeted [`SpawnQueue`] this ask calls for is
+//! still real and tested (see `tests/level_load.rs`), ready for whenever
+//! this crate's placement data is fine-grained enough (e.g. one
+//! `prefab::spawn_prefab` job per placement) to actually need spreading
+//! across frames — the same "generic, no second caller yet" shape as
+//! `pool::EntityPool`'s own note.
+//!
+//! There's no main-menu/gameplay split anywhere in this crate for a real
+//! "Playing" app-state to slot into (`level_select`'s own doc comment
+//! already covers that gap) — [`LevelLoadState::Idle`] doubles as "not
+//! currently loading a level" the same way `LevelSelect::Closed` doubles as
+//! "back in gameplay" today. Cancelling mid-load reuses the level-select
+//! screen's own Escape key ([`cancel_level_load`]); because the old
+//! `LevelEntity` set is only despawned inside [`spawn_level`] itself (right
+//! before the new one is spawned), cancelling during `Parse` leaves the
+//! level exactly as it was — there's nothing to roll back.
+
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+use bevy::tasks::{block_on, poll_once, AsyncComputeTaskPool, Task};
+
+use crate::accessibility::Palette;
+use crate::level_reload::LEVEL_PATH;
+use crate::map::{despawn_level, spawn_map_entities, LevelEntity};
+use crate::world_flags::WorldFlags;
+
+#[derive(States, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum LevelLoadState {
+    #[default]
+    Idle,
+    Loading,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoadStage {
+    Parse,
+    Textures,
+    Colliders,
+    Spawn,
+}
+
+/// Drives the loading-screen text. `spawned`/`total` only ever move during
+/// [`LoadStage::Spawn`] — see [`SpawnQueue`]'s own doc comment.
+#[derive(Resource)]
+pub struct LoadProgress {
+    pub stage: LoadStage,
+    pub spawned: usize,
+    pub total: usize,
+}
+
+impl LoadProgress {
+    /// `0.0` at the start of [`LoadStage::Parse`] to `1.0` once
+    /// [`LoadStage::Spawn`] finishes, treating the four stages as equal
+    /// quarters (this crate has no per-stage timing data to weight them
+    /// by).
+    pub fn fraction(&self) -> f32 {
+        let stage_index = match self.stage {
+            LoadStage::Parse => 0,
+            LoadStage::Textures => 1,
+            LoadStage::Colliders => 2,
+            LoadStage::Spawn => 3,
+        } as f32;
+        let stage_fraction = if self.total == 0 { 1.0 } else { self.spawned as f32 / self.total as f32 };
+        (stage_index + stage_fraction) / 4.0
+    }
+
+    fn stage_name(&self) -> &'static str {
+        match self.stage {
+            LoadStage::Parse => "parsing",
+            LoadStage::Textures => "textures",
+            LoadStage::Colliders => "colliders",
+            LoadStage::Spawn => "spawning",
+        }
+    }
+}
+
+/// Fired by `level_select::select_highlighted` when the player confirms an
+/// unlocked entry. `pub(crate)` so that module can request a load without
+/// this one needing to know the level-select UI exists.
+#[derive(Event)]
+pub(crate) struct LevelLoadRequested {
+    #[allow(dead_code)] // Only one level exists to load — see this module's own doc comment.
+    pub id: u32,
+}
+
+/// The bytes `start_level_load`'s background task reads from
+/// `level_reload::LEVEL_PATH`. Unused beyond proving the off-thread read —
+/// see this module's own doc comment on the `Parse` stage.
+#[allow(dead_code)]
+struct ParsedLevel(String);
+
+#[derive(Resource)]
+struct PendingParse(Task<ParsedLevel>);
+
+/// One deferred unit of spawn work. `Send + Sync` so it can be built inside
+/// a system and stored in [`SpawnQueue`] across frames.
+type SpawnJob = Box<dyn FnOnce(&mut Commands) + Send + Sync>;
+
+/// Generic, budgeted spawn queue — see this module's own doc comment on
+/// why nothing in this crate enqueues more than one job onto it today.
+#[derive(Resource, Default)]
+pub struct SpawnQueue(VecDeque<SpawnJob>);
+
+impl SpawnQueue {
+    pub fn push(&mut self, job: impl FnOnce(&mut Commands) + Send + Sync + 'static) {
+        self.0.push_back(Box::new(job));
+    }
+
+    /// Runs at most `budget` queued jobs against `commands`, returning how
+    /// many actually ran.
+    pub fn drain_budgeted(&mut self, commands: &mut Commands, budget: usize) -> usize {
+        let mut ran = 0;
+        while ran < budget {
+            let Some(job) = self.0.pop_front() else {
+                break;
+            };
+            job(commands);
+            ran += 1;
+        }
+        ran
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+#[derive(Component)]
+struct LoadingText;
+
+pub struct LevelLoadPlugin;
+
+impl Plugin for LevelLoadPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_state::<LevelLoadState>()
+            .init_resource::<SpawnQueue>()
+            .add_event::<LevelLoadRequested>()
+            .add_systems(Update, start_level_load.run_if(in_state(LevelLoadState::Idle)))
+            .add_systems(
+                Update,
+                (poll_parse_task, spawn_level, update_loading_text, cancel_level_load)
+                    .chain()
+                    .run_if(in_state(LevelLoadState::Loading)),
+            );
+    }
+}
+
+fn start_level_load(
+    mut commands: Commands,
+    mut requests: EventReader<LevelLoadRequested>,
+    mut next_state: ResMut<NextState<LevelLoadState>>,
+) {
+    if requests.read().count() == 0 {
+        return;
+    }
+
+    let pool = AsyncComputeTaskPool::get();
+    let task = pool.spawn(async move { ParsedLevel(std::fs::read_to_string(LEVEL_PATH).unwrap_or_default()) });
+    commands.insert_resource(PendingParse(task));
+    commands.insert_resource(LoadProgress {
+        stage: LoadStage::Parse,
+        spawned: 0,
+        total: 1,
+    });
+    spawn_loading_text(&mut commands);
+    next_state.set(LevelLoadState::Loading);
+    crate::event_log::record("level load started");
+}
+
+fn spawn_loading_text(commands: &mut Commands) {
+    commands.spawn((
+        LoadingText,
+        TextBundle {
+            text: Text::from_section("", TextStyle::default()),
+            style: Style {
+                position_type: PositionType::Absolute,
+                top: Val::Percent(45.0),
+                left: Val::Percent(45.0),
+                ..default()
+            },
+            ..default()
+        },
+    ));
+}
+
+/// Polls the background read; once it's ready, advances straight through
+/// the two pass-through stages into [`LoadStage::Spawn`] — see this
+/// module's own doc comment on why `Textures`/`Colliders` have nothing to
+/// actually wait on here.
+fn poll_parse_task(mut commands: Commands, pending: Option<ResMut<PendingParse>>, mut progress: ResMut<LoadProgress>) {
+    let Some(mut pending) = pending else {
+        return;
+    };
+    if block_on(poll_once(&mut pending.0)).is_none() {
+        return;
+    }
+    commands.remove_resource::<PendingParse>();
+    progress.stage = LoadStage::Spawn;
+}
+
+/// The one real spawn "batch": the whole demo layout, via the same
+/// `map::spawn_map_entities` `level_reload::reload_level` already calls.
+fn spawn_level(
+    mut commands: Commands,
+    level_entities: Query<Entity, With<LevelEntity>>,
+    palette: Res<Palette>,
+    world_flags: Res<WorldFlags>,
+    animations: Res<crate::animation::Animations>,
+    loading_text: Query<Entity, With<LoadingText>>,
+    mut progress: ResMut<LoadProgress>,
+    mut next_state: ResMut<NextState<LevelLoadState>>,
+) {
+    if progress.stage != LoadStage::Spawn || progress.spawned == progress.total {
+        return;
+    }
+    despawn_level(&mut commands, &level_entities);
+    for entity in &loading_text {
+        commands.entity(entity).despawn_recursive();
+    }
+    spawn_map_entities(commands, palette, world_flags, animations);
+    progress.spawned = progress.total;
+    next_state.set(LevelLoadState::Idle);
+    crate::event_log::record("level load finished");
+}
+
+fn update_loading_text(progress: Res<LoadProgress>, mut text: Query<&mut Text, With<LoadingText>>) {
+    let Ok(mut text) = text.get_single_mut() else {
+        return;
+    };
+    text.sections[0].value = format!("Loading ({}) {:.0}%", progress.stage_name(), progress.fraction() * 100.0);
+}
+
+/// Drops the pending task cleanly (`Task`'s `Drop` cancels the future if
+/// it hasn't finished) and clears every trace of the in-progress load.
+/// Only meaningful before [`LoadStage::Spawn`] runs — see this module's own
+/// doc comment on why there's nothing to undo once it has.
+fn cancel_level_load(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut next_state: ResMut<NextState<LevelLoadState>>,
+    loading_text: Query<Entity, With<LoadingText>>,
+) {
+    if !keys.just_pressed(KeyCode::Escape) {
+        return;
+    }
+    commands.remove_resource::<PendingParse>();
+    commands.remove_resource::<LoadProgress>();
+    for entity in &loading_text {
+        commands.entity(entity).despawn_recursive();
+    }
+    next_state.set(LevelLoadState::Idle);
+    crate::event_log::record("level load cancelled");
+}
@@ -0,0 +1,280 @@
+//! Hot-reload for the current level while the game is running.
+//!
+//! There's no external level file format yet (see `LaunchOptions::level`'s
+//! own note on that), so this can't watch a Tiled/LDtk/RON file that
+//! doesn't exist. What it does instead: poll `LEVEL_PATH`'s mtime once a
+//! frame (no `notify`-style OS file-watcher dependency anywhere else in
+//! this crate, so a poll matches the rest of the project rather than
+//! introducing one), debounce bursts of writes 200ms apart into a single
+//! reload, despawn every [`LevelEntity`](crate::map::LevelEntity) via
+//! `map::despawn_level`, and call `map::spawn_map_entities` again. Today
+//! that respawns the same hard-coded demo layout every time; once a real
+//! level format lands, `spawn_map_entities` becomes the one place that
+//! turns loaded data into entities, and this module needs no changes.
+//!
+//! [`drain_stale_gameplay_events`] runs right after that despawn is
+//! flushed, dropping any buffered `HitEvent`/`InteractEvent` that named a
+//! `LevelEntity` the reload just despawned — see its own doc comment for
+//! why nothing actually panics without it today.
+//!
+//! Score and inventory don't need special handling to survive a reload:
+//! neither is live per-run state stored on a `LevelEntity` (there's no
+//! inventory system, and `HighScores` is a persisted table of finished
+//! runs, not something reset mid-level) — the player entity itself is
+//! never despawned here, so its `Health`/`Abilities` ride through
+//! untouched. What *does* need explicit handling is the player's
+//! transform (repositioned below) and `LastCheckpoint` (re-resolved by
+//! [`Checkpoint::id`](crate::death::Checkpoint), since a checkpoint's
+//! `Entity` doesn't survive the despawn) — and, since `spawn_map_entities`
+//! now takes `world_flags::WorldFlags`, whether a flagged one-time entity
+//! (a collected `starman::StarPickup`, today) comes back at all.
+
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use bevy::ecs::schedule::apply_deferred;
+use bevy::prelude::*;
+
+use crate::accessibility::Palette;
+use crate::death::{Checkpoint, LastCheckpoint};
+#[cfg(feature = "serde")]
+use crate::enemy_ai::HitEvent;
+use crate::interact::InteractEvent;
+use crate::map::{despawn_level, spawn_map_entities, LevelBounds, LevelEntity};
+use crate::player::Player;
+use crate::schedule::PlatformerSet;
+use crate::world_flags::WorldFlags;
+
+/// `pub(crate)` (rather than private) so `level_load`'s own off-thread read
+/// of the same file, ahead of a real level-swap, names the exact path this
+/// module watches for the on-disk reload flow, instead of duplicating the
+/// literal.
+pub(crate) const LEVEL_PATH: &str = "assets/level.ron";
+const DEBOUNCE_SECONDS: f32 = 0.2;
+
+/// `pub(crate)` (rather than private) so `objectives::reset_on_reload` can
+/// listen for the same "a reload just happened" signal this module fires
+/// for itself, without this module needing to know objectives exist.
+#[derive(Event)]
+pub(crate) struct LevelReloadRequested;
+
+/// Set by `reload_level` right before it despawns the old level, so
+/// `reposition_after_reload` (which runs after the respawn commands are
+/// flushed) knows where the player was and that it has work to do.
+///
+/// `pub(crate)` rather than private: `reposition_after_reload` is itself
+/// `pub(crate)` so `camera_rail::constrain_to_rail` can order `.after` it,
+/// and a `pub(crate)` function can't take a private type as a parameter.
+#[derive(Resource)]
+pub(crate) struct PendingReposition(Vec2);
+
+#[derive(Resource)]
+struct LevelWatch {
+    path: PathBuf,
+    last_modified: Option<SystemTime>,
+    debouncer: Debouncer,
+}
+
+impl LevelWatch {
+    fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            last_modified: None,
+            debouncer: Debouncer::new(DEBOUNCE_SECONDS),
+        }
+    }
+}
+
+pub struct LevelReloadPlugin;
+
+impl Plugin for LevelReloadPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<LevelReloadRequested>()
+            .insert_resource(LevelWatch::new(LEVEL_PATH))
+            // `PostPhysics` because repositioning the player is exactly
+            // "reacting to resolved state", same as `fall_damage`'s chain.
+            .add_systems(
+                Update,
+                (
+                    poll_level_file,
+                    reload_level,
+                    apply_deferred,
+                    drain_stale_gameplay_events,
+                    reposition_after_reload,
+                )
+                    .chain()
+                    .in_set(PlatformerSet::PostPhysics),
+            );
+    }
+}
+
+/// Reset each time a file-modification is observed; fires once no further
+/// modification has been observed for `window` seconds, so two writes
+/// 50ms apart (an editor that saves twice) only trigger one reload. `pub`
+/// (rather than `pub(crate)`) purely so `tests/level_reload.rs` can drive
+/// it directly instead of going through a real file and `Time` resource.
+pub struct Debouncer {
+    window: f32,
+    pending_since: Option<f32>,
+}
+
+impl Debouncer {
+    pub fn new(window: f32) -> Self {
+        Self {
+            window,
+            pending_since: None,
+        }
+    }
+
+    pub fn notify(&mut self, now: f32) {
+        self.pending_since = Some(now);
+    }
+
+    /// Returns `true` at most once per `notify`, after `window` seconds
+    /// have passed with no further `notify` call.
+    pub fn poll(&mut self, now: f32) -> bool {
+        match self.pending_since {
+            Some(since) if now - since >= self.window => {
+                self.pending_since = None;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+fn poll_level_file(time: Res<Time>, mut watch: ResMut<LevelWatch>, mut events: EventWriter<LevelReloadRequested>) {
+    let now = time.elapsed_seconds();
+    if let Some(modified) = mtime(&watch.path) {
+        if watch.last_modified != Some(modified) {
+            watch.last_modified = Some(modified);
+            watch.debouncer.notify(now);
+        }
+    }
+    if watch.debouncer.poll(now) {
+        events.send(LevelReloadRequested);
+    }
+}
+
+fn mtime(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).ok()?.modified().ok()
+}
+
+fn reload_level(
+    mut commands: Commands,
+    mut events: EventReader<LevelReloadRequested>,
+    level_entities: Query<Entity, With<LevelEntity>>,
+    player: Query<&Transform, With<Player>>,
+    palette: Res<Palette>,
+    world_flags: Res<WorldFlags>,
+    animations: Res<crate::animation::Animations>,
+) {
+    if events.read().count() == 0 {
+        return;
+    }
+
+    let Ok(player_transform) = player.get_single() else {
+        return;
+    };
+    commands.insert_resource(PendingReposition(player_transform.translation.truncate()));
+
+    despawn_level(&mut commands, &level_entities);
+    spawn_map_entities(commands, palette, world_flags, animations);
+
+    info!("level reloaded");
+    crate::event_log::record("level reloaded");
+}
+
+/// Runs after `reload_level`'s despawn is flushed, so a [`HitEvent`] or
+/// [`InteractEvent`] sent against a `LevelEntity` the reload just despawned
+/// (`turret::fire_turret` and `interact::emit_interact_event` both run
+/// earlier in the same frame, in `PlatformerSet::Intent`/`Physics`) doesn't
+/// carry a dead `Entity` into the level `spawn_map_entities` just spawned.
+///
+/// Every current reader of either event already guards its own lookup with
+/// `Query::get(...).ok()` (see `enemy_ai::apply_hit_events` and
+/// `dialogue::open_sign_dialogue`, among others) — Bevy's generation
+/// counter means a stale `Entity` can't even accidentally resolve to a
+/// *different*, newly-respawned entity, so nothing panics without this
+/// pass today. It exists as the one place that decision is made instead of
+/// every reader re-deciding it, and so a reader added later that skips the
+/// guard drops a dead event instead of panicking on it.
+#[cfg(feature = "serde")]
+fn drain_stale_gameplay_events(
+    mut hit_events: ResMut<Events<HitEvent>>,
+    mut interact_events: ResMut<Events<InteractEvent>>,
+    entities: Query<Entity>,
+) {
+    drain_stale_events(&mut hit_events, &entities, |event: &HitEvent| event.target);
+    drain_stale_events(&mut interact_events, &entities, |event: &InteractEvent| event.target);
+}
+
+/// `enemy_ai` (and its `HitEvent`) only exists behind the `serde` feature
+/// (see `lib.rs`'s own gating), so a build without it has only
+/// `InteractEvent` to drain.
+#[cfg(not(feature = "serde"))]
+fn drain_stale_gameplay_events(mut interact_events: ResMut<Events<InteractEvent>>, entities: Query<Entity>) {
+    drain_stale_events(&mut interact_events, &entities, |event: &InteractEvent| event.target);
+}
+
+/// Drops every buffered `T` whose `entity_of` no longer names a live
+/// entity, then re-sends the rest so readers that haven't run yet this
+/// frame still see them. `entities` is a bare `Query<Entity>` rather than
+/// `&World` purely so this can run as an ordinary system in the chain
+/// above instead of an exclusive one.
+fn drain_stale_events<T: Event>(events: &mut Events<T>, entities: &Query<Entity>, entity_of: impl Fn(&T) -> Entity) {
+    for event in events.drain().collect::<Vec<_>>() {
+        if entities.contains(entity_of(&event)) {
+            events.send(event);
+        } else {
+            debug!("dropped a stale event referencing a despawned entity");
+        }
+    }
+}
+
+/// Runs after `reload_level`'s despawn/respawn commands are flushed (via
+/// the explicit `apply_deferred` between them), so `bounds` and
+/// `checkpoints` reflect the *new* level rather than the one just
+/// despawned.
+///
+/// `pub(crate)` so `camera_rail::constrain_to_rail` can order itself
+/// `.after` this — both write `Transform` on `With<Player>` in
+/// `PlatformerSet::PostPhysics`, and the ambiguity checker won't accept two
+/// unordered writers to the same filter (mirrors `death::apply_damage`'s
+/// own citation for the same reason).
+pub(crate) fn reposition_after_reload(
+    mut commands: Commands,
+    pending: Option<Res<PendingReposition>>,
+    bounds: Res<LevelBounds>,
+    checkpoints: Query<(&Checkpoint, &Transform)>,
+    mut last_checkpoint: ResMut<LastCheckpoint>,
+    mut player: Query<&mut Transform, (With<Player>, Without<Checkpoint>)>,
+) {
+    let Some(pending) = pending else {
+        return;
+    };
+    commands.remove_resource::<PendingReposition>();
+
+    let checkpoint_positions: Vec<(u32, Vec2)> =
+        checkpoints.iter().map(|(marker, transform)| (marker.id, transform.translation.truncate())).collect();
+    last_checkpoint.re_resolve(checkpoint_positions.iter().copied());
+
+    let Ok(mut transform) = player.get_single_mut() else {
+        return;
+    };
+    let new_position = if bounds.contains(pending.0) {
+        pending.0
+    } else {
+        let candidates: Vec<Vec2> = checkpoint_positions.iter().map(|&(_, position)| position).collect();
+        nearest_point(pending.0, &candidates).unwrap_or(last_checkpoint.position)
+    };
+    transform.translation = new_position.extend(transform.translation.z);
+}
+
+/// The candidate closest to `from`, or `None` if `candidates` is empty.
+pub fn nearest_point(from: Vec2, candidates: &[Vec2]) -> Option<Vec2> {
+    candidates
+        .iter()
+        .copied()
+        .min_by(|a, b| a.distance_squared(from).total_cmp(&b.distance_squared(from)))
+}
@@ -0,0 +1,568 @@
+//! Level select screen: a vertical list of levels read from a
+//! [`LevelManifest`], showing lock state and best time, navigable with
+//! keyboard or gamepad and confirmed the same way [`crate::shop`]'s menu
+//! is.
+//!
+//! Several pieces of the original ask have nowhere real to attach to yet:
+//!
+//! - There's no main menu anywhere in this crate (`main.rs` drops straight
+//!   into gameplay), so "reachable from the main menu" becomes a debug-key
+//!   toggle instead, the same stand-in `debug_overlay`'s F3 and
+//!   `time_scale`'s F6 already use for their own dev-only entry points.
+//! - There's no gameplay mouse input in this crate to build a hover-to-
+//!   select off of (only the `editor`-feature tools in `main.rs` read
+//!   `MouseButton` or the cursor), so navigation is keyboard and gamepad only, the
+//!   same two inputs `shop`'s menu and `interact`'s prompt already support.
+//! - There's no `LevelManager`, and this crate only ever has the one demo
+//!   map `map.rs` builds — so confirming an entry fires `level_load`'s
+//!   [`LevelLoadRequested`](crate::level_load::LevelLoadRequested) (that
+//!   module's own doc comment covers what "loading" it honestly means),
+//!   rather than actually routing to a different level's worth of data. A
+//!   "grid" layout instead of this single column is for whichever request
+//!   adds a second real level to navigate to.
+//! - Per-level coin fraction and medals aren't real either: only one
+//!   level's worth of gameplay data exists, via `challenge::ChallengeBest`,
+//!   so that's the only best-time this screen can honestly show, displayed
+//!   against every unlocked entry rather than invented per-id data.
+//!
+//! What *is* real: [`LevelManifest`] is the data format new levels are
+//! meant to be added to (mirroring `shop::CATALOG`'s "authored data, no
+//! per-entry code" shape), [`LevelCompleted`] is this crate's stand-in for
+//! "GoalReached" (`objectives`'s own doc comment already covers that gap),
+//! fired by [`complete_current_level`] using the exact same "`ExitGate`
+//! lost its `HitBox`" check `challenge::finish_on_exit` uses, and
+//! [`unlock_next_level`] persists unlock progress to
+//! `assets/level_unlocks.ron` with the `serde` feature, mirroring
+//! `high_scores`'s one-shot save/load. Selecting a locked entry plays a
+//! real (if brief) red [`DenyFlash`] on the highlighted row, rather than
+//! `shop::purchase_selected`'s silent no-op — that request explicitly asks
+//! for a deny animation where `shop`'s only had a sound to skip.
+//! [`LevelCoinBest`] is likewise real and persisted (`assets/level_coins.ron`),
+//! recorded by `results::open_results_on_level_completed` the moment a level
+//! completes and read back here to show a coin count next to each entry.
+
+use bevy::prelude::*;
+
+use crate::collision::HitBox;
+use crate::event_log;
+use crate::level_load::LevelLoadRequested;
+use crate::objectives::ExitGate;
+use crate::player::Player;
+use crate::prompt::{PromptAction, PromptText};
+use crate::schedule::PlatformerSet;
+
+const DENY_FLASH_SECONDS: f32 = 0.3;
+/// Matches `challenge::finish_on_exit`'s own reached-exit distance.
+const REACHED_EXIT_DISTANCE: f32 = 24.0;
+
+/// One entry in a level manifest: an id (stable across reordering, the
+/// same role `death::Checkpoint::id` plays) and a display name.
+#[derive(Reflect, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LevelEntry {
+    pub id: u32,
+    pub name: String,
+}
+
+/// The level list this screen shows. Only `id: 0` corresponds to a level
+/// this crate can actually load — see this module's own doc comment. Later
+/// entries exist to prove the manifest format holds more than one level's
+/// worth of data.
+#[derive(Resource, Reflect, Clone)]
+#[reflect(Resource)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LevelManifest(pub Vec<LevelEntry>);
+
+impl Default for LevelManifest {
+    fn default() -> Self {
+        Self(vec![
+            LevelEntry {
+                id: 0,
+                name: "Demo Level".to_string(),
+            },
+            LevelEntry {
+                id: 1,
+                name: "Level 2".to_string(),
+            },
+            LevelEntry {
+                id: 2,
+                name: "Level 3".to_string(),
+            },
+        ])
+    }
+}
+
+/// Coins collected per level id, persisted so this screen's row text can
+/// show a level's best coin count whether or not the player has completed
+/// it again this session. Owned here rather than by `results` since this is
+/// the only module that reads it back for display.
+#[derive(Resource, Reflect, Clone, Default)]
+#[reflect(Resource)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LevelCoinBest(Vec<(u32, u32)>);
+
+impl LevelCoinBest {
+    pub fn best_for(&self, id: u32) -> Option<u32> {
+        self.0.iter().find(|&&(candidate, _)| candidate == id).map(|&(_, coins)| coins)
+    }
+
+    /// Records `coins` for `id` if it's higher than what's already stored,
+    /// persisting immediately when it changes anything. `pub(crate)` so
+    /// `results::open_results_on_level_completed` can call it the moment a
+    /// level's attempt ends, without this module needing to know about the
+    /// results screen.
+    pub(crate) fn record_and_persist(&mut self, id: u32, coins: u32) {
+        let changed = match self.0.iter_mut().find(|(candidate, _)| *candidate == id) {
+            Some((_, existing)) if *existing >= coins => false,
+            Some((_, existing)) => {
+                *existing = coins;
+                true
+            }
+            None => {
+                self.0.push((id, coins));
+                true
+            }
+        };
+        if changed {
+            #[cfg(feature = "serde")]
+            coin_persistence::save_to_disk(self);
+        }
+    }
+}
+
+/// Ids unlocked so far. Id `0` is always unlocked; every other id needs a
+/// [`LevelCompleted`] for the id below it.
+#[derive(Resource, Reflect, Clone, Default)]
+#[reflect(Resource)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LevelUnlocks(Vec<u32>);
+
+impl LevelUnlocks {
+    pub fn is_unlocked(&self, id: u32) -> bool {
+        id == 0 || self.0.contains(&id)
+    }
+
+    /// Returns whether `id` was newly unlocked (`false` if already unlocked).
+    fn unlock(&mut self, id: u32) -> bool {
+        if self.is_unlocked(id) {
+            return false;
+        }
+        self.0.push(id);
+        true
+    }
+}
+
+#[derive(States, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum LevelSelect {
+    #[default]
+    Closed,
+    Open,
+}
+
+/// Which row is highlighted while the screen is open. Reset to `0` every
+/// time it opens, mirroring `shop::ShopSelection`. `pub(crate)` (rather
+/// than private) so `results::select_result_option`'s "Level Select" button can pass it
+/// straight to [`open`] instead of this module needing its own extra entry
+/// point just for that.
+#[derive(Resource, Default)]
+pub(crate) struct LevelSelectCursor(usize);
+
+/// Fired the moment the player reaches an unlocked `ExitGate` — this
+/// crate's stand-in for "GoalReached"; see this module's own doc comment.
+#[derive(Event)]
+pub struct LevelCompleted {
+    pub id: u32,
+}
+
+/// Marks an `ExitGate` [`complete_current_level`] has already reported,
+/// so reaching it again (or standing on it) doesn't fire `LevelCompleted`
+/// every frame — mirrors `objectives::ExitUnlocked`'s own "already
+/// processed" role.
+#[derive(Component)]
+struct LevelReported;
+
+#[derive(Component)]
+struct LevelSelectBox;
+
+/// `pub(crate)` (rather than private) purely so
+/// `testing::TestWorld::level_select_row_count` can count the screen's
+/// rendered `TextSection`s.
+#[derive(Component)]
+pub(crate) struct LevelSelectText;
+
+/// The confirm-row footer spawned once by [`open`] and left alone
+/// thereafter — `prompt::sync_prompt_text` keeps its label current as the
+/// player switches devices, without [`update_level_select_text`]'s own
+/// per-frame row rebuild ever touching it.
+#[derive(Component)]
+pub(crate) struct LevelSelectPrompt;
+
+/// Brief highlight-row flash after selecting a locked entry.
+#[derive(Component)]
+struct DenyFlash(Timer);
+
+pub struct LevelSelectPlugin;
+
+impl Plugin for LevelSelectPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<LevelManifest>()
+            .register_type::<LevelUnlocks>()
+            .register_type::<LevelCoinBest>()
+            .init_state::<LevelSelect>()
+            .init_resource::<LevelManifest>()
+            .init_resource::<LevelUnlocks>()
+            .init_resource::<LevelCoinBest>()
+            .init_resource::<LevelSelectCursor>()
+            .add_event::<LevelCompleted>()
+            .add_systems(
+                Update,
+                (complete_current_level, unlock_next_level)
+                    .chain()
+                    .in_set(PlatformerSet::PostPhysics),
+            )
+            .add_systems(Update, open_level_select.run_if(in_state(LevelSelect::Closed)))
+            .add_systems(
+                Update,
+                (navigate_level_select, select_highlighted, tick_deny_flash, close_level_select, update_level_select_text)
+                    .chain()
+                    .run_if(in_state(LevelSelect::Open)),
+            );
+
+        #[cfg(feature = "serde")]
+        app.add_systems(Startup, (persistence::load_from_disk, coin_persistence::load_from_disk));
+        #[cfg(feature = "serde")]
+        app.add_systems(Update, record_level_completed);
+    }
+}
+
+/// A run reaches the level's goal the same way `challenge::finish_on_exit`
+/// detects one: the player within `REACHED_EXIT_DISTANCE` of an `ExitGate`
+/// whose `HitBox` is already gone.
+fn complete_current_level(
+    mut commands: Commands,
+    manifest: Res<LevelManifest>,
+    mut completed: EventWriter<LevelCompleted>,
+    player: Query<&Transform, With<Player>>,
+    gates: Query<(Entity, &Transform), (With<ExitGate>, Without<HitBox>, Without<LevelReported>)>,
+) {
+    let Ok(player_transform) = player.get_single() else {
+        return;
+    };
+    // Today there's only ever one level loaded (see `LaunchOptions::level`'s
+    // own note), so the manifest's first entry is the only id this can
+    // honestly report as completed.
+    let Some(entry) = manifest.0.first() else {
+        return;
+    };
+    for (entity, gate_transform) in &gates {
+        let reached = (player_transform.translation.truncate() - gate_transform.translation.truncate()).length()
+            < REACHED_EXIT_DISTANCE;
+        if !reached {
+            continue;
+        }
+        commands.entity(entity).insert(LevelReported);
+        completed.send(LevelCompleted { id: entry.id });
+    }
+}
+
+fn unlock_next_level(
+    mut events: EventReader<LevelCompleted>,
+    manifest: Res<LevelManifest>,
+    mut unlocks: ResMut<LevelUnlocks>,
+) {
+    for event in events.read() {
+        let next_id = event.id + 1;
+        if !manifest.0.iter().any(|entry| entry.id == next_id) {
+            continue;
+        }
+        if unlocks.unlock(next_id) {
+            event_log::record(format!("level unlocked id={next_id}"));
+
+            #[cfg(feature = "serde")]
+            persistence::save_to_disk(&unlocks);
+        }
+    }
+}
+
+/// Reuses `speedrun_overlay::SessionTimer` rather than starting a second
+/// clock — see that module's own doc comment on why it's already the
+/// right one to read for "how long has this run been going".
+#[cfg(feature = "serde")]
+fn record_level_completed(
+    mut events: EventReader<LevelCompleted>,
+    timer: Res<crate::speedrun_overlay::SessionTimer>,
+    mut session: EventWriter<crate::session_recorder::RecordSessionEvent>,
+) {
+    for event in events.read() {
+        session.send(crate::session_recorder::RecordSessionEvent(
+            crate::session_recorder::SessionEvent::LevelCompleted {
+                level_id: event.id,
+                time_seconds: timer.elapsed_seconds,
+            },
+        ));
+    }
+}
+
+/// Stand-in for opening this screen from a main menu that doesn't exist —
+/// see this module's own doc comment.
+fn open_level_select(
+    keys: Res<ButtonInput<KeyCode>>,
+    commands: Commands,
+    cursor: ResMut<LevelSelectCursor>,
+    next_state: ResMut<NextState<LevelSelect>>,
+) {
+    if !keys.just_pressed(KeyCode::F7) {
+        return;
+    }
+    open(commands, cursor, next_state);
+}
+
+/// Spawns the level select box and opens [`LevelSelect`]. `pub(crate)` so
+/// `results::select_result_option`'s own "Level Select" button can reach
+/// the same entry point this module's own F7 toggle uses.
+pub(crate) fn open(
+    mut commands: Commands,
+    mut cursor: ResMut<LevelSelectCursor>,
+    mut next_state: ResMut<NextState<LevelSelect>>,
+) {
+    cursor.0 = 0;
+    commands
+        .spawn((
+            LevelSelectBox,
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    top: Val::Percent(20.0),
+                    left: Val::Percent(35.0),
+                    ..default()
+                },
+                ..default()
+            },
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                LevelSelectPrompt,
+                PromptText { action: PromptAction::Confirm, verb: Some("confirm") },
+                TextBundle::from_section("", TextStyle::default()).with_style(Style {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(140.0),
+                    ..default()
+                }),
+            ));
+        });
+    next_state.set(LevelSelect::Open);
+}
+
+fn navigate_level_select(
+    keys: Res<ButtonInput<KeyCode>>,
+    gamepads: Res<Gamepads>,
+    gamepad_buttons: Res<ButtonInput<GamepadButton>>,
+    manifest: Res<LevelManifest>,
+    mut cursor: ResMut<LevelSelectCursor>,
+) {
+    let pressed_up = keys.just_pressed(KeyCode::ArrowUp)
+        || gamepads.iter().any(|pad| gamepad_buttons.just_pressed(GamepadButton::new(pad, GamepadButtonType::DPadUp)));
+    let pressed_down = keys.just_pressed(KeyCode::ArrowDown)
+        || gamepads.iter().any(|pad| gamepad_buttons.just_pressed(GamepadButton::new(pad, GamepadButtonType::DPadDown)));
+    if pressed_up {
+        cursor.0 = cursor.0.checked_sub(1).unwrap_or(manifest.0.len() - 1);
+    }
+    if pressed_down {
+        cursor.0 = (cursor.0 + 1) % manifest.0.len();
+    }
+}
+
+/// Confirms the highlighted entry. An unlocked entry is logged, closes the
+/// screen and fires [`LevelLoadRequested`] — see this module's own doc
+/// comment on what that load amounts to. A locked entry starts a [`DenyFlash`] instead, this
+/// screen's real deny animation (unlike `shop::purchase_selected`'s silent
+/// no-op).
+fn select_highlighted(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    gamepads: Res<Gamepads>,
+    gamepad_buttons: Res<ButtonInput<GamepadButton>>,
+    manifest: Res<LevelManifest>,
+    unlocks: Res<LevelUnlocks>,
+    cursor: Res<LevelSelectCursor>,
+    level_box: Query<Entity, With<LevelSelectBox>>,
+    mut next_state: ResMut<NextState<LevelSelect>>,
+    mut load_requests: EventWriter<LevelLoadRequested>,
+) {
+    let pressed_gamepad_confirm = gamepads
+        .iter()
+        .any(|pad| gamepad_buttons.just_pressed(GamepadButton::new(pad, GamepadButtonType::South)));
+    if !keys.just_pressed(KeyCode::KeyE) && !keys.just_pressed(KeyCode::Space) && !pressed_gamepad_confirm {
+        return;
+    }
+    let Some(entry) = manifest.0.get(cursor.0) else {
+        return;
+    };
+    if !unlocks.is_unlocked(entry.id) {
+        if let Ok(entity) = level_box.get_single() {
+            commands.entity(entity).insert(DenyFlash(Timer::from_seconds(DENY_FLASH_SECONDS, TimerMode::Once)));
+        }
+        return;
+    }
+    event_log::record(format!("level select chose id={} name={}", entry.id, entry.name));
+    for entity in &level_box {
+        commands.entity(entity).despawn_recursive();
+    }
+    next_state.set(LevelSelect::Closed);
+    load_requests.send(LevelLoadRequested { id: entry.id });
+}
+
+fn tick_deny_flash(time: Res<Time>, mut commands: Commands, mut flashing: Query<(Entity, &mut DenyFlash)>) {
+    for (entity, mut flash) in &mut flashing {
+        flash.0.tick(time.delta());
+        if flash.0.finished() {
+            commands.entity(entity).remove::<DenyFlash>();
+        }
+    }
+}
+
+fn close_level_select(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    boxes: Query<Entity, With<LevelSelectBox>>,
+    mut next_state: ResMut<NextState<LevelSelect>>,
+) {
+    if !keys.just_pressed(KeyCode::Escape) {
+        return;
+    }
+    for entity in &boxes {
+        commands.entity(entity).despawn_recursive();
+    }
+    next_state.set(LevelSelect::Closed);
+}
+
+fn update_level_select_text(
+    mut commands: Commands,
+    manifest: Res<LevelManifest>,
+    unlocks: Res<LevelUnlocks>,
+    best: Res<crate::challenge::ChallengeBest>,
+    coin_best: Res<LevelCoinBest>,
+    cursor: Res<LevelSelectCursor>,
+    denying: Query<(), With<DenyFlash>>,
+    level_box: Query<Entity, With<LevelSelectBox>>,
+    mut text: Query<&mut Text, With<LevelSelectText>>,
+) {
+    let Ok(level_box) = level_box.get_single() else {
+        return;
+    };
+    let denying = !denying.is_empty();
+
+    let sections: Vec<TextSection> = manifest
+        .0
+        .iter()
+        .enumerate()
+        .map(|(index, entry)| {
+            let unlocked = unlocks.is_unlocked(entry.id);
+            let highlighted = index == cursor.0;
+            let cursor_marker = if highlighted { "> " } else { "  " };
+            let lock = if unlocked { "" } else { " [locked]" };
+            // Only one level's worth of real best-time data exists — see
+            // this module's own doc comment.
+            let best_time = if unlocked {
+                best.time.map(|time| format!(" best {time:.1}s")).unwrap_or_default()
+            } else {
+                String::new()
+            };
+            let best_coins = if unlocked {
+                coin_best.best_for(entry.id).map(|coins| format!(" coins {coins}")).unwrap_or_default()
+            } else {
+                String::new()
+            };
+            let color = if highlighted && denying {
+                Color::RED
+            } else if unlocked {
+                Color::WHITE
+            } else {
+                Color::GRAY
+            };
+            TextSection::new(
+                format!("{cursor_marker}{}{lock}{best_time}{best_coins}\n", entry.name),
+                TextStyle {
+                    color,
+                    ..default()
+                },
+            )
+        })
+        .collect();
+
+    if let Ok(mut text) = text.get_single_mut() {
+        text.sections = sections;
+    } else {
+        commands.entity(level_box).with_children(|parent| {
+            parent.spawn((LevelSelectText, TextBundle::from_sections(sections)));
+        });
+    }
+}
+
+#[cfg(feature = "serde")]
+mod persistence {
+    use super::LevelUnlocks;
+    use bevy::prelude::*;
+    use std::path::Path;
+
+    const SAVE_PATH: &str = "assets/level_unlocks.ron";
+
+    /// One-shot load of `assets/level_unlocks.ron` over the (empty) default,
+    /// if present. Mirrors `high_scores::persistence::load_from_disk`.
+    pub fn load_from_disk(mut unlocks: ResMut<LevelUnlocks>) {
+        let Ok(contents) = std::fs::read_to_string(Path::new(SAVE_PATH)) else {
+            return;
+        };
+        match ron::from_str::<LevelUnlocks>(&contents) {
+            Ok(loaded) => *unlocks = loaded,
+            Err(err) => warn!("failed to parse {SAVE_PATH}: {err}"),
+        }
+    }
+
+    /// Writes the current unlock set to `assets/level_unlocks.ron`. Called
+    /// right after a new unlock, mirroring `high_scores::save_to_disk`'s
+    /// save-immediately timing.
+    pub fn save_to_disk(unlocks: &LevelUnlocks) {
+        match ron::to_string(unlocks) {
+            Ok(serialized) => {
+                if let Err(err) = std::fs::write(SAVE_PATH, serialized) {
+                    warn!("failed to write {SAVE_PATH}: {err}");
+                }
+            }
+            Err(err) => warn!("failed to serialize level unlocks: {err}"),
+        }
+    }
+}
+
+/// Same one-shot load/save-immediately shape as [`persistence`], for
+/// [`LevelCoinBest`] instead — kept as its own module rather than folded
+/// into `persistence` since the two resources save to different files.
+#[cfg(feature = "serde")]
+mod coin_persistence {
+    use super::LevelCoinBest;
+    use bevy::prelude::*;
+    use std::path::Path;
+
+    const SAVE_PATH: &str = "assets/level_coins.ron";
+
+    pub fn load_from_disk(mut coin_best: ResMut<LevelCoinBest>) {
+        let Ok(contents) = std::fs::read_to_string(Path::new(SAVE_PATH)) else {
+            return;
+        };
+        match ron::from_str::<LevelCoinBest>(&contents) {
+            Ok(loaded) => *coin_best = loaded,
+            Err(err) => warn!("failed to parse {SAVE_PATH}: {err}"),
+        }
+    }
+
+    pub fn save_to_disk(coin_best: &LevelCoinBest) {
+        match ron::to_string(coin_best) {
+            Ok(serialized) => {
+                if let Err(err) = std::fs::write(SAVE_PATH, serialized) {
+                    warn!("failed to write {SAVE_PATH}: {err}");
+                }
+            }
+            Err(err) => warn!("failed to serialize level coin bests: {err}"),
+        }
+    }
+}
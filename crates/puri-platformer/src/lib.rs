@@ -0,0 +1,346 @@
+//! Core gameplay for the Puri platformer, exposed as a library so it can be
+//! reused by other cartridges and driven headlessly in tests.
+
+pub mod accessibility;
+pub mod achievements;
+pub mod ambience;
+pub mod animation;
+#[cfg(feature = "serde")]
+pub mod atlas_pack;
+pub mod attack;
+pub mod audio;
+pub mod boss;
+pub mod camera;
+pub mod camera_rail;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod capture;
+pub mod challenge;
+pub mod collision;
+pub mod combo;
+pub mod command_queue;
+pub mod crouch;
+pub mod crumbling;
+pub mod cutscene;
+pub mod death;
+pub mod debug_overlay;
+pub mod dialogue;
+pub mod difficulty_assist;
+#[cfg(feature = "serde")]
+pub mod enemy_ai;
+pub mod equipment;
+pub mod event_log;
+pub mod fall_damage;
+pub mod floating_text;
+pub mod grapple;
+pub mod high_scores;
+pub mod interact;
+pub mod launch_options;
+pub mod level_load;
+pub mod level_reload;
+pub mod level_select;
+pub mod lighting;
+pub mod localization;
+pub mod loot;
+pub mod map;
+pub mod math;
+pub mod minimap;
+#[cfg(feature = "serde")]
+pub mod mods;
+pub mod music;
+pub mod npc;
+pub mod objectives;
+pub mod photo;
+pub mod physics_config;
+pub mod player;
+pub mod player_state;
+pub mod pool;
+pub mod portal;
+#[cfg(feature = "serde")]
+pub mod prefab;
+pub mod projectile;
+pub mod prompt;
+pub mod pushable;
+pub mod render_layer;
+pub mod results;
+pub mod rhythm;
+pub mod rng;
+pub mod rumble;
+pub mod save;
+pub mod schedule;
+#[cfg(feature = "serde")]
+pub mod script;
+#[cfg(feature = "serde")]
+pub mod session_recorder;
+pub mod shield;
+pub mod shop;
+pub mod snapshot;
+pub mod spatial_grid;
+pub mod speedrun_overlay;
+pub mod starman;
+pub mod testing;
+pub mod tilemap;
+pub mod time_scale;
+pub mod toast;
+pub mod transition;
+pub mod turret;
+pub mod ui_scale;
+pub mod water;
+pub mod weather;
+pub mod window_config;
+pub mod world_flags;
+#[cfg(feature = "serde")]
+pub mod zone_population;
+
+use bevy::app::{PluginGroup, PluginGroupBuilder};
+
+use accessibility::AccessibilityPlugin;
+use achievements::AchievementsPlugin;
+use ambience::AmbiencePlugin;
+use animation::AnimationPlugin;
+use attack::AttackPlugin;
+use audio::AudioPlugin;
+use boss::BossPlugin;
+use camera::CameraPlugin;
+use camera_rail::CameraRailPlugin;
+use challenge::ChallengePlugin;
+use collision::CollisionPlugin;
+use combo::ComboPlugin;
+use command_queue::CommandQueuePlugin;
+use crouch::CrouchPlugin;
+use crumbling::CrumblingPlugin;
+use cutscene::CutscenePlugin;
+use death::DeathPlugin;
+use debug_overlay::DebugOverlayPlugin;
+use dialogue::DialoguePlugin;
+use difficulty_assist::DifficultyAssistPlugin;
+#[cfg(feature = "serde")]
+use enemy_ai::EnemyAiPlugin;
+use equipment::EquipmentPlugin;
+use fall_damage::FallDamagePlugin;
+use floating_text::FloatingTextPlugin;
+use grapple::GrapplePlugin;
+use high_scores::HighScoresPlugin;
+use interact::InteractPlugin;
+use level_load::LevelLoadPlugin;
+use level_reload::LevelReloadPlugin;
+use level_select::LevelSelectPlugin;
+use lighting::LightingPlugin;
+use localization::LocalizationPlugin;
+use loot::LootPlugin;
+use map::MapPlugin;
+use minimap::MinimapPlugin;
+#[cfg(feature = "serde")]
+use mods::ModsPlugin;
+use music::MusicPlugin;
+use npc::NpcPlugin;
+use objectives::ObjectivesPlugin;
+use photo::PhotoModePlugin;
+use physics_config::PhysicsConfigPlugin;
+use player::PlayerPlugin;
+use player_state::PlayerStatePlugin;
+use portal::PortalPlugin;
+#[cfg(feature = "serde")]
+use prefab::PrefabPlugin;
+use projectile::ProjectilePlugin;
+use prompt::PromptPlugin;
+use pushable::PushablePlugin;
+use results::ResultsPlugin;
+use rhythm::RhythmPlugin;
+use rng::RngPlugin;
+use rumble::RumblePlugin;
+use save::SaveManagerPlugin;
+use schedule::SchedulePlugin;
+#[cfg(feature = "serde")]
+use script::ScriptPlugin;
+#[cfg(feature = "serde")]
+use session_recorder::SessionRecorderPlugin;
+use shield::ShieldPlugin;
+use shop::ShopPlugin;
+use speedrun_overlay::SpeedrunOverlayPlugin;
+use starman::StarmanPlugin;
+use tilemap::TilemapPlugin;
+use time_scale::TimeScalePlugin;
+use toast::ToastPlugin;
+use transition::TransitionPlugin;
+use turret::TurretPlugin;
+use ui_scale::UiScalePlugin;
+use water::WaterPlugin;
+use weather::WeatherPlugin;
+use window_config::WindowConfigPlugin;
+use world_flags::WorldFlagsPlugin;
+#[cfg(feature = "serde")]
+use zone_population::ZonePopulationPlugin;
+
+/// Bundles every gameplay plugin the platformer needs. `main.rs` (and the
+/// test harness) just add this one group instead of wiring plugins by hand.
+/// `SchedulePlugin` is added first so every other plugin's `.in_set(..)`
+/// calls resolve against sets that already exist.
+pub struct PlatformerPlugins;
+
+impl PluginGroup for PlatformerPlugins {
+    fn build(self) -> PluginGroupBuilder {
+        let builder = PluginGroupBuilder::start::<Self>()
+            .add(SchedulePlugin)
+            .add(DebugOverlayPlugin)
+            .add(WindowConfigPlugin)
+            .add(UiScalePlugin)
+            .add(RngPlugin)
+            .add(TimeScalePlugin)
+            .add(PhysicsConfigPlugin)
+            .add(AccessibilityPlugin)
+            .add(LocalizationPlugin)
+            .add(ToastPlugin)
+            .add(TransitionPlugin)
+            .add(RumblePlugin)
+            .add(CommandQueuePlugin)
+            .add(AudioPlugin)
+            .add(MusicPlugin)
+            .add(RhythmPlugin)
+            .add(PlayerPlugin)
+            .add(PlayerStatePlugin)
+            .add(AnimationPlugin)
+            .add(AttackPlugin)
+            .add(CameraPlugin)
+            .add(CameraRailPlugin)
+            .add(PhotoModePlugin)
+            .add(WorldFlagsPlugin)
+            .add(MapPlugin)
+            .add(MinimapPlugin)
+            .add(LevelReloadPlugin)
+            .add(TilemapPlugin)
+            .add(CollisionPlugin)
+            .add(ComboPlugin)
+            .add(LootPlugin)
+            .add(FloatingTextPlugin)
+            .add(ChallengePlugin)
+            .add(PromptPlugin)
+            .add(SpeedrunOverlayPlugin)
+            .add(InteractPlugin)
+            .add(DialoguePlugin)
+            .add(CutscenePlugin)
+            .add(NpcPlugin)
+            .add(BossPlugin)
+            .add(ObjectivesPlugin)
+            .add(GrapplePlugin)
+            .add(ShieldPlugin)
+            .add(CrouchPlugin)
+            .add(PushablePlugin)
+            .add(CrumblingPlugin)
+            .add(FallDamagePlugin)
+            .add(DeathPlugin)
+            .add(DifficultyAssistPlugin)
+            .add(PortalPlugin)
+            .add(WaterPlugin)
+            .add(WeatherPlugin)
+            .add(HighScoresPlugin)
+            .add(AmbiencePlugin)
+            .add(LightingPlugin)
+            .add(ShopPlugin)
+            .add(EquipmentPlugin)
+            .add(LevelSelectPlugin)
+            .add(LevelLoadPlugin)
+            .add(AchievementsPlugin)
+            .add(ResultsPlugin)
+            .add(SaveManagerPlugin)
+            .add(StarmanPlugin)
+            .add(ProjectilePlugin)
+            .add(TurretPlugin);
+        #[cfg(feature = "serde")]
+        let builder = builder.add(PrefabPlugin);
+        #[cfg(feature = "serde")]
+        let builder = builder.add(ModsPlugin);
+        #[cfg(feature = "serde")]
+        let builder = builder.add(EnemyAiPlugin);
+        #[cfg(feature = "serde")]
+        let builder = builder.add(ScriptPlugin);
+        #[cfg(feature = "serde")]
+        let builder = builder.add(ZonePopulationPlugin);
+        #[cfg(feature = "serde")]
+        let builder = builder.add(SessionRecorderPlugin);
+        #[cfg(not(target_arch = "wasm32"))]
+        let builder = builder.add(capture::CapturePlugin);
+        builder
+    }
+}
+
+pub mod prelude {
+    pub use crate::accessibility::{AccessibilityOptions, Palette};
+    pub use crate::achievements::{AchievementBackend, AchievementDef, AchievementDefs, AchievementId, AchievementProgress, AchievementUnlocked};
+    pub use crate::ambience::{DayNightCycle, LevelAmbience};
+    pub use crate::animation::{set_animation, FrameTime, SpriteAnimation};
+    pub use crate::attack::{AttackPhase, Attacking, Hurtbox, ATTACK_KEY};
+    pub use crate::audio::{LoopingEmitter, PlaySfx, PlaySfxAt, SfxId, SfxLibrary};
+    #[cfg(not(target_arch = "wasm32"))]
+    pub use crate::capture::CaptureConfig;
+    pub use crate::challenge::{start_challenge, ChallengeBest, ChallengeFinished, ChallengeRun, Medal, MedalThresholds};
+    pub use crate::collision::{CollisionEvent, Contact, GroundedBody, HitBox, MovementMode, Sensor};
+    pub use crate::combo::{ComboCount, EnemyDefeated, Stompable};
+    pub use crate::command_queue::{CommandQueue, PlayerCommand, PlayerId};
+    pub use crate::crouch::{Crouching, CROUCH_KEY};
+    pub use crate::cutscene::{ActorId, Cutscene, CutsceneCommand, CutscenePlayed, CutscenePlaying};
+    pub use crate::death::{Checkpoint, Dying, PlayerDied};
+    pub use crate::debug_overlay::{overlay_visible, DebugOverlayVisible};
+    pub use crate::dialogue::{DialogueClosed, Sign};
+    pub use crate::difficulty_assist::DifficultyAssist;
+    #[cfg(feature = "serde")]
+    pub use crate::enemy_ai::{HitEvent, Hitstun};
+    pub use crate::equipment::{Equipment, EquipmentId, EquipmentSlot, ModifierOp, Stat, StatModifier, CATALOG as EQUIPMENT_CATALOG};
+    pub use crate::fall_damage::{DamageEvent, FallDamageConfig};
+    pub use crate::floating_text::spawn_floating_text;
+    pub use crate::high_scores::{HighScores, ScoreEntry};
+    pub use crate::interact::{InteractEvent, Interactable};
+    pub use crate::launch_options::LaunchOptions;
+    pub use crate::level_load::{LevelLoadState, LoadProgress, LoadStage, SpawnQueue};
+    pub use crate::level_select::{LevelCoinBest, LevelCompleted, LevelEntry, LevelManifest, LevelSelect, LevelUnlocks};
+    pub use crate::lighting::{flicker_intensity, Light2d, LightCulled, MAX_ACTIVE_LIGHTS};
+    pub use crate::localization::{Localization, LocalizationPlugin};
+    pub use crate::loot::{HealEvent, LootDrop, LootKind, LootTable};
+    pub use crate::math::{exp_decay, move_toward, spring_damp};
+    pub use crate::minimap::{minimap_visible, MinimapOptions, MinimapVisible};
+    #[cfg(feature = "serde")]
+    pub use crate::mods::{InstalledMod, InstalledMods};
+    pub use crate::music::{MusicIntensity, MusicStem, MusicTrack, StemRole};
+    pub use crate::npc::{Npc, NpcReward, Wandering};
+    pub use crate::objectives::{ExitGate, Objective, ObjectiveCompleted, ObjectiveKind, Objectives};
+    pub use crate::photo::photo_mode_inactive;
+    pub use crate::physics_config::PlayerPhysicsConfig;
+    pub use crate::player::{
+        Abilities, BodyPart, DamageKind, Facing, Grounded, Health, Jump, JumpAnalytics, PartSlot, Player, PlayerBundle, PlayerSpawner,
+        Resistances, Velocity,
+    };
+    pub use crate::player_state::{PlayerState, PlayerStateChanged};
+    pub use crate::pool::{EntityPool, Pooled};
+    pub use crate::portal::{Portal, PortalId};
+    #[cfg(feature = "serde")]
+    pub use crate::prefab::{spawn_prefab, Coin, Fish, Flyer, Patroller, PrefabError, PrefabRegistry, Spike, Spring};
+    pub use crate::projectile::{spawn_projectile, Projectile, ProjectileBundle};
+    pub use crate::prompt::{InputDevice, InputIcons, LastUsedDevice, PromptAction, PromptText};
+    pub use crate::render_layer::{z_for, GameLayer};
+    pub use crate::results::{LevelStats, ResultsScreen};
+    pub use crate::rhythm::{BeatActive, BeatSynced, BeatTelegraph, Crusher, MusicClock, BEATS_PER_BAR};
+    pub use crate::rng::GameRng;
+    pub use crate::rumble::{RumbleEvent, RumbleSettings};
+    pub use crate::save::{ActiveSlot, ProfileSelect, SaveManager, SaveSlotData, SLOT_COUNT};
+    pub use crate::schedule::PlatformerSet;
+    #[cfg(feature = "serde")]
+    pub use crate::script::{Action, Door, LevelId, ScriptEntry, ScriptPlugin, Trigger};
+    #[cfg(feature = "serde")]
+    pub use crate::session_recorder::{RecordSessionEvent, SessionEvent};
+    pub use crate::shield::{Shield, Stamina, SHIELD_KEY};
+    pub use crate::shop::{ShopKeeper, UpgradeId, UpgradeInfo, Wallet, CATALOG};
+    pub use crate::snapshot::{restore, snapshot, GameSnapshot};
+    pub use crate::speedrun_overlay::{SessionSplits, SessionTimer};
+    pub use crate::starman::{Starman, StarPickup};
+    pub use crate::tilemap::{AnimatedTile, TileAnimations, TileLayer, TileLayerMaterial, CHUNK_SIZE};
+    pub use crate::time_scale::{GameTime, TimeScale};
+    pub use crate::toast::ToastEvent;
+    pub use crate::transition::{Transition, TransitionAction, TransitionKind};
+    pub use crate::turret::{spawn_turret, Turret};
+    pub use crate::ui_scale::{SafeAreaAnchor, SafeAreaInsets};
+    pub use crate::water::{Buoyant, Water};
+    pub use crate::window_config::WindowConfig;
+    pub use crate::world_flags::{WorldFlagId, WorldFlags};
+    #[cfg(feature = "serde")]
+    pub use crate::zone_population::{TurretBlueprint, ZonePopulation};
+    pub use crate::PlatformerPlugins;
+}
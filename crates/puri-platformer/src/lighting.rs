@@ -0,0 +1,192 @@
+//! Simple 2D point lights: [`Light2d`] marks a light source (radius, color,
+//! intensity, optional flicker) that can be attached to the player's torch,
+//! a lantern prop, or any other entity that already has a `Transform` — it
+//! moves with that entity for free, since it is a component on that entity
+//! itself rather than a separate child entity.
+//!
+//! There's no render-to-texture lightmap or custom `Material2d` shader in
+//! this crate to punch holes in `ambience::LevelAmbience`'s darkness
+//! overlay with — every existing 2D system here (`tilemap`'s chunk meshes,
+//! `ambience`'s overlay) deliberately sticks to `ColorMaterial`/`bevy_ui`
+//! rather than hand-written WGSL, since a shader can't be checked without a
+//! compiler and a GPU in this environment (see `tilemap`'s own note on
+//! that same tradeoff). So today `Light2d` is real, spawnable,
+//! reflect-registered data and nothing more: [`flicker_intensity`] is the
+//! pure function a future lightmap pass would call per light per frame,
+//! and [`cap_active_lights`] enforces the 50-light performance target by
+//! tagging whichever lights sort past the cap — farthest from the player
+//! first — with [`LightCulled`], logging when the budget is first exceeded
+//! and again whenever the over-budget light count changes.
+//! Whichever system ends up compositing the actual lightmap just needs to
+//! skip [`LightCulled`] entities; it doesn't change this module.
+//!
+//! [`spawn_dark_demo_room`] is a standalone demo layout showcasing a few
+//! `Light2d`s, the same way `boss::spawn_boss_arena` is a standalone demo
+//! layout for the boss fight: neither is spawned by the default
+//! `map::spawn_map_entities` `Startup` layout, since there's no level
+//! selection wired up yet to choose between them (see
+//! `LaunchOptions::level`'s own note on that gap).
+
+use bevy::prelude::*;
+
+use crate::collision::HitBox;
+use crate::player::Player;
+use crate::render_layer::{z_for, GameLayer};
+
+/// Lights beyond this many (closest-to-player first) get [`LightCulled`]
+/// instead of participating, so a level that spawns too many still holds
+/// the 50-light performance target instead of degrading silently.
+pub const MAX_ACTIVE_LIGHTS: usize = 50;
+
+/// A point light: `radius` and `color` describe its unlit extent and hue,
+/// `intensity` scales its brightness, and `flicker_hz` (torches, campfires)
+/// optionally modulates that intensity over time via [`flicker_intensity`].
+/// A steady light (a lantern, a glowing crystal) leaves `flicker_hz` `None`.
+#[derive(Component, Reflect, Clone, Copy)]
+#[reflect(Component)]
+pub struct Light2d {
+    pub radius: f32,
+    pub color: Color,
+    pub intensity: f32,
+    pub flicker_hz: Option<f32>,
+}
+
+impl Default for Light2d {
+    fn default() -> Self {
+        Self {
+            radius: 96.0,
+            color: Color::rgb(1.0, 0.85, 0.6),
+            intensity: 1.0,
+            flicker_hz: None,
+        }
+    }
+}
+
+/// Marks a [`Light2d`] disabled by [`cap_active_lights`] because more than
+/// [`MAX_ACTIVE_LIGHTS`] were active at once.
+#[derive(Component)]
+pub struct LightCulled;
+
+pub struct LightingPlugin;
+
+impl Plugin for LightingPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Light2d>().add_systems(Update, cap_active_lights);
+    }
+}
+
+/// `light`'s effective intensity at `elapsed` seconds into the global
+/// clock: steady at `light.intensity` with no flicker configured,
+/// otherwise smoothly varying between 60% and 100% of it at `flicker_hz`.
+/// Pure so a future lightmap pass (or a test) can call it without spinning
+/// up a `World`.
+pub fn flicker_intensity(light: &Light2d, elapsed: f32) -> f32 {
+    match light.flicker_hz {
+        Some(hz) if hz > 0.0 => {
+            let wave = (elapsed * hz * std::f32::consts::TAU).sin() * 0.5 + 0.5;
+            light.intensity * (0.6 + 0.4 * wave)
+        }
+        _ => light.intensity,
+    }
+}
+
+/// Keeps at most [`MAX_ACTIVE_LIGHTS`] `Light2d`s un-[`LightCulled`] at
+/// once, nearest the player first, warning when the budget is first
+/// exceeded and again whenever the over-budget total changes (not every
+/// frame). Lights un-cull themselves the
+/// moment the total drops back at or under the cap.
+fn cap_active_lights(
+    mut commands: Commands,
+    mut last_warned_total: Local<Option<usize>>,
+    player: Query<&Transform, With<Player>>,
+    lights: Query<(Entity, &Transform, Has<LightCulled>), With<Light2d>>,
+) {
+    let Ok(player_transform) = player.get_single() else {
+        return;
+    };
+    let player_pos = player_transform.translation.truncate();
+
+    let mut by_distance: Vec<(Entity, f32, bool)> = lights
+        .iter()
+        .map(|(entity, transform, culled)| {
+            let distance = (transform.translation.truncate() - player_pos).length_squared();
+            (entity, distance, culled)
+        })
+        .collect();
+
+    let total = by_distance.len();
+    if total <= MAX_ACTIVE_LIGHTS {
+        for (entity, _, culled) in &by_distance {
+            if *culled {
+                commands.entity(*entity).remove::<LightCulled>();
+            }
+        }
+        *last_warned_total = None;
+        return;
+    }
+
+    if *last_warned_total != Some(total) {
+        warn!("{total} Light2d entities active; the {MAX_ACTIVE_LIGHTS}-light budget only keeps the {MAX_ACTIVE_LIGHTS} closest to the player lit");
+        *last_warned_total = Some(total);
+    }
+
+    by_distance.sort_by(|a, b| a.1.total_cmp(&b.1));
+    for (index, (entity, _, culled)) in by_distance.iter().enumerate() {
+        let should_cull = index >= MAX_ACTIVE_LIGHTS;
+        if should_cull && !culled {
+            commands.entity(*entity).insert(LightCulled);
+        } else if !should_cull && *culled {
+            commands.entity(*entity).remove::<LightCulled>();
+        }
+    }
+}
+
+/// A standalone dark room with a flickering torch and two steady lanterns,
+/// demonstrating [`Light2d`] the way `boss::spawn_boss_arena` demonstrates
+/// the boss fight — not wired into any default startup (see this module's
+/// own note on that).
+pub fn spawn_dark_demo_room(mut commands: Commands) {
+    let torch_pos = Vec2::new(0.0, 0.0);
+    commands.spawn((
+        Light2d {
+            radius: 140.0,
+            color: Color::rgb(1.0, 0.6, 0.3),
+            intensity: 1.0,
+            flicker_hz: Some(6.0),
+        },
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::rgb(0.6, 0.3, 0.15),
+                custom_size: Some(Vec2::new(10.0, 24.0)),
+                ..default()
+            },
+            transform: Transform::from_translation(torch_pos.extend(z_for(GameLayer::Entities, torch_pos.y, true))),
+            ..default()
+        },
+        HitBox {
+            size: Vec2::new(10.0, 24.0),
+        },
+    ));
+
+    for lantern_pos in [Vec2::new(-180.0, -20.0), Vec2::new(200.0, 40.0)] {
+        commands.spawn((
+            Light2d {
+                radius: 80.0,
+                color: Color::rgb(0.6, 0.8, 1.0),
+                intensity: 0.8,
+                flicker_hz: None,
+            },
+            SpriteBundle {
+                sprite: Sprite {
+                    color: Color::rgb(0.7, 0.9, 1.0),
+                    custom_size: Some(Vec2::new(12.0, 12.0)),
+                    ..default()
+                },
+                transform: Transform::from_translation(
+                    lantern_pos.extend(z_for(GameLayer::Entities, lantern_pos.y, true)),
+                ),
+                ..default()
+            },
+        ));
+    }
+}
@@ -0,0 +1,316 @@
+//! Loot drops from defeated enemies. [`LootTable`] rolls a [`LootKind`]
+//! through the seeded `rng::GameRng` (never wall-clock randomness — see
+//! that module's own doc comment) the instant an enemy dies; [`spawn_drops`]
+//! pops the result out of the kill with a little scatter velocity so it
+//! doesn't spawn stacked exactly on the spot. From there a drop rides the
+//! same `GroundedBody` + `collision::aabb::move_bodies` sweep the player and
+//! `enemy_ai::Patroller` already do, falling under [`apply_drop_gravity`] —
+//! a third, narrowly `LootDrop`-scoped instance of the same
+//! `player::apply_gravity`-is-`With<Player>`-only gap `enemy_ai`'s own doc
+//! comment already covers — so it settles onto whatever solid ground it
+//! lands on rather than the level's specific geometry.
+//!
+//! [`LootTable::standard`] (80% `Coin`, 15% `Heart`, 5% `Nothing`, matching
+//! the ask) is the crate's only loot table today, attached to
+//! `turret::spawn_turret` since `Turret` is the only prefab with a real
+//! despawn path (`combo::stomp_enemies`) to roll it from — a `Patroller`
+//! kill has nowhere to roll one from yet, since nothing currently damages
+//! one down to zero `Health` and despawns it (see `enemy_ai`'s own note on
+//! that same gap). `combo::EnemyDefeated::loot` is `None` for any kill
+//! without a `LootTable` attached, and a `Nothing` roll despawns no drop at
+//! all, so both cases look identical from here.
+//!
+//! [`collect_drops`] is the crate's usual manual-AABB-overlap pickup check
+//! (`starman::collect_star_pickup`'s own pattern, since `CollisionEvent` is
+//! defined but nothing in this crate ever sends one): `Coin` credits
+//! `shop::Wallet`, `Heart` fires [`HealEvent`]. Healing is uncapped — there
+//! is no max-health concept anywhere in this crate to clamp against;
+//! `Health` is a bare `u32`, and `PlayerSpawner::max_health` only ever sets
+//! the *starting* value, not a ceiling — so a `Heart` can overheal past it.
+//! An uncollected drop despawns after [`DROP_LIFETIME_SECONDS`], blinking
+//! for the last [`BLINK_WARNING_SECONDS`] the same way `Starman` does
+//! before it runs out.
+//!
+//! "Must not fall through floors at high spawn velocity" is handled the way
+//! this crate already handles fast movement generally: keeping
+//! [`POP_VELOCITY_Y_MAX`] modest against the 60Hz per-frame `move_bodies`
+//! sweep and the demo map's solid floor thickness, the same tolerance the
+//! player's own much larger terminal velocity already relies on, rather
+//! than adding continuous collision detection this crate has never had.
+
+use bevy::prelude::*;
+
+use crate::collision::{GroundedBody, HitBox, Sensor};
+use crate::combo::EnemyDefeated;
+use crate::floating_text::spawn_floating_text;
+use crate::physics_config::PlayerPhysicsConfig;
+use crate::player::{Health, Player, Velocity};
+use crate::render_layer::{z_for, GameLayer};
+use crate::rng::GameRng;
+use crate::schedule::PlatformerSet;
+use crate::shop::Wallet;
+use crate::time_scale::GameTime;
+
+const DROP_LIFETIME_SECONDS: f32 = 10.0;
+const BLINK_WARNING_SECONDS: f32 = 2.0;
+const BLINK_HZ: f32 = 8.0;
+const DROP_SIZE: Vec2 = Vec2::new(12.0, 12.0);
+const HEAL_AMOUNT: u32 = 1;
+/// Kept modest against the 60Hz `move_bodies` sweep and the demo map's
+/// solid floor thickness — see this module's own doc comment.
+const POP_VELOCITY_X: f32 = 60.0;
+const POP_VELOCITY_Y_MIN: f32 = 120.0;
+const POP_VELOCITY_Y_MAX: f32 = 180.0;
+const COIN_POPUP_RISE_SPEED: f32 = 30.0;
+const COIN_POPUP_LIFETIME_SECONDS: f32 = 0.6;
+
+/// A rolled outcome from a [`LootTable`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum LootKind {
+    Coin,
+    Heart,
+    Nothing,
+}
+
+/// Weighted table of possible drops, rolled once per kill by
+/// [`LootTable::roll`]. Weights don't need to sum to any particular total —
+/// `roll` normalizes against their sum — but [`LootTable::standard`]'s add
+/// to 1.0 to match the ask's percentages directly.
+#[derive(Component, Clone)]
+pub struct LootTable {
+    entries: Vec<(LootKind, f32)>,
+}
+
+impl LootTable {
+    pub fn new(entries: Vec<(LootKind, f32)>) -> Self {
+        Self { entries }
+    }
+
+    /// Coin 80%, Heart 15%, Nothing 5% — the one loot table this crate
+    /// actually uses today, attached to `turret::spawn_turret`.
+    pub fn standard() -> Self {
+        Self::new(vec![(LootKind::Coin, 0.8), (LootKind::Heart, 0.15), (LootKind::Nothing, 0.05)])
+    }
+
+    /// Rolls a weighted outcome. Falls back to `Nothing` if the table is
+    /// empty or every weight is non-positive, rather than panicking.
+    pub fn roll(&self, rng: &mut GameRng) -> LootKind {
+        let total: f32 = self.entries.iter().map(|(_, weight)| weight.max(0.0)).sum();
+        if total <= 0.0 {
+            return LootKind::Nothing;
+        }
+        let mut roll = rng.range_f32(0.0, total);
+        for (kind, weight) in &self.entries {
+            let weight = weight.max(0.0);
+            if roll < weight {
+                return *kind;
+            }
+            roll -= weight;
+        }
+        LootKind::Nothing
+    }
+}
+
+/// Fired by [`collect_drops`] when a `Heart` is picked up. See this
+/// module's own doc comment on why the heal it applies is uncapped.
+#[derive(Event)]
+pub struct HealEvent {
+    pub amount: u32,
+}
+
+/// A popped-out loot drop waiting to be collected or to expire.
+#[derive(Component)]
+pub struct LootDrop {
+    pub kind: LootKind,
+    lifetime: Timer,
+}
+
+impl LootDrop {
+    fn is_blinking(&self) -> bool {
+        self.lifetime.remaining_secs() <= BLINK_WARNING_SECONDS
+    }
+}
+
+pub struct LootPlugin;
+
+impl Plugin for LootPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<HealEvent>()
+            .add_systems(
+                Update,
+                spawn_drops.in_set(PlatformerSet::PostPhysics).after(crate::combo::stomp_enemies),
+            )
+            .add_systems(Update, apply_drop_gravity.in_set(PlatformerSet::Physics))
+            .add_systems(
+                Update,
+                (collect_drops, apply_heal, tick_drop_lifetimes)
+                    .chain()
+                    .in_set(PlatformerSet::PostPhysics)
+                    .after(spawn_drops)
+                    .after(crate::shop::purchase_selected)
+                    .after(crate::death::apply_damage),
+            );
+        #[cfg(feature = "serde")]
+        app.add_systems(Update, record_coin_pickups.after(collect_drops));
+    }
+}
+
+/// Pops a [`LootDrop`] out of every [`EnemyDefeated`] that rolled something
+/// other than `Nothing`. Scatter velocity is a fresh `GameRng` draw per
+/// drop, not a fixed direction, so several drops from the same kill (once
+/// anything ever sends more than one) don't stack in a neat pile.
+fn spawn_drops(mut commands: Commands, mut rng: ResMut<GameRng>, mut defeated: EventReader<EnemyDefeated>) {
+    for event in defeated.read() {
+        let Some(kind) = event.loot else { continue };
+        if kind == LootKind::Nothing {
+            continue;
+        }
+        let velocity = Vec2::new(rng.range_f32(-POP_VELOCITY_X, POP_VELOCITY_X), rng.range_f32(POP_VELOCITY_Y_MIN, POP_VELOCITY_Y_MAX));
+        commands.spawn((
+            LootDrop {
+                kind,
+                lifetime: Timer::from_seconds(DROP_LIFETIME_SECONDS, TimerMode::Once),
+            },
+            GroundedBody,
+            Sensor,
+            HitBox { size: DROP_SIZE },
+            Velocity(velocity),
+            SpriteBundle {
+                sprite: Sprite {
+                    color: color_for(kind),
+                    custom_size: Some(DROP_SIZE),
+                    ..default()
+                },
+                transform: Transform::from_translation(event.position.extend(z_for(GameLayer::Entities, event.position.y, true))),
+                ..default()
+            },
+        ));
+    }
+}
+
+fn color_for(kind: LootKind) -> Color {
+    match kind {
+        LootKind::Coin => Color::rgb(1.0, 0.85, 0.2),
+        LootKind::Heart => Color::rgb(1.0, 0.3, 0.4),
+        LootKind::Nothing => Color::WHITE,
+    }
+}
+
+/// This module's own gravity pull for a falling `LootDrop` — see this
+/// module's own doc comment on why it doesn't ride `player::apply_gravity`
+/// instead. `Without<Player>` keeps this query's `Velocity` access provably
+/// disjoint from `player`'s own `With<Player>`-filtered writers, the same
+/// fix `enemy_ai::apply_hitstun_gravity` applies for the same reason.
+fn apply_drop_gravity(time: GameTime, config: Res<PlayerPhysicsConfig>, mut drops: Query<&mut Velocity, (With<LootDrop>, Without<Player>)>) {
+    for mut velocity in &mut drops {
+        velocity.0.y = (velocity.0.y - config.gravity * time.delta_seconds()).max(-config.terminal_velocity);
+    }
+}
+
+/// Manual AABB overlap against the player, mirroring
+/// `starman::collect_star_pickup`. Credits `Coin`s to `Wallet` directly and
+/// fires [`HealEvent`] for `Heart`s rather than touching `Health` here, so
+/// the actual heal amount lives in one place ([`apply_heal`]) instead of
+/// two. `pub(crate)` so `enemy_ai::spawn_damage_numbers` can order itself
+/// `.after` this — both write `GameRng` (this to jitter a coin's popup via
+/// `floating_text::spawn_floating_text`, that to jitter a damage number the
+/// same way), mirroring `combo::stomp_enemies`'s own citation for the same
+/// reason.
+pub(crate) fn collect_drops(
+    mut commands: Commands,
+    mut rng: ResMut<GameRng>,
+    mut wallet: ResMut<Wallet>,
+    mut heal: EventWriter<HealEvent>,
+    player: Query<(&Transform, &HitBox), With<Player>>,
+    drops: Query<(Entity, &Transform, &HitBox, &LootDrop)>,
+) {
+    let Ok((player_transform, player_box)) = player.get_single() else {
+        return;
+    };
+    for (entity, transform, hitbox, drop) in &drops {
+        let touch_distance = (player_box.size + hitbox.size) / 2.0;
+        let overlapping = (player_transform.translation.truncate() - transform.translation.truncate())
+            .abs()
+            .cmplt(touch_distance)
+            .all();
+        if !overlapping {
+            continue;
+        }
+        match drop.kind {
+            LootKind::Coin => {
+                wallet.add(1);
+                spawn_floating_text(
+                    &mut commands,
+                    &mut rng,
+                    transform.translation.truncate(),
+                    "+1".to_string(),
+                    color_for(LootKind::Coin),
+                    Vec2::new(0.0, COIN_POPUP_RISE_SPEED),
+                    COIN_POPUP_LIFETIME_SECONDS,
+                );
+            }
+            LootKind::Heart => {
+                heal.send(HealEvent { amount: HEAL_AMOUNT });
+            }
+            LootKind::Nothing => {}
+        }
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+/// Applies every queued [`HealEvent`] to the player's `Health`, uncapped —
+/// see this module's own doc comment on why there's nothing to clamp
+/// against. `Without<Dying>` mirrors `death::apply_damage`'s own filter on
+/// the same component, so a drop collected the instant the player dies
+/// doesn't heal a body already mid-death-animation.
+fn apply_heal(mut heal: EventReader<HealEvent>, mut player: Query<&mut Health, (With<Player>, Without<crate::death::Dying>)>) {
+    let total: u32 = heal.read().map(|event| event.amount).sum();
+    if total == 0 {
+        return;
+    }
+    let Ok(mut health) = player.get_single_mut() else {
+        return;
+    };
+    health.0 += total;
+}
+
+/// Ticks every drop's lifetime, blinking it for the last
+/// [`BLINK_WARNING_SECONDS`] the same way `starman::animate_starman_tint`
+/// does, and despawns it once the timer finishes.
+fn tick_drop_lifetimes(time: GameTime, mut commands: Commands, mut drops: Query<(Entity, &mut LootDrop, &mut Sprite)>) {
+    for (entity, mut drop, mut sprite) in &mut drops {
+        drop.lifetime.tick(time.delta());
+        if drop.lifetime.finished() {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+        if drop.is_blinking() {
+            let blink_on = (time.elapsed_seconds() * BLINK_HZ) as u32 % 2 == 0;
+            sprite.color = if blink_on { Color::WHITE } else { color_for(drop.kind) };
+        }
+    }
+}
+
+/// [`collect_drops`] is the only place anything ever credits [`Wallet`]
+/// (see this module's own doc comment on why coins are credited directly
+/// rather than through an event), so this watches the balance instead of
+/// adding a `CoinCollected` event of its own next to `HealEvent`. Skips the
+/// very first frame so a save-restored starting balance doesn't read as a
+/// burst of pickups.
+#[cfg(feature = "serde")]
+fn record_coin_pickups(
+    wallet: Res<Wallet>,
+    mut last_seen: Local<Option<u32>>,
+    mut session: EventWriter<crate::session_recorder::RecordSessionEvent>,
+) {
+    let Some(previous) = *last_seen else {
+        *last_seen = Some(wallet.0);
+        return;
+    };
+    for _ in previous..wallet.0 {
+        session.send(crate::session_recorder::RecordSessionEvent(
+            crate::session_recorder::SessionEvent::CoinCollected { level_id: 0 },
+        ));
+    }
+    *last_seen = Some(wallet.0);
+}
@@ -0,0 +1,726 @@
+use bevy::prelude::*;
+
+#[cfg(feature = "editor")]
+use bevy::ecs::entity::EntityHashMap;
+#[cfg(feature = "editor")]
+use bevy::scene::serde::SceneDeserializer;
+#[cfg(feature = "editor")]
+use bevy::scene::DynamicSceneBuilder;
+#[cfg(feature = "editor")]
+use bevy::window::PrimaryWindow;
+#[cfg(feature = "editor")]
+use bevy_editor_pls::default_windows::hierarchy::HierarchyWindow;
+#[cfg(feature = "editor")]
+use bevy_editor_pls::editor::Editor;
+#[cfg(feature = "editor")]
+use bevy_editor_pls::prelude::*;
+#[cfg(feature = "editor")]
+use serde::de::DeserializeSeed;
+
+use puri_platformer::launch_options::{LaunchOptions, USAGE};
+use puri_platformer::map::Path;
+use puri_platformer::prelude::*;
+use puri_platformer::spatial_grid::SpatialGrid;
+use puri_platformer::PlatformerPlugins;
+
+fn main() {
+    puri_platformer::event_log::install_panic_hook();
+
+    let options = match LaunchOptions::parse(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("{message}\n\n{USAGE}");
+            std::process::exit(1);
+        }
+    };
+
+    #[cfg(feature = "serde")]
+    if let Some(path) = &options.analyze {
+        match puri_platformer::session_recorder::analyze::read_events(path) {
+            Ok(events) => {
+                print!("{}", puri_platformer::session_recorder::analyze::summarize(&events));
+                std::process::exit(0);
+            }
+            Err(err) => {
+                eprintln!("--analyze: failed to read {}: {err}", path.display());
+                std::process::exit(1);
+            }
+        }
+    }
+    #[cfg(not(feature = "serde"))]
+    if options.analyze.is_some() {
+        eprintln!("--analyze was given but this build doesn't have the `serde` feature enabled, ignoring");
+    }
+    #[cfg(not(feature = "serde"))]
+    if options.analytics.is_some() {
+        eprintln!("--analytics was given but this build doesn't have the `serde` feature enabled, ignoring");
+    }
+
+    // Must run before `DefaultPlugins` (below) installs its own `LogPlugin`
+    // subscriber: `tracing` only accepts the first global default per
+    // process, so ours has to get there first for chrome tracing to work.
+    // `LogPlugin` then just skips its own setup once it sees one is
+    // already installed.
+    #[cfg(feature = "trace")]
+    let _trace_guard = match &options.trace {
+        Some(path) => {
+            use tracing_subscriber::layer::SubscriberExt;
+            let (chrome_layer, guard) =
+                tracing_chrome::ChromeLayerBuilder::new().file(path).build();
+            let subscriber = tracing_subscriber::registry().with(chrome_layer);
+            if tracing::subscriber::set_global_default(subscriber).is_err() {
+                eprintln!("--trace: a tracing subscriber was already installed, ignoring");
+            }
+            Some(guard)
+        }
+        None => None,
+    };
+    #[cfg(not(feature = "trace"))]
+    if options.trace.is_some() {
+        eprintln!(
+            "--trace was given but this build doesn't have the `trace` feature enabled, ignoring"
+        );
+    }
+
+    let mut app = App::new();
+    if options.headless {
+        app.add_plugins(MinimalPlugins);
+    } else {
+        app.add_plugins(DefaultPlugins);
+    }
+    let debug_draw = options.debug_draw;
+    app.insert_resource(options)
+        .add_plugins(PlatformerPlugins)
+        .add_systems(Update, draw_hitbox_gizmos.run_if(overlay_visible));
+
+    // F3 toggles hitbox gizmos and the debug overlay together at runtime
+    // (see `debug_overlay::DebugOverlayVisible`); `--debug-draw` and the
+    // `editor` feature just pick the starting state.
+    if debug_draw || cfg!(feature = "editor") {
+        app.world.resource_mut::<DebugOverlayVisible>().0 = true;
+    }
+
+    #[cfg(feature = "editor")]
+    app.add_plugins(EditorPlugin::default())
+        .init_resource::<EditorHistory>()
+        .add_systems(
+            Update,
+            (
+                free_camera,
+                save_debug_scene,
+                pick_entity,
+                draw_selection_gizmo,
+                draw_path_gizmos,
+                edit_path_handles,
+                delete_selected_entity,
+                undo_redo_editor_history,
+            ),
+        )
+        .add_systems(OnEnter(LevelLoadState::Loading), clear_editor_history);
+
+    app.run();
+}
+
+/// Dumps the whole world to `debug_dump.scn.ron` on F5, for attaching a
+/// snapshot of the current state to a bug report.
+#[cfg(feature = "editor")]
+fn save_debug_scene(world: &mut World) {
+    let keys = world.resource::<ButtonInput<KeyCode>>();
+    if !keys.just_pressed(KeyCode::F5) {
+        return;
+    }
+
+    let scene = DynamicSceneBuilder::from_world(world)
+        .extract_entities(world.iter_entities().map(|e| e.id()))
+        .build();
+    let registry = world.resource::<AppTypeRegistry>();
+    match scene.serialize_ron(registry) {
+        Ok(ron) => {
+            if let Err(err) = std::fs::write("debug_dump.scn.ron", ron) {
+                error!("failed to write debug_dump.scn.ron: {err}");
+            } else {
+                info!("wrote debug_dump.scn.ron");
+            }
+        }
+        Err(err) => error!("failed to serialize debug scene: {err}"),
+    }
+}
+
+fn draw_hitbox_gizmos(mut gizmos: Gizmos, query: Query<(&Transform, &HitBox)>) {
+    for (transform, hitbox) in &query {
+        gizmos.rect_2d(
+            transform.translation.truncate(),
+            0.0,
+            hitbox.size,
+            Color::GREEN,
+        );
+    }
+}
+
+#[cfg(feature = "editor")]
+fn free_camera(
+    keys: Res<ButtonInput<KeyCode>>,
+    time: Res<Time>,
+    mut camera: Query<&mut Transform, With<Camera2d>>,
+) {
+    let Ok(mut transform) = camera.get_single_mut() else {
+        return;
+    };
+    if !keys.pressed(KeyCode::AltLeft) {
+        return;
+    }
+    let mut dir = Vec2::ZERO;
+    if keys.pressed(KeyCode::ArrowUp) {
+        dir.y += 1.0;
+    }
+    if keys.pressed(KeyCode::ArrowDown) {
+        dir.y -= 1.0;
+    }
+    if keys.pressed(KeyCode::ArrowLeft) {
+        dir.x -= 1.0;
+    }
+    if keys.pressed(KeyCode::ArrowRight) {
+        dir.x += 1.0;
+    }
+    transform.translation += (dir * 400.0 * time.delta_seconds()).extend(0.0);
+}
+
+/// Same bucket size `collision::aabb::move_bodies` uses for its own
+/// per-frame [`SpatialGrid`] — the demo map's solids are the same ones
+/// either way, so there's no reason to pick a different cell size here.
+#[cfg(feature = "editor")]
+const PICK_GRID_CELL_SIZE: f32 = 128.0;
+
+/// Click-to-select for the editor's hierarchy/inspector windows: converts
+/// the cursor to world space via the active camera, gathers every `HitBox`
+/// (bucketed through the same [`SpatialGrid`] the collision system uses)
+/// and every `Sprite` with a `custom_size` under that point, and hands the
+/// topmost one (highest `Transform::translation.z`) to
+/// `bevy_editor_pls`'s own selection state so it opens in the Hierarchy and
+/// Inspector windows exactly as if it had been clicked there. Shift-clicking
+/// the same spot again walks to the next candidate underneath instead of
+/// re-selecting the top one, so overlapping entities are all reachable.
+#[cfg(feature = "editor")]
+fn pick_entity(
+    mut editor: ResMut<Editor>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    keys: Res<ButtonInput<KeyCode>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    camera: Query<(&Camera, &GlobalTransform)>,
+    hitboxes: Query<(Entity, &Transform, &HitBox)>,
+    sprites: Query<(Entity, &Transform, &Sprite), Without<HitBox>>,
+    mut last_pick: Local<Option<(Vec2, usize)>>,
+) {
+    if !mouse.just_pressed(MouseButton::Left) || editor.pointer_used() {
+        return;
+    }
+    let Ok(window) = windows.get_single() else {
+        return;
+    };
+    let Some(cursor) = window.cursor_position() else {
+        return;
+    };
+    let Ok((camera, camera_transform)) = camera.get_single() else {
+        return;
+    };
+    let Some(world_pos) = camera.viewport_to_world_2d(camera_transform, cursor) else {
+        return;
+    };
+
+    let entities: Vec<Entity> = hitboxes.iter().map(|(entity, ..)| entity).collect();
+    let boxes: Vec<(Vec2, Vec2)> = hitboxes
+        .iter()
+        .map(|(_, transform, hitbox)| (transform.translation.truncate(), hitbox.size))
+        .collect();
+    let grid = SpatialGrid::build(&boxes, PICK_GRID_CELL_SIZE);
+    let mut candidates: Vec<(Entity, f32)> = grid
+        .query(world_pos, Vec2::ONE)
+        .filter(|&index| {
+            let (pos, size) = boxes[index];
+            (world_pos - pos).abs().cmple(size / 2.0).all()
+        })
+        .map(|index| {
+            (
+                entities[index],
+                hitboxes.get(entities[index]).unwrap().1.translation.z,
+            )
+        })
+        .collect();
+    for (entity, transform, sprite) in &sprites {
+        let Some(size) = sprite.custom_size else {
+            continue;
+        };
+        let pos = transform.translation.truncate();
+        if (world_pos - pos).abs().cmple(size / 2.0).all() {
+            candidates.push((entity, transform.translation.z));
+        }
+    }
+    candidates.sort_by(|a, b| b.1.total_cmp(&a.1));
+    candidates.dedup_by_key(|(entity, _)| *entity);
+    if candidates.is_empty() {
+        return;
+    }
+
+    let shift = keys.pressed(KeyCode::ShiftLeft) || keys.pressed(KeyCode::ShiftRight);
+    let index = match *last_pick {
+        Some((last_pos, last_index)) if shift && last_pos == world_pos => {
+            (last_index + 1) % candidates.len()
+        }
+        _ => 0,
+    };
+    *last_pick = Some((world_pos, index));
+
+    if let Some(hierarchy) = editor.window_state_mut::<HierarchyWindow>() {
+        hierarchy.selected.select_replace(candidates[index].0);
+    }
+}
+
+/// Yellow outline around whatever's currently selected in the editor's
+/// Hierarchy window, so a pick made with [`pick_entity`] (or by clicking
+/// the hierarchy tree directly) is visible in the viewport without opening
+/// the Inspector.
+#[cfg(feature = "editor")]
+fn draw_selection_gizmo(
+    editor: Res<Editor>,
+    mut gizmos: Gizmos,
+    transforms: Query<&Transform>,
+    hitboxes: Query<&HitBox>,
+    sprites: Query<&Sprite>,
+) {
+    let Some(hierarchy) = editor.window_state::<HierarchyWindow>() else {
+        return;
+    };
+    let Some(entity) = hierarchy.selected.iter().next() else {
+        return;
+    };
+    let Ok(transform) = transforms.get(entity) else {
+        return;
+    };
+    let size = if let Ok(hitbox) = hitboxes.get(entity) {
+        hitbox.size
+    } else if let Ok(sprite) = sprites.get(entity) {
+        sprite.custom_size.unwrap_or(Vec2::splat(32.0))
+    } else {
+        Vec2::splat(32.0)
+    };
+    gizmos.rect_2d(
+        transform.translation.truncate(),
+        0.0,
+        size * 1.1,
+        Color::YELLOW,
+    );
+}
+
+/// World-space radius for a draggable path handle, and how close a click
+/// needs to land on a handle/segment to hit it — same value doing double
+/// duty the way `difficulty_assist::CLUSTER_RADIUS` doubles as both "how
+/// clustered" and (indirectly) the toast trigger.
+#[cfg(feature = "editor")]
+const PATH_HANDLE_RADIUS: f32 = 8.0;
+
+#[cfg(feature = "editor")]
+const DOUBLE_CLICK_SECONDS: f32 = 0.35;
+
+/// Draws every `map::Path` as a polyline with a circle at each waypoint,
+/// the first drawn larger to mark where `map::path_direction`/
+/// `map::advance_along_path` start. `Gizmos` has no text primitive in this
+/// Bevy version, so "numbered handles" per the ask are distinguished only
+/// by that start marker rather than actual digits — the same tradeoff
+/// `main.rs`'s hitbox gizmos already make (a plain rect, no label) rather
+/// than spawning and tearing down a `Text2dBundle` per handle every time a
+/// path changes.
+#[cfg(feature = "editor")]
+fn draw_path_gizmos(mut gizmos: Gizmos, paths: Query<&Path>) {
+    for path in &paths {
+        if path.points.len() < 2 {
+            continue;
+        }
+        gizmos.linestrip_2d(path.points.iter().copied(), Color::CYAN);
+        for (index, point) in path.points.iter().enumerate() {
+            let radius = if index == 0 {
+                PATH_HANDLE_RADIUS * 1.5
+            } else {
+                PATH_HANDLE_RADIUS
+            };
+            gizmos.circle_2d(*point, radius, Color::CYAN);
+        }
+    }
+}
+
+/// Mouse editing for every `map::Path` in the world: drag a handle to move
+/// it, double-click a segment to insert a point on it, right-click a
+/// handle to delete it (a path never drops below two points, so it can't
+/// stop being a path). Reuses [`pick_entity`]'s cursor-to-world conversion
+/// but hit-tests path points/segments instead of `HitBox`es, since a
+/// handle is a few pixels wide and would rarely win a spatial-grid query
+/// built for whole-entity hitboxes.
+#[cfg(feature = "editor")]
+fn edit_path_handles(
+    editor: Res<Editor>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    time: Res<Time>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    camera: Query<(&Camera, &GlobalTransform)>,
+    mut paths: Query<(Entity, &mut Path)>,
+    mut history: ResMut<EditorHistory>,
+    mut dragging: Local<Option<(Entity, usize, Vec2)>>,
+    mut last_click: Local<Option<(f32, Vec2)>>,
+) {
+    if editor.pointer_used() {
+        return;
+    }
+    let Ok(window) = windows.get_single() else {
+        return;
+    };
+    let Some(cursor) = window.cursor_position() else {
+        return;
+    };
+    let Ok((camera, camera_transform)) = camera.get_single() else {
+        return;
+    };
+    let Some(world_pos) = camera.viewport_to_world_2d(camera_transform, cursor) else {
+        return;
+    };
+
+    if mouse.just_released(MouseButton::Left) {
+        if let Some((entity, index, from)) = dragging.take() {
+            if let Ok((_, path)) = paths.get(entity) {
+                let to = path.points[index];
+                if to != from {
+                    history.push(EditorOp::MovePathPoint {
+                        entity,
+                        index,
+                        from,
+                        to,
+                    });
+                }
+            }
+        }
+    }
+    if let Some((entity, index, _)) = *dragging {
+        if let Ok((_, mut path)) = paths.get_mut(entity) {
+            if let Some(point) = path.points.get_mut(index) {
+                *point = world_pos;
+            }
+        }
+        return;
+    }
+
+    if mouse.just_pressed(MouseButton::Right) {
+        for (entity, mut path) in &mut paths {
+            if let Some(index) = handle_at(&path, world_pos) {
+                if path.points.len() > 2 {
+                    let point = path.points.remove(index);
+                    history.push(EditorOp::DeletePathPoint {
+                        entity,
+                        index,
+                        point,
+                    });
+                }
+                return;
+            }
+        }
+        return;
+    }
+
+    if !mouse.just_pressed(MouseButton::Left) {
+        return;
+    }
+
+    for (entity, path) in &paths {
+        if let Some(index) = handle_at(&path, world_pos) {
+            *dragging = Some((entity, index, path.points[index]));
+            *last_click = None;
+            return;
+        }
+    }
+
+    let elapsed = time.elapsed_seconds();
+    let is_double_click = matches!(*last_click, Some((t, pos)) if elapsed - t < DOUBLE_CLICK_SECONDS && pos.distance(world_pos) < PATH_HANDLE_RADIUS);
+    *last_click = Some((elapsed, world_pos));
+    if !is_double_click {
+        return;
+    }
+    for (entity, mut path) in &mut paths {
+        if let Some(index) = segment_at(&path, world_pos) {
+            let index = index + 1;
+            path.points.insert(index, world_pos);
+            history.push(EditorOp::InsertPathPoint {
+                entity,
+                index,
+                point: world_pos,
+            });
+            return;
+        }
+    }
+}
+
+/// A reversible editor-mode edit. Only covers the operations this editor
+/// can actually perform today — dragging/inserting/deleting a `map::Path`
+/// handle ([`edit_path_handles`]) and deleting the selected entity
+/// ([`delete_selected_entity`]). There's no in-game placement palette yet
+/// (see `prefab::spawn_prefab`'s own doc comment on that), so "spawn
+/// prefab" from the request this exists to satisfy has nothing to record
+/// an op for — undoing a spawn will get an [`EditorOp`] variant once
+/// placement itself exists.
+#[cfg(feature = "editor")]
+enum EditorOp {
+    MovePathPoint {
+        entity: Entity,
+        index: usize,
+        from: Vec2,
+        to: Vec2,
+    },
+    InsertPathPoint {
+        entity: Entity,
+        index: usize,
+        point: Vec2,
+    },
+    DeletePathPoint {
+        entity: Entity,
+        index: usize,
+        point: Vec2,
+    },
+    /// `ron` is a single-entity `DynamicScene` dump, the same format
+    /// `save_debug_scene` writes for the whole world — only
+    /// `#[reflect(Component)]`-registered components survive the
+    /// round-trip, same caveat `save_debug_scene` already carries.
+    DeleteEntity { entity: Entity, ron: String },
+}
+
+/// Capped undo/redo stacks of [`EditorOp`]s. `redo` is cleared whenever a
+/// new op is pushed, the usual undo-tree-becomes-a-line-again rule. Cleared
+/// entirely on level load since none of its recorded `Entity`s survive a
+/// `level_reload::reload_level`/`level_load::spawn_level` respawn.
+#[cfg(feature = "editor")]
+#[derive(Resource, Default)]
+struct EditorHistory {
+    undo: Vec<EditorOp>,
+    redo: Vec<EditorOp>,
+}
+
+#[cfg(feature = "editor")]
+const EDITOR_HISTORY_CAP: usize = 100;
+
+#[cfg(feature = "editor")]
+impl EditorHistory {
+    fn push(&mut self, op: EditorOp) {
+        self.redo.clear();
+        self.undo.push(op);
+        if self.undo.len() > EDITOR_HISTORY_CAP {
+            self.undo.remove(0);
+        }
+    }
+}
+
+#[cfg(feature = "editor")]
+fn clear_editor_history(mut history: ResMut<EditorHistory>) {
+    history.undo.clear();
+    history.redo.clear();
+}
+
+/// Ctrl+Z undoes `history.undo`'s top op; Ctrl+Shift+Z redoes
+/// `history.redo`'s top op. Both go through [`undo_op`], which applies an
+/// op's inverse and hands back the op that reverses *that* — pushed onto
+/// the other stack — so the same function drives both directions except
+/// for [`EditorOp::DeleteEntity`], which isn't its own inverse-of-inverse
+/// (undoing a delete respawns under a new `Entity`; redoing it has to
+/// delete that new entity, not respawn again), and is special-cased in
+/// [`redo_op`].
+#[cfg(feature = "editor")]
+fn undo_redo_editor_history(world: &mut World) {
+    let keys = world.resource::<ButtonInput<KeyCode>>();
+    let ctrl = keys.pressed(KeyCode::ControlLeft) || keys.pressed(KeyCode::ControlRight);
+    if !ctrl || !keys.just_pressed(KeyCode::KeyZ) {
+        return;
+    }
+    let shift = keys.pressed(KeyCode::ShiftLeft) || keys.pressed(KeyCode::ShiftRight);
+
+    if shift {
+        let Some(op) = world.resource_mut::<EditorHistory>().redo.pop() else {
+            return;
+        };
+        let inverse = redo_op(op, world);
+        world.resource_mut::<EditorHistory>().undo.push(inverse);
+    } else {
+        let Some(op) = world.resource_mut::<EditorHistory>().undo.pop() else {
+            return;
+        };
+        let inverse = undo_op(op, world);
+        world.resource_mut::<EditorHistory>().redo.push(inverse);
+    }
+}
+
+/// Applies `op`'s inverse to `world` and returns the op that would redo it.
+#[cfg(feature = "editor")]
+fn undo_op(op: EditorOp, world: &mut World) -> EditorOp {
+    match op {
+        EditorOp::MovePathPoint {
+            entity,
+            index,
+            from,
+            to,
+        } => {
+            if let Some(mut path) = world.get_mut::<Path>(entity) {
+                if let Some(point) = path.points.get_mut(index) {
+                    *point = from;
+                }
+            }
+            EditorOp::MovePathPoint {
+                entity,
+                index,
+                from: to,
+                to: from,
+            }
+        }
+        EditorOp::InsertPathPoint {
+            entity,
+            index,
+            point,
+        } => {
+            if let Some(mut path) = world.get_mut::<Path>(entity) {
+                if index < path.points.len() {
+                    path.points.remove(index);
+                }
+            }
+            EditorOp::DeletePathPoint {
+                entity,
+                index,
+                point,
+            }
+        }
+        EditorOp::DeletePathPoint {
+            entity,
+            index,
+            point,
+        } => {
+            if let Some(mut path) = world.get_mut::<Path>(entity) {
+                let index = index.min(path.points.len());
+                path.points.insert(index, point);
+            }
+            EditorOp::InsertPathPoint {
+                entity,
+                index,
+                point,
+            }
+        }
+        EditorOp::DeleteEntity { ron, .. } => {
+            let entity = respawn_entity_from_ron(world, &ron);
+            EditorOp::DeleteEntity { entity, ron }
+        }
+    }
+}
+
+/// Redoes `op` — for every variant but [`EditorOp::DeleteEntity`] this is
+/// just [`undo_op`] again (each of those variants' inverse is itself
+/// invertible back to the original, see [`undo_redo_editor_history`]'s own
+/// doc comment); `DeleteEntity` instead despawns the entity [`undo_op`]
+/// respawned, rather than respawning a second time.
+#[cfg(feature = "editor")]
+fn redo_op(op: EditorOp, world: &mut World) -> EditorOp {
+    if let EditorOp::DeleteEntity { entity, ron } = op {
+        world.despawn(entity);
+        return EditorOp::DeleteEntity { entity, ron };
+    }
+    undo_op(op, world)
+}
+
+/// Dumps `entity` alone to a RON `DynamicScene`, the single-entity version
+/// of `save_debug_scene`'s whole-world dump.
+#[cfg(feature = "editor")]
+fn capture_entity_ron(world: &mut World, entity: Entity) -> String {
+    let scene = DynamicSceneBuilder::from_world(world)
+        .extract_entity(entity)
+        .build();
+    let registry = world.resource::<AppTypeRegistry>();
+    scene.serialize_ron(registry).unwrap_or_default()
+}
+
+/// The inverse of [`capture_entity_ron`]: parses `ron` back into a
+/// `DynamicScene` and spawns it into `world`, returning the freshly spawned
+/// entity. Uses a fresh `EntityHashMap` every call rather than threading
+/// one through, since nothing here needs to remap references between two
+/// entities the way `bevy_scene`'s own asset-driven scene spawning does.
+#[cfg(feature = "editor")]
+fn respawn_entity_from_ron(world: &mut World, ron: &str) -> Entity {
+    let registry = world.resource::<AppTypeRegistry>().0.clone();
+    let scene = {
+        let registry = registry.read();
+        let mut deserializer =
+            ron::de::Deserializer::from_str(ron).expect("undo: malformed entity snapshot RON");
+        SceneDeserializer {
+            type_registry: &registry,
+        }
+        .deserialize(&mut deserializer)
+        .expect("undo: entity snapshot didn't match the live TypeRegistry")
+    };
+    let mut entity_map = EntityHashMap::default();
+    scene
+        .write_to_world(world, &mut entity_map)
+        .expect("undo: failed to respawn entity snapshot");
+    *entity_map
+        .values()
+        .next()
+        .expect("undo: entity snapshot RON had no entities")
+}
+
+/// Deletes whatever's selected in the editor's Hierarchy window on
+/// Delete/Backspace, pushing an [`EditorOp::DeleteEntity`] so Ctrl+Z brings
+/// it back (modulo the reflection-only-components caveat on that variant's
+/// own doc comment). Leaves the Hierarchy's selection pointing at the
+/// now-despawned entity rather than clearing it — every other system here
+/// that reads the selection (`draw_selection_gizmo`) already tolerates a
+/// stale/missing entity, and `bevy_inspector_egui`'s `SelectedEntities`
+/// doesn't expose a documented "clear" call this crate could verify from
+/// its vendored source (see this crate's other editor code for the same
+/// caveat on that type).
+#[cfg(feature = "editor")]
+fn delete_selected_entity(world: &mut World) {
+    if !world
+        .resource::<ButtonInput<KeyCode>>()
+        .just_pressed(KeyCode::Delete)
+        && !world
+            .resource::<ButtonInput<KeyCode>>()
+            .just_pressed(KeyCode::Backspace)
+    {
+        return;
+    }
+    let Some(entity) = world
+        .get_resource::<Editor>()
+        .and_then(|editor| editor.window_state::<HierarchyWindow>())
+        .and_then(|hierarchy| hierarchy.selected.iter().next())
+    else {
+        return;
+    };
+
+    let ron = capture_entity_ron(world, entity);
+    world.despawn(entity);
+    world
+        .resource_mut::<EditorHistory>()
+        .push(EditorOp::DeleteEntity { entity, ron });
+}
+
+#[cfg(feature = "editor")]
+fn handle_at(path: &Path, point: Vec2) -> Option<usize> {
+    path.points
+        .iter()
+        .position(|p| p.distance(point) <= PATH_HANDLE_RADIUS)
+}
+
+#[cfg(feature = "editor")]
+fn segment_at(path: &Path, point: Vec2) -> Option<usize> {
+    path.points.windows(2).position(|segment| {
+        distance_to_segment(segment[0], segment[1], point) <= PATH_HANDLE_RADIUS
+    })
+}
+
+#[cfg(feature = "editor")]
+fn distance_to_segment(a: Vec2, b: Vec2, point: Vec2) -> f32 {
+    let ab = b - a;
+    let len_sq = ab.length_squared();
+    if len_sq <= f32::EPSILON {
+        return a.distance(point);
+    }
+    let t = ((point - a).dot(ab) / len_sq).clamp(0.0, 1.0);
+    (a + ab * t).distance(point)
+}
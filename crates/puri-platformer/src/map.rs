@@ -0,0 +1,832 @@
+use bevy::prelude::*;
+
+use crate::accessibility::Palette;
+use crate::ambience::LevelAmbience;
+use crate::animation::{Animations, ClipOffset, FrameTime};
+use crate::attack::Hurtbox;
+use crate::collision::{GroundedBody, HitBox, PlatformTop, SurfaceMaterial};
+use crate::crumbling::CrumblingPlatform;
+use crate::cutscene::ActorId;
+use crate::death::Checkpoint;
+use crate::dialogue::Sign;
+use crate::grapple::GrapplePoint;
+use crate::interact::{InteractEvent, Interactable};
+use crate::music::MusicTrack;
+use crate::npc::{Npc, NpcReward, Wandering};
+use crate::objectives::ExitGate;
+use crate::player::{Abilities, Facing, Health, Velocity};
+use crate::portal::{Portal, PortalId};
+use crate::pushable::Pushable;
+use crate::render_layer::{z_for, GameLayer};
+use crate::shop::ShopKeeper;
+use crate::starman::StarPickup;
+use crate::world_flags::{WorldFlagId, WorldFlags};
+
+/// `world_flags::WorldFlagId` for the demo layout's one `StarPickup` — see
+/// that module's own doc comment on why this is a literal here rather
+/// than derived from anything about the pickup's spawn call.
+const STAR_PICKUP_FLAG_ID: u32 = 0;
+
+/// A moving platform that only slides back and forth while `active`.
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+pub struct MovingPlatform {
+    pub active: bool,
+    pub speed: f32,
+    pub range: f32,
+    origin: Vec2,
+}
+
+impl MovingPlatform {
+    /// `origin` is where `move_platform`'s sine sweep centers itself — kept
+    /// private (set once at spawn, same as `origin` never changes once a
+    /// platform exists in `spawn_map_entities`) so this constructor is the
+    /// one place that decides it, for `testing::TestWorld::spawn_moving_platform`
+    /// to build one from another module without exposing the field itself.
+    pub(crate) fn new(origin: Vec2, active: bool, speed: f32, range: f32) -> Self {
+        Self { active, speed, range, origin }
+    }
+}
+
+/// Marks an `Interactable` as a lever that toggles a `MovingPlatform`.
+#[derive(Component)]
+pub struct Lever {
+    pub platform: Entity,
+}
+
+/// A sequence of world-space waypoints a [`MovingPlatform`] or
+/// `prefab::Patroller` can walk at constant speed instead of
+/// `MovingPlatform`'s own origin/range sine sweep or `Patroller`'s own
+/// origin/range back-and-forth — attaching one overrides that entity's
+/// simpler default motion (see `move_platform` and `enemy_ai::patrol`).
+/// Reflected rather than `serde`-derived, the same as `MovingPlatform`
+/// itself and `prefab::Patroller`: this crate has no external level
+/// format for a path to round-trip through yet (see `level_reload`'s own
+/// note on that gap), so "serializes into the level format" today means
+/// exactly what it means for any other reflected component — a prefab's
+/// `.ron` can name it, resolved through the same `AppTypeRegistry`-backed
+/// reflection `prefab::spawn_prefab` already uses for everything else.
+///
+/// The in-game editor added in `main.rs` (`draw_path_gizmos`,
+/// `edit_path_handles`) draws and edits `points` directly, so dragging a
+/// handle or double-click-inserting a point is visible immediately without
+/// this component needing any editor-specific state of its own.
+#[derive(Component, Reflect, Clone, Debug, Default)]
+#[reflect(Component)]
+pub struct Path {
+    pub points: Vec<Vec2>,
+    pub mode: PathMode,
+}
+
+#[derive(Reflect, Clone, Copy, Debug, PartialEq, Default)]
+pub enum PathMode {
+    /// Reverse at each end.
+    #[default]
+    PingPong,
+    /// Wrap from the last point back to the first.
+    Loop,
+}
+
+/// Per-entity progress along a [`Path`]: the index of the waypoint it's
+/// currently heading to, and (for [`PathMode::PingPong`]) which way it's
+/// walking the list. Lazily inserted by [`ensure_path_progress`] the same
+/// way `enemy_ai::PatrolState` is lazily inserted for a bare `Patroller` —
+/// see that module's own doc comment on why this isn't just baked into
+/// `Path` itself (a prefab-authored `Path` should stay the plain
+/// RON-deserializable waypoint list, not carry runtime state).
+#[derive(Component)]
+pub struct PathProgress {
+    target: usize,
+    direction: i32,
+}
+
+/// Inserts a fresh [`PathProgress`] (heading for waypoint 1, or 0 for a
+/// degenerate one-point path) on every entity that grew a [`Path`] since
+/// last frame — spawned with one already, or one dropped in by the editor.
+pub(crate) fn ensure_path_progress(
+    mut commands: Commands,
+    paths: Query<(Entity, &Path), Without<PathProgress>>,
+) {
+    for (entity, path) in &paths {
+        commands.entity(entity).insert(PathProgress {
+            target: 1.min(path.points.len().saturating_sub(1)),
+            direction: 1,
+        });
+    }
+}
+
+/// Moves `progress` `distance` further along `path` from `from`, wrapping
+/// to the next segment (and, at either end, reversing for
+/// [`PathMode::PingPong`] or wrapping to index 0 for [`PathMode::Loop`]) as
+/// many times as a large `distance` needs, and returns the new position.
+/// `move_platform` feeds this straight into `Transform`; `enemy_ai::patrol`
+/// instead uses [`path_direction`] to steer a `Velocity`, since a
+/// `Patroller` rides `collision::aabb`'s own movement sweep rather than
+/// teleporting.
+pub(crate) fn advance_along_path(
+    path: &Path,
+    progress: &mut PathProgress,
+    from: Vec2,
+    distance: f32,
+) -> Vec2 {
+    if path.points.len() < 2 {
+        return from;
+    }
+    let mut position = from;
+    let mut remaining = distance;
+    while remaining > f32::EPSILON {
+        let target = path.points[progress.target];
+        let to_target = target - position;
+        let step_len = to_target.length();
+        if step_len <= remaining {
+            position = target;
+            remaining -= step_len;
+            advance_path_progress(path, progress);
+        } else {
+            position += to_target.normalize_or_zero() * remaining;
+            remaining = 0.0;
+        }
+    }
+    position
+}
+
+/// The horizontal direction (`-1.0`/`1.0`) from `from` toward `progress`'s
+/// current target, advancing `progress` once `from` has arrived. Used by
+/// `enemy_ai::patrol`, which only ever needs a walk direction for its
+/// `Velocity`, not an absolute position.
+pub(crate) fn path_direction(path: &Path, progress: &mut PathProgress, from: Vec2) -> f32 {
+    const ARRIVAL_EPSILON: f32 = 2.0;
+    if path.points.len() < 2 {
+        return 0.0;
+    }
+    let target = path.points[progress.target];
+    if target.distance(from) <= ARRIVAL_EPSILON {
+        advance_path_progress(path, progress);
+    }
+    let target = path.points[progress.target];
+    (target.x - from.x).signum()
+}
+
+fn advance_path_progress(path: &Path, progress: &mut PathProgress) {
+    let last = path.points.len() - 1;
+    match path.mode {
+        PathMode::Loop => progress.target = (progress.target + 1) % path.points.len(),
+        PathMode::PingPong => {
+            if progress.target == last {
+                progress.direction = -1;
+            } else if progress.target == 0 {
+                progress.direction = 1;
+            }
+            progress.target =
+                (progress.target as i32 + progress.direction).clamp(0, last as i32) as usize;
+        }
+    }
+}
+
+/// Everything `spawn_map_entities` spawns, and nothing else — in
+/// particular not the player or camera, which outlive a level reload.
+/// `level_reload::reload_level` despawns exactly this set before calling
+/// `spawn_map_entities` again.
+#[derive(Component)]
+pub struct LevelEntity;
+
+/// Despawns every [`LevelEntity`] root still in the world, recursively —
+/// every `PlatformTop` root (the ground slab, the moving platform) carries
+/// the snow-cap child `weather::attach_snow_cap` gives it, and a plain
+/// `despawn` would silently orphan that child, and any other a
+/// `LevelEntity` grows, instead of erroring loudly. `pub(crate)` so both
+/// `level_reload::reload_level` and `level_load::spawn_level` share the
+/// one despawn instead of each re-deciding whether "despawn" means
+/// "despawn_recursive" here.
+pub(crate) fn despawn_level(
+    commands: &mut Commands,
+    level_entities: &Query<Entity, With<LevelEntity>>,
+) {
+    for entity in level_entities {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+/// The axis-aligned extent of the current level's solid geometry, so
+/// `level_reload` can tell whether the player's pre-reload position is
+/// still somewhere sensible in the new layout. Recomputed by
+/// `spawn_map_entities` every time it runs.
+#[derive(Resource, Clone, Copy)]
+pub struct LevelBounds {
+    pub min: Vec2,
+    pub max: Vec2,
+}
+
+impl LevelBounds {
+    pub fn contains(&self, point: Vec2) -> bool {
+        point.cmpge(self.min).all() && point.cmple(self.max).all()
+    }
+}
+
+pub struct MapPlugin;
+
+impl Plugin for MapPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<MovingPlatform>()
+            .register_type::<Path>()
+            .add_systems(Startup, setup_map)
+            .add_systems(
+                Update,
+                (ensure_path_progress, toggle_lever, move_platform).chain(),
+            );
+        #[cfg(feature = "serde")]
+        app.add_systems(Update, record_level_started);
+    }
+}
+
+/// Fires once per [`spawn_map_entities`] call — `Startup`'s initial spawn
+/// and every `level_reload::reload_level` after it — by watching
+/// [`LevelBounds`] change rather than adding an
+/// `EventWriter`/`RecordSessionEvent` parameter to that function's already
+/// long signature: it re-inserts [`LevelBounds`] via `Commands` every time
+/// it runs, which is exactly the "did a level just (re)start" signal this
+/// needs, decoupled the same way `attack`/`shield`/`grapple`'s own
+/// `Added<T>`-based ability-usage recorders don't touch that gameplay code
+/// either.
+#[cfg(feature = "serde")]
+fn record_level_started(
+    bounds: Res<LevelBounds>,
+    mut session: EventWriter<crate::session_recorder::RecordSessionEvent>,
+) {
+    if bounds.is_changed() {
+        session.send(crate::session_recorder::RecordSessionEvent(
+            crate::session_recorder::SessionEvent::LevelStarted { level_id: 0 },
+        ));
+    }
+}
+
+fn toggle_lever(
+    mut events: EventReader<InteractEvent>,
+    levers: Query<&Lever>,
+    mut platforms: Query<&mut MovingPlatform>,
+) {
+    for event in events.read() {
+        let Ok(lever) = levers.get(event.target) else {
+            continue;
+        };
+        if let Ok(mut platform) = platforms.get_mut(lever.platform) {
+            platform.active = !platform.active;
+        }
+    }
+}
+
+/// Follows `path`/`progress` at `platform.speed` world units/second when
+/// both are present (from the same frame `Path` is first attached, since
+/// [`ensure_path_progress`] runs just before this in `MapPlugin`'s chain
+/// and its insert is applied in between); otherwise falls back to the
+/// original origin/range sine sweep. `platform.speed` doubles as this
+/// path-following speed, so a platform doesn't need two differently-named
+/// fields for the same "how fast" knob depending on which mode it's in.
+fn move_platform(
+    time: Res<Time>,
+    mut query: Query<(
+        &MovingPlatform,
+        &mut Transform,
+        Option<&Path>,
+        Option<&mut PathProgress>,
+    )>,
+) {
+    for (platform, mut transform, path, progress) in &mut query {
+        if !platform.active {
+            continue;
+        }
+        match (path, progress) {
+            (Some(path), Some(mut progress)) if path.points.len() >= 2 => {
+                let distance = platform.speed * time.delta_seconds();
+                let position = advance_along_path(
+                    path,
+                    &mut progress,
+                    transform.translation.truncate(),
+                    distance,
+                );
+                transform.translation.x = position.x;
+                transform.translation.y = position.y;
+            }
+            _ => {
+                let t = time.elapsed_seconds() * platform.speed;
+                transform.translation.x = platform.origin.x + t.sin() * platform.range;
+            }
+        }
+    }
+}
+
+/// `pub(crate)` so `world_flags::WorldFlagsPlugin` can order its own
+/// `Startup` load `.before` this — the very first `spawn_map_entities`
+/// call needs a restored `WorldFlags` to consult, same reasoning as any
+/// other `Startup`-ordered load, not just the one after a `level_reload`.
+pub(crate) fn setup_map(
+    commands: Commands,
+    palette: Res<Palette>,
+    world_flags: Res<WorldFlags>,
+    animations: Res<Animations>,
+) {
+    spawn_map_entities(commands, palette, world_flags, animations);
+}
+
+/// Spawns the demo layout, tagging every entity with [`LevelEntity`] and
+/// recomputing [`LevelBounds`] from what got spawned. Called once at
+/// `Startup`, and again by `level_reload::reload_level` after despawning
+/// the previous [`LevelEntity`] set.
+///
+/// This is still the same hard-coded layout every time — there's no
+/// external level file format yet (see `LaunchOptions::level`'s own note),
+/// so "new data" from a reload is identical to the old data today. Once a
+/// real format lands, this becomes the one place that turns loaded data
+/// into entities, and `level_reload` needs no changes.
+///
+/// `world_flags` gates the one entity this layout has wired up to
+/// `world_flags::WorldFlagId` today (the star pickup, below) — see that
+/// module's own doc comment on why the rest of the layout doesn't consult
+/// it yet.
+pub(crate) fn spawn_map_entities(
+    mut commands: Commands,
+    palette: Res<Palette>,
+    world_flags: Res<WorldFlags>,
+    animations: Res<Animations>,
+) {
+    debug!(name = "demo", "level loaded");
+    crate::event_log::record("level loaded name=demo");
+
+    let mut bounds = Bounds::default();
+
+    bounds.include(Vec2::new(0.0, -160.0), Vec2::new(800.0, 32.0));
+    commands.spawn((
+        LevelEntity,
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::rgb(0.3, 0.3, 0.35),
+                custom_size: Some(Vec2::new(800.0, 32.0)),
+                ..default()
+            },
+            transform: Transform::from_xyz(0.0, -160.0, z_for(GameLayer::TilesBack, -160.0, false)),
+            ..default()
+        },
+        HitBox {
+            size: Vec2::new(800.0, 32.0),
+        },
+        SurfaceMaterial::Stone,
+        PlatformTop,
+    ));
+
+    bounds.include(Vec2::new(-200.0, -60.0), Vec2::new(32.0, 200.0));
+    commands.spawn((
+        LevelEntity,
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::rgb(0.3, 0.3, 0.35),
+                custom_size: Some(Vec2::new(32.0, 200.0)),
+                ..default()
+            },
+            transform: Transform::from_xyz(
+                -200.0,
+                -60.0,
+                z_for(GameLayer::TilesBack, -60.0, false),
+            ),
+            ..default()
+        },
+        HitBox {
+            size: Vec2::new(32.0, 200.0),
+        },
+    ));
+
+    let platform_pos = Vec2::new(120.0, -100.0);
+    bounds.include(platform_pos, Vec2::new(64.0, 16.0));
+    let platform = commands
+        .spawn((
+            LevelEntity,
+            SpriteBundle {
+                sprite: Sprite {
+                    color: Color::rgb(0.5, 0.4, 0.2),
+                    custom_size: Some(Vec2::new(64.0, 16.0)),
+                    ..default()
+                },
+                transform: Transform::from_translation(platform_pos.extend(z_for(
+                    GameLayer::Entities,
+                    platform_pos.y,
+                    true,
+                ))),
+                ..default()
+            },
+            HitBox {
+                size: Vec2::new(64.0, 16.0),
+            },
+            MovingPlatform::new(platform_pos, false, 1.0, 80.0),
+            PlatformTop,
+        ))
+        .id();
+
+    bounds.include(Vec2::new(60.0, -142.0), Vec2::new(12.0, 20.0));
+    commands.spawn((
+        LevelEntity,
+        Interactable {
+            prompt: "Toggle platform".into(),
+        },
+        Lever { platform },
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::rgb(0.8, 0.7, 0.2),
+                custom_size: Some(Vec2::new(12.0, 20.0)),
+                ..default()
+            },
+            transform: Transform::from_xyz(60.0, -142.0, z_for(GameLayer::Entities, -142.0, true)),
+            ..default()
+        },
+        HitBox {
+            size: Vec2::new(12.0, 20.0),
+        },
+        crate::collision::Sensor,
+    ));
+
+    bounds.include(Vec2::new(-60.0, -132.0), Vec2::new(20.0, 24.0));
+    commands.spawn((
+        LevelEntity,
+        Interactable {
+            prompt: "Read sign".into(),
+        },
+        Sign {
+            pages: vec![
+                "Arrow keys or A/D to move.".into(),
+                "Space to jump, E to interact.".into(),
+            ],
+        },
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::rgb(0.6, 0.5, 0.3),
+                custom_size: Some(Vec2::new(20.0, 24.0)),
+                ..default()
+            },
+            transform: Transform::from_xyz(-60.0, -132.0, z_for(GameLayer::Entities, -132.0, true)),
+            ..default()
+        },
+        HitBox {
+            size: Vec2::new(20.0, 24.0),
+        },
+        crate::collision::Sensor,
+    ));
+
+    // Stands on the same ground slab as the sign, a little further along,
+    // and hands out double jump once its dialogue is read to the end.
+    let npc_pos = Vec2::new(30.0, -128.0);
+    bounds.include(npc_pos, Vec2::new(24.0, 32.0));
+    commands.spawn((
+        LevelEntity,
+        // `id: 0` is the actor `cutscene::level_1_intro` walks during the
+        // demo level's intro cutscene.
+        ActorId(0),
+        Npc {
+            leash_origin: npc_pos,
+            leash_radius: 40.0,
+            wander_speed: 20.0,
+        },
+        Wandering::default(),
+        NpcReward(Abilities {
+            double_jump: true,
+            ..default()
+        }),
+        Interactable {
+            prompt: "Talk".into(),
+        },
+        Sign {
+            pages: vec![
+                "You look like you could use a boost.".into(),
+                "Here, take this — double jump is yours now.".into(),
+            ],
+        },
+        Facing::default(),
+        Velocity::default(),
+        GroundedBody,
+        {
+            let (texture, layout, animation, offset) = animations.resolve("npc", "walk");
+            (
+                SpriteSheetBundle {
+                    texture,
+                    atlas: TextureAtlas {
+                        layout,
+                        index: offset,
+                    },
+                    sprite: Sprite {
+                        custom_size: Some(Vec2::new(24.0, 32.0)),
+                        ..default()
+                    },
+                    transform: Transform::from_translation(npc_pos.extend(z_for(
+                        GameLayer::Entities,
+                        npc_pos.y,
+                        true,
+                    ))),
+                    ..default()
+                },
+                animation,
+                FrameTime::default(),
+                ClipOffset(offset),
+            )
+        },
+        HitBox {
+            size: Vec2::new(24.0, 32.0),
+        },
+    ));
+
+    // A block that can be pushed right along the ground slab toward its
+    // edge at x=400, past which the crumbling platforms start at x=750.
+    bounds.include(Vec2::new(220.0, -128.0), Vec2::new(32.0, 32.0));
+    commands.spawn((
+        LevelEntity,
+        Pushable { weight: 1.0 },
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::rgb(0.4, 0.3, 0.2),
+                custom_size: Some(Vec2::new(32.0, 32.0)),
+                ..default()
+            },
+            transform: Transform::from_xyz(220.0, -128.0, z_for(GameLayer::Entities, -128.0, true)),
+            ..default()
+        },
+        HitBox {
+            size: Vec2::new(32.0, 32.0),
+        },
+    ));
+
+    for x in [750.0, 810.0, 870.0] {
+        bounds.include(Vec2::new(x, -120.0), Vec2::new(48.0, 12.0));
+        commands.spawn((
+            LevelEntity,
+            CrumblingPlatform {
+                delay: 0.6,
+                respawn: 3.0,
+            },
+            SpriteBundle {
+                sprite: Sprite {
+                    color: Color::rgb(0.55, 0.45, 0.35),
+                    custom_size: Some(Vec2::new(48.0, 12.0)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(x, -120.0, z_for(GameLayer::Entities, -120.0, true)),
+                ..default()
+            },
+            HitBox {
+                size: Vec2::new(48.0, 12.0),
+            },
+        ));
+    }
+
+    for x in [500.0, 700.0] {
+        bounds.include(Vec2::new(x, 80.0), Vec2::new(8.0, 8.0));
+        commands.spawn((
+            LevelEntity,
+            GrapplePoint,
+            SpriteBundle {
+                sprite: Sprite {
+                    color: Color::rgb(0.7, 0.7, 0.9),
+                    custom_size: Some(Vec2::new(8.0, 8.0)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(x, 80.0, z_for(GameLayer::Entities, 80.0, true)),
+                ..default()
+            },
+        ));
+    }
+
+    // A portal pair: step onto the floor near the wall to be whisked up to
+    // the floating platform beyond the crumbling run.
+    bounds.include(Vec2::new(-320.0, -128.0), Vec2::new(24.0, 32.0));
+    commands.spawn((
+        LevelEntity,
+        Portal {
+            id: PortalId(0),
+            link: PortalId(1),
+        },
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::rgb(0.3, 0.6, 0.9),
+                custom_size: Some(Vec2::new(24.0, 32.0)),
+                ..default()
+            },
+            transform: Transform::from_xyz(
+                -320.0,
+                -128.0,
+                z_for(GameLayer::Entities, -128.0, true),
+            ),
+            ..default()
+        },
+        HitBox {
+            size: Vec2::new(24.0, 32.0),
+        },
+        crate::collision::Sensor,
+    ));
+
+    bounds.include(Vec2::new(870.0, -100.0), Vec2::new(24.0, 32.0));
+    commands.spawn((
+        LevelEntity,
+        Portal {
+            id: PortalId(1),
+            link: PortalId(0),
+        },
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::rgb(0.3, 0.6, 0.9),
+                custom_size: Some(Vec2::new(24.0, 32.0)),
+                ..default()
+            },
+            transform: Transform::from_xyz(870.0, -100.0, z_for(GameLayer::Entities, -100.0, true)),
+            ..default()
+        },
+        HitBox {
+            size: Vec2::new(24.0, 32.0),
+        },
+        crate::collision::Sensor,
+    ));
+
+    // Stands past the checkpoint; talking to it opens the upgrade shop
+    // rather than a `Sign`/`Npc` dialogue.
+    let shopkeeper_pos = Vec2::new(120.0, -128.0);
+    bounds.include(shopkeeper_pos, Vec2::new(20.0, 24.0));
+    commands.spawn((
+        LevelEntity,
+        ShopKeeper,
+        Interactable {
+            prompt: "Shop".into(),
+        },
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::rgb(0.7, 0.5, 0.7),
+                custom_size: Some(Vec2::new(20.0, 24.0)),
+                ..default()
+            },
+            transform: Transform::from_translation(shopkeeper_pos.extend(z_for(
+                GameLayer::Entities,
+                shopkeeper_pos.y,
+                true,
+            ))),
+            ..default()
+        },
+        HitBox {
+            size: Vec2::new(20.0, 24.0),
+        },
+        crate::collision::Sensor,
+    ));
+
+    // Blocks the way past the second portal until every objective on
+    // `Objectives::demo` is checked off; `objectives::unlock_exit_when_all_
+    // complete` removes the `HitBox` (and so the block) at that point.
+    let exit_gate_pos = Vec2::new(930.0, -100.0);
+    bounds.include(exit_gate_pos, Vec2::new(16.0, 96.0));
+    commands.spawn((
+        LevelEntity,
+        ExitGate,
+        SpriteBundle {
+            sprite: Sprite {
+                color: palette.hazard,
+                custom_size: Some(Vec2::new(16.0, 96.0)),
+                ..default()
+            },
+            transform: Transform::from_translation(exit_gate_pos.extend(z_for(
+                GameLayer::Entities,
+                exit_gate_pos.y,
+                true,
+            ))),
+            ..default()
+        },
+        HitBox {
+            size: Vec2::new(16.0, 96.0),
+        },
+    ));
+
+    // A star to try out `starman`'s invincibility window; sitting out in
+    // the open rather than gated behind anything, since nothing else in
+    // the demo layout depends on it being missed. Skipped entirely once
+    // `starman::collect_star_pickup` has flagged it collected in
+    // `world_flags::WorldFlags` — see that module's own doc comment on why
+    // that check lives here rather than in a spawn-then-immediately-
+    // despawn dance.
+    let star_pos = Vec2::new(480.0, -96.0);
+    if !world_flags.is_set(STAR_PICKUP_FLAG_ID) {
+        bounds.include(star_pos, Vec2::new(16.0, 16.0));
+        commands.spawn((
+            LevelEntity,
+            StarPickup,
+            WorldFlagId(STAR_PICKUP_FLAG_ID),
+            SpriteBundle {
+                sprite: Sprite {
+                    color: Color::rgb(1.0, 0.9, 0.2),
+                    custom_size: Some(Vec2::new(16.0, 16.0)),
+                    ..default()
+                },
+                transform: Transform::from_translation(star_pos.extend(z_for(
+                    GameLayer::Entities,
+                    star_pos.y,
+                    true,
+                ))),
+                ..default()
+            },
+            HitBox {
+                size: Vec2::new(16.0, 16.0),
+            },
+            crate::collision::Sensor,
+        ));
+    }
+
+    crate::water::spawn_demo_pool(&mut commands);
+    crate::camera_rail::spawn_demo_rail(&mut commands);
+
+    // A turret to exercise `turret`'s telegraph/fire/stomp loop; placed
+    // where it has a clear line to the walkway rather than gated behind
+    // an objective, the same "sits out in the open" call made for the
+    // star pickup above.
+    let turret_pos = Vec2::new(650.0, -104.0);
+    #[cfg(feature = "serde")]
+    crate::zone_population::spawn_turret_zone(
+        &mut commands,
+        "turret_zone",
+        turret_pos,
+        Vec2::new(220.0, 180.0),
+        crate::zone_population::TurretBlueprint {
+            interval_seconds: 2.0,
+            projectile_speed: 140.0,
+            range: 220.0,
+        },
+    );
+    #[cfg(not(feature = "serde"))]
+    crate::turret::spawn_turret(&mut commands, turret_pos, crate::turret::Turret::new(2.0, 140.0, 220.0));
+
+    bounds.include(Vec2::new(0.0, -142.0), Vec2::new(8.0, 32.0));
+    commands.spawn((
+        LevelEntity,
+        Checkpoint { id: 0 },
+        SpriteBundle {
+            sprite: Sprite {
+                color: palette.checkpoint,
+                custom_size: Some(Vec2::new(8.0, 32.0)),
+                ..default()
+            },
+            transform: Transform::from_xyz(0.0, -142.0, z_for(GameLayer::Entities, -142.0, true)),
+            ..default()
+        },
+    ));
+
+    // A stationary target for the melee attack (see `attack.rs`) with
+    // nothing else to react to being hit — no death, no knockback, just a
+    // `Health` a swing can actually decrement.
+    bounds.include(Vec2::new(140.0, -142.0), Vec2::new(24.0, 32.0));
+    commands.spawn((
+        LevelEntity,
+        Hurtbox,
+        Health(10),
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::rgb(0.6, 0.6, 0.6),
+                custom_size: Some(Vec2::new(24.0, 32.0)),
+                ..default()
+            },
+            transform: Transform::from_xyz(140.0, -142.0, z_for(GameLayer::Entities, -142.0, true)),
+            ..default()
+        },
+        HitBox {
+            size: Vec2::new(24.0, 32.0),
+        },
+    ));
+
+    commands.insert_resource(LevelBounds {
+        min: bounds.min,
+        max: bounds.max,
+    });
+
+    // Always the neutral default today — there's no per-level data format
+    // yet for this to read a tint from (see this function's own note on
+    // that). A real level loader replaces this line with one that reads
+    // the level's own ambience instead; `ambience::apply_ambience` doesn't
+    // change either way.
+    commands.insert_resource(LevelAmbience::default());
+
+    // Same "no per-level data format yet" gap as `LevelAmbience` above —
+    // every level gets `music`'s one demo stem set until a real loader can
+    // read a track's stems out of level data.
+    commands.insert_resource(MusicTrack::demo());
+}
+
+/// Running min/max accumulator for [`LevelBounds`], kept private since it's
+/// only useful mid-spawn — everything downstream reads the finished
+/// [`LevelBounds`] resource instead.
+struct Bounds {
+    min: Vec2,
+    max: Vec2,
+}
+
+impl Default for Bounds {
+    fn default() -> Self {
+        Self {
+            min: Vec2::splat(f32::INFINITY),
+            max: Vec2::splat(f32::NEG_INFINITY),
+        }
+    }
+}
+
+impl Bounds {
+    fn include(&mut self, center: Vec2, size: Vec2) {
+        self.min = self.min.min(center - size / 2.0);
+        self.max = self.max.max(center + size / 2.0);
+    }
+}
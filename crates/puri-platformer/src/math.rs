@@ -0,0 +1,12 @@
+//! Generalized into `puri_core` now that camera follow and animation aren't
+//! the only systems in the arcade that will want frame-rate-independent
+//! easing. Re-exported here unchanged so existing `crate::math::...` call
+//! sites (and their tests) keep compiling.
+//!
+//! Audit: `camera::camera_follow` was the one offender and now uses
+//! `exp_decay`; `player`'s dash-deceleration-style clamp shares
+//! `move_toward` instead of a private copy. There's no squash-and-stretch
+//! or knockback system in the game yet for `spring_damp` to replace —
+//! reach for it when one lands.
+
+pub use puri_core::math::{exp_decay, move_toward, spring_damp};
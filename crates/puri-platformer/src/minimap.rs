@@ -0,0 +1,463 @@
+//! Corner minimap, toggled with Tab (mirroring `debug_overlay`'s F3
+//! toggle — see [`MinimapVisible`]).
+//!
+//! The request this exists for describes a "collision grid" the minimap is
+//! rasterized from. This crate has no such thing: `spatial_grid::SpatialGrid`
+//! is a broad-phase hash over plain `(Vec2, Vec2)` pairs, not a tile grid,
+//! and `tilemap`'s chunked tile renderer is unused (`map::spawn_map_entities`
+//! still hand-spawns individual sprites — see that function's own doc
+//! comment). What's built here instead: [`build_minimap`] rasterizes the
+//! same static-solids query `collision::aabb::move_bodies` uses
+//! (`Query<(&Transform, &HitBox), (Without<GroundedBody>, Without<Sensor>)>`)
+//! into a fixed-size grid of its own, once, at level-load time.
+//!
+//! Solid cells are baked as filled pixels into a single [`Image`] asset
+//! ([`MinimapTexture`]) rather than one UI node per tile — the request's own
+//! complaint about spawning thousands of nodes for a 200×200 grid. The
+//! player dot and `death::Checkpoint`/`objectives::ExitGate` icons don't fit
+//! that texture (they move, or need to blink), so those stay as a handful of
+//! UI nodes positioned by percentage inside the minimap's container, the
+//! same way `objectives`'s own HUD icons are laid out.
+//!
+//! Fog of war is a `revealed` bitset on [`MinimapGrid`], persisted the same
+//! one-file-per-system way `world_flags::WorldFlags` is (a `HashSet<u32>` of
+//! revealed cell indices, `assets/minimap_fog.ron`, `serde` feature) — there's
+//! no unified `SaveData` struct anywhere in this crate for it to join
+//! instead (`world_flags`'s own doc comment already covers why).
+//! [`reveal_fog`] only touches cells newly revealed this frame — both in the
+//! bitset and in the texture's raw pixel buffer — rather than rewriting the
+//! whole image every tick, so cost scales with how much new ground the
+//! player has covered, not with total grid size. There's no actual 200×200
+//! level in this demo to stress-test that against (`map::spawn_map_entities`'s
+//! layout is a few hundred units across), but the update path is already
+//! shaped for one.
+//!
+//! Scale: there's no persisted `Settings` file anywhere in this codebase yet
+//! (`window_config`'s own doc comment covers that gap), so
+//! [`MinimapOptions::scale`] gets the same stand-in `accessibility`'s F8/F9/F10
+//! use for its own missing settings menu — bracket keys `[`/`]` adjust it
+//! directly, in place of a settings-menu slider that doesn't exist.
+
+use bevy::ecs::schedule::apply_deferred;
+use bevy::prelude::*;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+
+use crate::collision::{GroundedBody, HitBox, Sensor};
+use crate::death::Checkpoint;
+use crate::map::LevelBounds;
+use crate::objectives::ExitGate;
+use crate::player::Player;
+use crate::schedule::PlatformerSet;
+use crate::ui_scale::SafeAreaAnchor;
+
+/// World units per grid cell, for both rasterizing solids and mapping the
+/// player's position into cell coordinates. `16.0` matches the smallest
+/// `HitBox` sizes in `map::spawn_map_entities`'s demo layout.
+const CELL_SIZE: f32 = 16.0;
+
+/// How many cells around the player get revealed per frame, in each axis —
+/// a Chebyshev-distance radius, so the revealed area is a square, not a
+/// diamond.
+const REVEAL_RADIUS: i32 = 4;
+
+const FOG_COLOR: [u8; 4] = [10, 10, 14, 235];
+const SOLID_COLOR: [u8; 4] = [200, 200, 210, 255];
+const OPEN_COLOR: [u8; 4] = [40, 40, 55, 200];
+const BLINK_PERIOD_SECONDS: f32 = 0.6;
+
+/// Single source of truth for whether the minimap is showing, same shape as
+/// `debug_overlay::DebugOverlayVisible`.
+#[derive(Resource, Default)]
+pub struct MinimapVisible(pub bool);
+
+/// Pixels-per-cell the minimap is drawn at on screen. Adjusted with `[`/`]`
+/// in place of a settings-menu slider — see this module's own doc comment.
+#[derive(Resource)]
+pub struct MinimapOptions {
+    pub scale: f32,
+}
+
+impl Default for MinimapOptions {
+    fn default() -> Self {
+        Self { scale: 2.0 }
+    }
+}
+
+/// The rasterized level grid: which cells are solid (fixed at build time)
+/// and which have been revealed (grows as the player explores). `origin` is
+/// `LevelBounds::min`, so `world_to_cell` can map a world position into grid
+/// coordinates.
+#[derive(Resource)]
+pub struct MinimapGrid {
+    origin: Vec2,
+    cols: u32,
+    rows: u32,
+    solid: Vec<bool>,
+    revealed: Vec<bool>,
+}
+
+impl MinimapGrid {
+    fn index(&self, x: u32, y: u32) -> usize {
+        (y * self.cols + x) as usize
+    }
+
+    fn world_to_cell(&self, world: Vec2) -> Option<(u32, u32)> {
+        let relative = world - self.origin;
+        if relative.x < 0.0 || relative.y < 0.0 {
+            return None;
+        }
+        let x = (relative.x / CELL_SIZE) as u32;
+        let y = (relative.y / CELL_SIZE) as u32;
+        if x >= self.cols || y >= self.rows {
+            return None;
+        }
+        Some((x, y))
+    }
+
+    fn color_at(&self, x: u32, y: u32) -> [u8; 4] {
+        if self.solid[self.index(x, y)] {
+            SOLID_COLOR
+        } else {
+            OPEN_COLOR
+        }
+    }
+
+    /// `pub(crate)` purely so `testing::TestWorld::minimap_revealed_count`
+    /// can assert fog reveals grow over time, mirroring how other resources
+    /// in this crate expose a read used only by the test harness.
+    pub(crate) fn revealed_count(&self) -> usize {
+        self.revealed.iter().filter(|&&set| set).count()
+    }
+}
+
+/// The dynamically-baked solids texture; one pixel per grid cell.
+#[derive(Resource)]
+pub struct MinimapTexture(pub Handle<Image>);
+
+#[derive(Component)]
+struct MinimapRoot;
+
+#[derive(Component)]
+struct MinimapPlayerDot;
+
+pub struct MinimapPlugin;
+
+impl Plugin for MinimapPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MinimapVisible>()
+            .init_resource::<MinimapOptions>()
+            // `apply_deferred` flushes `map::setup_map`'s (and, below,
+            // `build_minimap`'s own) `Commands` before the next system reads
+            // what they inserted — the same explicit sandwich
+            // `level_reload::reload_level`/`reposition_after_reload` chain
+            // on, rather than assuming an implicit flush between ordered
+            // Startup systems.
+            .add_systems(Startup, (apply_deferred, build_minimap).chain().after(crate::map::setup_map))
+            .add_systems(
+                Update,
+                (toggle_minimap, adjust_minimap_scale, reveal_fog, update_player_dot)
+                    .chain()
+                    .in_set(PlatformerSet::PostPhysics),
+            );
+
+        #[cfg(feature = "serde")]
+        app.add_systems(Startup, (apply_deferred, persistence::load_from_disk).chain().after(build_minimap));
+    }
+}
+
+pub fn minimap_visible(visible: Res<MinimapVisible>) -> bool {
+    visible.0
+}
+
+fn toggle_minimap(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut visible: ResMut<MinimapVisible>,
+    mut root: Query<&mut Visibility, With<MinimapRoot>>,
+) {
+    if !keys.just_pressed(KeyCode::Tab) {
+        return;
+    }
+    visible.0 = !visible.0;
+    for mut node_visibility in &mut root {
+        *node_visibility = if visible.0 { Visibility::Inherited } else { Visibility::Hidden };
+    }
+}
+
+fn adjust_minimap_scale(
+    keys: Res<ButtonInput<KeyCode>>,
+    grid: Res<MinimapGrid>,
+    mut options: ResMut<MinimapOptions>,
+    mut root: Query<&mut Style, With<MinimapRoot>>,
+) {
+    let mut changed = false;
+    if keys.just_pressed(KeyCode::BracketLeft) {
+        options.scale = (options.scale - 0.5).max(0.5);
+        changed = true;
+    }
+    if keys.just_pressed(KeyCode::BracketRight) {
+        options.scale = (options.scale + 0.5).min(8.0);
+        changed = true;
+    }
+    if !changed {
+        return;
+    }
+    let Ok(mut style) = root.get_single_mut() else {
+        return;
+    };
+    style.width = Val::Px(grid.cols as f32 * options.scale);
+    style.height = Val::Px(grid.rows as f32 * options.scale);
+}
+
+/// Builds the grid, bakes the initial (fully-fogged) texture, and spawns the
+/// minimap UI. Ordered `.after(map::setup_map)` since it needs the
+/// `LevelBounds` that call produces.
+fn build_minimap(
+    mut commands: Commands,
+    mut images: ResMut<Assets<Image>>,
+    bounds: Res<LevelBounds>,
+    options: Res<MinimapOptions>,
+    solids: Query<(&Transform, &HitBox), (Without<GroundedBody>, Without<Sensor>)>,
+    checkpoints: Query<&Transform, With<Checkpoint>>,
+    exits: Query<&Transform, With<ExitGate>>,
+) {
+    let cols = ((bounds.max.x - bounds.min.x) / CELL_SIZE).ceil().max(1.0) as u32;
+    let rows = ((bounds.max.y - bounds.min.y) / CELL_SIZE).ceil().max(1.0) as u32;
+    let origin = bounds.min;
+
+    let mut solid = vec![false; (cols * rows) as usize];
+    for (transform, hitbox) in &solids {
+        let center = transform.translation.truncate();
+        let half = hitbox.size / 2.0;
+        let min_cell = world_to_cell_clamped(origin, cols, rows, center - half);
+        let max_cell = world_to_cell_clamped(origin, cols, rows, center + half);
+        for y in min_cell.1..=max_cell.1 {
+            for x in min_cell.0..=max_cell.0 {
+                solid[(y * cols + x) as usize] = true;
+            }
+        }
+    }
+
+    let mut data = Vec::with_capacity((cols * rows * 4) as usize);
+    for _ in 0..(cols * rows) {
+        data.extend_from_slice(&FOG_COLOR);
+    }
+    let image = Image::new(
+        Extent3d { width: cols, height: rows, depth_or_array_layers: 1 },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        default(),
+    );
+    let texture = images.add(image);
+
+    let grid = MinimapGrid { origin, cols, rows, solid, revealed: vec![false; (cols * rows) as usize] };
+
+    commands
+        .spawn((
+            MinimapRoot,
+            SafeAreaAnchor { top: Some(8.0), right: Some(8.0), ..default() },
+            Visibility::Hidden,
+            ImageBundle {
+                image: UiImage::new(texture.clone()),
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(8.0),
+                    right: Val::Px(8.0),
+                    width: Val::Px(cols as f32 * options.scale),
+                    height: Val::Px(rows as f32 * options.scale),
+                    ..default()
+                },
+                ..default()
+            },
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                MinimapPlayerDot,
+                NodeBundle {
+                    style: Style {
+                        position_type: PositionType::Absolute,
+                        width: Val::Px(4.0),
+                        height: Val::Px(4.0),
+                        ..default()
+                    },
+                    background_color: Color::rgb(1.0, 1.0, 1.0).into(),
+                    ..default()
+                },
+            ));
+
+            for transform in &checkpoints {
+                spawn_icon(parent, &grid, transform.translation.truncate(), Color::rgb(0.9, 0.8, 0.3));
+            }
+            for transform in &exits {
+                spawn_icon(parent, &grid, transform.translation.truncate(), Color::rgb(0.7, 0.6, 0.2));
+            }
+        });
+
+    commands.insert_resource(grid);
+    commands.insert_resource(MinimapTexture(texture));
+}
+
+/// Icons don't move, so their `Style::left`/`top` percentages (relative to
+/// the minimap container) are computed once here instead of every frame like
+/// [`update_player_dot`] has to for the player.
+fn spawn_icon(parent: &mut ChildBuilder, grid: &MinimapGrid, world_pos: Vec2, color: Color) {
+    let Some((x, y)) = grid.world_to_cell(world_pos) else {
+        return;
+    };
+    parent.spawn(NodeBundle {
+        style: Style {
+            position_type: PositionType::Absolute,
+            left: Val::Percent(x as f32 / grid.cols as f32 * 100.0),
+            top: Val::Percent(y as f32 / grid.rows as f32 * 100.0),
+            width: Val::Px(4.0),
+            height: Val::Px(4.0),
+            ..default()
+        },
+        background_color: color.into(),
+        ..default()
+    });
+}
+
+fn world_to_cell_clamped(origin: Vec2, cols: u32, rows: u32, world: Vec2) -> (u32, u32) {
+    let relative = world - origin;
+    let x = (relative.x / CELL_SIZE).floor().clamp(0.0, (cols - 1) as f32) as u32;
+    let y = (relative.y / CELL_SIZE).floor().clamp(0.0, (rows - 1) as f32) as u32;
+    (x, y)
+}
+
+/// Marks every not-yet-revealed cell within [`REVEAL_RADIUS`] of the player
+/// as revealed, writing only those cells' pixels into the texture — see
+/// this module's own doc comment on why that's a dirty-region write rather
+/// than a full-image rebuild.
+fn reveal_fog(
+    mut images: ResMut<Assets<Image>>,
+    texture: Res<MinimapTexture>,
+    mut grid: ResMut<MinimapGrid>,
+    player: Query<&Transform, With<Player>>,
+) {
+    let Ok(player_transform) = player.get_single() else {
+        return;
+    };
+    let Some((px, py)) = grid.world_to_cell(player_transform.translation.truncate()) else {
+        return;
+    };
+
+    let mut newly_revealed = Vec::new();
+    let min_x = px.saturating_sub(REVEAL_RADIUS as u32);
+    let min_y = py.saturating_sub(REVEAL_RADIUS as u32);
+    let max_x = (px + REVEAL_RADIUS as u32).min(grid.cols - 1);
+    let max_y = (py + REVEAL_RADIUS as u32).min(grid.rows - 1);
+    for y in min_y..=max_y {
+        for x in min_x..=max_x {
+            let idx = grid.index(x, y);
+            if !grid.revealed[idx] {
+                grid.revealed[idx] = true;
+                newly_revealed.push((x, y));
+            }
+        }
+    }
+    if newly_revealed.is_empty() {
+        return;
+    }
+
+    if let Some(image) = images.get_mut(&texture.0) {
+        for (x, y) in &newly_revealed {
+            let color = grid.color_at(*x, *y);
+            let pixel_start = ((*y * grid.cols + *x) * 4) as usize;
+            image.data[pixel_start..pixel_start + 4].copy_from_slice(&color);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    persistence::save_to_disk(&grid);
+}
+
+/// Moves the dot to the player's current cell and blinks it, the same
+/// "steady state most frames, flip a boolean on a timer" shape
+/// `starman::animate_starman_tint` uses for its own blink warning.
+fn update_player_dot(
+    time: Res<Time>,
+    grid: Res<MinimapGrid>,
+    player: Query<&Transform, With<Player>>,
+    mut dot: Query<(&mut Style, &mut Visibility), With<MinimapPlayerDot>>,
+) {
+    let Ok(player_transform) = player.get_single() else {
+        return;
+    };
+    let Ok((mut style, mut visibility)) = dot.get_single_mut() else {
+        return;
+    };
+    let Some((x, y)) = grid.world_to_cell(player_transform.translation.truncate()) else {
+        return;
+    };
+    style.left = Val::Percent(x as f32 / grid.cols as f32 * 100.0);
+    style.top = Val::Percent(y as f32 / grid.rows as f32 * 100.0);
+
+    let blink_on = (time.elapsed_seconds() % BLINK_PERIOD_SECONDS) < BLINK_PERIOD_SECONDS / 2.0;
+    *visibility = if blink_on { Visibility::Inherited } else { Visibility::Hidden };
+}
+
+#[cfg(feature = "serde")]
+mod persistence {
+    use super::MinimapGrid;
+    use bevy::prelude::*;
+    use std::collections::HashSet;
+    use std::path::Path;
+
+    const SAVE_PATH: &str = "assets/minimap_fog.ron";
+
+    /// One-shot load of `assets/minimap_fog.ron` over the freshly-built
+    /// (fully-fogged) grid, if present. Mirrors
+    /// `world_flags::persistence::load_from_disk`, but the grid it's
+    /// applied to already exists (built by [`super::build_minimap`]) rather
+    /// than starting from `Default`, so out-of-range indices from a
+    /// since-edited level are dropped instead of panicking.
+    pub fn load_from_disk(
+        mut images: ResMut<Assets<Image>>,
+        texture: Res<super::MinimapTexture>,
+        mut grid: ResMut<MinimapGrid>,
+    ) {
+        let Ok(contents) = std::fs::read_to_string(Path::new(SAVE_PATH)) else {
+            return;
+        };
+        let revealed: HashSet<u32> = match ron::from_str(&contents) {
+            Ok(revealed) => revealed,
+            Err(err) => {
+                warn!("failed to parse {SAVE_PATH}: {err}");
+                return;
+            }
+        };
+
+        let Some(image) = images.get_mut(&texture.0) else {
+            return;
+        };
+        for idx in revealed {
+            let idx = idx as usize;
+            if idx >= grid.revealed.len() {
+                continue;
+            }
+            grid.revealed[idx] = true;
+            let x = (idx as u32) % grid.cols;
+            let y = (idx as u32) / grid.cols;
+            let color = grid.color_at(x, y);
+            let pixel_start = idx * 4;
+            image.data[pixel_start..pixel_start + 4].copy_from_slice(&color);
+        }
+    }
+
+    /// Writes the revealed-cell indices to `assets/minimap_fog.ron`. Called
+    /// right after `reveal_fog` reveals anything new, mirroring
+    /// `world_flags::save_to_disk`'s save-immediately timing.
+    pub fn save_to_disk(grid: &MinimapGrid) {
+        let revealed: HashSet<u32> =
+            grid.revealed.iter().enumerate().filter(|&(_, &set)| set).map(|(idx, _)| idx as u32).collect();
+        match ron::to_string(&revealed) {
+            Ok(serialized) => {
+                if let Err(err) = std::fs::write(SAVE_PATH, serialized) {
+                    warn!("failed to write {SAVE_PATH}: {err}");
+                }
+            }
+            Err(err) => warn!("failed to serialize minimap fog: {err}"),
+        }
+    }
+}
@@ -0,0 +1,151 @@
+//! Third-party content: `mods/<mod-name>/mod.ron` manifests naming extra
+//! prefabs and levels to fold into the base game's [`PrefabRegistry`] and
+//! [`LevelManifest`], the same RON-driven shape `prefab::load_prefabs`
+//! already reads `assets/prefabs/*.ron` with.
+//!
+//! [`load_mods`] runs at `Startup`, ordered `.after` `prefab::load_prefabs`
+//! so the base game's own prefabs are already registered before any mod is
+//! scanned — that ordering, plus [`PrefabRegistry::insert_if_absent`], is
+//! what "conflicts are resolved by load order" means here: whichever
+//! prefab or level id was registered first (base game, then mods in
+//! whatever order [`std::fs::read_dir`] hands `mods/` back) wins, and
+//! every later claim on the same name or id is skipped with a `warn!`.
+//!
+//! A mod missing its `mod.ron`, one that fails to parse, or one naming a
+//! prefab file that doesn't exist or won't parse is skipped with a
+//! `warn!` and never stops the rest of this system from scanning the
+//! other mods — the same tolerance `prefab::load_prefabs` already has for
+//! a broken `assets/prefabs/*.ron` file, extended here to a whole mod so
+//! one broken mod can never keep the base game from starting.
+//!
+//! Animation overrides are not applied: `animation::Animations` looks
+//! clips up by character and clip name, but nothing here writes a mod's
+//! overrides into it, so base clips are never replaced. A `mod.ron`
+//! naming animation overrides still parses cleanly (the field is optional
+//! and simply unused), rather than failing the whole mod.
+//!
+//! [`InstalledMods`] is what a launcher's cartridge screen would read to
+//! list what's active — `puri-arcade`'s carousel doesn't do so today (see
+//! that crate's own doc comment on how little UI it has), so this is
+//! real, queryable state that nothing in the workspace displays.
+
+use std::fs;
+use std::path::Path;
+
+use bevy::prelude::*;
+
+use crate::level_select::LevelManifest;
+use crate::prefab::{self, Prefab, PrefabRegistry};
+
+const MODS_DIR: &str = "mods";
+
+#[derive(serde::Deserialize)]
+struct ModManifestFile {
+    name: String,
+    version: String,
+    #[serde(default)]
+    prefabs: Vec<String>,
+    #[serde(default)]
+    levels: Vec<crate::level_select::LevelEntry>,
+    /// Parsed so a manifest naming overrides doesn't fail to parse at all
+    /// — see this module's own doc comment on why nothing reads it yet.
+    #[serde(default)]
+    #[allow(dead_code)]
+    animation_overrides: Vec<AnimationOverride>,
+}
+
+#[derive(serde::Deserialize)]
+#[allow(dead_code)]
+struct AnimationOverride {
+    clip: String,
+    first: usize,
+    last: usize,
+}
+
+/// One successfully-loaded mod: its declared name and version, for a
+/// launcher's cartridge screen to list.
+#[derive(Clone, Debug)]
+pub struct InstalledMod {
+    pub name: String,
+    pub version: String,
+}
+
+/// Every mod [`load_mods`] finished loading without error, in load order.
+#[derive(Resource, Default)]
+pub struct InstalledMods(pub Vec<InstalledMod>);
+
+pub struct ModsPlugin;
+
+impl Plugin for ModsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<InstalledMods>()
+            .add_systems(Startup, load_mods.after(prefab::load_prefabs));
+    }
+}
+
+fn load_mods(mut registry: ResMut<PrefabRegistry>, mut manifest: ResMut<LevelManifest>, mut installed: ResMut<InstalledMods>) {
+    let Ok(entries) = fs::read_dir(MODS_DIR) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let dir = entry.path();
+        if !dir.is_dir() {
+            continue;
+        }
+        if let Some(loaded) = load_one_mod(&dir, &mut registry, &mut manifest) {
+            installed.0.push(loaded);
+        }
+    }
+}
+
+fn load_one_mod(dir: &Path, registry: &mut PrefabRegistry, manifest: &mut LevelManifest) -> Option<InstalledMod> {
+    let manifest_path = dir.join("mod.ron");
+    let contents = match fs::read_to_string(&manifest_path) {
+        Ok(contents) => contents,
+        Err(err) => {
+            warn!("skipping mod {}: couldn't read mod.ron: {err}", dir.display());
+            return None;
+        }
+    };
+    let file = match ron::from_str::<ModManifestFile>(&contents) {
+        Ok(file) => file,
+        Err(err) => {
+            warn!("skipping mod {}: failed to parse mod.ron: {err}", dir.display());
+            return None;
+        }
+    };
+
+    for prefab_name in &file.prefabs {
+        load_mod_prefab(dir, prefab_name, registry, &file.name);
+    }
+    for level in file.levels {
+        if manifest.0.iter().any(|existing| existing.id == level.id) {
+            warn!("mod {} declares level id {} which is already taken; skipping", file.name, level.id);
+            continue;
+        }
+        manifest.0.push(level);
+    }
+
+    Some(InstalledMod { name: file.name, version: file.version })
+}
+
+fn load_mod_prefab(dir: &Path, prefab_name: &str, registry: &mut PrefabRegistry, mod_name: &str) {
+    let path = dir.join("prefabs").join(format!("{prefab_name}.ron"));
+    let contents = match fs::read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(err) => {
+            warn!("mod {mod_name}: couldn't read prefab {}: {err}", path.display());
+            return;
+        }
+    };
+    let prefab = match Prefab::parse(&contents) {
+        Ok(prefab) => prefab,
+        Err(err) => {
+            warn!("mod {mod_name}: failed to parse prefab {}: {err}", path.display());
+            return;
+        }
+    };
+    if !registry.insert_if_absent(prefab_name.to_string(), prefab) {
+        warn!("mod {mod_name}: prefab {prefab_name:?} is already registered; skipping (load order wins)");
+    }
+}
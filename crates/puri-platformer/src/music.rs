@@ -0,0 +1,230 @@
+//! Layered background music: a level's [`MusicTrack`] is a set of stems
+//! (base, percussion, danger) that all start together and loop
+//! indefinitely; [`MusicIntensity`] — raised while an aggroed
+//! `boss::Boss` exists, lowered otherwise — crossfades each stem's volume
+//! in and out over a couple of seconds rather than snapping, matching
+//! `ambience::apply_ambience`'s own `math::exp_decay` chase for the same
+//! "level state changed, ease into it" feel.
+//!
+//! The request that asked for this names `Chaser` as a second aggro
+//! source alongside `Boss` — grep turns up no such component anywhere in
+//! this crate (`npc::Npc` is friendly and never aggroes), so
+//! [`detect_combat`] is "any aggroed `Boss` exists" alone; a future
+//! Chaser enemy just needs to flip the same [`MusicIntensity`] target
+//! this reads, not a rewrite of this module.
+//!
+//! Every stem is one `AudioBundle` entity tagged [`MusicStemPlayer`],
+//! spawned by [`spawn_stems`] with every other stem in the same track in
+//! the same system call — so bevy's own `play_queued_audio_system` starts
+//! every stem's `Sink` in the same `Update` tick, as close to
+//! sample-synchronized as this crate's one-system-per-tick audio pipeline
+//! gets. There's also no `Paused` state anywhere in this crate
+//! (`starman`'s own doc comment covers the same gap for its invincibility
+//! timer), so nothing here ever calls `AudioSink::pause` and there's no
+//! opportunity for stems to drift out of sync today; a real pause would
+//! call `.pause()`/`.play()` on every stem's sink from the same system in
+//! the same frame, keeping them frozen together for free the same way
+//! they start together for free.
+//!
+//! `level_reload::LevelReloadRequested` starts [`FadeOut`], a short shared
+//! fade that pulls every stem's volume toward zero; `tick_fade_out`
+//! despawns them once it's run its course and re-spawns fresh stems for
+//! whatever `MusicTrack` is current at that point — the track restarts
+//! from the top rather than resuming mid-loop, which is what "level
+//! transitions fade all stems out together" reads as for a reload that
+//! (today) always reloads the same one track.
+
+use bevy::audio::{AudioBundle, AudioSink, AudioSinkPlayback, PlaybackSettings};
+use bevy::prelude::*;
+
+use crate::audio::{SfxId, SfxLibrary};
+use crate::boss::Boss;
+use crate::level_reload::LevelReloadRequested;
+use crate::math::exp_decay;
+
+/// How fast a stem's displayed volume chases its target, in `exp_decay`
+/// rate units — tuned so a full swing between `0.0` and `1.0` reads as
+/// "a couple of seconds", the request's own words for the crossfade.
+const CROSSFADE_RATE: f32 = 1.5;
+/// How long a level-transition fade-out runs before `tick_fade_out` cuts
+/// the stems, regardless of how close `exp_decay` has actually gotten to
+/// zero by then.
+const FADE_OUT_SECONDS: f32 = 1.0;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum StemRole {
+    Base,
+    Percussion,
+    Danger,
+}
+
+#[derive(Clone)]
+pub struct MusicStem {
+    pub role: StemRole,
+    pub sfx: SfxId,
+}
+
+/// The current level's stem set; empty plays nothing. `pub` so a real
+/// level loader (there isn't one — see `map::spawn_map_entities`'s own
+/// doc comment on that gap) can build one from level-authored data the
+/// same way it'll eventually build `ambience::LevelAmbience`.
+///
+/// `bpm` is `0.0` by default (via `#[derive(Default)]`), which
+/// `rhythm::tick_music_clock` reads as "no track playing, don't advance the
+/// beat clock" — the same "absent means do nothing" shape
+/// `equipment::Equipment`'s own `Option`-based callers use.
+#[derive(Resource, Clone, Default)]
+pub struct MusicTrack {
+    pub stems: Vec<MusicStem>,
+    pub bpm: f32,
+}
+
+impl MusicTrack {
+    /// Two-stem demo config `map::spawn_map_entities` installs: a `Base`
+    /// stem always at full volume, and a `Percussion` stem that fades in
+    /// as `MusicIntensity` rises. Points at `SfxId`s nothing registers in
+    /// `SfxLibrary` yet — there's no `assets/sfx` directory in this crate
+    /// (`audio`'s own doc comment covers the same gap) — so today this
+    /// wires the whole crossfade pipeline end-to-end and plays silence;
+    /// registering two real audio files under these names is all a future
+    /// asset pass needs to do.
+    pub fn demo() -> Self {
+        Self {
+            stems: vec![
+                MusicStem {
+                    role: StemRole::Base,
+                    sfx: SfxId("music_base".to_string()),
+                },
+                MusicStem {
+                    role: StemRole::Percussion,
+                    sfx: SfxId("music_percussion".to_string()),
+                },
+            ],
+            bpm: 120.0,
+        }
+    }
+}
+
+/// `0.0` (calm) .. `1.0` (combat). [`detect_combat`] drives this; nothing
+/// else should write it directly outside a test.
+#[derive(Resource, Clone, Copy)]
+pub struct MusicIntensity(pub f32);
+
+impl Default for MusicIntensity {
+    fn default() -> Self {
+        Self(0.0)
+    }
+}
+
+/// Marks a spawned stem's audio entity. `displayed_volume` is what
+/// [`crossfade_stems`] eases toward the stem's target each frame, then
+/// writes to the entity's `AudioSink` — read back next frame as the
+/// ease's starting point, the same role `toast::ActiveToast::x_offset`
+/// plays for its own per-frame ease.
+#[derive(Component)]
+struct MusicStemPlayer {
+    role: StemRole,
+    displayed_volume: f32,
+}
+
+/// `Some` while a level-transition fade-out is running; `None` the rest
+/// of the time.
+#[derive(Resource, Default)]
+struct FadeOut(Option<Timer>);
+
+pub struct MusicPlugin;
+
+impl Plugin for MusicPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MusicTrack>()
+            .init_resource::<MusicIntensity>()
+            .init_resource::<FadeOut>()
+            .add_systems(Startup, start_music.after(crate::map::setup_map))
+            .add_systems(Update, (detect_combat, start_fade_out, crossfade_stems, tick_fade_out).chain());
+    }
+}
+
+/// Spawns one `AudioBundle` per stem in `track`, all in this one call, so
+/// they're picked up by bevy's queued-audio system on the same tick.
+/// Stems whose `SfxId` isn't in `library` are silently skipped — see
+/// `MusicTrack::demo`'s own note on that being the expected case today.
+fn spawn_stems(commands: &mut Commands, track: &MusicTrack, library: &SfxLibrary) {
+    for stem in &track.stems {
+        let Some(source) = library.get(&stem.sfx) else { continue };
+        commands.spawn((
+            MusicStemPlayer {
+                role: stem.role,
+                displayed_volume: 0.0,
+            },
+            AudioBundle {
+                source,
+                settings: PlaybackSettings::LOOP,
+            },
+        ));
+    }
+}
+
+/// Starts the initial `MusicTrack` at boot. `map::spawn_map_entities`
+/// itself inserts `MusicTrack` before this runs (`MusicPlugin` orders this
+/// `.after` `map::setup_map`, which calls it at `Startup`), so `track` already
+/// holds the demo config by the time this fires.
+fn start_music(mut commands: Commands, track: Res<MusicTrack>, library: Res<SfxLibrary>) {
+    spawn_stems(&mut commands, &track, &library);
+}
+
+/// "combat" is "an aggroed `Boss` exists" — see this module's own doc
+/// comment on why a `Chaser` from the request has nothing to check
+/// against here.
+fn detect_combat(bosses: Query<&Boss>, mut intensity: ResMut<MusicIntensity>) {
+    intensity.0 = if bosses.iter().any(|boss| boss.aggroed) { 1.0 } else { 0.0 };
+}
+
+fn stem_target_volume(role: StemRole, intensity: f32) -> f32 {
+    match role {
+        StemRole::Base => 1.0,
+        StemRole::Percussion => intensity,
+        StemRole::Danger => {
+            if intensity >= 1.0 {
+                1.0
+            } else {
+                0.0
+            }
+        }
+    }
+}
+
+fn crossfade_stems(time: Res<Time>, intensity: Res<MusicIntensity>, fade: Res<FadeOut>, mut stems: Query<(&mut MusicStemPlayer, &AudioSink)>) {
+    let dt = time.delta_seconds();
+    for (mut player, sink) in &mut stems {
+        let target = if fade.0.is_some() { 0.0 } else { stem_target_volume(player.role, intensity.0) };
+        player.displayed_volume = exp_decay(player.displayed_volume, target, CROSSFADE_RATE, dt);
+        sink.set_volume(player.displayed_volume);
+    }
+}
+
+fn start_fade_out(mut events: EventReader<LevelReloadRequested>, mut fade: ResMut<FadeOut>) {
+    if events.read().next().is_some() {
+        fade.0 = Some(Timer::from_seconds(FADE_OUT_SECONDS, TimerMode::Once));
+    }
+}
+
+fn tick_fade_out(
+    time: Res<Time>,
+    mut fade: ResMut<FadeOut>,
+    mut commands: Commands,
+    track: Res<MusicTrack>,
+    library: Res<SfxLibrary>,
+    stems: Query<Entity, With<MusicStemPlayer>>,
+) {
+    let Some(timer) = fade.0.as_mut() else {
+        return;
+    };
+    timer.tick(time.delta());
+    if timer.finished() {
+        for entity in &stems {
+            commands.entity(entity).despawn();
+        }
+        spawn_stems(&mut commands, &track, &library);
+        fade.0 = None;
+    }
+}
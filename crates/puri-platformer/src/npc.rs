@@ -0,0 +1,210 @@
+//! Friendly NPCs: idle wander within a leash radius, a dialogue box on
+//! interact, and turning to face the player while talking.
+//!
+//! [`Npc`] wanders by riding the same generic collision path
+//! `collision::GroundedBody` opened up for "any entity with `Velocity`, a
+//! `HitBox`, and this marker" (see that type's own note) — [`wander`] just
+//! picks small random walk targets and sets `Velocity.x` toward them; the
+//! AABB sweep in `collision::aabb` resolves the actual movement the same
+//! way it does for the player. Nothing applies gravity to an `Npc`
+//! (`player::apply_gravity` is `With<Player>` only, and `loot`'s and
+//! `enemy_ai`'s gravity only pull their own drops and stunned enemies), so
+//! an `Npc` needs to be placed standing on solid ground already rather than
+//! dropped in mid-air.
+//!
+//! Dialogue itself isn't reimplemented here: an `Npc` is expected to also
+//! carry `dialogue::Sign` and `interact::Interactable`, the same two
+//! components a sign uses, so `dialogue::open_sign_dialogue` opens its box
+//! without this module needing to know anything about typewriter text.
+//! [`face_player_during_dialogue`] and [`grant_reward_on_dialogue_complete`]
+//! are the only NPC-specific behavior, both keyed off
+//! `dialogue::ActiveDialogue::source`/[`dialogue::DialogueClosed`].
+//!
+//! [`NpcReward`] exercises `player::PlayerSpawner::abilities`'s
+//! ability-gating from the other side: instead of a spawner setting
+//! `Abilities` once up front, [`grant_reward_on_dialogue_complete`] mutates
+//! the player's `Abilities` in place once the reward's dialogue has been
+//! read to the end, then removes itself so talking again doesn't re-grant.
+
+use bevy::prelude::*;
+
+use crate::collision::GroundedBody;
+use crate::dialogue::{ActiveDialogue, Dialogue, DialogueClosed};
+use crate::player::{Abilities, Facing, Player, Velocity};
+use crate::rng::GameRng;
+use crate::schedule::PlatformerSet;
+
+/// How close the player has to be for [`show_exclamation`] to flag that an
+/// `Npc` has noticed them, independent of `interact::INTERACT_RANGE`'s much
+/// tighter "you can press E now" radius.
+const NOTICE_RANGE: f32 = 80.0;
+
+/// A friendly NPC that idles near `leash_origin`, occasionally wandering up
+/// to `leash_radius` away from it at `wander_speed`, and never damages the
+/// player (nothing in this module reads or writes `fall_damage::DamageEvent`
+/// or `Health`).
+#[derive(Component, Reflect, Clone, Copy, Default)]
+#[reflect(Component)]
+pub struct Npc {
+    pub leash_origin: Vec2,
+    pub leash_radius: f32,
+    pub wander_speed: f32,
+}
+
+/// Per-entity wander state. Not `Reflect`/prefab data — it's the live
+/// output of [`wander`], not level authoring input.
+#[derive(Component, Default)]
+pub struct Wandering {
+    target: Option<Vec2>,
+    idle_timer: Timer,
+}
+
+/// The `Abilities` an `Npc` hands to the player once its dialogue has been
+/// read to the end. Removed by [`grant_reward_on_dialogue_complete`] after
+/// granting, so re-reading the same dialogue doesn't re-grant it.
+#[derive(Component, Reflect, Clone, Copy, Default)]
+#[reflect(Component)]
+pub struct NpcReward(pub Abilities);
+
+/// Marks the small "!" spawned over an `Npc`'s head while the player is
+/// within [`NOTICE_RANGE`], the same throwaway-and-respawn approach
+/// `interact::InteractPrompt` uses for its "E" prompt. `pub(crate)` so
+/// `testing::TestWorld` can count how many are on screen.
+#[derive(Component)]
+pub(crate) struct ExclamationIndicator;
+
+pub struct NpcPlugin;
+
+impl Plugin for NpcPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Npc>()
+            .register_type::<NpcReward>()
+            .add_systems(
+                Update,
+                (
+                    wander
+                        .run_if(crate::dialogue::playing_and_not_talking)
+                        .run_if(crate::cutscene::not_playing),
+                    show_exclamation,
+                )
+                    .in_set(PlatformerSet::Intent),
+            )
+            .add_systems(Update, face_player_during_dialogue.run_if(in_state(Dialogue::Open)))
+            .add_systems(Update, grant_reward_on_dialogue_complete);
+    }
+}
+
+/// Every couple of seconds, picks a new random point within `leash_radius`
+/// of `leash_origin` and walks toward it; holds still in between. Movement
+/// is horizontal only — an `Npc` doesn't jump.
+fn wander(
+    time: Res<Time>,
+    mut rng: ResMut<GameRng>,
+    mut npcs: Query<(&Npc, &mut Wandering, &mut Velocity, &Transform), With<GroundedBody>>,
+) {
+    for (npc, mut wandering, mut velocity, transform) in &mut npcs {
+        let pos = transform.translation.truncate();
+
+        if let Some(target) = wandering.target {
+            let to_target = target.x - pos.x;
+            if to_target.abs() < 2.0 {
+                wandering.target = None;
+                velocity.0.x = 0.0;
+            } else {
+                velocity.0.x = to_target.signum() * npc.wander_speed;
+            }
+            continue;
+        }
+
+        velocity.0.x = 0.0;
+        wandering.idle_timer.tick(time.delta());
+        if wandering.idle_timer.finished() {
+            let offset = rng.range_f32(-npc.leash_radius, npc.leash_radius);
+            wandering.target = Some(Vec2::new(npc.leash_origin.x + offset, npc.leash_origin.y));
+            wandering.idle_timer = Timer::from_seconds(rng.range_f32(2.0, 5.0), TimerMode::Once);
+        }
+    }
+}
+
+/// Spawns/despawns a "!" above every `Npc` within [`NOTICE_RANGE`] of the
+/// player, mirroring `interact::update_prompt`'s despawn-and-respawn-each-
+/// frame approach rather than tracking per-NPC visibility state.
+fn show_exclamation(
+    mut commands: Commands,
+    player: Query<&Transform, With<Player>>,
+    npcs: Query<&Transform, With<Npc>>,
+    existing: Query<Entity, With<ExclamationIndicator>>,
+) {
+    for entity in &existing {
+        commands.entity(entity).despawn_recursive();
+    }
+
+    let Ok(player_transform) = player.get_single() else {
+        return;
+    };
+    let player_pos = player_transform.translation.truncate();
+
+    for npc_transform in &npcs {
+        if npc_transform.translation.truncate().distance(player_pos) > NOTICE_RANGE {
+            continue;
+        }
+        commands.spawn((
+            ExclamationIndicator,
+            Text2dBundle {
+                text: Text::from_section("!", TextStyle::default()),
+                transform: Transform::from_translation(npc_transform.translation + Vec3::new(0.0, 28.0, 2.0)),
+                ..default()
+            },
+        ));
+    }
+}
+
+/// While a dialogue box is open, turns the speaking `Npc` (and its sprite)
+/// to face the player, and leaves everyone else's `Facing` alone.
+fn face_player_during_dialogue(
+    active: Option<Res<ActiveDialogue>>,
+    player: Query<&Transform, With<Player>>,
+    mut npcs: Query<(&Transform, &mut Facing, Option<&mut Sprite>), With<Npc>>,
+) {
+    let Some(active) = active else {
+        return;
+    };
+    let Ok((npc_transform, mut facing, sprite)) = npcs.get_mut(active.source) else {
+        return;
+    };
+    let Ok(player_transform) = player.get_single() else {
+        return;
+    };
+
+    *facing = if player_transform.translation.x >= npc_transform.translation.x {
+        Facing::Right
+    } else {
+        Facing::Left
+    };
+    if let Some(mut sprite) = sprite {
+        sprite.flip_x = *facing == Facing::Left;
+    }
+}
+
+/// Grants `NpcReward`'s `Abilities` to the player once their dialogue has
+/// closed, then removes `NpcReward` so the same NPC can be talked to again
+/// without re-granting.
+fn grant_reward_on_dialogue_complete(
+    mut commands: Commands,
+    mut events: EventReader<DialogueClosed>,
+    rewards: Query<&NpcReward>,
+    mut player: Query<&mut Abilities, With<Player>>,
+) {
+    for event in events.read() {
+        let Ok(reward) = rewards.get(event.source) else {
+            continue;
+        };
+        let Ok(mut abilities) = player.get_single_mut() else {
+            continue;
+        };
+        abilities.double_jump |= reward.0.double_jump;
+        abilities.dash |= reward.0.dash;
+        abilities.wall_jump |= reward.0.wall_jump;
+        commands.entity(event.source).remove::<NpcReward>();
+    }
+}
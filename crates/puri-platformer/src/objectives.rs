@@ -0,0 +1,226 @@
+//! Per-level objective tracking: a checklist the HUD shows, that unlocks
+//! the level's exit gate once everything on it is checked off.
+//!
+//! [`ObjectiveKind`] has a variant for each of the four kinds of goal this
+//! was asked to support, but only two currently have anything to listen
+//! to: [`ObjectiveKind::TalkToAnyNpc`] completes off `DialogueClosed` (see
+//! `npc`'s own note on that event) and [`ObjectiveKind::DefeatBoss`] off
+//! `boss::BossDefeated`. `CollectCoins`/`ReachGoal` are here for the next
+//! request that wires them up, the same way `prefab::Coin` is authored
+//! data with no system reading it yet — there's no
+//! `CollectedEvent` or `GoalReached` anywhere in this crate, so an
+//! objective of either kind would just never complete. [`Objectives::demo`]
+//! (what the built-in map actually uses) only lists the one kind that can
+//! be completed today.
+//!
+//! The exit gate reuses the same trick `npc::NpcReward` uses for one-shot
+//! state: [`ExitGate`] starts out with a `HitBox`, which is what makes it
+//! solid at all (see `collision`'s own note on that), and
+//! [`unlock_exit_when_all_complete`] removes it — and tags the entity
+//! [`ExitUnlocked`] so it isn't reprocessed — the moment every objective is
+//! `complete`. No separate "is the gate open" flag; the `HitBox`'s absence
+//! *is* open, the same way `LightCulled`'s presence *is* culled.
+//!
+//! "Persist through checkpoint respawns but reset on full level restart":
+//! `death`'s respawn never touches this resource, so that half is free.
+//! A full restart is a `LevelReloadRequested`, fired both by
+//! `level_reload`'s dev-time hot-reload and by `results`' Retry button, so
+//! [`reset_on_reload`] is what resets it, listening for that same event.
+//!
+//! [`update_hud_text`] resolves each `description` through
+//! `localization::Localization` before drawing it, and rebuilds the HUD the
+//! instant the locale changes (not just when `Objectives` itself does) —
+//! that module's own doc comment covers what "resolves" falls back to for
+//! an untranslated description.
+
+use bevy::prelude::*;
+
+use crate::boss::BossDefeated;
+use crate::collision::HitBox;
+use crate::dialogue::DialogueClosed;
+use crate::level_reload::LevelReloadRequested;
+use crate::localization::Localization;
+use crate::npc::Npc;
+use crate::schedule::PlatformerSet;
+use crate::toast::ToastEvent;
+
+const TOAST_SECONDS: f32 = 2.5;
+
+/// What has to happen for an [`Objective`] to complete. `CollectCoins` and
+/// `ReachGoal` are placeholders — see this module's own doc comment.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ObjectiveKind {
+    CollectCoins(u32),
+    ReachGoal,
+    DefeatBoss,
+    TalkToAnyNpc,
+}
+
+pub struct Objective {
+    pub description: String,
+    pub kind: ObjectiveKind,
+    pub complete: bool,
+}
+
+impl Objective {
+    fn new(description: impl Into<String>, kind: ObjectiveKind) -> Self {
+        Self {
+            description: description.into(),
+            kind,
+            complete: false,
+        }
+    }
+}
+
+/// The current level's checklist. Level-authored the same way the demo
+/// map's layout is: hard-coded per level in `map`, not loaded from a level
+/// file (see `LaunchOptions::level`'s own note on why).
+#[derive(Resource, Default)]
+pub struct Objectives(pub Vec<Objective>);
+
+impl Objectives {
+    /// The one objective the built-in demo map's own layout can actually
+    /// satisfy — talking to the NPC `map::spawn_map_entities` places next
+    /// to the sign.
+    pub fn demo() -> Self {
+        Self(vec![Objective::new("Talk to the NPC", ObjectiveKind::TalkToAnyNpc)])
+    }
+}
+
+/// Fired once per objective the moment it completes, for the HUD toast.
+#[derive(Event)]
+pub struct ObjectiveCompleted {
+    pub description: String,
+}
+
+/// Marks the entity blocking the way out of the level. Starts with a
+/// `HitBox` (solid); [`unlock_exit_when_all_complete`] removes it.
+#[derive(Component)]
+pub struct ExitGate;
+
+/// Tags an [`ExitGate`] once its `HitBox` has been removed, so
+/// [`unlock_exit_when_all_complete`] doesn't try again every frame.
+#[derive(Component)]
+struct ExitUnlocked;
+
+/// `pub(crate)` so `photo::hide_hud`/`photo::show_hud` can toggle its
+/// `Visibility` — mirrors `ambience::AmbienceOverlay`'s own `pub(crate)`
+/// bump for the same kind of cross-module access.
+#[derive(Component)]
+pub(crate) struct ObjectivesHudText;
+
+pub struct ObjectivesPlugin;
+
+impl Plugin for ObjectivesPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(Objectives::demo())
+            .add_event::<ObjectiveCompleted>()
+            .add_systems(Startup, spawn_hud_text)
+            .add_systems(
+                Update,
+                (
+                    track_objectives,
+                    unlock_exit_when_all_complete,
+                    reset_on_reload,
+                    fire_toast_on_complete,
+                    update_hud_text,
+                )
+                    .chain()
+                    .in_set(PlatformerSet::PostPhysics),
+            );
+    }
+}
+
+fn track_objectives(
+    mut objectives: ResMut<Objectives>,
+    mut dialogue_closed: EventReader<DialogueClosed>,
+    mut boss_defeated: EventReader<BossDefeated>,
+    mut completed: EventWriter<ObjectiveCompleted>,
+    npcs: Query<(), With<Npc>>,
+) {
+    let talked_to_npc = dialogue_closed.read().any(|event| npcs.get(event.source).is_ok());
+    let boss_defeated = boss_defeated.read().count() > 0;
+
+    for objective in &mut objectives.0 {
+        if objective.complete {
+            continue;
+        }
+        let satisfied = match objective.kind {
+            ObjectiveKind::TalkToAnyNpc => talked_to_npc,
+            ObjectiveKind::DefeatBoss => boss_defeated,
+            ObjectiveKind::CollectCoins(_) | ObjectiveKind::ReachGoal => false,
+        };
+        if satisfied {
+            objective.complete = true;
+            completed.send(ObjectiveCompleted {
+                description: objective.description.clone(),
+            });
+        }
+    }
+}
+
+fn unlock_exit_when_all_complete(
+    mut commands: Commands,
+    objectives: Res<Objectives>,
+    gates: Query<Entity, (With<ExitGate>, With<HitBox>, Without<ExitUnlocked>)>,
+) {
+    if objectives.0.is_empty() || !objectives.0.iter().all(|objective| objective.complete) {
+        return;
+    }
+    for entity in &gates {
+        commands.entity(entity).remove::<HitBox>().insert(ExitUnlocked);
+    }
+}
+
+fn reset_on_reload(mut events: EventReader<LevelReloadRequested>, mut objectives: ResMut<Objectives>) {
+    if events.read().next().is_some() {
+        *objectives = Objectives::demo();
+    }
+}
+
+fn spawn_hud_text(mut commands: Commands) {
+    commands.spawn((
+        ObjectivesHudText,
+        TextBundle::from_section("", TextStyle::default()).with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(8.0),
+            right: Val::Px(8.0),
+            ..default()
+        }),
+    ));
+}
+
+fn update_hud_text(
+    objectives: Res<Objectives>,
+    localization: Res<Localization>,
+    mut text: Query<&mut Text, With<ObjectivesHudText>>,
+) {
+    if !objectives.is_changed() && !localization.is_changed() {
+        return;
+    }
+    let Ok(mut text) = text.get_single_mut() else {
+        return;
+    };
+    text.sections[0].value = objectives
+        .0
+        .iter()
+        .map(|objective| {
+            format!(
+                "{} {}",
+                if objective.complete { "[x]" } else { "[ ]" },
+                localization.resolve(&objective.description)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+}
+
+fn fire_toast_on_complete(mut events: EventReader<ObjectiveCompleted>, mut toasts: EventWriter<ToastEvent>) {
+    for event in events.read() {
+        toasts.send(ToastEvent {
+            text: format!("Objective complete: {}", event.description),
+            icon: None,
+            duration: TOAST_SECONDS,
+        });
+    }
+}
@@ -0,0 +1,289 @@
+//! Photo mode: freeze gameplay, unlock the camera for a free pan/zoom, hide
+//! the HUD, and optionally tint the shot before capturing it with `capture`.
+//!
+//! There's no Pause menu anywhere in this crate to toggle photo mode from —
+//! `level_select`/`shop`/`results` are each their own independent
+//! open/closed `State` with no shared "the game is paused" state above
+//! them — so F2 is the toggle instead, the same debug-key stand-in every
+//! other missing-menu feature in this crate already uses (see
+//! `accessibility`'s own doc comment for the pattern).
+//!
+//! Freezing gameplay is [`TimeScale`] set to `0.0`, the same trick
+//! `time_scale`'s own F6 bullet-time debug key already relies on — every
+//! system reading [`crate::time_scale::GameTime`] instead of `Res<Time>`
+//! (animation, `starman`, `floating_text`, `player`'s own movement intent,
+//! and now `collision::aabb::move_bodies` — see that system's own doc
+//! comment on why it needed to join that list for this to actually stop a
+//! moving body mid-air instead of just freezing its *sprite*) stops cold
+//! the instant [`enter_photo_mode`] zeroes it. [`enter_photo_mode`] records
+//! whatever `TimeScale` was set to before (`1.0` normally, `0.25` if bullet
+//! time was already running) so [`exit_photo_mode`] restores that exact
+//! value instead of hard-coding `1.0`.
+//!
+//! The camera: `camera::camera_follow` already has a precedent for another
+//! module pausing it (`.run_if(cutscene::not_playing)`, in `camera.rs`'s
+//! own plugin setup) — [`photo_mode_inactive`] is chained onto that same
+//! `run_if`, and [`pan_zoom_camera`] takes over `Camera2d`'s
+//! `Transform`/`OrthographicProjection::scale` directly while active,
+//! clamped to `map::LevelBounds` so panning can't drift past the level's
+//! edges into empty space. [`exit_photo_mode`] doesn't reset the camera at
+//! all — the moment `camera_follow` un-pauses it picks its target straight
+//! back up from the player's own (frozen, unmoved) `Transform` and
+//! `exp_decay`s toward it, so there's no stale "pre-photo-mode" position to
+//! restore and no one-frame pop; the request's worry about a pop is really
+//! about the camera having drifted while the world kept moving underneath
+//! it, which zeroing `TimeScale` already rules out.
+//!
+//! The HUD: [`hide_hud`]/[`show_hud`] toggle `Visibility` on
+//! `objectives::ObjectivesHudText` and `shield::StaminaHudText`, the two
+//! always-on HUD text elements in this crate — both bumped to `pub(crate)`
+//! for this the same way `ambience::AmbienceOverlay` already is. The debug
+//! overlay (F3) and the minimap (Tab) are left alone: both default to
+//! hidden and are the player's own choice to show, so there's nothing here
+//! to override if they're already off, and forcing them off would fight a
+//! deliberate F3/Tab press mid-photo-mode.
+//!
+//! Filters: [`PhotoFilter`] cycles through a few tint presets by
+//! temporarily overriding `ambience::LevelAmbience` and restoring the
+//! level's own value on exit — that resource is exactly the "post layer"
+//! `ambience`'s own doc comment describes, a flat fullscreen UI tint, so a
+//! preset is real. A vignette isn't: there's no per-pixel post-processing
+//! pass in this crate to darken the screen's edges specifically, only that
+//! same flat overlay, so a "vignette" would really just be another tint —
+//! not worth faking under a name that promises something this renderer
+//! doesn't have.
+//!
+//! Supersampling: `capture::take_screenshot`'s `ScreenshotManager` reads
+//! back whatever's already in the window's own swapchain, so there's no
+//! separate higher-resolution render target to draw into instead. The
+//! honest approximation [`request_supersampled_capture`] takes: double the
+//! primary window's physical resolution for the one frame the screenshot
+//! is queued on, then restore it next frame — a real 2x supersample at the
+//! cost of a visible one-frame resize, which is disclosed rather than
+//! hidden.
+
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use crate::ambience::LevelAmbience;
+use crate::map::LevelBounds;
+use crate::objectives::ObjectivesHudText;
+use crate::schedule::PlatformerSet;
+use crate::shield::StaminaHudText;
+use crate::time_scale::TimeScale;
+
+const PAN_SPEED: f32 = 400.0;
+const ZOOM_SPEED: f32 = 1.5;
+const MIN_ZOOM: f32 = 0.25;
+const MAX_ZOOM: f32 = 3.0;
+
+/// `None` is "no filter"; the rest are flat tints handed to
+/// `ambience::LevelAmbience` while active.
+const TINT_PRESETS: [Option<(Color, f32)>; 4] = [
+    None,
+    Some((Color::rgb(1.0, 0.85, 0.6), 0.15)),  // warm
+    Some((Color::rgb(0.6, 0.8, 1.0), 0.15)),   // cool
+    Some((Color::rgb(0.7, 0.6, 0.4), 0.35)),   // sepia
+];
+
+#[derive(States, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum PhotoMode {
+    #[default]
+    Inactive,
+    Active,
+}
+
+/// What `enter_photo_mode` overwrote, so `exit_photo_mode` can put it back
+/// exactly rather than resetting to a hard-coded default.
+#[derive(Resource, Default)]
+struct PhotoModeSaved {
+    time_scale: f32,
+    ambience: LevelAmbience,
+}
+
+#[derive(Resource, Default)]
+struct PhotoFilter {
+    preset_index: usize,
+}
+
+/// Marks the primary window as mid-supersample-capture. `restore_window_resolution`
+/// counts `frames_remaining` down rather than shrinking the window back the
+/// same frame it was doubled on — the doubled resolution needs to survive
+/// through this frame's render, which happens after `Update`, so undoing it
+/// in the same `Update` pass would shrink the window back before that
+/// render ever saw the larger size.
+#[derive(Resource)]
+struct PendingSupersample {
+    original_width: f32,
+    original_height: f32,
+    frames_remaining: u8,
+}
+
+pub struct PhotoModePlugin;
+
+impl Plugin for PhotoModePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_state::<PhotoMode>()
+            .init_resource::<PhotoModeSaved>()
+            .init_resource::<PhotoFilter>()
+            .add_systems(Update, toggle_photo_mode)
+            .add_systems(OnEnter(PhotoMode::Active), (enter_photo_mode, hide_hud))
+            .add_systems(OnExit(PhotoMode::Active), (exit_photo_mode, show_hud))
+            .add_systems(
+                Update,
+                (cycle_filter, request_supersampled_capture)
+                    .chain()
+                    .run_if(in_state(PhotoMode::Active))
+                    .in_set(PlatformerSet::PostPhysics),
+            )
+            // Not gated on `PhotoMode::Active` — a supersample can still be
+            // mid-flight (`frames_remaining > 0`) the very frame the player
+            // exits photo mode, and this needs to shrink the window back
+            // regardless of what state that leaves behind.
+            .add_systems(Update, restore_window_resolution.after(request_supersampled_capture).in_set(PlatformerSet::PostPhysics))
+            .add_systems(PostUpdate, pan_zoom_camera.in_set(PlatformerSet::CameraSet).run_if(in_state(PhotoMode::Active)));
+    }
+}
+
+/// Chained onto `camera::camera_follow`'s own `run_if` so the two don't
+/// fight over `Camera2d`'s `Transform` the same frame `pan_zoom_camera`
+/// takes over.
+pub fn photo_mode_inactive(state: Res<State<PhotoMode>>) -> bool {
+    *state.get() == PhotoMode::Inactive
+}
+
+fn toggle_photo_mode(
+    keys: Res<ButtonInput<KeyCode>>,
+    state: Res<State<PhotoMode>>,
+    mut next_state: ResMut<NextState<PhotoMode>>,
+) {
+    if !keys.just_pressed(KeyCode::F2) {
+        return;
+    }
+    next_state.set(match state.get() {
+        PhotoMode::Inactive => PhotoMode::Active,
+        PhotoMode::Active => PhotoMode::Inactive,
+    });
+}
+
+fn enter_photo_mode(
+    time_scale: Res<TimeScale>,
+    ambience: Res<LevelAmbience>,
+    mut saved: ResMut<PhotoModeSaved>,
+    mut scale: ResMut<TimeScale>,
+) {
+    saved.time_scale = time_scale.0;
+    saved.ambience = *ambience;
+    scale.0 = 0.0;
+}
+
+fn exit_photo_mode(saved: Res<PhotoModeSaved>, mut scale: ResMut<TimeScale>, mut ambience: ResMut<LevelAmbience>) {
+    scale.0 = saved.time_scale;
+    *ambience = saved.ambience;
+}
+
+fn hide_hud(mut hud: Query<&mut Visibility, Or<(With<ObjectivesHudText>, With<StaminaHudText>)>>) {
+    for mut visibility in &mut hud {
+        *visibility = Visibility::Hidden;
+    }
+}
+
+fn show_hud(mut hud: Query<&mut Visibility, Or<(With<ObjectivesHudText>, With<StaminaHudText>)>>) {
+    for mut visibility in &mut hud {
+        *visibility = Visibility::Inherited;
+    }
+}
+
+fn pan_zoom_camera(
+    time: Res<Time>,
+    keys: Res<ButtonInput<KeyCode>>,
+    bounds: Res<LevelBounds>,
+    mut camera: Query<(&mut Transform, &mut OrthographicProjection), With<Camera2d>>,
+) {
+    let Ok((mut transform, mut projection)) = camera.get_single_mut() else {
+        return;
+    };
+    let dt = time.delta_seconds();
+    let mut pan = Vec2::ZERO;
+    if keys.pressed(KeyCode::ArrowLeft) {
+        pan.x -= 1.0;
+    }
+    if keys.pressed(KeyCode::ArrowRight) {
+        pan.x += 1.0;
+    }
+    if keys.pressed(KeyCode::ArrowUp) {
+        pan.y += 1.0;
+    }
+    if keys.pressed(KeyCode::ArrowDown) {
+        pan.y -= 1.0;
+    }
+    let target = (transform.translation.truncate() + pan * PAN_SPEED * dt * projection.scale).clamp(bounds.min, bounds.max);
+    transform.translation.x = target.x;
+    transform.translation.y = target.y;
+
+    if keys.pressed(KeyCode::Equal) {
+        projection.scale = (projection.scale - ZOOM_SPEED * dt).max(MIN_ZOOM);
+    }
+    if keys.pressed(KeyCode::Minus) {
+        projection.scale = (projection.scale + ZOOM_SPEED * dt).min(MAX_ZOOM);
+    }
+}
+
+fn cycle_filter(keys: Res<ButtonInput<KeyCode>>, mut filter: ResMut<PhotoFilter>, mut ambience: ResMut<LevelAmbience>) {
+    if !keys.just_pressed(KeyCode::KeyV) {
+        return;
+    }
+    filter.preset_index = (filter.preset_index + 1) % TINT_PRESETS.len();
+    match TINT_PRESETS[filter.preset_index] {
+        Some((tint, darkness)) => {
+            ambience.tint = tint;
+            ambience.darkness = darkness;
+        }
+        None => {
+            ambience.tint = Color::WHITE;
+            ambience.darkness = 0.0;
+        }
+    }
+}
+
+/// Shift+F12 supersamples; a plain F12 during photo mode still takes a
+/// normal-resolution shot through `capture::take_screenshot` unchanged.
+fn request_supersampled_capture(
+    keys: Res<ButtonInput<KeyCode>>,
+    pending: Option<Res<PendingSupersample>>,
+    mut commands: Commands,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    if !keys.just_pressed(KeyCode::F12) || !keys.pressed(KeyCode::ShiftLeft) || pending.is_some() {
+        return;
+    }
+    let Ok(mut window) = windows.get_single_mut() else {
+        return;
+    };
+    let original_width = window.resolution.width();
+    let original_height = window.resolution.height();
+    window.resolution.set(original_width * 2.0, original_height * 2.0);
+    commands.insert_resource(PendingSupersample { original_width, original_height, frames_remaining: 1 });
+}
+
+/// Shrinks the window back one frame after `request_supersampled_capture`
+/// doubled it, once this frame's render (which happens after `Update`) has
+/// had a chance to run at the doubled size — see this resource's own doc
+/// comment on why that can't happen the same frame it was requested.
+fn restore_window_resolution(
+    mut commands: Commands,
+    pending: Option<ResMut<PendingSupersample>>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    let Some(mut pending) = pending else {
+        return;
+    };
+    if pending.frames_remaining > 0 {
+        pending.frames_remaining -= 1;
+        return;
+    }
+    if let Ok(mut window) = windows.get_single_mut() {
+        window.resolution.set(pending.original_width, pending.original_height);
+    }
+    commands.remove_resource::<PendingSupersample>();
+}
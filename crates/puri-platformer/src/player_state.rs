@@ -0,0 +1,146 @@
+//! A single, query-friendly answer to "what is the player doing right
+//! now?", computed once by [`compute_player_state`] instead of every
+//! interested system re-deriving it from `Grounded`/`Jump`/`Attacking`/etc.
+//! and disagreeing at the edges.
+//!
+//! This crate has no `FixedUpdate` schedule (every gameplay system runs
+//! once per variable-length `Update` frame — see `schedule`'s own doc
+//! comment, and `challenge`'s own note making the same substitution), so
+//! "computed once per fixed tick" is implemented as "computed once per
+//! `Update` frame", in [`PlatformerSet::PostPhysics`] — the set
+//! `schedule`'s own doc comment already earmarks for "animation state
+//! selection", right after `Grounded` is resolved for the frame and before
+//! anything reads it.
+//!
+//! [`PlayerState`] and [`PlayerStateChanged`] are added additively: nothing
+//! in this crate yet has a generic "animation state machine" to plug into
+//! (`attack`'s own doc comment notes no gameplay code swapped
+//! `SpriteAnimation` clips at all before that module), so there's no
+//! existing consumer to rewire. The component and event exist for whatever
+//! reads them next — the same "hookup exists, no caller uses it today" gap
+//! `player::PartSlot`'s own doc comment leaves for its own caller.
+//!
+//! Two of the ten requested variants have no real signal to derive from in
+//! this crate: [`PlayerState::WallSlide`] (no wall-slide mechanic exists —
+//! `death`'s own doc comment notes there's no `WallSlide` component either)
+//! and [`PlayerState::Climb`], which is instead entered for `grapple::Grappled`
+//! — a swing on a grapple line is the closest thing to "climbing" this
+//! crate has. `WallSlide` is kept in the enum for the day a real wall-slide
+//! lands, but [`compute_player_state`] never produces it.
+
+use bevy::prelude::*;
+
+use crate::attack::Attacking;
+use crate::command_queue::{CommandQueue, PlayerId};
+use crate::death::Dying;
+use crate::fall_damage::DamageEvent;
+use crate::grapple::Grappled;
+use crate::player::{Abilities, Grounded, Player, Velocity};
+use crate::schedule::PlatformerSet;
+
+/// Below this horizontal speed the player reads as holding still rather
+/// than running, and below this vertical speed as holding still in the air
+/// rather than rising/falling — small enough that Idle/Jump don't flicker
+/// on floating-point noise around exactly zero.
+const STILL_THRESHOLD: f32 = 1.0;
+
+#[derive(Component, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum PlayerState {
+    #[default]
+    Idle,
+    Run,
+    Jump,
+    Fall,
+    WallSlide,
+    Dash,
+    Climb,
+    Hurt,
+    Dead,
+    Attack,
+}
+
+/// Fired by [`compute_player_state`] the frame `PlayerState` actually
+/// changes — not every frame it's recomputed, most of which land on the
+/// same state as last frame.
+#[derive(Event, Clone, Copy)]
+pub struct PlayerStateChanged {
+    pub from: PlayerState,
+    pub to: PlayerState,
+}
+
+pub struct PlayerStatePlugin;
+
+impl Plugin for PlayerStatePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<PlayerStateChanged>()
+            .add_systems(Update, attach_player_state)
+            .add_systems(Update, compute_player_state.in_set(PlatformerSet::PostPhysics));
+    }
+}
+
+/// `Added<Player>` rather than folding `PlayerState` into `PlayerBundle`
+/// itself — mirrors `shop::sync_health_with_upgrades`'s own reason for the
+/// same pattern: this module shouldn't need `player::PlayerBundle` to know
+/// it exists.
+fn attach_player_state(mut commands: Commands, players: Query<Entity, Added<Player>>) {
+    for entity in &players {
+        commands.entity(entity).insert(PlayerState::default());
+    }
+}
+
+fn compute_player_state(
+    frame: Res<bevy::core::FrameCount>,
+    queue: Res<CommandQueue>,
+    mut damage_events: EventReader<DamageEvent>,
+    mut changed_events: EventWriter<PlayerStateChanged>,
+    mut player: Query<
+        (
+            Option<&Grounded>,
+            &Velocity,
+            &Abilities,
+            Option<&Attacking>,
+            Option<&Dying>,
+            Option<&Grappled>,
+            &mut PlayerState,
+        ),
+        With<Player>,
+    >,
+) {
+    let Ok((grounded, velocity, abilities, attacking, dying, grappled, mut state)) = player.get_single_mut() else {
+        damage_events.clear();
+        return;
+    };
+
+    let took_damage = damage_events.read().next().is_some();
+    let command = queue.command_for(frame.0, PlayerId::LOCAL).unwrap_or_default();
+    let dashed = command.dash_just_pressed && abilities.dash;
+
+    let next = if dying.is_some() {
+        PlayerState::Dead
+    } else if took_damage {
+        PlayerState::Hurt
+    } else if attacking.is_some() {
+        PlayerState::Attack
+    } else if grappled.is_some() {
+        PlayerState::Climb
+    } else if dashed {
+        PlayerState::Dash
+    } else if grounded.is_some() {
+        // `Grounded`'s own doc comment: most readers only care that it's
+        // present at all, not which solid it names — same here.
+        if velocity.0.x.abs() > STILL_THRESHOLD {
+            PlayerState::Run
+        } else {
+            PlayerState::Idle
+        }
+    } else if velocity.0.y > STILL_THRESHOLD {
+        PlayerState::Jump
+    } else {
+        PlayerState::Fall
+    };
+
+    if next != *state {
+        changed_events.send(PlayerStateChanged { from: *state, to: next });
+        *state = next;
+    }
+}
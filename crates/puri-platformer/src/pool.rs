@@ -0,0 +1,85 @@
+//! Generic entity pooling for the spawn/despawn-heavy systems: projectiles
+//! today, per `projectile::PROJECTILE_POOL_CAPACITY`; particles too once
+//! this crate ever has any (see this module's own note on that gap below).
+//!
+//! [`EntityPool<T>`] pre-spawns `capacity` entities carrying `T` (plus
+//! [`Pooled`] and a hidden `Visibility`) once, at construction. Since every
+//! pooled entity already has every component `T` contributes, handing one
+//! out via [`EntityPool::acquire`] only ever *overwrites* those components'
+//! values with `insert` and clears [`Pooled`] — never adds or removes a `T`
+//! component — so the only archetype move an acquire/release pair costs is
+//! the one for [`Pooled`] itself, not a full respawn's worth of moves.
+//! [`EntityPool::release`] reinserts [`Pooled`], hides the entity, and
+//! returns it to the free list rather than despawning it.
+//!
+//! [`EntityPool::acquire`] falls back to a normal, unpooled `commands.spawn`
+//! once the free list runs dry, bumping [`EntityPool::exhausted_count`] so
+//! `debug_overlay` can surface it — a pool that silently degrades to
+//! "spawn like nothing changed" under load beats one that drops requests or
+//! panics, and the counter is exactly what a profiler chasing the
+//! allocation spikes this module exists to fix would want to see.
+//!
+//! There's no particle system anywhere in this crate for this pool to serve
+//! a second caller yet — nothing here fires anything resembling a burst of
+//! short-lived visual-only entities (`floating_text::FloatingText` is the
+//! closest thing, but it already recycles via its own simultaneous cap, not
+//! a pool). `EntityPool<T>` is generic precisely so a future particle
+//! system can reuse it the same way `projectile.rs` does, without this
+//! module needing to know particles exist.
+
+use bevy::prelude::*;
+
+/// Marks a currently-idle pooled entity, hidden and excluded from every
+/// gameplay query that filters on the entity's real marker component (e.g.
+/// `projectile::Projectile`) — see this module's own doc comment on why
+/// reusing it never causes a `T`-archetype move.
+#[derive(Component)]
+pub struct Pooled;
+
+/// A fixed-capacity pool of entities carrying bundle `T`. See this module's
+/// own doc comment for the acquire/release contract.
+#[derive(Resource)]
+pub struct EntityPool<T: Bundle + Clone> {
+    free: Vec<Entity>,
+    template: T,
+    exhausted_count: u32,
+}
+
+impl<T: Bundle + Clone> EntityPool<T> {
+    /// Pre-spawns `capacity` entities cloned from `template`, hidden and
+    /// marked [`Pooled`].
+    pub fn new(commands: &mut Commands, capacity: usize, template: T) -> Self {
+        let free = (0..capacity)
+            .map(|_| commands.spawn((template.clone(), Pooled, Visibility::Hidden)).id())
+            .collect();
+        Self { free, template, exhausted_count: 0 }
+    }
+
+    /// Hands out a pooled entity with `bundle`'s values and made visible
+    /// again, or falls back to a fresh `commands.spawn(bundle)` (bumping
+    /// [`Self::exhausted_count`]) if the pool is empty.
+    pub fn acquire(&mut self, commands: &mut Commands, bundle: T) -> Entity {
+        if let Some(entity) = self.free.pop() {
+            commands.entity(entity).insert(bundle).insert(Visibility::Inherited).remove::<Pooled>();
+            entity
+        } else {
+            self.exhausted_count += 1;
+            commands.spawn(bundle).id()
+        }
+    }
+
+    /// Returns `entity` to the free list, restoring `template`'s values so
+    /// the next [`Self::acquire`] doesn't observe whatever the last user
+    /// left behind, hiding it, and re-marking it [`Pooled`].
+    pub fn release(&mut self, commands: &mut Commands, entity: Entity) {
+        commands.entity(entity).insert(self.template.clone()).insert((Pooled, Visibility::Hidden));
+        self.free.push(entity);
+    }
+
+    /// How many times [`Self::acquire`] has had to fall back to an unpooled
+    /// spawn because the free list was empty — `debug_overlay`'s warning
+    /// counter for this pool.
+    pub fn exhausted_count(&self) -> u32 {
+        self.exhausted_count
+    }
+}
@@ -0,0 +1,75 @@
+//! Paired teleporters. `PortalId` links two `Portal` sensors together.
+//! [`PortalCooldown`] ticks off `time_scale::GameTime` for the same reason
+//! `crumbling`'s timers do — a cooldown shouldn't keep draining during
+//! slow-motion (or a future pause) any more than the platform it's paired
+//! with a level's timing around.
+
+use bevy::prelude::*;
+
+use crate::camera::CameraSnap;
+use crate::collision::{HitBox, Sensor};
+use crate::player::{Player, Velocity};
+use crate::time_scale::GameTime;
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct PortalId(pub u32);
+
+#[derive(Component)]
+pub struct Portal {
+    pub link: PortalId,
+    pub id: PortalId,
+}
+
+#[derive(Component)]
+struct PortalCooldown(Timer);
+
+pub struct PortalPlugin;
+
+impl Plugin for PortalPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (tick_cooldowns, teleport_on_overlap));
+    }
+}
+
+fn tick_cooldowns(time: GameTime, mut player: Query<&mut PortalCooldown>) {
+    for mut cooldown in &mut player {
+        cooldown.0.tick(time.delta());
+    }
+}
+
+fn teleport_on_overlap(
+    mut commands: Commands,
+    mut player: Query<(Entity, &mut Transform, &Velocity, Option<&PortalCooldown>), With<Player>>,
+    portals: Query<(&Transform, &HitBox, &Portal), (With<Sensor>, Without<Player>)>,
+) {
+    let Ok((entity, mut transform, velocity, cooldown)) = player.get_single_mut() else {
+        return;
+    };
+    if cooldown.is_some_and(|cooldown| !cooldown.0.finished()) {
+        return;
+    }
+
+    for (portal_transform, portal_box, portal) in &portals {
+        let overlapping = (transform.translation.truncate() - portal_transform.translation.truncate())
+            .abs()
+            .cmplt(portal_box.size / 2.0)
+            .all();
+        if !overlapping {
+            continue;
+        }
+
+        let Some((destination, _, _)) = portals.iter().find(|(_, _, other)| other.id == portal.link)
+        else {
+            warn!("portal {:?} has no matching link {:?}", portal.id, portal.link);
+            return;
+        };
+
+        transform.translation = destination.translation;
+        let _ = velocity; // preserved as-is; only position changes.
+        commands
+            .entity(entity)
+            .insert(PortalCooldown(Timer::from_seconds(0.5, TimerMode::Once)));
+        commands.entity(entity).insert(CameraSnap);
+        return;
+    }
+}
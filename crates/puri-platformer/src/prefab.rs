@@ -0,0 +1,287 @@
+//! Data-driven entity prefabs, loaded once at `Startup` from
+//! `assets/prefabs/*.ron` into a [`PrefabRegistry`].
+//!
+//! A prefab is a named map from a component's fully qualified type path
+//! (what `std::any::type_name::<T>()` returns, e.g.
+//! `puri_platformer::prefab::Coin`) to that component's RON value. Loading
+//! resolves each entry against the live `AppTypeRegistry` using the same
+//! reflection machinery Bevy's own scene format uses
+//! ([`UntypedReflectDeserializer`]), so a prefab can reference any
+//! `#[reflect(Component)]` type already registered by any plugin — nothing
+//! prefab-specific needs to be derived beyond the `register_type::<T>()`
+//! every reflected component already gets for the inspector.
+//!
+//! Coins, spikes, and springs don't exist as gameplay systems in this
+//! crate yet (no pickup, damage-on-touch, or bounce systems), so [`Coin`],
+//! [`Spike`], and [`Spring`] here are the minimal data components that
+//! give `assets/prefabs/*.ron` something concrete to name — wiring
+//! behavior on top of them is separate, future work. [`Patroller`],
+//! [`Flyer`], and [`Fish`] are the same kind of RON-deserializable data
+//! component, but `enemy_ai`'s `patrol`/`fly`/`swim` systems already read
+//! them.
+//!
+//! `map::spawn_map_entities` still spawns its demo layout by hand rather
+//! than through prefabs; there's no level file format yet for it to read
+//! prefab placements from (see `level_reload`'s own note on that). The
+//! in-game editor is `bevy_editor_pls`'s generic world inspector — it has
+//! no placement palette to hand entity kinds to. [`spawn_prefab`] is
+//! written so either can call it once they exist; today it's exercised by
+//! `tests/prefab.rs`.
+
+use std::collections::HashMap;
+
+use bevy::ecs::reflect::AppTypeRegistry;
+use bevy::prelude::*;
+use bevy::reflect::serde::UntypedReflectDeserializer;
+use bevy::reflect::TypeRegistry;
+use serde::de::DeserializeSeed;
+
+use crate::render_layer::{z_for, GameLayer};
+
+const PREFAB_DIR: &str = "assets/prefabs";
+
+/// Marker for a collectible. No pickup system exists yet.
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+pub struct Coin;
+
+/// Marker for a hazard that damages the player on touch. No damage-on-touch
+/// system exists yet — `fall_damage::DamageEvent` is currently only sent by
+/// landing hard, not by touching a `Spike`. That also means this component
+/// has nowhere to carry a `player::DamageKind::Spike` tag today: a damage
+/// source's kind is data on the event that fires it (see `fall_damage.rs`'s
+/// and `projectile.rs`'s own `DamageEvent { kind, .. }` sends), and `Spike`
+/// doesn't fire one yet, so there's no RON field to add here until it does.
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+pub struct Spike;
+
+/// A surface that launches the player upward on contact. No bounce system
+/// reads this yet.
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+pub struct Spring {
+    pub launch_velocity: f32,
+}
+
+/// An enemy that walks back and forth over `range` at `speed`, mirroring
+/// `map::MovingPlatform`'s fields. Read by `enemy_ai::patrol`.
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+pub struct Patroller {
+    pub speed: f32,
+    pub range: f32,
+}
+
+/// A hovering enemy that patrols back and forth over `range` at `speed`
+/// like [`Patroller`], sine-wave bobbing `bob_amplitude` units up and down
+/// around its spawn height at `bob_speed` radians/sec, and diving straight
+/// down at `dive_speed` once the player is within `dive_range` on the x
+/// axis. [`enemy_ai::fly`] is the system that reads it — needs
+/// `collision::GroundedBody` and `collision::MovementMode::Flying` to move
+/// and collide with world tiles the way `assets/prefabs/flyer.ron` gives
+/// it both.
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+pub struct Flyer {
+    pub speed: f32,
+    pub range: f32,
+    pub bob_amplitude: f32,
+    pub bob_speed: f32,
+    pub dive_range: f32,
+    pub dive_speed: f32,
+}
+
+/// A swimming enemy that patrols back and forth at `speed`, turning around
+/// at the edges of whichever `water::Water` zone it's inside rather than a
+/// fixed `range` like [`Patroller`]/[`Flyer`] — see [`enemy_ai::swim`],
+/// the system that reads it. Also needs `collision::GroundedBody`, same as
+/// `Flyer`.
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+pub struct Fish {
+    pub speed: f32,
+}
+
+/// One `assets/prefabs/<name>.ron` file: a bundle of components keyed by
+/// type path. Components are resolved lazily, against the live
+/// `TypeRegistry`, by [`spawn_prefab`] rather than at load time — a prefab
+/// naming a type that isn't registered yet at `Startup` still loads
+/// cleanly and only errors when actually spawned.
+pub struct Prefab {
+    components: HashMap<String, ron::Value>,
+}
+
+impl Prefab {
+    /// Parses one `mod.ron`-referenced prefab file's contents, the same
+    /// format `load_prefabs` reads from `assets/prefabs/*.ron`. `pub(crate)`
+    /// so `mods::load_mod_prefab` can build a [`Prefab`] from a mod's own
+    /// prefab file without duplicating this crate's RON shape.
+    pub(crate) fn parse(contents: &str) -> Result<Self, impl std::fmt::Display> {
+        ron::from_str::<PrefabFile>(contents).map(|file| Prefab { components: file.components })
+    }
+}
+
+#[derive(serde::Deserialize)]
+struct PrefabFile {
+    components: HashMap<String, ron::Value>,
+}
+
+/// Every prefab found under `assets/prefabs/*.ron` at startup, keyed by
+/// file stem (`coin.ron` -> `"coin"`).
+#[derive(Resource, Default)]
+pub struct PrefabRegistry {
+    prefabs: HashMap<String, Prefab>,
+}
+
+impl PrefabRegistry {
+    pub fn get(&self, name: &str) -> Option<&Prefab> {
+        self.prefabs.get(name)
+    }
+
+    /// Inserts `prefab` under `name` unless one's already registered under
+    /// that name, in which case it's left untouched. Returns whether the
+    /// insert happened, so `mods::load_mod_prefab` can `warn!` on a
+    /// conflict rather than silently overwriting an earlier mod (or the
+    /// base game's own `assets/prefabs/`) load-order winner.
+    pub(crate) fn insert_if_absent(&mut self, name: String, prefab: Prefab) -> bool {
+        if self.prefabs.contains_key(&name) {
+            return false;
+        }
+        self.prefabs.insert(name, prefab);
+        true
+    }
+}
+
+/// Why [`spawn_prefab`] couldn't finish spawning a prefab. The `Display`
+/// impl always names the prefab file and the offending field, per the
+/// request this module exists to satisfy.
+#[derive(Debug)]
+pub enum PrefabError {
+    UnknownPrefab(String),
+    UnknownComponent { prefab: String, field: String },
+    Deserialize { prefab: String, field: String, message: String },
+}
+
+impl std::fmt::Display for PrefabError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PrefabError::UnknownPrefab(name) => {
+                write!(f, "no prefab named {name:?} is registered")
+            }
+            PrefabError::UnknownComponent { prefab, field } => write!(
+                f,
+                "assets/prefabs/{prefab}.ron: field {field:?} names a component type that isn't registered"
+            ),
+            PrefabError::Deserialize { prefab, field, message } => write!(
+                f,
+                "assets/prefabs/{prefab}.ron: field {field:?} failed to deserialize: {message}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PrefabError {}
+
+pub struct PrefabPlugin;
+
+impl Plugin for PrefabPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Coin>()
+            .register_type::<Spike>()
+            .register_type::<Spring>()
+            .register_type::<Patroller>()
+            .register_type::<Flyer>()
+            .register_type::<Fish>()
+            .init_resource::<PrefabRegistry>()
+            .add_systems(Startup, load_prefabs);
+    }
+}
+
+/// `pub(crate)` so `mods::load_mods` can order itself `.after` this — mods
+/// only ever add to a `PrefabRegistry` the base game has already finished
+/// populating, so a mod prefab can never accidentally win a conflict
+/// against one of this crate's own by being scanned first.
+pub(crate) fn load_prefabs(mut registry: ResMut<PrefabRegistry>) {
+    let Ok(entries) = std::fs::read_dir(PREFAB_DIR) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some("ron") {
+            continue;
+        }
+        let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) else {
+            continue;
+        };
+        let name = name.to_string();
+        let Ok(contents) = std::fs::read_to_string(&path) else {
+            continue;
+        };
+        match Prefab::parse(&contents) {
+            Ok(prefab) => {
+                registry.prefabs.insert(name, prefab);
+            }
+            Err(err) => warn!("failed to parse {}: {err}", path.display()),
+        }
+    }
+}
+
+/// Spawns a new entity with `transform` and every component named in the
+/// `name` prefab, resolving each against `world`'s `AppTypeRegistry`.
+/// Reflection-based insertion needs direct `World` access (there's no
+/// `Commands`-based equivalent of `ReflectComponent::insert` in this Bevy
+/// version), so unlike `map::spawn_map_entities` this takes a `World`
+/// rather than `Commands`.
+///
+/// `transform`'s z is overwritten with `z_for(GameLayer::Entities, ...)` —
+/// prefabs (coins, spikes, springs, patrollers) are all gameplay entities,
+/// so callers place them by x/y only and don't need to know this crate's
+/// layering conventions.
+pub fn spawn_prefab(world: &mut World, name: &str, mut transform: Transform) -> Result<Entity, PrefabError> {
+    let components = {
+        let registry = world.resource::<PrefabRegistry>();
+        let prefab = registry.get(name).ok_or_else(|| PrefabError::UnknownPrefab(name.to_string()))?;
+        prefab.components.clone()
+    };
+
+    transform.translation.z = z_for(GameLayer::Entities, transform.translation.y, true);
+    let entity = world.spawn(TransformBundle::from_transform(transform)).id();
+
+    let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+    let type_registry = type_registry.read();
+    if let Err(err) = insert_components(world, entity, &type_registry, name, &components) {
+        world.entity_mut(entity).despawn();
+        return Err(err);
+    }
+    Ok(entity)
+}
+
+fn insert_components(
+    world: &mut World,
+    entity: Entity,
+    type_registry: &TypeRegistry,
+    prefab_name: &str,
+    components: &HashMap<String, ron::Value>,
+) -> Result<(), PrefabError> {
+    for (type_path, value) in components {
+        let field = || PrefabError::UnknownComponent {
+            prefab: prefab_name.to_string(),
+            field: type_path.clone(),
+        };
+        let registration = type_registry.get_with_type_path(type_path).ok_or_else(field)?;
+        let reflect_component = registration.data::<ReflectComponent>().ok_or_else(field)?;
+        let reflected =
+            UntypedReflectDeserializer::new(type_registry)
+                .deserialize(value.clone())
+                .map_err(|err| PrefabError::Deserialize {
+                    prefab: prefab_name.to_string(),
+                    field: type_path.clone(),
+                    message: err.to_string(),
+                })?;
+
+        let mut entity_mut = world.entity_mut(entity);
+        reflect_component.insert(&mut entity_mut, &*reflected, type_registry);
+    }
+    Ok(())
+}
@@ -0,0 +1,178 @@
+//! Shared projectile system: a straight-line `HitBox` that despawns the
+//! first solid it hits, or the player it damages via
+//! `fall_damage::DamageEvent` — the crate's one damage pipeline, same one
+//! `starman::Starman` already gates. Nothing spawns a `Projectile` yet
+//! except `turret::Turret`.
+//!
+//! `tilemap::TileLayer` is purely visual (no per-tile `HitBox`es exist), so
+//! "collide with world tiles" reduces to the same static solids
+//! `collision::aabb::move_bodies` already resolves the player against —
+//! anything with a `HitBox` that isn't a `Sensor` and isn't itself a
+//! `Projectile` or the `Player`.
+//!
+//! Projectiles are the churniest entity in this crate — a turret can fire
+//! and despawn several a second — so they're the one thing pooled via
+//! `pool::EntityPool` today: [`ProjectilePlugin`] owns an
+//! `EntityPool<ProjectileBundle>` sized to [`PROJECTILE_POOL_CAPACITY`],
+//! [`spawn_projectile`] now hands out through it instead of a bare
+//! `commands.spawn`, and both despawn sites (a hit landing,
+//! [`despawn_projectiles_on_solid_hit`]) release back to it instead.
+
+use bevy::diagnostic::{Diagnostic, Diagnostics, RegisterDiagnostic};
+use bevy::prelude::*;
+
+use crate::collision::{HitBox, Sensor};
+use crate::debug_overlay::PROJECTILE_POOL_EXHAUSTED;
+use crate::fall_damage::DamageEvent;
+use crate::player::{DamageKind, Player};
+use crate::pool::{EntityPool, Pooled};
+use crate::schedule::PlatformerSet;
+use crate::time_scale::GameTime;
+
+/// How many projectiles `ProjectilePlugin` pre-spawns. A handful more than
+/// this crate's one turret could plausibly have in flight at once; past it,
+/// `spawn_projectile` still works, just via an unpooled fallback spawn (see
+/// `pool::EntityPool::acquire`).
+const PROJECTILE_POOL_CAPACITY: usize = 16;
+
+/// A moving hazard entity. `velocity` is constant for the projectile's
+/// whole lifetime — nothing here curves or homes.
+#[derive(Component, Clone)]
+pub struct Projectile {
+    pub velocity: Vec2,
+    pub damage: u32,
+}
+
+/// Every component a pooled projectile carries, so `pool::EntityPool` can
+/// pre-spawn and reuse whole projectiles without an insert/remove of any of
+/// these individually causing an archetype move — see that module's own
+/// doc comment.
+#[derive(Bundle, Clone)]
+pub struct ProjectileBundle {
+    pub projectile: Projectile,
+    pub hitbox: HitBox,
+    pub sensor: Sensor,
+    pub sprite: SpriteBundle,
+}
+
+pub struct ProjectilePlugin;
+
+impl Plugin for ProjectilePlugin {
+    fn build(&self, app: &mut App) {
+        // Registered here (rather than only by `debug_overlay`) so
+        // `report_pool_exhaustion` can report it even if this plugin is
+        // ever added on its own — mirrors `collision::CollisionPlugin`'s
+        // own citation for the same reason.
+        app.register_diagnostic(Diagnostic::new(PROJECTILE_POOL_EXHAUSTED))
+            .add_systems(Startup, init_projectile_pool)
+            .add_systems(
+                Update,
+                (move_projectiles, damage_player_on_hit, despawn_projectiles_on_solid_hit, report_pool_exhaustion)
+                    .chain()
+                    .in_set(PlatformerSet::PostPhysics),
+            );
+    }
+}
+
+fn init_projectile_pool(mut commands: Commands) {
+    let template = ProjectileBundle {
+        projectile: Projectile { velocity: Vec2::ZERO, damage: 0 },
+        hitbox: HitBox { size: Vec2::ZERO },
+        sensor: Sensor,
+        sprite: SpriteBundle::default(),
+    };
+    let pool = EntityPool::new(&mut commands, PROJECTILE_POOL_CAPACITY, template);
+    commands.insert_resource(pool);
+}
+
+/// Spawns a projectile with `hitbox_size` centered on `position`, tinted
+/// `color`, moving at `velocity`, through `ProjectilePlugin`'s
+/// `EntityPool<ProjectileBundle>`. Callers (currently only
+/// `turret::fire_turret`) own tagging it `LevelEntity` themselves, the same
+/// way `map::spawn_map_entities` tags everything it spawns.
+pub fn spawn_projectile(
+    commands: &mut Commands,
+    pool: &mut EntityPool<ProjectileBundle>,
+    position: Vec2,
+    velocity: Vec2,
+    hitbox_size: Vec2,
+    damage: u32,
+    color: Color,
+    z: f32,
+) -> Entity {
+    pool.acquire(
+        commands,
+        ProjectileBundle {
+            projectile: Projectile { velocity, damage },
+            hitbox: HitBox { size: hitbox_size },
+            sensor: Sensor,
+            sprite: SpriteBundle {
+                sprite: Sprite {
+                    color,
+                    custom_size: Some(hitbox_size),
+                    ..default()
+                },
+                transform: Transform::from_translation(position.extend(z)),
+                ..default()
+            },
+        },
+    )
+}
+
+fn move_projectiles(time: GameTime, mut projectiles: Query<(&mut Transform, &Projectile), Without<Pooled>>) {
+    let dt = time.delta_seconds();
+    for (mut transform, projectile) in &mut projectiles {
+        transform.translation += (projectile.velocity * dt).extend(0.0);
+    }
+}
+
+fn damage_player_on_hit(
+    mut commands: Commands,
+    mut pool: ResMut<EntityPool<ProjectileBundle>>,
+    projectiles: Query<(Entity, &Transform, &HitBox, &Projectile), Without<Pooled>>,
+    player: Query<(&Transform, &HitBox), With<Player>>,
+    mut damage_events: EventWriter<DamageEvent>,
+) {
+    let Ok((player_transform, player_box)) = player.get_single() else {
+        return;
+    };
+    for (entity, transform, hitbox, projectile) in &projectiles {
+        let touch_distance = (hitbox.size + player_box.size) / 2.0;
+        let overlapping = (transform.translation.truncate() - player_transform.translation.truncate())
+            .abs()
+            .cmplt(touch_distance)
+            .all();
+        if overlapping {
+            damage_events.send(DamageEvent {
+                amount: projectile.damage,
+                kind: DamageKind::Projectile,
+            });
+            pool.release(&mut commands, entity);
+        }
+    }
+}
+
+fn despawn_projectiles_on_solid_hit(
+    mut commands: Commands,
+    mut pool: ResMut<EntityPool<ProjectileBundle>>,
+    projectiles: Query<(Entity, &Transform, &HitBox), (With<Projectile>, Without<Pooled>)>,
+    solids: Query<(&Transform, &HitBox), (Without<Projectile>, Without<Sensor>, Without<Player>)>,
+) {
+    for (entity, transform, hitbox) in &projectiles {
+        for (solid_transform, solid_box) in &solids {
+            let touch_distance = (hitbox.size + solid_box.size) / 2.0;
+            let overlapping = (transform.translation.truncate() - solid_transform.translation.truncate())
+                .abs()
+                .cmplt(touch_distance)
+                .all();
+            if overlapping {
+                pool.release(&mut commands, entity);
+                break;
+            }
+        }
+    }
+}
+
+fn report_pool_exhaustion(pool: Res<EntityPool<ProjectileBundle>>, mut diagnostics: Diagnostics) {
+    diagnostics.add_measurement(&PROJECTILE_POOL_EXHAUSTED, || pool.exhausted_count() as f64);
+}
@@ -0,0 +1,120 @@
+//! Device-aware "Press {key} to {verb}" text, so prompts don't hard-code a
+//! keyboard key when the player is on a gamepad (or vice versa).
+//!
+//! What the original ask wanted doesn't quite exist to plug into:
+//! [`InputIcons`] maps to plain bracketed labels ("[E]", "[A]") rather than
+//! icon atlas indices — there's no icon spritesheet asset anywhere in this
+//! crate to index into (`animation.rs`'s only `TextureAtlas` is the
+//! player's own movement sheet, not a UI icon set), so a text label is the
+//! honest stand-in. Likewise there's no "detected gamepad brand": Bevy
+//! 0.13's `GamepadButtonType` is already brand-normalized to semantic
+//! positions (`North`/`South`/...) rather than a physical vendor's glyphs,
+//! and `Gamepad`'s own info exposes nothing more specific than a driver
+//! name string, so every gamepad gets one generic label set rather than a
+//! PlayStation/Xbox split that has nothing real underneath it.
+//!
+//! [`LastUsedDevice`] is the "most-recently-used device" this switches on,
+//! flipped by [`track_last_used_device`] the instant either input source is
+//! pressed. [`sync_prompt_text`] then rewrites every [`PromptText`]
+//! entity's `Text` in place every frame — cheap enough (there are only ever
+//! a couple of these on screen) that gating it behind change detection
+//! like `accessibility::sync_palette_colors` does isn't worth the added
+//! complexity — so a prompt's label updates live without the *text* ever
+//! needing a rebuild. That benefit is only visible on `level_select`'s
+//! confirm footer, spawned once and left alone by the row list that
+//! rebuilds around it; `interact::update_prompt` already despawns and
+//! respawns its own prompt entity every frame regardless of anything this
+//! module does, a pre-existing habit of that system unrelated to device
+//! switching.
+//!
+//! Wired into `interact::update_prompt`'s floating "who to interact with"
+//! hint and `level_select`'s confirm-row footer, the only two places in
+//! this crate with a real onscreen prompt tied to a key. `dialogue.rs`'s
+//! `DialogueBox` has nowhere to wire into at all — see its own doc comment
+//! on there being no rendered dialogue text yet, page or prompt alike — and
+//! `results.rs`'s confirm handler doesn't read gamepad input to begin with
+//! (unlike `level_select`'s), so it's left alone rather than showing a
+//! gamepad hint for a button press that wouldn't actually confirm there.
+
+use bevy::prelude::*;
+
+/// One of the handful of actions this crate has a real onscreen prompt for.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum PromptAction {
+    Interact,
+    Confirm,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+pub enum InputDevice {
+    #[default]
+    Keyboard,
+    Gamepad,
+}
+
+/// The input device most recently seen pressing anything, kept up to date
+/// by [`track_last_used_device`].
+#[derive(Resource, Default)]
+pub struct LastUsedDevice(pub InputDevice);
+
+/// See this module's own doc comment on why these are text labels rather
+/// than icon atlas indices.
+#[derive(Resource, Default)]
+pub struct InputIcons;
+
+impl InputIcons {
+    pub fn label(&self, action: PromptAction, device: InputDevice) -> &'static str {
+        match (action, device) {
+            (PromptAction::Interact, InputDevice::Keyboard) => "[E]",
+            (PromptAction::Interact, InputDevice::Gamepad) => "[Y]",
+            (PromptAction::Confirm, InputDevice::Keyboard) => "[E/Space]",
+            (PromptAction::Confirm, InputDevice::Gamepad) => "[A]",
+        }
+    }
+}
+
+/// Marks a `Text` entity whose first section [`sync_prompt_text`] keeps in
+/// sync with [`LastUsedDevice`]. `verb: None` renders just the icon (small
+/// floating hints like the interact prompt); `Some(verb)` renders the full
+/// "Press {icon} to {verb}" sentence (menu footers with room for one).
+#[derive(Component)]
+pub struct PromptText {
+    pub action: PromptAction,
+    pub verb: Option<&'static str>,
+}
+
+pub struct PromptPlugin;
+
+impl Plugin for PromptPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LastUsedDevice>()
+            .init_resource::<InputIcons>()
+            .add_systems(Update, (track_last_used_device, sync_prompt_text).chain());
+    }
+}
+
+fn track_last_used_device(
+    keys: Res<ButtonInput<KeyCode>>,
+    gamepad_buttons: Res<ButtonInput<GamepadButton>>,
+    mut device: ResMut<LastUsedDevice>,
+) {
+    if keys.get_just_pressed().next().is_some() {
+        if device.0 != InputDevice::Keyboard {
+            device.0 = InputDevice::Keyboard;
+        }
+    } else if gamepad_buttons.get_just_pressed().next().is_some() {
+        if device.0 != InputDevice::Gamepad {
+            device.0 = InputDevice::Gamepad;
+        }
+    }
+}
+
+fn sync_prompt_text(icons: Res<InputIcons>, device: Res<LastUsedDevice>, mut prompts: Query<(&PromptText, &mut Text)>) {
+    for (prompt, mut text) in &mut prompts {
+        let icon = icons.label(prompt.action, device.0);
+        text.sections[0].value = match prompt.verb {
+            Some(verb) => format!("Press {icon} to {verb}"),
+            None => icon.to_string(),
+        };
+    }
+}
@@ -0,0 +1,94 @@
+//! Pushable blocks: nudged by the player, fall under gravity, and can rest
+//! on the floor or on top of each other.
+
+use bevy::prelude::*;
+
+use crate::collision::HitBox;
+use crate::player::{Grounded, Player, Velocity};
+
+const MOVE_SPEED: f32 = 180.0;
+const FALL_SPEED: f32 = 420.0;
+
+#[derive(Component)]
+pub struct Pushable {
+    pub weight: f32,
+}
+
+pub struct PushablePlugin;
+
+impl Plugin for PushablePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (push_blocks, fall_blocks)
+                .chain()
+                .after(crate::schedule::PlatformerSet::CollisionResolve),
+        );
+    }
+}
+
+fn push_blocks(
+    time: Res<Time>,
+    player: Query<(&Transform, &HitBox, &Velocity), (With<Player>, With<Grounded>)>,
+    mut blocks: Query<(&mut Transform, &HitBox, &Pushable), Without<Player>>,
+    solids: Query<(&Transform, &HitBox), (Without<Player>, Without<Pushable>)>,
+) {
+    let Ok((player_transform, player_box, velocity)) = player.get_single() else {
+        return;
+    };
+    if velocity.0.x == 0.0 {
+        return;
+    }
+
+    for (mut block_transform, block_box, pushable) in &mut blocks {
+        let delta = block_transform.translation.truncate() - player_transform.translation.truncate();
+        let touching_x = delta.x.signum() == velocity.0.x.signum()
+            && delta.x.abs() < (player_box.size.x + block_box.size.x) / 2.0 + 2.0;
+        let touching_y = delta.y.abs() < (player_box.size.y + block_box.size.y) / 2.0;
+        if !touching_x || !touching_y {
+            continue;
+        }
+
+        let step = velocity.0.x.signum() * MOVE_SPEED / pushable.weight.max(0.1) * time.delta_seconds();
+        let next_x = block_transform.translation.x + step;
+        let blocked = solids.iter().any(|(solid_transform, solid_box)| {
+            (next_x - solid_transform.translation.x).abs()
+                < (block_box.size.x + solid_box.size.x) / 2.0
+                && (block_transform.translation.y - solid_transform.translation.y).abs()
+                    < (block_box.size.y + solid_box.size.y) / 2.0
+        });
+        if !blocked {
+            block_transform.translation.x = next_x;
+        }
+    }
+}
+
+fn fall_blocks(
+    time: Res<Time>,
+    mut blocks: Query<(Entity, &mut Transform, &HitBox), With<Pushable>>,
+    solids: Query<(&Transform, &HitBox), Without<Pushable>>,
+) {
+    // Other blocks support too, at the positions they held before this pass.
+    let block_supports: Vec<(Entity, Vec3, Vec2)> =
+        blocks.iter().map(|(entity, transform, hit_box)| (entity, transform.translation, hit_box.size)).collect();
+    for (entity, mut block_transform, block_box) in &mut blocks {
+        let next_y = block_transform.translation.y - FALL_SPEED * time.delta_seconds();
+        let block_bottom = block_transform.translation.y - block_box.size.y / 2.0;
+        let mut resting_y = next_y;
+        let mut supported = false;
+        let supports = solids.iter().map(|(transform, hit_box)| (transform.translation, hit_box.size)).chain(
+            block_supports.iter().filter(|(other, ..)| *other != entity).map(|&(_, translation, size)| (translation, size)),
+        );
+        for (solid_translation, solid_size) in supports {
+            let overlapping_x = (block_transform.translation.x - solid_translation.x).abs()
+                < (block_box.size.x + solid_size.x) / 2.0;
+            let solid_top = solid_translation.y + solid_size.y / 2.0;
+            // Only something at or below the block's bottom can hold it up.
+            if overlapping_x && solid_top <= block_bottom + 0.01 && next_y - block_box.size.y / 2.0 <= solid_top {
+                resting_y = resting_y.max(solid_top + block_box.size.y / 2.0);
+                supported = true;
+            }
+        }
+        block_transform.translation.y = if supported { resting_y } else { next_y };
+    }
+}
@@ -0,0 +1,59 @@
+//! Shared z-ordering conventions, so paint order stops depending on spawn
+//! order.
+//!
+//! Bevy's default 2D pipeline paints higher `Transform::translation.z` on
+//! top of lower z, within the same 2D camera. Before this, every spawn site
+//! (`map::spawn_map_entities`, `player::spawn_player`, ...) wrote its own
+//! literal z (`0.0`, `1.0`, ...) with no shared meaning, so a coin prefab
+//! spawned after a platform could end up in front of or behind it purely by
+//! accident of spawn order.
+//!
+//! [`GameLayer`] gives every kind of thing a named, ordered slot; [`z_for`]
+//! turns a layer (and, optionally, a world-space y for pseudo-depth) into a
+//! concrete z. Layers are spaced [`LAYER_STEP`] apart so y-sorting within a
+//! layer can never spill into a neighboring one.
+//!
+//! `weather`'s particles draw on [`GameLayer::Particles`], above
+//! `Entities`, `Player` and `TilesFront`.
+
+/// Spacing between adjacent layers' base z. Large enough that
+/// [`z_for`]'s y-sort term, bounded by [`Y_SORT_SCALE`], never reaches the
+/// next layer's base for any y this crate's levels actually use.
+pub const LAYER_STEP: f32 = 100.0;
+
+/// How much one world-space unit of y shifts z when sorting within a layer.
+/// `map::spawn_map_entities`'s demo layout only spans a few hundred units of
+/// y, so even at the widest plausible level (tens of thousands of units)
+/// this keeps the sorted z within `LAYER_STEP / 2` of the layer's base.
+const Y_SORT_SCALE: f32 = 0.001;
+
+/// Named paint-order slots, back to front. Foreground tiles
+/// ([`GameLayer::TilesFront`]) sit above [`GameLayer::Player`], so a level
+/// can place tiles that occlude the player (a tree canopy, a doorway
+/// frame) just by tagging that `TileLayer` with this layer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum GameLayer {
+    ParallaxFar,
+    ParallaxNear,
+    TilesBack,
+    Entities,
+    Player,
+    TilesFront,
+    Particles,
+    Ui,
+}
+
+/// The z to give a sprite/mesh in `layer`. When `y_sort` is true, entities
+/// lower on screen (smaller y) paint in front of ones higher up within the
+/// same layer, approximating depth without a full isometric sort — the
+/// convention `map::spawn_map_entities` uses for its gameplay entities so a
+/// sign standing in front of a taller platform still looks right regardless
+/// of spawn order.
+pub fn z_for(layer: GameLayer, y: f32, y_sort: bool) -> f32 {
+    let base = layer as u32 as f32 * LAYER_STEP;
+    if y_sort {
+        base - y * Y_SORT_SCALE
+    } else {
+        base
+    }
+}
@@ -0,0 +1,351 @@
+//! Results screen: shown the moment [`level_select::LevelCompleted`]
+//! fires (this crate's stand-in for "GoalReached" — see that module's own
+//! doc comment), tallying the attempt's [`LevelStats`] with each line
+//! counting up from zero, and three buttons — Retry, Next Level, Level
+//! Select — navigated the same way `shop`'s menu is.
+//!
+//! What's real: [`LevelStats`] resets on `level_reload::LevelReloadRequested`
+//! (this crate's only "restart" signal — there's no separate "level start"
+//! event to reset on instead), counts deaths off `death::PlayerDied` and
+//! kills off the new `combo::EnemyDefeated` (added alongside this so there
+//! was something to count), and the tick-up counters use
+//! `math::move_toward` at a rate that lands exactly on the true value after
+//! [`COUNT_UP_SECONDS`]. [`LevelStats::death_positions`] is this module's
+//! own counter too (`difficulty_assist` is the only reader; nothing here
+//! displays it), and the screen shows an "Assist active" line whenever
+//! `difficulty_assist::DifficultyAssist::accepted` was set the moment the
+//! run finished — see that module's own doc comment for what accepting
+//! changes and why it disables medal eligibility. Coin bests get recorded into
+//! `level_select::LevelCoinBest` (persisted `SaveData`, owned by that module
+//! since its row text is what reads it back) the moment a level completes.
+//!
+//! What isn't: coins collected is always `0`/`0` today — there's no
+//! `CollectedEvent` anywhere in this crate (`objectives`'s own doc comment
+//! already covers that gap), so [`LevelStats::coins_collected`] can never
+//! move and `coins_total` is whatever `Objectives::CollectCoins` sums to,
+//! which is zero for the built-in demo map. "Time vs best" only has
+//! something to show when the completed attempt was a `challenge` run —
+//! outside challenge mode nothing times a level at all, so this reads
+//! `challenge::ChallengeBest` and shows nothing when it's empty rather
+//! than fabricating a timer. There's no tick-up "ding" sound; this
+//! screen plays no sound at all. And "Next Level" has nowhere to go: this crate only ever loads
+//! the one demo map (see `LaunchOptions::level`'s own note), so it falls
+//! back to opening Level Select exactly like that button does, logged as
+//! such rather than pretending to advance.
+
+use bevy::prelude::*;
+
+use crate::challenge::ChallengeBest;
+use crate::combo::EnemyDefeated;
+use crate::death::PlayerDied;
+use crate::difficulty_assist::DifficultyAssist;
+use crate::event_log;
+use crate::level_reload::LevelReloadRequested;
+use crate::level_select::{LevelCoinBest, LevelCompleted, LevelSelectCursor};
+use crate::objectives::{ObjectiveKind, Objectives};
+use crate::schedule::PlatformerSet;
+
+/// How long each stat line's counter takes to reach its true value.
+const COUNT_UP_SECONDS: f32 = 0.6;
+
+const OPTIONS: [&str; 3] = ["Retry", "Next Level", "Level Select"];
+
+/// This attempt's tally, reset whenever the level restarts. `pub` fields,
+/// mirroring `death::LastCheckpoint`, since this is plain observed state
+/// with no invariant beyond "only this module's own systems increment it".
+#[derive(Resource, Default)]
+pub struct LevelStats {
+    pub deaths: u32,
+    pub enemies_defeated: u32,
+    pub coins_collected: u32,
+    pub coins_total: u32,
+    /// Where each death this attempt happened, oldest first —
+    /// `difficulty_assist::detect_death_clustering` is the reason this
+    /// exists; nothing else in this module reads it.
+    pub death_positions: Vec<Vec2>,
+}
+
+impl LevelStats {
+    fn reset(&mut self, coins_total: u32) {
+        *self = Self {
+            coins_total,
+            ..Default::default()
+        };
+    }
+}
+
+#[derive(States, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ResultsScreen {
+    #[default]
+    Closed,
+    Open,
+}
+
+/// Which button is highlighted. Reset to `0` every time the screen opens,
+/// mirroring `shop::ShopSelection`.
+#[derive(Resource, Default)]
+struct ResultsSelection(usize);
+
+/// A single tallied line's live (counting-up) and true value.
+struct CountingStat {
+    displayed: f32,
+    target: f32,
+}
+
+impl CountingStat {
+    fn new(target: u32) -> Self {
+        Self {
+            displayed: 0.0,
+            target: target as f32,
+        }
+    }
+
+    fn tick(&mut self, dt: f32) {
+        let max_delta = (self.target / COUNT_UP_SECONDS).max(1.0) * dt;
+        self.displayed = crate::math::move_toward(self.displayed, self.target, max_delta);
+    }
+}
+
+/// The frozen snapshot the results screen counts up toward, taken the
+/// moment it opens so later deaths (there shouldn't be any — the player
+/// can't act while this is open) can't retroactively change what's shown.
+#[derive(Resource)]
+struct ResultsSnapshot {
+    deaths: CountingStat,
+    enemies_defeated: CountingStat,
+    coins_collected: CountingStat,
+    coins_total: u32,
+    /// `Some` only when this attempt was a `challenge` run — see this
+    /// module's own doc comment on why outside challenge mode nothing
+    /// times a level at all.
+    time: Option<f32>,
+    best_time: Option<f32>,
+    /// `difficulty_assist::DifficultyAssist::accepted` at the moment this
+    /// attempt finished, so a later toggle can't retroactively "clean up"
+    /// what was actually an assisted clear.
+    assist_active: bool,
+}
+
+#[derive(Component)]
+struct ResultsBox;
+
+/// `pub(crate)` rather than private: `testing::TestWorld` needs the marker
+/// to query the results screen's rendered rows for `results_row_count`.
+#[derive(Component)]
+pub(crate) struct ResultsText;
+
+pub struct ResultsPlugin;
+
+impl Plugin for ResultsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_state::<ResultsScreen>()
+            .init_resource::<LevelStats>()
+            .init_resource::<ResultsSelection>()
+            .add_systems(
+                Update,
+                (count_deaths, count_enemies_defeated, reset_stats_on_reload).in_set(PlatformerSet::PostPhysics),
+            )
+            .add_systems(Update, open_results_on_level_completed.in_set(PlatformerSet::PostPhysics))
+            .add_systems(
+                Update,
+                (navigate_results, select_result_option, tick_counters, update_results_text)
+                    .chain()
+                    .run_if(in_state(ResultsScreen::Open)),
+            );
+    }
+}
+
+fn count_deaths(mut died: EventReader<PlayerDied>, mut stats: ResMut<LevelStats>) {
+    for event in died.read() {
+        stats.deaths += 1;
+        stats.death_positions.push(event.position);
+    }
+}
+
+fn count_enemies_defeated(mut defeated: EventReader<EnemyDefeated>, mut stats: ResMut<LevelStats>) {
+    stats.enemies_defeated += defeated.read().count() as u32;
+}
+
+fn reset_stats_on_reload(
+    mut events: EventReader<LevelReloadRequested>,
+    objectives: Res<Objectives>,
+    mut stats: ResMut<LevelStats>,
+) {
+    if events.read().next().is_none() {
+        return;
+    }
+    stats.reset(coins_total(&objectives));
+}
+
+fn coins_total(objectives: &Objectives) -> u32 {
+    objectives
+        .0
+        .iter()
+        .map(|objective| match objective.kind {
+            ObjectiveKind::CollectCoins(total) => total,
+            _ => 0,
+        })
+        .sum()
+}
+
+/// Both `level_select::complete_current_level` and `challenge::finish_on_exit`
+/// detect the same "reached the unlocked exit" moment, so a `ChallengeFinished`
+/// this same frame is this attempt's own time — not just whatever
+/// `ChallengeBest` happens to hold, which could be stale (no run active) or
+/// from a different, earlier attempt.
+fn open_results_on_level_completed(
+    mut commands: Commands,
+    mut completed: EventReader<LevelCompleted>,
+    mut challenge_finished: EventReader<crate::challenge::ChallengeFinished>,
+    mut selection: ResMut<ResultsSelection>,
+    mut coin_best: ResMut<LevelCoinBest>,
+    stats: Res<LevelStats>,
+    best: Res<ChallengeBest>,
+    assist: Res<DifficultyAssist>,
+    mut next_state: ResMut<NextState<ResultsScreen>>,
+) {
+    let Some(event) = completed.read().last() else {
+        return;
+    };
+    let this_run = challenge_finished.read().last();
+    selection.0 = 0;
+
+    coin_best.record_and_persist(event.id, stats.coins_collected);
+
+    commands.insert_resource(ResultsSnapshot {
+        deaths: CountingStat::new(stats.deaths),
+        enemies_defeated: CountingStat::new(stats.enemies_defeated),
+        coins_collected: CountingStat::new(stats.coins_collected),
+        coins_total: stats.coins_total,
+        time: this_run.map(|finished| finished.time),
+        best_time: best.time,
+        assist_active: assist.accepted,
+    });
+    commands.spawn((
+        ResultsBox,
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                top: Val::Percent(25.0),
+                left: Val::Percent(35.0),
+                ..default()
+            },
+            ..default()
+        },
+    ));
+    next_state.set(ResultsScreen::Open);
+}
+
+fn navigate_results(keys: Res<ButtonInput<KeyCode>>, mut selection: ResMut<ResultsSelection>) {
+    if keys.just_pressed(KeyCode::ArrowUp) {
+        selection.0 = selection.0.checked_sub(1).unwrap_or(OPTIONS.len() - 1);
+    }
+    if keys.just_pressed(KeyCode::ArrowDown) {
+        selection.0 = (selection.0 + 1) % OPTIONS.len();
+    }
+}
+
+fn select_result_option(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    selection: Res<ResultsSelection>,
+    boxes: Query<Entity, With<ResultsBox>>,
+    cursor: ResMut<LevelSelectCursor>,
+    level_select_state: ResMut<NextState<crate::level_select::LevelSelect>>,
+    mut results_state: ResMut<NextState<ResultsScreen>>,
+    mut reload: EventWriter<LevelReloadRequested>,
+) {
+    if !keys.just_pressed(KeyCode::KeyE) && !keys.just_pressed(KeyCode::Space) {
+        return;
+    }
+    for entity in &boxes {
+        commands.entity(entity).despawn_recursive();
+    }
+    commands.remove_resource::<ResultsSnapshot>();
+    results_state.set(ResultsScreen::Closed);
+
+    match OPTIONS[selection.0] {
+        "Retry" => {
+            event_log::record("results: retry");
+            reload.send(LevelReloadRequested);
+        }
+        "Next Level" => {
+            // There's no second level to advance to — see this module's
+            // own doc comment.
+            event_log::record("results: next level (falling back to level select)");
+            crate::level_select::open(commands, cursor, level_select_state);
+        }
+        _ => {
+            event_log::record("results: level select");
+            crate::level_select::open(commands, cursor, level_select_state);
+        }
+    }
+}
+
+fn tick_counters(time: Res<Time>, snapshot: Option<ResMut<ResultsSnapshot>>) {
+    let Some(mut snapshot) = snapshot else {
+        return;
+    };
+    let dt = time.delta_seconds();
+    snapshot.deaths.tick(dt);
+    snapshot.enemies_defeated.tick(dt);
+    snapshot.coins_collected.tick(dt);
+}
+
+fn update_results_text(
+    mut commands: Commands,
+    snapshot: Option<Res<ResultsSnapshot>>,
+    selection: Res<ResultsSelection>,
+    results_box: Query<Entity, With<ResultsBox>>,
+    mut text: Query<&mut Text, With<ResultsText>>,
+) {
+    let Some(snapshot) = snapshot else {
+        return;
+    };
+    let Ok(results_box) = results_box.get_single() else {
+        return;
+    };
+
+    let time_line = match (snapshot.time, snapshot.best_time) {
+        (Some(time), Some(best)) => format!("Time: {time:.1}s (best {best:.1}s)\n"),
+        _ => String::new(),
+    };
+    let assist_line = if snapshot.assist_active {
+        "Assist active (medal ineligible)\n".to_string()
+    } else {
+        String::new()
+    };
+
+    let mut sections = vec![
+        TextSection::new(assist_line, TextStyle::default()),
+        TextSection::new(time_line, TextStyle::default()),
+        TextSection::new(
+            format!("Deaths: {}\n", snapshot.deaths.displayed.round() as u32),
+            TextStyle::default(),
+        ),
+        TextSection::new(
+            format!("Enemies defeated: {}\n", snapshot.enemies_defeated.displayed.round() as u32),
+            TextStyle::default(),
+        ),
+        TextSection::new(
+            format!(
+                "Coins: {}/{}\n",
+                snapshot.coins_collected.displayed.round() as u32,
+                snapshot.coins_total
+            ),
+            TextStyle::default(),
+        ),
+    ];
+    sections.extend(OPTIONS.iter().enumerate().map(|(index, label)| {
+        let cursor = if index == selection.0 { "> " } else { "  " };
+        TextSection::new(format!("{cursor}{label}\n"), TextStyle::default())
+    }));
+
+    if let Ok(mut text) = text.get_single_mut() {
+        text.sections = sections;
+    } else {
+        commands.entity(results_box).with_children(|parent| {
+            parent.spawn((ResultsText, TextBundle::from_sections(sections)));
+        });
+    }
+}
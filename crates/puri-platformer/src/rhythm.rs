@@ -0,0 +1,145 @@
+//! Hazards that fire on the beat of the current `music::MusicTrack`.
+//!
+//! [`MusicClock`] tracks beats elapsed since boot from `MusicTrack::bpm`,
+//! driven by `time_scale::GameTime` rather than `Res<Time>` directly — see
+//! `time_scale`'s own doc comment on why that's this crate's whole answer
+//! to "pause". Ticking off `GameTime::delta_seconds` means `TimeScale(0.0)`
+//! freezes [`MusicClock`] exactly where it was and resumes it from there
+//! for free, the same way it already keeps `starman::Starman`'s countdown
+//! from drifting across a pause — no extra bookkeeping needed to keep a
+//! hazard's phase aligned with the music on resume.
+//!
+//! [`BeatSynced`] marks a hazard with an on/off `pattern`, one step per
+//! beat; [`sync_beat_hazards`] adds [`BeatActive`] on the beats
+//! [`BeatSynced::pattern`] marks `true` and removes it otherwise, and adds
+//! [`BeatTelegraph`] for the one beat immediately before — the request's
+//! "short telegraph one beat earlier". `phase` offsets which step of
+//! `pattern` lines up with beat zero, so several hazards can share one
+//! `pattern` and still fire out of sync with each other.
+//!
+//! There's no damage-on-touch system for any hazard in this crate yet
+//! (`prefab::Spike`'s own doc comment already covers that gap, and a
+//! [`Crusher`] is no different), so [`BeatActive`]/[`BeatTelegraph`] don't
+//! drive anything visual on the hazard itself either — there's no real art
+//! to swap (same gap `prefab`'s own doc comment names). The "beat grid" the
+//! request asks for lives in `debug_overlay` instead, reading
+//! [`MusicClock`] directly.
+
+use bevy::prelude::*;
+
+use crate::music::MusicTrack;
+use crate::schedule::PlatformerSet;
+use crate::time_scale::GameTime;
+
+/// How many beats `debug_overlay`'s metronome grid draws per bar. Not
+/// authored per-track — every `MusicTrack` in this crate is 4/4.
+pub const BEATS_PER_BAR: usize = 4;
+
+/// Beats elapsed since boot (counted only while `MusicTrack::bpm` is
+/// non-zero; never reset when the track changes), as a fractional
+/// count so [`beat_phase`](Self::beat_phase) can read how far through the
+/// current beat playback is.
+#[derive(Resource, Default)]
+pub struct MusicClock {
+    elapsed_beats: f32,
+}
+
+impl MusicClock {
+    pub fn beat(&self) -> usize {
+        self.elapsed_beats.floor() as usize
+    }
+
+    /// `0.0` at the start of the current beat, approaching `1.0` at its end.
+    pub fn beat_phase(&self) -> f32 {
+        self.elapsed_beats.fract()
+    }
+
+    pub fn bar(&self) -> usize {
+        self.beat() / BEATS_PER_BAR
+    }
+}
+
+/// One step in `pattern` is "on" for a whole beat. `phase` is added to the
+/// current beat, mod `pattern.len()`, before indexing.
+#[derive(Component, Reflect, Clone, Default)]
+#[reflect(Component)]
+pub struct BeatSynced {
+    pub pattern: Vec<bool>,
+    pub phase: usize,
+}
+
+impl BeatSynced {
+    fn step(&self, beat: usize) -> bool {
+        if self.pattern.is_empty() {
+            return false;
+        }
+        self.pattern[(beat + self.phase) % self.pattern.len()]
+    }
+}
+
+/// Present on a [`BeatSynced`] entity exactly on the beats its `pattern`
+/// marks `true`. See this module's own doc comment on why nothing reads
+/// this yet beyond [`sync_beat_hazards`] itself.
+#[derive(Component)]
+pub struct BeatActive;
+
+/// Present for the one beat immediately before [`BeatActive`] — the
+/// request's "short telegraph one beat earlier".
+#[derive(Component)]
+pub struct BeatTelegraph;
+
+/// Marks a crushing hazard prefab (`assets/prefabs/crusher.ron`). Pairs
+/// with [`BeatSynced`] to fire on a pattern; carries no fields of its own,
+/// the same minimal-marker shape as `prefab::Spike`.
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+pub struct Crusher;
+
+pub struct RhythmPlugin;
+
+impl Plugin for RhythmPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<BeatSynced>()
+            .register_type::<Crusher>()
+            .init_resource::<MusicClock>()
+            .add_systems(Update, (tick_music_clock, sync_beat_hazards).chain().in_set(PlatformerSet::PostPhysics));
+    }
+}
+
+fn tick_music_clock(time: GameTime, track: Res<MusicTrack>, mut clock: ResMut<MusicClock>) {
+    if track.bpm <= 0.0 {
+        return;
+    }
+    clock.elapsed_beats += time.delta_seconds() * track.bpm / 60.0;
+}
+
+fn sync_beat_hazards(
+    clock: Res<MusicClock>,
+    mut commands: Commands,
+    hazards: Query<(Entity, &BeatSynced, Option<&BeatActive>, Option<&BeatTelegraph>)>,
+) {
+    let beat = clock.beat();
+    for (entity, synced, active, telegraph) in &hazards {
+        let is_active = synced.step(beat);
+        let is_telegraph = !is_active && synced.step(beat + 1);
+
+        match (is_active, active.is_some()) {
+            (true, false) => {
+                commands.entity(entity).insert(BeatActive);
+            }
+            (false, true) => {
+                commands.entity(entity).remove::<BeatActive>();
+            }
+            _ => {}
+        }
+        match (is_telegraph, telegraph.is_some()) {
+            (true, false) => {
+                commands.entity(entity).insert(BeatTelegraph);
+            }
+            (false, true) => {
+                commands.entity(entity).remove::<BeatTelegraph>();
+            }
+            _ => {}
+        }
+    }
+}
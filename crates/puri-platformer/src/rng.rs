@@ -0,0 +1,86 @@
+//! Deterministic RNG for anything gameplay-visible — procedural chunks,
+//! particle spread, enemy behavior. `rand::thread_rng()` (or any wall-clock
+//! seeded source) must never be used for these: it would make replays and
+//! `TestWorld` runs non-reproducible. By convention, gameplay systems pull
+//! randomness from the [`GameRng`] resource, not `rand` directly.
+//!
+//! No replay recorder/player exists in this codebase yet (see
+//! [`crate::launch_options::LaunchOptions::record`]/`replay`), but the
+//! contract they'll need is already true today: the seed lives on
+//! `LaunchOptions`, so a recorder just has to persist that same value
+//! alongside its input script, and a player restores it the same way
+//! `RngPlugin` does at startup.
+
+use std::time::SystemTime;
+
+use bevy::prelude::*;
+
+use crate::launch_options::LaunchOptions;
+
+/// A small PCG32 generator. Not cryptographically secure, chosen for being
+/// tiny, dependency-free, and reproducible bit-for-bit across platforms
+/// given the same seed — all that gameplay determinism actually needs.
+#[derive(Resource, Clone)]
+pub struct GameRng {
+    state: u64,
+    inc: u64,
+}
+
+const MULTIPLIER: u64 = 6364136223846793005;
+
+impl GameRng {
+    pub fn from_seed(seed: u64) -> Self {
+        let mut rng = Self { state: 0, inc: (seed << 1) | 1 };
+        rng.next_u32();
+        rng.state = rng.state.wrapping_add(seed);
+        rng.next_u32();
+        rng
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        let old_state = self.state;
+        self.state = old_state.wrapping_mul(MULTIPLIER).wrapping_add(self.inc);
+        let xorshifted = (((old_state >> 18) ^ old_state) >> 27) as u32;
+        let rot = (old_state >> 59) as u32;
+        xorshifted.rotate_right(rot)
+    }
+
+    /// A uniform value in `[min, max)`.
+    pub fn range_f32(&mut self, min: f32, max: f32) -> f32 {
+        // The top 24 bits fit an f32 exactly, so `t` never rounds up to 1.0.
+        let t = (self.next_u32() >> 8) as f32 / (1u32 << 24) as f32;
+        min + t * (max - min)
+    }
+
+    /// `true` with probability `probability`, clamped to `[0.0, 1.0]`.
+    pub fn chance(&mut self, probability: f32) -> bool {
+        self.range_f32(0.0, 1.0) < probability.clamp(0.0, 1.0)
+    }
+
+    /// A uniformly random element of `slice`, or `None` if it's empty.
+    pub fn pick_slice<'a, T>(&mut self, slice: &'a [T]) -> Option<&'a T> {
+        if slice.is_empty() {
+            return None;
+        }
+        let index = (self.next_u32() as usize) % slice.len();
+        slice.get(index)
+    }
+}
+
+pub struct RngPlugin;
+
+impl Plugin for RngPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, seed_rng);
+    }
+}
+
+fn seed_rng(mut commands: Commands, options: Option<Res<LaunchOptions>>) {
+    let seed = options.as_ref().and_then(|options| options.seed).unwrap_or_else(|| {
+        SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map(|duration| duration.as_nanos() as u64)
+            .unwrap_or_default()
+    });
+    commands.insert_resource(GameRng::from_seed(seed));
+}
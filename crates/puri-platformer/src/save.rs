@@ -0,0 +1,605 @@
+//! Three save slots and a profile select screen shown before gameplay
+//! starts, with create/copy/delete and a delete confirmation dialog.
+//!
+//! Several pieces of the original ask have nowhere real to attach to yet —
+//! the same kind of gap `level_select`'s and `shop`'s own doc comments
+//! already document for this crate:
+//! - There's no main menu anywhere in this crate (`main.rs` drops straight
+//!   into gameplay — see `level_select`'s own note on the same gap), so
+//!   "before the main menu" becomes "before anything else": [`ProfileSelect`]
+//!   defaults to `Open`, and Bevy fires `OnEnter` for a state's default
+//!   variant on the very first frame, so [`spawn_profile_select_box`] spawns
+//!   its box right away rather than waiting on a menu button that doesn't
+//!   exist.
+//! - There's no `GameState::Playing` (or any global app state) anywhere in
+//!   this crate to gate playtime accumulation on, so [`accumulate_playtime`]
+//!   uses [`ActiveSlot`] being `Some` as the honest stand-in — the same
+//!   substitution `weather`'s own doc comment makes for a "storm" concept
+//!   with nothing upstream driving it.
+//! - There's no pause or settings menu to reopen [`ProfileSelect`] once
+//!   gameplay is underway (`accessibility`'s own doc comment already covers
+//!   this gap and its fallback of one debug key per toggle), so the only
+//!   way "deleting the active slot mid-session" is reachable today is
+//!   through [`DeleteSlotRequested`] fired by this screen's own delete
+//!   confirmation — [`handle_delete_requests`] still does the real
+//!   "clear `ActiveSlot` and reopen `ProfileSelect`" work regardless of who
+//!   sends the event, so a future pause menu only needs to fire the same
+//!   event, not reimplement the cleanup.
+//!
+//! What's real: [`SaveSlotData::levels_cleared`] counts real
+//! `level_select::LevelCompleted` events for whichever slot is active.
+//! [`SaveSlotData::total_coins`] reads `results::LevelStats::coins_collected`
+//! the moment each of those events fires — real wiring, but per that
+//! module's own doc comment nothing in this crate's demo map increments
+//! `coins_collected` yet, so it stays `0` today, the same gap
+//! `achievements::AchievementId::TotalCoins` already documents.
+//! [`SaveSlotData::playtime_seconds`] is genuinely live, ticked by
+//! [`accumulate_playtime`] and flushed to disk every [`PLAYTIME_SAVE_SECONDS`]
+//! rather than every frame, to avoid writing a `.ron` file sixty times a
+//! second.
+//!
+//! Persistence is slot-aware paths (`assets/save_slot_1.ron` through
+//! `_3.ron`), one file per slot rather than one file holding all three, the
+//! same per-thing-persisted-separately shape `level_select`'s
+//! `persistence`/`coin_persistence` split already uses. This crate never
+//! shipped a single-file save format before this module existed, so there's
+//! no real `assets/save.ron` anywhere in this tree for [`migrate_legacy_save`]
+//! to find — it's written the shape a lone predecessor file would have had
+//! (a bare [`SaveSlotData`]) so a save left over from a hypothetical earlier
+//! build becomes slot 1 instead of silently vanishing, exactly like the ask
+//! wants, without this module needing a version flag to tell "no file" from
+//! "already migrated" apart.
+
+use bevy::prelude::*;
+
+use crate::event_log;
+use crate::level_select::LevelCompleted;
+use crate::results::LevelStats;
+
+pub const SLOT_COUNT: usize = 3;
+
+/// How often [`accumulate_playtime`] flushes the active slot to disk while
+/// playtime is ticking, rather than on every frame.
+const PLAYTIME_SAVE_SECONDS: f32 = 5.0;
+
+/// One save slot's progress summary. `Default` is the shape a freshly
+/// [`SaveManager::create`]d slot starts at.
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SaveSlotData {
+    pub levels_cleared: u32,
+    pub total_coins: u32,
+    pub playtime_seconds: f32,
+}
+
+/// Every save slot, indexed `0..SLOT_COUNT`; `None` means the slot has
+/// never been created (or was deleted). Displayed to the player as
+/// "Slot 1"/"Slot 2"/"Slot 3", one higher than the index used here.
+#[derive(Resource, Default)]
+pub struct SaveManager {
+    slots: [Option<SaveSlotData>; SLOT_COUNT],
+}
+
+impl SaveManager {
+    pub fn slot(&self, index: usize) -> Option<&SaveSlotData> {
+        self.slots.get(index).and_then(|slot| slot.as_ref())
+    }
+
+    fn slot_mut(&mut self, index: usize) -> Option<&mut SaveSlotData> {
+        self.slots.get_mut(index).and_then(|slot| slot.as_mut())
+    }
+
+    /// Creates `index` with fresh data if it doesn't already hold a save,
+    /// persisting the new slot. `pub(crate)` rather than private so
+    /// `testing::TestWorld::create_save_slot` can populate a slot without
+    /// going through the confirm key, which also activates it and closes
+    /// [`ProfileSelect`] — tests exercising copy/delete need the slot
+    /// filled while the screen stays open.
+    pub(crate) fn create(&mut self, index: usize) {
+        if index >= SLOT_COUNT || self.slots[index].is_some() {
+            return;
+        }
+        self.slots[index] = Some(SaveSlotData::default());
+        event_log::record(format!("save slot created index={}", index + 1));
+
+        #[cfg(feature = "serde")]
+        persistence::save_slot_to_disk(index, self.slots[index].as_ref().unwrap());
+    }
+
+    /// Overwrites `to` with a clone of `from`'s data. No-ops (returns
+    /// `false`) if `from` is empty or either index is out of range.
+    fn copy(&mut self, from: usize, to: usize) -> bool {
+        if from >= SLOT_COUNT || to >= SLOT_COUNT {
+            return false;
+        }
+        let Some(data) = self.slots[from] else {
+            return false;
+        };
+        self.slots[to] = Some(data);
+        event_log::record(format!("save slot copied from={} to={}", from + 1, to + 1));
+
+        #[cfg(feature = "serde")]
+        persistence::save_slot_to_disk(to, self.slots[to].as_ref().unwrap());
+
+        true
+    }
+
+    /// Clears `index` and removes its file. `pub(crate)` for the same
+    /// reason as [`create`](Self::create).
+    pub(crate) fn delete(&mut self, index: usize) {
+        if index >= SLOT_COUNT {
+            return;
+        }
+        self.slots[index] = None;
+        event_log::record(format!("save slot deleted index={}", index + 1));
+
+        #[cfg(feature = "serde")]
+        persistence::delete_slot_from_disk(index);
+    }
+}
+
+/// Which slot is currently being played, if any. `None` while
+/// [`ProfileSelect`] is open and no slot has been chosen yet.
+#[derive(Resource, Default)]
+pub struct ActiveSlot(pub Option<usize>);
+
+#[derive(States, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ProfileSelect {
+    #[default]
+    Open,
+    Closed,
+}
+
+/// Which row is highlighted while [`ProfileSelect`] is open. Reset to `0`
+/// every time it (re)opens, mirroring `level_select::LevelSelectCursor`.
+#[derive(Resource, Default)]
+struct ProfileSelectCursor(usize);
+
+/// The slot marked with a first press of copy, waiting for a second press
+/// on the destination row. `None` means nothing is pending.
+#[derive(Resource, Default)]
+struct CopySource(Option<usize>);
+
+const DELETE_CONFIRM_OPTIONS: [&str; 2] = ["No", "Yes"];
+
+#[derive(States, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+enum DeleteConfirm {
+    #[default]
+    Closed,
+    Open,
+}
+
+/// The slot [`open_delete_confirm`] is asking about, and which of
+/// [`DELETE_CONFIRM_OPTIONS`] is highlighted. Defaults to "No" (index `0`)
+/// every time the dialog opens, so an extra confirm press can't ever land
+/// on "Yes" by accident.
+#[derive(Resource, Default)]
+struct DeleteConfirmState {
+    target: usize,
+    selection: usize,
+}
+
+/// Fired the moment the delete confirmation dialog's "Yes" is chosen.
+/// `pub(crate)` so `testing::TestWorld` can fire one directly, standing in
+/// for the pause menu this crate doesn't have yet — see this module's own
+/// doc comment.
+#[derive(Event)]
+pub(crate) struct DeleteSlotRequested(pub usize);
+
+#[derive(Component)]
+struct ProfileSelectBox;
+
+/// `pub(crate)` rather than private: `testing::TestWorld::save_slot_row_text`
+/// queries this marker to read back a rendered row's text.
+#[derive(Component)]
+pub(crate) struct ProfileSelectText;
+
+#[derive(Component)]
+struct DeleteConfirmBox;
+
+#[derive(Component)]
+pub(crate) struct DeleteConfirmText;
+
+pub struct SaveManagerPlugin;
+
+impl Plugin for SaveManagerPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_state::<ProfileSelect>()
+            .init_state::<DeleteConfirm>()
+            .init_resource::<SaveManager>()
+            .init_resource::<ActiveSlot>()
+            .init_resource::<ProfileSelectCursor>()
+            .init_resource::<CopySource>()
+            .init_resource::<DeleteConfirmState>()
+            .add_event::<DeleteSlotRequested>()
+            .add_systems(
+                Update,
+                (track_slot_progress, accumulate_playtime).in_set(crate::schedule::PlatformerSet::PostPhysics),
+            )
+            .add_systems(Update, handle_delete_requests)
+            .add_systems(OnEnter(ProfileSelect::Open), spawn_profile_select_box)
+            .add_systems(OnEnter(DeleteConfirm::Open), open_delete_confirm)
+            .add_systems(
+                Update,
+                (navigate_profile_select, copy_selected, delete_selected, confirm_profile_select, update_profile_select_text)
+                    .chain()
+                    .run_if(in_state(ProfileSelect::Open))
+                    .run_if(in_state(DeleteConfirm::Closed)),
+            )
+            .add_systems(
+                Update,
+                (navigate_delete_confirm, confirm_delete_confirm, update_delete_confirm_text)
+                    .chain()
+                    .run_if(in_state(DeleteConfirm::Open)),
+            );
+
+        #[cfg(feature = "serde")]
+        app.add_systems(Startup, persistence::load_from_disk);
+    }
+}
+
+/// Spawned by `OnEnter(ProfileSelect::Open)` — which fires for the default
+/// state on the very first frame the same way `Startup` does (see
+/// `bevy_app::MainScheduleOrder`'s own doc comment on `StateTransition`
+/// running right after `Startup`), so this is both "opens before gameplay"
+/// and "reopens after `handle_delete_requests` clears the active slot" in
+/// one system rather than two — see this module's own doc comment on why
+/// there's no main menu for this to otherwise be "before".
+fn spawn_profile_select_box(mut commands: Commands, mut cursor: ResMut<ProfileSelectCursor>) {
+    cursor.0 = 0;
+    commands
+        .spawn((
+            ProfileSelectBox,
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    top: Val::Percent(20.0),
+                    left: Val::Percent(30.0),
+                    ..default()
+                },
+                ..default()
+            },
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                ProfileSelectText,
+                TextBundle::from_sections(Vec::<TextSection>::new()),
+            ));
+        });
+}
+
+fn navigate_profile_select(keys: Res<ButtonInput<KeyCode>>, mut cursor: ResMut<ProfileSelectCursor>) {
+    if keys.just_pressed(KeyCode::ArrowUp) {
+        cursor.0 = cursor.0.checked_sub(1).unwrap_or(SLOT_COUNT - 1);
+    }
+    if keys.just_pressed(KeyCode::ArrowDown) {
+        cursor.0 = (cursor.0 + 1) % SLOT_COUNT;
+    }
+}
+
+/// First press on a slot with data marks it as the copy source; a second
+/// press on a different row copies into it. Pressing the source row again
+/// cancels instead of copying a slot onto itself.
+fn copy_selected(
+    keys: Res<ButtonInput<KeyCode>>,
+    cursor: Res<ProfileSelectCursor>,
+    mut source: ResMut<CopySource>,
+    mut manager: ResMut<SaveManager>,
+) {
+    if !keys.just_pressed(KeyCode::KeyC) {
+        return;
+    }
+    match source.0 {
+        None => {
+            if manager.slot(cursor.0).is_some() {
+                source.0 = Some(cursor.0);
+            }
+        }
+        Some(from) if from == cursor.0 => source.0 = None,
+        Some(from) => {
+            manager.copy(from, cursor.0);
+            source.0 = None;
+        }
+    }
+}
+
+fn delete_selected(
+    keys: Res<ButtonInput<KeyCode>>,
+    cursor: Res<ProfileSelectCursor>,
+    manager: Res<SaveManager>,
+    mut confirm: ResMut<DeleteConfirmState>,
+    mut next_state: ResMut<NextState<DeleteConfirm>>,
+) {
+    if !keys.just_pressed(KeyCode::KeyX) {
+        return;
+    }
+    if manager.slot(cursor.0).is_none() {
+        return;
+    }
+    confirm.target = cursor.0;
+    confirm.selection = 0;
+    next_state.set(DeleteConfirm::Open);
+}
+
+/// Confirms the highlighted slot: creates it if empty, then either way
+/// activates it and closes [`ProfileSelect`].
+fn confirm_profile_select(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    gamepads: Res<Gamepads>,
+    gamepad_buttons: Res<ButtonInput<GamepadButton>>,
+    cursor: Res<ProfileSelectCursor>,
+    mut manager: ResMut<SaveManager>,
+    mut active: ResMut<ActiveSlot>,
+    boxes: Query<Entity, With<ProfileSelectBox>>,
+    mut next_state: ResMut<NextState<ProfileSelect>>,
+) {
+    let pressed_gamepad_confirm = gamepads
+        .iter()
+        .any(|pad| gamepad_buttons.just_pressed(GamepadButton::new(pad, GamepadButtonType::South)));
+    if !keys.just_pressed(KeyCode::KeyE) && !keys.just_pressed(KeyCode::Space) && !pressed_gamepad_confirm {
+        return;
+    }
+    manager.create(cursor.0);
+    active.0 = Some(cursor.0);
+    event_log::record(format!("save slot activated index={}", cursor.0 + 1));
+
+    for entity in &boxes {
+        commands.entity(entity).despawn_recursive();
+    }
+    next_state.set(ProfileSelect::Closed);
+}
+
+fn navigate_delete_confirm(keys: Res<ButtonInput<KeyCode>>, mut confirm: ResMut<DeleteConfirmState>) {
+    if keys.just_pressed(KeyCode::ArrowUp) || keys.just_pressed(KeyCode::ArrowDown) {
+        confirm.selection = (confirm.selection + 1) % DELETE_CONFIRM_OPTIONS.len();
+    }
+}
+
+fn confirm_delete_confirm(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    confirm: Res<DeleteConfirmState>,
+    boxes: Query<Entity, With<DeleteConfirmBox>>,
+    mut next_state: ResMut<NextState<DeleteConfirm>>,
+    mut requests: EventWriter<DeleteSlotRequested>,
+) {
+    if !keys.just_pressed(KeyCode::KeyE) && !keys.just_pressed(KeyCode::Space) {
+        return;
+    }
+    for entity in &boxes {
+        commands.entity(entity).despawn_recursive();
+    }
+    next_state.set(DeleteConfirm::Closed);
+
+    if DELETE_CONFIRM_OPTIONS[confirm.selection] == "Yes" {
+        requests.send(DeleteSlotRequested(confirm.target));
+    }
+}
+
+/// Deletes the requested slot and, if it was the active one, clears
+/// [`ActiveSlot`] and reopens [`ProfileSelect`] — the real "return to
+/// profile select cleanly" behavior the ask wants, regardless of what fired
+/// the event. See this module's own doc comment on why this dialog is the
+/// only real sender today.
+fn handle_delete_requests(
+    mut requests: EventReader<DeleteSlotRequested>,
+    mut manager: ResMut<SaveManager>,
+    mut active: ResMut<ActiveSlot>,
+    mut next_state: ResMut<NextState<ProfileSelect>>,
+) {
+    for event in requests.read() {
+        manager.delete(event.0);
+        if active.0 == Some(event.0) {
+            active.0 = None;
+            next_state.set(ProfileSelect::Open);
+        }
+    }
+}
+
+fn track_slot_progress(
+    mut completed: EventReader<LevelCompleted>,
+    stats: Res<LevelStats>,
+    active: Res<ActiveSlot>,
+    mut manager: ResMut<SaveManager>,
+) {
+    let count = completed.read().count() as u32;
+    if count == 0 {
+        return;
+    }
+    let Some(index) = active.0 else {
+        return;
+    };
+    let Some(data) = manager.slot_mut(index) else {
+        return;
+    };
+    data.levels_cleared += count;
+    data.total_coins += stats.coins_collected;
+
+    #[cfg(feature = "serde")]
+    persistence::save_slot_to_disk(index, data);
+}
+
+fn accumulate_playtime(
+    time: Res<Time>,
+    active: Res<ActiveSlot>,
+    mut manager: ResMut<SaveManager>,
+    mut save_timer: Local<Option<Timer>>,
+) {
+    let Some(index) = active.0 else {
+        return;
+    };
+    let Some(data) = manager.slot_mut(index) else {
+        return;
+    };
+    data.playtime_seconds += time.delta_seconds();
+
+    let timer = save_timer.get_or_insert_with(|| Timer::from_seconds(PLAYTIME_SAVE_SECONDS, TimerMode::Repeating));
+    timer.tick(time.delta());
+    if timer.finished() {
+        #[cfg(feature = "serde")]
+        persistence::save_slot_to_disk(index, data);
+    }
+}
+
+fn update_profile_select_text(
+    manager: Res<SaveManager>,
+    cursor: Res<ProfileSelectCursor>,
+    source: Res<CopySource>,
+    mut text: Query<&mut Text, With<ProfileSelectText>>,
+) {
+    let Ok(mut text) = text.get_single_mut() else {
+        return;
+    };
+
+    text.sections = (0..SLOT_COUNT)
+        .map(|index| {
+            let highlighted = index == cursor.0;
+            let cursor_marker = if highlighted { "> " } else { "  " };
+            let marked = if source.0 == Some(index) { " [copy source]" } else { "" };
+            let summary = match manager.slot(index) {
+                Some(data) => format!(
+                    "Slot {} - Levels: {}  Coins: {}  Playtime: {}m{:02}s{marked}",
+                    index + 1,
+                    data.levels_cleared,
+                    data.total_coins,
+                    data.playtime_seconds as u32 / 60,
+                    data.playtime_seconds as u32 % 60,
+                ),
+                None => format!("Slot {} - Empty", index + 1),
+            };
+            TextSection::new(
+                format!("{cursor_marker}{summary}\n"),
+                TextStyle {
+                    color: if highlighted { Color::WHITE } else { Color::GRAY },
+                    ..default()
+                },
+            )
+        })
+        .chain(std::iter::once(TextSection::new(
+            "\n[Confirm] play  [C] copy  [X] delete\n",
+            TextStyle::default(),
+        )))
+        .collect();
+}
+
+fn update_delete_confirm_text(
+    mut commands: Commands,
+    confirm: Res<DeleteConfirmState>,
+    box_query: Query<Entity, With<DeleteConfirmBox>>,
+    mut text: Query<&mut Text, With<DeleteConfirmText>>,
+) {
+    let sections: Vec<TextSection> = std::iter::once(TextSection::new(
+        format!("Delete slot {}?\n", confirm.target + 1),
+        TextStyle::default(),
+    ))
+    .chain(DELETE_CONFIRM_OPTIONS.iter().enumerate().map(|(index, label)| {
+        let cursor_marker = if index == confirm.selection { "> " } else { "  " };
+        TextSection::new(format!("{cursor_marker}{label}\n"), TextStyle::default())
+    }))
+    .collect();
+
+    if let Ok(mut text) = text.get_single_mut() {
+        text.sections = sections;
+    } else if let Ok(entity) = box_query.get_single() {
+        commands.entity(entity).with_children(|parent| {
+            parent.spawn((DeleteConfirmText, TextBundle::from_sections(sections)));
+        });
+    }
+}
+
+/// Spawned by [`delete_selected`]'s state transition rather than a system
+/// of its own — mirrors `results.rs`'s snapshot-on-open shape, just with
+/// nothing to snapshot beyond which slot is being asked about.
+fn open_delete_confirm(mut commands: Commands) {
+    commands.spawn((
+        DeleteConfirmBox,
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                top: Val::Percent(40.0),
+                left: Val::Percent(40.0),
+                ..default()
+            },
+            ..default()
+        },
+    ));
+}
+
+#[cfg(feature = "serde")]
+mod persistence {
+    use std::path::Path;
+
+    use bevy::prelude::*;
+
+    use super::{SaveManager, SaveSlotData, SLOT_COUNT};
+
+    fn slot_path(index: usize) -> String {
+        format!("assets/save_slot_{}.ron", index + 1)
+    }
+
+    /// The shape a single-file save from before this module existed would
+    /// have had — see this module's own doc comment on why there's no real
+    /// file in this shape anywhere in this tree today.
+    const LEGACY_PATH: &str = "assets/save.ron";
+
+    /// One-shot load of every `assets/save_slot_N.ron` present, then
+    /// [`migrate_legacy_save`] if slot 1 is still empty. Mirrors
+    /// `high_scores::persistence::load_from_disk`.
+    pub fn load_from_disk(mut manager: ResMut<SaveManager>) {
+        for index in 0..SLOT_COUNT {
+            let path = slot_path(index);
+            let Ok(contents) = std::fs::read_to_string(&path) else {
+                continue;
+            };
+            match ron::from_str::<SaveSlotData>(&contents) {
+                Ok(loaded) => manager.slots[index] = Some(loaded),
+                Err(err) => warn!("failed to parse {path}: {err}"),
+            }
+        }
+        migrate_legacy_save(&mut manager);
+    }
+
+    /// Loads `assets/save.ron` into slot 1 if slot 1 has no save of its own
+    /// yet — see this module's own doc comment on why that file never
+    /// actually exists in this tree, only in a hypothetical predecessor.
+    fn migrate_legacy_save(manager: &mut SaveManager) {
+        if manager.slots[0].is_some() {
+            return;
+        }
+        let Ok(contents) = std::fs::read_to_string(LEGACY_PATH) else {
+            return;
+        };
+        match ron::from_str::<SaveSlotData>(&contents) {
+            Ok(loaded) => {
+                manager.slots[0] = Some(loaded);
+                save_slot_to_disk(0, &loaded);
+                crate::event_log::record("migrated legacy assets/save.ron into slot 1");
+            }
+            Err(err) => warn!("failed to parse {LEGACY_PATH}: {err}"),
+        }
+    }
+
+    /// Writes `data` to `index`'s file. Called right after anything changes
+    /// it, mirroring `high_scores::save_to_disk`'s save-immediately timing.
+    pub fn save_slot_to_disk(index: usize, data: &SaveSlotData) {
+        let path = slot_path(index);
+        match ron::to_string(data) {
+            Ok(serialized) => {
+                if let Err(err) = std::fs::write(&path, serialized) {
+                    warn!("failed to write {path}: {err}");
+                }
+            }
+            Err(err) => warn!("failed to serialize save slot {}: {err}", index + 1),
+        }
+    }
+
+    pub fn delete_slot_from_disk(index: usize) {
+        let path = slot_path(index);
+        if Path::new(&path).exists() {
+            if let Err(err) = std::fs::remove_file(&path) {
+                warn!("failed to remove {path}: {err}");
+            }
+        }
+    }
+}
@@ -0,0 +1,42 @@
+//! Explicit ordering for the gameplay `Update` schedule. Every gameplay
+//! system is assigned to one of these sets rather than relying on ad-hoc
+//! `.chain()`/`.after()` calls, so new systems have an obvious place to go
+//! and Bevy's ambiguity checker can tell us when two of them race on the
+//! same data.
+//!
+//! Contract:
+//! - `Input` reads raw device state into intent (nothing else touches devices).
+//! - `Intent` turns intent into desired `Velocity` (player input, AI).
+//! - `Physics` integrates `Velocity` into `Transform` (gravity, movement).
+//! - `CollisionResolve` corrects `Transform` for overlaps and sets `Grounded`.
+//! - `PostPhysics` reacts to the resolved state (animation state selection).
+//! - `AnimationSet` advances sprite frames.
+//! - `CameraSet` follows the player; must run after physics so it never lags.
+
+use bevy::prelude::*;
+
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PlatformerSet {
+    Input,
+    Intent,
+    Physics,
+    CollisionResolve,
+    PostPhysics,
+    AnimationSet,
+    CameraSet,
+}
+
+pub struct SchedulePlugin;
+
+impl Plugin for SchedulePlugin {
+    fn build(&self, app: &mut App) {
+        use PlatformerSet::*;
+        app.configure_sets(
+            Update,
+            (Input, Intent, Physics, CollisionResolve, PostPhysics, AnimationSet).chain(),
+        )
+        // CameraSet lives in PostUpdate, which Bevy already runs after all of
+        // Update, so it can never lag a frame behind CollisionResolve.
+        .configure_sets(PostUpdate, CameraSet);
+    }
+}
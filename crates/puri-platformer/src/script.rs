@@ -0,0 +1,386 @@
+//! Data-driven level scripting: a small trigger/action table that lets a
+//! level open a door, spawn a prefab, or nudge a platform without a new
+//! Rust system for every level-specific hookup.
+//!
+//! [`ScriptEntry`] is loaded from `assets/scripts/*.ron`, scanned as a
+//! directory at `Startup` the same way `prefab::load_prefabs` scans
+//! `assets/prefabs/*.ron`. [`Trigger`] and [`Action`] are plain `enum`s
+//! deserialized by name — adding a new variant is backward compatible on
+//! its own, since `ron` only errors on a variant name it doesn't recognize;
+//! an old level file that never names the new variant keeps parsing exactly
+//! as before. That's what makes the format "extensible without breaking
+//! old levels" rather than something this module has to version by hand.
+//!
+//! Entities are looked up by [`LevelId`], a stable, level-authored string
+//! (`"zone_3"`, `"door_a"`, `"lift"`, ...) — the string-keyed equivalent of
+//! `world_flags::WorldFlagId`'s integer keys, chosen because the request's
+//! own trigger/action examples name entities by human-readable string
+//! rather than index.
+//!
+//! [`evaluate_triggers`] checks every [`ScriptEntry`] whose [`Trigger`]
+//! hasn't fired yet against this crate's existing event streams and marks
+//! it fired (so it can never re-fire) the moment its trigger is satisfied,
+//! sending a private [`TriggerFired`] event for [`execute_actions`] to act
+//! on — the same fire-once-and-tell-someone-else-to-react split
+//! `objectives::track_objectives`/`ObjectiveCompleted` already uses.
+//! Concretely:
+//! - [`Trigger::PlayerEnters`] does the same AABB overlap check
+//!   `portal::teleport_on_overlap` already does, against a
+//!   [`LevelId`]-tagged `collision::Sensor`, rather than reading
+//!   `collision::CollisionEvent` — nothing in this crate's default (AABB)
+//!   collision backend actually sends that event yet (only the `rapier`
+//!   backend does), so a trigger built on it would silently never fire for
+//!   most players.
+//! - [`Trigger::LeverToggled`] reads `interact::InteractEvent` against a
+//!   [`LevelId`]-tagged `map::Lever`, the same event `map::toggle_lever`
+//!   itself consumes.
+//! - [`Trigger::AllEnemiesDead`] is true once at least one
+//!   `combo::Stompable` has existed and none remain — the only notion of
+//!   "enemy" this crate currently tracks (see `combo`'s own doc comment on
+//!   `Stompable` today meaning just `turret::Turret`).
+//!
+//! Actions:
+//! - [`Action::OpenDoor`] removes the `HitBox` from the [`LevelId`]-tagged
+//!   [`Door`], exactly how `objectives::unlock_exit_when_all_complete`
+//!   already opens `ExitGate` — "open" means "no longer solid", not a
+//!   sprite/animation swap; nothing in this crate authors door art yet.
+//! - [`Action::SpawnPrefab`] resolves `at` to a [`LevelId`]-tagged
+//!   waypoint's `Transform` and runs a [`SpawnPrefabAt`] `Command` —
+//!   `prefab::spawn_prefab` needs a `&mut World` (see that module's own
+//!   note on why), so this follows `challenge::CaptureRetrySnapshot`'s
+//!   precedent for queuing exclusive-world-access work through `Commands`
+//!   rather than a plain system parameter.
+//! - [`Action::MovePlatform`] resolves `target`/`to` to two [`LevelId`]s
+//!   and retargets the `target` `map::MovingPlatform` to glide toward `to`'s
+//!   position over `secs` seconds — a one-shot glide, not
+//!   `MovingPlatform`'s own back-and-forth patrol, driven by
+//!   [`tick_moving_targets`].
+//! - [`Action::SetWeather`] starts a `weather`-module tween gliding
+//!   `weather::Weather`'s intensity toward `kind`/`intensity` over `secs`
+//!   seconds — the same one-shot-glide idea as [`Action::MovePlatform`],
+//!   just for a `Resource` instead of a `Transform` (see `weather`'s own
+//!   doc comment on why that needs its own tween type rather than reusing
+//!   [`tick_moving_targets`]).
+//!
+//! `assets/scripts/demo.ron` is empty (comments only): the built-in demo
+//! layout (`map::spawn_map_entities`) doesn't tag any of its hard-coded
+//! entities with a [`LevelId`] yet, so there's nothing today's one script
+//! file could reference — see `map`'s own doc comment on why the demo
+//! layout is still hard-coded Rust rather than loaded level data. Wiring a
+//! `LevelId` onto specific demo entities (and authoring a real script
+//! against them) is separate, future, per-entity work; `tests/script.rs`
+//! exercises the interpreter directly against entities spawned in-test.
+
+use bevy::ecs::system::Command;
+use bevy::prelude::*;
+
+use crate::collision::{HitBox, Sensor};
+use crate::combo::Stompable;
+use crate::interact::InteractEvent;
+use crate::map::{Lever, MovingPlatform};
+use crate::player::Player;
+use crate::prefab::spawn_prefab;
+use crate::render_layer::{z_for, GameLayer};
+use crate::weather::{self, Weather, WeatherKind};
+
+const SCRIPT_DIR: &str = "assets/scripts";
+
+/// A stable, level-authored name for an entity a [`Trigger`]/[`Action`]
+/// refers to. Unlike `world_flags::WorldFlagId` this isn't meant to be
+/// unique forever (a script only needs it to be unique within one level's
+/// entities), so it's a plain `String` rather than a save-file-stable `u32`.
+/// `Reflect`-registered (unlike this module's other components) so the
+/// in-game editor's delete-entity undo can capture and restore it through
+/// the same reflection-based scene round-trip `main.rs::save_debug_scene`
+/// already uses for the whole world — losing a `LevelId` on delete+undo
+/// would leave a script's `Trigger`/`Action` pointing at nothing.
+#[derive(Component, Reflect, Clone, PartialEq, Eq, Debug, Default)]
+#[reflect(Component)]
+pub struct LevelId(pub String);
+
+/// A `HitBox`-blocked passage. [`Action::OpenDoor`] removes the `HitBox`;
+/// there's no separate "is it open" flag, the same reasoning
+/// `objectives::ExitGate`'s own doc comment gives for `ExitGate`.
+#[derive(Component, Default)]
+pub struct Door;
+
+/// What has to happen for a [`ScriptEntry`]'s [`Action`] to run. New
+/// variants are safe to add — see this module's own doc comment on why.
+#[derive(Clone, serde::Deserialize)]
+pub enum Trigger {
+    PlayerEnters(String),
+    AllEnemiesDead,
+    LeverToggled(String),
+}
+
+/// What a [`ScriptEntry`] does once its [`Trigger`] fires.
+#[derive(Clone, serde::Deserialize)]
+pub enum Action {
+    OpenDoor(String),
+    SpawnPrefab {
+        name: String,
+        at: String,
+    },
+    MovePlatform {
+        target: String,
+        to: String,
+        secs: f32,
+    },
+    SetWeather {
+        kind: WeatherKind,
+        intensity: f32,
+        secs: f32,
+    },
+}
+
+/// One row of a level's trigger/action table.
+#[derive(Clone, serde::Deserialize)]
+pub struct ScriptEntry {
+    pub trigger: Trigger,
+    pub action: Action,
+    /// Set by [`evaluate_triggers`] once this entry has fired, so it never
+    /// fires twice. Never present in an authored `.ron` file.
+    #[serde(skip)]
+    fired: bool,
+}
+
+impl ScriptEntry {
+    /// `pub(crate)` so `testing::TestWorld::add_script_entry` can build one
+    /// directly, standing in for authoring a real `.ron` file.
+    pub(crate) fn new(trigger: Trigger, action: Action) -> Self {
+        Self {
+            trigger,
+            action,
+            fired: false,
+        }
+    }
+}
+
+/// Every [`ScriptEntry`] loaded from `assets/scripts/*.ron` at `Startup`.
+#[derive(Resource, Default)]
+pub struct LevelScript(Vec<ScriptEntry>);
+
+impl LevelScript {
+    /// `pub(crate)` for the same reason as [`ScriptEntry::new`].
+    pub(crate) fn push(&mut self, entry: ScriptEntry) {
+        self.0.push(entry);
+    }
+}
+
+/// Fired once per [`ScriptEntry`] the moment its [`Trigger`] is satisfied,
+/// for [`execute_actions`] to run the matching [`Action`].
+#[derive(Event, Clone)]
+struct TriggerFired(Action);
+
+/// Whether any `Stompable` has been seen yet, for [`Trigger::AllEnemiesDead`]
+/// — without this, an empty level (no `Stompable` ever spawned) would
+/// satisfy "all enemies dead" on the very first frame.
+#[derive(Resource, Default)]
+struct SeenAnyEnemy(bool);
+
+/// Glides `target`'s `MovingPlatform` from wherever it is toward `to` over
+/// `secs` seconds, driven by [`tick_moving_targets`] rather than
+/// `map::move_platform`'s own sine patrol.
+#[derive(Component)]
+struct MoveTarget {
+    to: Vec2,
+    timer: Timer,
+    from: Vec2,
+}
+
+pub struct ScriptPlugin;
+
+impl Plugin for ScriptPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<LevelId>()
+            .init_resource::<LevelScript>()
+            .init_resource::<SeenAnyEnemy>()
+            .add_event::<TriggerFired>()
+            .add_systems(Startup, load_scripts)
+            .add_systems(
+                Update,
+                (evaluate_triggers, execute_actions, tick_moving_targets).chain(),
+            );
+    }
+}
+
+fn load_scripts(mut script: ResMut<LevelScript>) {
+    let Ok(entries) = std::fs::read_dir(SCRIPT_DIR) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some("ron") {
+            continue;
+        }
+        let Ok(contents) = std::fs::read_to_string(&path) else {
+            continue;
+        };
+        match ron::from_str::<Vec<ScriptEntry>>(&contents) {
+            Ok(entries) => script.0.extend(entries),
+            Err(err) => warn!("failed to parse {}: {err}", path.display()),
+        }
+    }
+}
+
+fn evaluate_triggers(
+    mut script: ResMut<LevelScript>,
+    mut fired: EventWriter<TriggerFired>,
+    mut interact_events: EventReader<InteractEvent>,
+    mut seen_enemy: ResMut<SeenAnyEnemy>,
+    player: Query<(&Transform, &HitBox), With<Player>>,
+    zones: Query<(&LevelId, &Transform, &HitBox), (With<Sensor>, Without<Player>)>,
+    levers: Query<&LevelId, With<Lever>>,
+    stompable: Query<(), With<Stompable>>,
+) {
+    let toggled_levers: Vec<String> = interact_events
+        .read()
+        .filter_map(|event| levers.get(event.target).ok().map(|id| id.0.clone()))
+        .collect();
+
+    let enemy_count = stompable.iter().count();
+    if enemy_count > 0 {
+        seen_enemy.0 = true;
+    }
+    let all_enemies_dead = seen_enemy.0 && enemy_count == 0;
+
+    let player_box = player.get_single().ok();
+
+    for entry in &mut script.0 {
+        if entry.fired {
+            continue;
+        }
+        let satisfied = match &entry.trigger {
+            Trigger::PlayerEnters(zone) => {
+                player_box.is_some_and(|(player_transform, player_box)| {
+                    zones.iter().any(|(id, zone_transform, zone_box)| {
+                        &id.0 == zone
+                            && overlapping(
+                                player_transform.translation.truncate(),
+                                player_box.size,
+                                zone_transform.translation.truncate(),
+                                zone_box.size,
+                            )
+                    })
+                })
+            }
+            Trigger::AllEnemiesDead => all_enemies_dead,
+            Trigger::LeverToggled(lever) => toggled_levers.iter().any(|id| id == lever),
+        };
+        if satisfied {
+            entry.fired = true;
+            fired.send(TriggerFired(entry.action.clone()));
+        }
+    }
+}
+
+fn overlapping(a_pos: Vec2, a_size: Vec2, b_pos: Vec2, b_size: Vec2) -> bool {
+    (a_pos - b_pos).abs().cmplt((a_size + b_size) / 2.0).all()
+}
+
+fn execute_actions(
+    mut commands: Commands,
+    mut events: EventReader<TriggerFired>,
+    weather: Res<Weather>,
+    doors: Query<(Entity, &LevelId), With<Door>>,
+    platforms: Query<(Entity, &LevelId), With<MovingPlatform>>,
+    named: Query<(&LevelId, &Transform)>,
+) {
+    for TriggerFired(action) in events.read() {
+        match action {
+            Action::OpenDoor(id) => {
+                let Some((entity, _)) = doors.iter().find(|(_, door_id)| &door_id.0 == id) else {
+                    warn!("script: no Door with LevelId {id:?} to open");
+                    continue;
+                };
+                commands.entity(entity).remove::<HitBox>();
+            }
+            Action::SpawnPrefab { name, at } => {
+                let Some((_, transform)) =
+                    named.iter().find(|(waypoint_id, _)| &waypoint_id.0 == at)
+                else {
+                    warn!("script: no entity with LevelId {at:?} to spawn {name:?} at");
+                    continue;
+                };
+                commands.add(SpawnPrefabAt {
+                    name: name.clone(),
+                    position: transform.translation.truncate(),
+                });
+            }
+            Action::MovePlatform { target, to, secs } => {
+                let Some((entity, _)) = platforms.iter().find(|(_, id)| &id.0 == target) else {
+                    warn!("script: no MovingPlatform with LevelId {target:?}");
+                    continue;
+                };
+                let Some((_, to_transform)) = named.iter().find(|(id, _)| &id.0 == to) else {
+                    warn!("script: no entity with LevelId {to:?} to move {target:?} toward");
+                    continue;
+                };
+                let Ok((_, from_transform)) = named.get(entity) else {
+                    warn!("script: MovingPlatform {target:?} has no LevelId-queryable Transform");
+                    continue;
+                };
+                commands.entity(entity).insert(MoveTarget {
+                    to: to_transform.translation.truncate(),
+                    from: from_transform.translation.truncate(),
+                    timer: Timer::from_seconds(*secs, TimerMode::Once),
+                });
+            }
+            Action::SetWeather { kind, intensity, secs } => {
+                weather::start_tween(&mut commands, *weather, *kind, *intensity, *secs);
+            }
+        }
+    }
+}
+
+/// A [`Command`] rather than an ordinary system parameter — `spawn_prefab`
+/// needs a `&mut World` (see `prefab`'s own note on why), the same reason
+/// `challenge::CaptureRetrySnapshot` queues its own world-touching work
+/// through `Commands`.
+struct SpawnPrefabAt {
+    name: String,
+    position: Vec2,
+}
+
+impl Command for SpawnPrefabAt {
+    fn apply(self, world: &mut World) {
+        let z = z_for(GameLayer::Entities, self.position.y, true);
+        if let Err(err) = spawn_prefab(
+            world,
+            &self.name,
+            Transform::from_translation(self.position.extend(z)),
+        ) {
+            warn!("script: {err}");
+        }
+    }
+}
+
+/// Ticks every [`MoveTarget`], overriding `map::move_platform`'s own sine
+/// patrol for the entities it applies to — `active` is forced off for the
+/// glide's duration (and left off once it lands) so the two motions never
+/// fight over the same `Transform` in one frame.
+fn tick_moving_targets(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut moving: Query<(
+        Entity,
+        &mut Transform,
+        &mut MoveTarget,
+        Option<&mut MovingPlatform>,
+    )>,
+) {
+    for (entity, mut transform, mut target, platform) in &mut moving {
+        if let Some(mut platform) = platform {
+            platform.active = false;
+        }
+        target.timer.tick(time.delta());
+        let t =
+            (target.timer.elapsed_secs() / target.timer.duration().as_secs_f32()).clamp(0.0, 1.0);
+        let position = target.from.lerp(target.to, t);
+        transform.translation.x = position.x;
+        transform.translation.y = position.y;
+        if target.timer.finished() {
+            commands.entity(entity).remove::<MoveTarget>();
+        }
+    }
+}
@@ -0,0 +1,238 @@
+//! Opt-in, event-sourced session analytics: appends JSON lines to
+//! `--analytics <PATH>` (see `launch_options::LaunchOptions::analytics`) so
+//! deaths, level start/completion, coin pickups, damage taken, and ability
+//! usage can be studied after a playtest without instrumenting a replay.
+//! `--analyze <PATH>` (handled in `main`, not here — this module only reads
+//! back what it wrote) then prints aggregate stats from a recorded file.
+//!
+//! Every gameplay module that reports something fires [`RecordSessionEvent`]
+//! into the normal event queue rather than calling into this module
+//! directly — the same independent-consumer shape `event_log::record`'s
+//! call sites already use, except here the sink itself is optional, so a
+//! build without `--analytics` just drains an unread queue instead of
+//! reaching for `Option` checks at every call site.
+//!
+//! [`start_recorder`] only spawns the writer thread when `--analytics` was
+//! passed; [`queue_recorded_events`] forwards [`RecordSessionEvent`] into
+//! it (or drops it on the floor if recording is off). The writer thread
+//! does the actual `serde_json` serialization and file IO off the main
+//! thread, so a slow disk never stalls a frame; [`SCHEMA_VERSION`] rides
+//! along on every line so a later `--analyze` build can still make sense
+//! of an older recording, or at least know it's older.
+//!
+//! Each line is one `serde_json::to_string` call followed by a single
+//! `write_all` + `flush`, with no multi-line record and no trailing
+//! comma/bracket to close — so a crash mid-write can only ever corrupt the
+//! *last* line, and [`analyze::read_events`] already skips lines that fail
+//! to parse for exactly that reason.
+
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::mpsc::{self, Sender};
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::launch_options::LaunchOptions;
+
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// One thing worth remembering about a play session. `cause` on [`Death`]
+/// is always `"unknown"`: `death::record_player_death` fills it from
+/// `death::PlayerDied`, which carries only a position, not the
+/// `player::DamageKind` of the `fall_damage::DamageEvent` that killed the
+/// player.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum SessionEvent {
+    LevelStarted { level_id: u32 },
+    LevelCompleted { level_id: u32, time_seconds: f32 },
+    Death { x: f32, y: f32, cause: String },
+    CoinCollected { level_id: u32 },
+    DamageTaken { amount: u32 },
+    AbilityUsed { ability: String },
+}
+
+/// One JSON line: [`SCHEMA_VERSION`] plus the event, flattened so the line
+/// reads as a single flat object instead of a nested `"event": {...}`.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct RecordedEvent {
+    pub schema_version: u32,
+    #[serde(flatten)]
+    pub event: SessionEvent,
+}
+
+/// Fired by gameplay systems to report something worth recording, and
+/// drained by [`queue_recorded_events`]. A plain Bevy event rather than a
+/// direct call into this module, so gameplay code doesn't need to know
+/// whether recording is even on.
+#[derive(Event, Clone, Debug)]
+pub struct RecordSessionEvent(pub SessionEvent);
+
+/// Channel to the background writer thread. `None` when `--analytics`
+/// wasn't passed, in which case [`queue_recorded_events`] just drains the
+/// event queue without sending anything.
+#[derive(Resource, Default)]
+struct SessionRecorder(Option<Sender<RecordedEvent>>);
+
+pub struct SessionRecorderPlugin;
+
+impl Plugin for SessionRecorderPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<RecordSessionEvent>()
+            .init_resource::<SessionRecorder>()
+            .add_systems(Startup, start_recorder)
+            .add_systems(Update, queue_recorded_events);
+    }
+}
+
+fn start_recorder(mut recorder: ResMut<SessionRecorder>, options: Res<LaunchOptions>) {
+    let Some(path) = options.analytics.clone() else {
+        return;
+    };
+    let (tx, rx) = mpsc::channel();
+    std::thread::spawn(move || run_writer(path, rx));
+    recorder.0 = Some(tx);
+}
+
+/// Owns the file for the life of the run: opened once here, appended to
+/// (never truncated, so a `--analytics` path can be reused run over run to
+/// build up one long history) and flushed after every line, then closed
+/// when `tx` drops alongside the `App`.
+fn run_writer(path: PathBuf, rx: mpsc::Receiver<RecordedEvent>) {
+    let file = match std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+    {
+        Ok(file) => file,
+        Err(err) => {
+            eprintln!("session_recorder: failed to open {}: {err}", path.display());
+            return;
+        }
+    };
+    let mut writer = std::io::BufWriter::new(file);
+    while let Ok(recorded) = rx.recv() {
+        let Ok(line) = serde_json::to_string(&recorded) else {
+            continue;
+        };
+        if writeln!(writer, "{line}").is_err() || writer.flush().is_err() {
+            break;
+        }
+    }
+}
+
+fn queue_recorded_events(
+    recorder: Res<SessionRecorder>,
+    mut events: EventReader<RecordSessionEvent>,
+) {
+    let Some(sender) = &recorder.0 else {
+        events.clear();
+        return;
+    };
+    for RecordSessionEvent(event) in events.read() {
+        let _ = sender.send(RecordedEvent {
+            schema_version: SCHEMA_VERSION,
+            event: event.clone(),
+        });
+    }
+}
+
+/// Reads back a `--analytics` recording for `--analyze` (see `main`).
+/// Public so `main`'s CLI mode can stay a thin wrapper around real,
+/// testable logic instead of doing the parsing/aggregation inline.
+pub mod analyze {
+    use std::path::Path;
+
+    use super::{RecordedEvent, SessionEvent};
+
+    /// Parses `path` line by line, silently skipping any line that isn't
+    /// valid JSON for [`RecordedEvent`] — the one a crash caught mid-write
+    /// left truncated, per this module's own doc comment, plus (once
+    /// [`super::SCHEMA_VERSION`] ever bumps) any from an incompatible
+    /// older or newer writer.
+    pub fn read_events(path: &Path) -> std::io::Result<Vec<RecordedEvent>> {
+        let contents = std::fs::read_to_string(path)?;
+        Ok(contents
+            .lines()
+            .filter_map(|line| serde_json::from_str(line).ok())
+            .collect())
+    }
+
+    /// Human-readable aggregate stats: a coarse text-grid death heatmap and
+    /// the average `LevelCompleted::time_seconds`.
+    pub fn summarize(events: &[RecordedEvent]) -> String {
+        let deaths: Vec<(f32, f32)> = events
+            .iter()
+            .filter_map(|recorded| match &recorded.event {
+                SessionEvent::Death { x, y, .. } => Some((*x, *y)),
+                _ => None,
+            })
+            .collect();
+        let completion_times: Vec<f32> = events
+            .iter()
+            .filter_map(|recorded| match &recorded.event {
+                SessionEvent::LevelCompleted { time_seconds, .. } => Some(*time_seconds),
+                _ => None,
+            })
+            .collect();
+
+        let mut out = String::new();
+        out.push_str(&format!(
+            "{} events ({} deaths, {} level completions)\n",
+            events.len(),
+            deaths.len(),
+            completion_times.len()
+        ));
+        out.push_str(&death_heatmap(&deaths));
+        if completion_times.is_empty() {
+            out.push_str("average completion time: n/a\n");
+        } else {
+            let average = completion_times.iter().sum::<f32>() / completion_times.len() as f32;
+            out.push_str(&format!("average completion time: {average:.1}s\n"));
+        }
+        out
+    }
+
+    const GRID_COLS: usize = 20;
+    const GRID_ROWS: usize = 10;
+    const CELL_WORLD_UNITS: f32 = 64.0;
+
+    /// A `GRID_COLS`x`GRID_ROWS` text grid centered on the mean death
+    /// position, one character per `CELL_WORLD_UNITS`-wide cell: `.` for
+    /// empty, `1`-`9` for a death count, `+` for ten or more. There's no
+    /// real level-geometry-aware renderer in this crate to align cells
+    /// against (`minimap`'s own grid is the closest thing, but it's baked
+    /// from `collision::HitBox`es at level-load time, not something this
+    /// offline tool has access to), so this centers on the death data
+    /// itself instead.
+    fn death_heatmap(deaths: &[(f32, f32)]) -> String {
+        if deaths.is_empty() {
+            return "death heatmap: no deaths recorded\n".to_string();
+        }
+        let center_x = deaths.iter().map(|(x, _)| x).sum::<f32>() / deaths.len() as f32;
+        let center_y = deaths.iter().map(|(_, y)| y).sum::<f32>() / deaths.len() as f32;
+
+        let mut grid = [[0u32; GRID_COLS]; GRID_ROWS];
+        for &(x, y) in deaths {
+            let col = ((x - center_x) / CELL_WORLD_UNITS + GRID_COLS as f32 / 2.0) as isize;
+            let row = ((center_y - y) / CELL_WORLD_UNITS + GRID_ROWS as f32 / 2.0) as isize;
+            if (0..GRID_COLS as isize).contains(&col) && (0..GRID_ROWS as isize).contains(&row) {
+                grid[row as usize][col as usize] += 1;
+            }
+        }
+
+        let mut out = String::from("death heatmap:\n");
+        for row in grid {
+            for count in row {
+                out.push(match count {
+                    0 => '.',
+                    1..=9 => char::from_digit(count, 10).unwrap(),
+                    _ => '+',
+                });
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
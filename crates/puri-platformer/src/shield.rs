@@ -0,0 +1,233 @@
+//! Hold [`SHIELD_KEY`] while grounded to raise a [`Shield`] sensor in front
+//! of the player, draining a per-player [`Stamina`] meter shown on the HUD.
+//! `Stamina` regenerates whenever the shield isn't up. With
+//! `accessibility::AccessibilityOptions::toggle_input_mode` on, a press
+//! raises it and the next press drops it instead — see
+//! [`raise_or_drop_shield`]'s own doc comment.
+//!
+//! The shield blocks and reflects nothing: `projectile`'s hit check never
+//! looks for a [`Shield`], so a `turret::Turret` shot that reaches the
+//! player still sends its `fall_damage::DamageEvent` with the shield up,
+//! and `collision::HitBox`/`Sensor` have no notion of a "layer" to reverse
+//! a projectile into (see `collision`'s own doc comment on the
+//! gameplay-facing API it exposes — layers aren't part of it). What's real: the sensor appears and disappears at the right times,
+//! sized and positioned off `Facing` the way `grapple::fire_grapple` reads
+//! `Facing` for its own direction, movement stops while it's up, and the
+//! stamina drain/regen/HUD loop works end to end.
+
+use bevy::prelude::*;
+
+use crate::accessibility::AccessibilityOptions;
+use crate::collision::{HitBox, Sensor};
+use crate::player::{Facing, Grounded, Player, Velocity};
+use crate::schedule::PlatformerSet;
+use crate::time_scale::GameTime;
+
+pub const SHIELD_KEY: KeyCode = KeyCode::KeyC;
+const MAX_STAMINA: f32 = 100.0;
+const DRAIN_PER_SECOND: f32 = 40.0;
+const REGEN_PER_SECOND: f32 = 25.0;
+const SHIELD_SIZE: Vec2 = Vec2::new(10.0, 28.0);
+/// How far in front of the player's center the shield sensor sits.
+const SHIELD_OFFSET: f32 = 18.0;
+
+/// A player's stamina pool. Starts full; [`drain_or_regen_stamina`] is the
+/// only system that ever changes it.
+#[derive(Component)]
+pub struct Stamina(pub f32);
+
+impl Default for Stamina {
+    fn default() -> Self {
+        Self(MAX_STAMINA)
+    }
+}
+
+/// The raised shield sensor, spawned as a child of the player. Its own
+/// `Transform` is local to the player, offset toward whichever way the
+/// player was facing the frame it went up.
+#[derive(Component)]
+pub struct Shield;
+
+pub struct ShieldPlugin;
+
+impl Plugin for ShieldPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                ensure_stamina_component,
+                raise_or_drop_shield,
+                drain_or_regen_stamina,
+                hold_still_while_shielding,
+            )
+                .chain()
+                .in_set(PlatformerSet::Intent)
+                // `hold_still_while_shielding` and `player::dash` both write
+                // `Velocity` in this same set; see `dash`'s own note on why
+                // it's `pub(crate)` for exactly this ordering.
+                .after(crate::player::dash)
+                .run_if(crate::dialogue::playing_and_not_talking)
+                .run_if(crate::death::player_not_dying),
+        )
+        .add_systems(Startup, spawn_hud_text)
+        .add_systems(Update, update_hud_text.in_set(PlatformerSet::PostPhysics));
+        #[cfg(feature = "serde")]
+        app.add_systems(Update, record_shield_usage);
+    }
+}
+
+/// Backfills a default `Stamina` onto the player the frame it appears,
+/// the same "don't fight `Startup` ordering against `player::spawn_player`"
+/// approach `shop::sync_health_with_upgrades` documents for its own
+/// player-state add-on.
+fn ensure_stamina_component(mut commands: Commands, player: Query<Entity, (With<Player>, Without<Stamina>)>) {
+    for entity in &player {
+        commands.entity(entity).insert(Stamina::default());
+    }
+}
+
+/// Raises the shield while [`SHIELD_KEY`] is held and the player is
+/// grounded with stamina left; drops it the instant any of those stops
+/// being true, including a fully-drained meter mid-hold.
+///
+/// With `AccessibilityOptions::toggle_input_mode` on, a press raises it
+/// (if grounded with stamina) and the *next* press drops it, rather than
+/// needing the key held the whole time — losing ground or draining the
+/// meter still force it down either way.
+fn raise_or_drop_shield(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    options: Res<AccessibilityOptions>,
+    player: Query<(Entity, &Facing, &Stamina, Option<&Grounded>, Option<&Children>), With<Player>>,
+    shields: Query<(), With<Shield>>,
+) {
+    let Ok((entity, facing, stamina, grounded, children)) = player.get_single() else {
+        return;
+    };
+    let existing_shield = children
+        .into_iter()
+        .flatten()
+        .find(|&&child| shields.get(child).is_ok())
+        .copied();
+
+    let can_raise = grounded.is_some() && stamina.0 > 0.0;
+    let should_raise = if options.toggle_input_mode {
+        if keys.just_pressed(SHIELD_KEY) {
+            existing_shield.is_none() && can_raise
+        } else {
+            existing_shield.is_some() && can_raise
+        }
+    } else {
+        keys.pressed(SHIELD_KEY) && can_raise
+    };
+
+    match (should_raise, existing_shield) {
+        (true, None) => {
+            let offset = match facing {
+                Facing::Right => SHIELD_OFFSET,
+                Facing::Left => -SHIELD_OFFSET,
+            };
+            commands.entity(entity).with_children(|parent| {
+                parent.spawn((
+                    Shield,
+                    Sensor,
+                    HitBox { size: SHIELD_SIZE },
+                    SpriteBundle {
+                        sprite: Sprite {
+                            color: Color::rgba(0.4, 0.8, 1.0, 0.8),
+                            custom_size: Some(SHIELD_SIZE),
+                            ..default()
+                        },
+                        transform: Transform::from_xyz(offset, 0.0, 0.1),
+                        ..default()
+                    },
+                ));
+            });
+        }
+        (false, Some(shield_entity)) => {
+            commands.entity(shield_entity).despawn_recursive();
+        }
+        _ => {}
+    }
+}
+
+/// Drains while a `Shield` child actually exists rather than re-reading
+/// [`SHIELD_KEY`] itself, so toggle-mode shields (which stay up without the
+/// key held) still drain the way a held shield does.
+fn drain_or_regen_stamina(
+    time: GameTime,
+    mut player: Query<(&mut Stamina, Option<&Children>), With<Player>>,
+    shields: Query<(), With<Shield>>,
+) {
+    let dt = time.delta_seconds();
+    for (mut stamina, children) in &mut player {
+        let shielding =
+            children.into_iter().flatten().any(|&child| shields.get(child).is_ok()) && stamina.0 > 0.0;
+        if shielding {
+            stamina.0 = (stamina.0 - DRAIN_PER_SECOND * dt).max(0.0);
+        } else {
+            stamina.0 = (stamina.0 + REGEN_PER_SECOND * dt).min(MAX_STAMINA);
+        }
+    }
+}
+
+/// Zeroes horizontal velocity for a player currently holding a `Shield`
+/// child, overriding whatever `player_input` set it to this frame — this
+/// runs later in the same `Intent` set, so it's the last write before
+/// `Physics` integrates `Velocity`.
+fn hold_still_while_shielding(
+    mut player: Query<(&mut Velocity, &Children), With<Player>>,
+    shields: Query<(), With<Shield>>,
+) {
+    let Ok((mut velocity, children)) = player.get_single_mut() else {
+        return;
+    };
+    if children.iter().any(|&child| shields.get(child).is_ok()) {
+        velocity.0.x = 0.0;
+    }
+}
+
+/// `pub(crate)` so `photo::hide_hud`/`photo::show_hud` can toggle its
+/// `Visibility` — mirrors `ambience::AmbienceOverlay`'s own `pub(crate)`
+/// bump for the same kind of cross-module access.
+#[derive(Component)]
+pub(crate) struct StaminaHudText;
+
+fn spawn_hud_text(mut commands: Commands) {
+    commands.spawn((
+        StaminaHudText,
+        TextBundle::from_section("", TextStyle::default()).with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(8.0),
+            left: Val::Px(8.0),
+            ..default()
+        }),
+    ));
+}
+
+fn update_hud_text(player: Query<&Stamina, With<Player>>, mut text: Query<&mut Text, With<StaminaHudText>>) {
+    let Ok(stamina) = player.get_single() else {
+        return;
+    };
+    let Ok(mut text) = text.get_single_mut() else {
+        return;
+    };
+    text.sections[0].value = format!("Stamina: {:.0}", stamina.0);
+}
+
+/// Mirrors `speedrun_overlay`'s own citation: an independent consumer of
+/// [`Shield`] rather than [`raise_or_drop_shield`] needing to know session
+/// recording exists.
+#[cfg(feature = "serde")]
+fn record_shield_usage(
+    raised: Query<(), Added<Shield>>,
+    mut session: EventWriter<crate::session_recorder::RecordSessionEvent>,
+) {
+    for () in &raised {
+        session.send(crate::session_recorder::RecordSessionEvent(
+            crate::session_recorder::SessionEvent::AbilityUsed {
+                ability: "shield".to_string(),
+            },
+        ));
+    }
+}
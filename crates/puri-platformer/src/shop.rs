@@ -0,0 +1,384 @@
+//! Between-level upgrade shop: talk to a [`ShopKeeper`] to spend [`Wallet`]
+//! coins on a fixed [`CATALOG`] of permanent upgrades.
+//!
+//! `FasterAttack` is authored catalog data that nothing reads (`attack`'s
+//! swing timings never look at [`PurchasedUpgrades`]), the same as
+//! `prefab::Spring` being data-only with no bounce system; and an
+//! unaffordable purchase plays no "deny sound" — this module plays no
+//! sound at all.
+//! [`Wallet`] starts at zero; `loot::collect_drops` is the one in-game
+//! system that credits it today (a `Coin` drop), and [`Wallet::add`] stays
+//! `pub` for tests and for whatever else eventually deposits into it.
+//!
+//! Persistence mirrors `high_scores`' one-shot `.ron` file, saved under its
+//! own path since a wallet/upgrade save has nothing to do with a score
+//! table. [`sync_abilities_with_upgrades`], [`sync_health_with_upgrades`],
+//! and [`sync_resistances_with_upgrades`] are what make a purchase outlive
+//! the process: they apply [`PurchasedUpgrades`] to the player every time
+//! one exists, so a dash bought last session is already unlocked the
+//! instant the player entity spawns after `persistence::load_from_disk`
+//! restores the save — not just at the moment of purchase.
+//!
+//! `SpikeBoots` is the "Spike boots... makes the player immune to Spike"
+//! upgrade a `player::DamageKind`/`player::Resistances`-adding request
+//! asked for; unlike `FasterAttack` above, it does have something real to
+//! wire into (`player::Resistances::set`), so [`sync_resistances_with_upgrades`]
+//! keeps it in sync the same idempotent way [`sync_abilities_with_upgrades`]
+//! keeps `Dash`/`DoubleJump` in sync with `Abilities`.
+//!
+//! `SpeedBoots` and `FallGuardCharm` are catalog rows for `equipment::CATALOG`
+//! instead — that module's own `equip_purchased_gear` reads
+//! [`PurchasedUpgrades::owns`] the same way the three sync systems here do,
+//! just to fill an `equipment::Equipment` slot instead of a boolean flag or
+//! a resistance entry.
+
+use bevy::prelude::*;
+
+use crate::interact::InteractEvent;
+use crate::player::{Abilities, DamageKind, Health, Player, Resistances};
+
+#[derive(Reflect, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum UpgradeId {
+    ExtraHeart,
+    Dash,
+    DoubleJump,
+    FasterAttack,
+    SpikeBoots,
+    SpeedBoots,
+    FallGuardCharm,
+}
+
+pub struct UpgradeInfo {
+    pub id: UpgradeId,
+    pub name: &'static str,
+    pub price: u32,
+}
+
+/// The shop's fixed offering. Prices are flat, tuning data rather than
+/// anything read from a level file (there isn't one — see
+/// `LaunchOptions::level`'s own note).
+pub const CATALOG: &[UpgradeInfo] = &[
+    UpgradeInfo {
+        id: UpgradeId::ExtraHeart,
+        name: "Extra Heart",
+        price: 30,
+    },
+    UpgradeInfo {
+        id: UpgradeId::Dash,
+        name: "Dash",
+        price: 50,
+    },
+    UpgradeInfo {
+        id: UpgradeId::DoubleJump,
+        name: "Double Jump",
+        price: 50,
+    },
+    UpgradeInfo {
+        id: UpgradeId::FasterAttack,
+        name: "Faster Attack",
+        price: 40,
+    },
+    UpgradeInfo {
+        id: UpgradeId::SpikeBoots,
+        name: "Spike Boots",
+        price: 40,
+    },
+    UpgradeInfo {
+        id: UpgradeId::SpeedBoots,
+        name: "Speed Boots",
+        price: 45,
+    },
+    UpgradeInfo {
+        id: UpgradeId::FallGuardCharm,
+        name: "Fall Guard Charm",
+        price: 35,
+    },
+];
+
+/// Coins the player can spend in the shop.
+#[derive(Resource, Reflect, Clone, Copy, Default)]
+#[reflect(Resource)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Wallet(pub u32);
+
+impl Wallet {
+    pub fn add(&mut self, amount: u32) {
+        self.0 += amount;
+    }
+}
+
+/// Every upgrade bought so far, this run or a past one. Each [`UpgradeId`]
+/// can only appear once — the shop has nothing repeatable to sell.
+#[derive(Resource, Reflect, Clone, Default)]
+#[reflect(Resource)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PurchasedUpgrades(Vec<UpgradeId>);
+
+impl PurchasedUpgrades {
+    pub fn owns(&self, id: UpgradeId) -> bool {
+        self.0.contains(&id)
+    }
+
+    fn purchase(&mut self, id: UpgradeId) {
+        self.0.push(id);
+    }
+
+    /// How many `ExtraHeart`s have been bought — `0` or `1` today, since
+    /// [`purchase`](Self::purchase) is only ever called once per id, but
+    /// written as a count rather than a bool in case that ever changes.
+    /// `pub(crate)` so `death::tick_dying` can respawn at the upgraded
+    /// baseline instead of the hard-coded one.
+    pub(crate) fn extra_hearts(&self) -> u32 {
+        self.0.iter().filter(|&&id| id == UpgradeId::ExtraHeart).count() as u32
+    }
+}
+
+/// Marks the NPC (or standalone fixture) that opens the shop on interact.
+#[derive(Component)]
+pub struct ShopKeeper;
+
+#[derive(States, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ShopMenu {
+    #[default]
+    Closed,
+    Open,
+}
+
+/// Which catalog row is highlighted while the menu is open. Reset to `0`
+/// every time the shop opens.
+///
+/// `pub(crate)` rather than private: `purchase_selected` is itself
+/// `pub(crate)` so `loot::LootPlugin` can order itself `.after` it, and a
+/// `pub(crate)` function can't take a private type as a parameter.
+#[derive(Resource, Default)]
+pub(crate) struct ShopSelection(usize);
+
+#[derive(Component)]
+struct ShopBox;
+
+#[derive(Component)]
+struct ShopText;
+
+pub struct ShopPlugin;
+
+impl Plugin for ShopPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Wallet>()
+            .register_type::<PurchasedUpgrades>()
+            .init_state::<ShopMenu>()
+            .init_resource::<Wallet>()
+            .init_resource::<PurchasedUpgrades>()
+            .init_resource::<ShopSelection>()
+            .add_systems(Update, open_shop.run_if(in_state(ShopMenu::Closed)))
+            .add_systems(
+                Update,
+                (sync_abilities_with_upgrades, sync_health_with_upgrades, sync_resistances_with_upgrades),
+            )
+            .add_systems(
+                Update,
+                (navigate_shop, purchase_selected, close_shop, update_shop_text)
+                    .chain()
+                    .run_if(in_state(ShopMenu::Open)),
+            );
+
+        #[cfg(feature = "serde")]
+        app.add_systems(Startup, persistence::load_from_disk);
+    }
+}
+
+fn open_shop(
+    mut commands: Commands,
+    mut events: EventReader<InteractEvent>,
+    keepers: Query<(), With<ShopKeeper>>,
+    mut selection: ResMut<ShopSelection>,
+    mut next_state: ResMut<NextState<ShopMenu>>,
+) {
+    for event in events.read() {
+        if keepers.get(event.target).is_err() {
+            continue;
+        }
+        selection.0 = 0;
+        commands.spawn((
+            ShopBox,
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    top: Val::Percent(20.0),
+                    left: Val::Percent(30.0),
+                    ..default()
+                },
+                ..default()
+            },
+        ));
+        next_state.set(ShopMenu::Open);
+    }
+}
+
+fn navigate_shop(keys: Res<ButtonInput<KeyCode>>, mut selection: ResMut<ShopSelection>) {
+    if keys.just_pressed(KeyCode::ArrowUp) {
+        selection.0 = selection.0.checked_sub(1).unwrap_or(CATALOG.len() - 1);
+    }
+    if keys.just_pressed(KeyCode::ArrowDown) {
+        selection.0 = (selection.0 + 1) % CATALOG.len();
+    }
+}
+
+/// `pub(crate)` so `loot::collect_drops` can order itself `.after` this —
+/// both write `Wallet`, and the ambiguity checker `tests/schedule_ambiguity.rs`
+/// runs at `LogLevel::Error` won't accept two unordered writers to the same
+/// resource (mirrors `player::dash`'s own citation for the same reason).
+pub(crate) fn purchase_selected(
+    keys: Res<ButtonInput<KeyCode>>,
+    selection: Res<ShopSelection>,
+    mut wallet: ResMut<Wallet>,
+    mut owned: ResMut<PurchasedUpgrades>,
+) {
+    if !keys.just_pressed(KeyCode::KeyE) && !keys.just_pressed(KeyCode::Space) {
+        return;
+    }
+    let upgrade = &CATALOG[selection.0];
+    if owned.owns(upgrade.id) || wallet.0 < upgrade.price {
+        return;
+    }
+    wallet.0 -= upgrade.price;
+    owned.purchase(upgrade.id);
+
+    #[cfg(feature = "serde")]
+    persistence::save_to_disk(&wallet, &owned);
+}
+
+fn close_shop(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    boxes: Query<Entity, With<ShopBox>>,
+    mut next_state: ResMut<NextState<ShopMenu>>,
+) {
+    if !keys.just_pressed(KeyCode::Escape) {
+        return;
+    }
+    for entity in &boxes {
+        commands.entity(entity).despawn_recursive();
+    }
+    next_state.set(ShopMenu::Closed);
+}
+
+fn update_shop_text(
+    mut commands: Commands,
+    wallet: Res<Wallet>,
+    owned: Res<PurchasedUpgrades>,
+    selection: Res<ShopSelection>,
+    shop_box: Query<Entity, With<ShopBox>>,
+    mut text: Query<&mut Text, With<ShopText>>,
+) {
+    let Ok(shop_box) = shop_box.get_single() else {
+        return;
+    };
+
+    let mut sections = vec![TextSection::new(format!("Coins: {}\n", wallet.0), TextStyle::default())];
+    sections.extend(CATALOG.iter().enumerate().map(|(index, upgrade)| {
+        let owned = owned.owns(upgrade.id);
+        let cursor = if index == selection.0 { "> " } else { "  " };
+        let color = if owned { Color::GRAY } else { Color::WHITE };
+        TextSection::new(
+            format!("{cursor}{} ({}){}\n", upgrade.name, upgrade.price, if owned { " [owned]" } else { "" }),
+            TextStyle {
+                color,
+                ..default()
+            },
+        )
+    }));
+
+    if let Ok(mut text) = text.get_single_mut() {
+        text.sections = sections;
+    } else {
+        commands.entity(shop_box).with_children(|parent| {
+            parent.spawn((ShopText, TextBundle::from_sections(sections)));
+        });
+    }
+}
+
+/// Turns on every ability the player has purchased so far. Runs every
+/// frame rather than once on purchase, so an ability bought last session
+/// is unlocked the instant the player spawns this session too, once
+/// [`persistence::load_from_disk`] has restored [`PurchasedUpgrades`].
+fn sync_abilities_with_upgrades(owned: Res<PurchasedUpgrades>, mut player: Query<&mut Abilities, With<Player>>) {
+    let Ok(mut abilities) = player.get_single_mut() else {
+        return;
+    };
+    abilities.dash |= owned.owns(UpgradeId::Dash);
+    abilities.double_jump |= owned.owns(UpgradeId::DoubleJump);
+}
+
+/// Same idempotent, every-frame shape as [`sync_abilities_with_upgrades`],
+/// for the one upgrade that changes `Resistances` instead of `Abilities`.
+fn sync_resistances_with_upgrades(owned: Res<PurchasedUpgrades>, mut player: Query<&mut Resistances, With<Player>>) {
+    let Ok(mut resistances) = player.get_single_mut() else {
+        return;
+    };
+    if owned.owns(UpgradeId::SpikeBoots) {
+        resistances.set(DamageKind::Spike, 0.0);
+    }
+}
+
+/// Grants the `ExtraHeart` bonus once, the frame the player entity
+/// appears — `Added<Player>` rather than every frame, since `Health` is
+/// live, damageable state and re-adding the bonus every tick would let it
+/// out-heal fall damage instead of just raising the respawn baseline (see
+/// `death::tick_dying`, which reads the same [`PurchasedUpgrades::extra_hearts`]
+/// for what to respawn at).
+fn sync_health_with_upgrades(owned: Res<PurchasedUpgrades>, mut player: Query<&mut Health, Added<Player>>) {
+    let Ok(mut health) = player.get_single_mut() else {
+        return;
+    };
+    health.0 += owned.extra_hearts();
+}
+
+#[cfg(feature = "serde")]
+mod persistence {
+    use super::{PurchasedUpgrades, Wallet};
+    use bevy::prelude::*;
+    use serde::{Deserialize, Serialize};
+    use std::path::Path;
+
+    const SAVE_PATH: &str = "assets/shop_save.ron";
+
+    #[derive(Serialize, Deserialize, Default)]
+    struct ShopSave {
+        wallet: Wallet,
+        owned: PurchasedUpgrades,
+    }
+
+    /// One-shot load of `assets/shop_save.ron` over the (empty) defaults,
+    /// if present. Mirrors `high_scores::persistence::load_from_disk`.
+    pub fn load_from_disk(mut wallet: ResMut<Wallet>, mut owned: ResMut<PurchasedUpgrades>) {
+        let Ok(contents) = std::fs::read_to_string(Path::new(SAVE_PATH)) else {
+            return;
+        };
+        match ron::from_str::<ShopSave>(&contents) {
+            Ok(loaded) => {
+                *wallet = loaded.wallet;
+                *owned = loaded.owned;
+            }
+            Err(err) => warn!("failed to parse {SAVE_PATH}: {err}"),
+        }
+    }
+
+    /// Writes the current wallet and owned upgrades to `assets/shop_save.ron`.
+    /// Called right after a purchase, mirroring `high_scores::save_to_disk`'s
+    /// "save immediately on the state change that needs to survive" timing.
+    pub fn save_to_disk(wallet: &Wallet, owned: &PurchasedUpgrades) {
+        let save = ShopSave {
+            wallet: *wallet,
+            owned: owned.clone(),
+        };
+        match ron::to_string(&save) {
+            Ok(serialized) => {
+                if let Err(err) = std::fs::write(SAVE_PATH, serialized) {
+                    warn!("failed to write {SAVE_PATH}: {err}");
+                }
+            }
+            Err(err) => warn!("failed to serialize shop save: {err}"),
+        }
+    }
+}
@@ -0,0 +1,124 @@
+//! Reflect-based snapshot/restore of gameplay state, for rollback netcode
+//! and `challenge`'s instant-retry key.
+//!
+//! [`snapshot`] and [`restore`] cover a fixed whitelist of components this
+//! crate already made `#[reflect(Component)]` for the editor inspector —
+//! the same `AppTypeRegistry` `prefab::spawn_prefab` reads component data
+//! from, reused here rather than building a second registry. The
+//! whitelist ([`whitelist_type_ids`]) is exactly "the gameplay state this
+//! crate already reflects": `Transform` (position/rotation/scale),
+//! `player::{Velocity, Jump, Grounded, Health, CoyoteBuffer, Facing,
+//! Abilities}`, `npc::{Npc, NpcReward}`, and `boss::BossPhase` — captured on
+//! every entity that carries any of them, which (through `Transform`) is
+//! nearly every entity, enemies included.
+//! [`world_flags::WorldFlags`] is the one gameplay resource that's both
+//! `Clone` and meaningfully mid-run state, so it's captured alongside the
+//! per-entity data rather than through the same reflection path.
+//!
+//! Deliberately excluded: render-only data (`Sprite`, `Handle<Image>`,
+//! `TextureAtlas`, `SpriteAnimation`'s frame-advance state) and gameplay
+//! state that isn't reflected yet (`collision::HitBox`/`Sensor`,
+//! `combo::Stompable`, most per-feature timers living inside
+//! non-`Reflect` resources like `challenge::ChallengeRun` itself) —
+//! widening the whitelist is just adding another `TypeId::of::<T>()` once
+//! a type picks up `#[reflect(Component)]`, but a resource needs its own
+//! explicit line here since it isn't looked up through the registry.
+//!
+//! [`restore`] only ever writes onto entities that were already alive when
+//! [`snapshot`] ran — it never spawns or despawns anything. An entity
+//! despawned after the snapshot (a `boss::Boss` beaten by
+//! `boss::check_defeated` or touched under `starman::Starman`) stays gone
+//! after a restore, and one spawned after it is left as it is.
+
+use std::any::TypeId;
+use std::collections::HashMap;
+
+use bevy::ecs::reflect::AppTypeRegistry;
+use bevy::prelude::*;
+
+use crate::boss::BossPhase;
+use crate::npc::{Npc, NpcReward};
+use crate::player::{Abilities, CoyoteBuffer, Facing, Grounded, Health, Jump, Velocity};
+use crate::world_flags::WorldFlags;
+
+fn whitelist_type_ids() -> [TypeId; 11] {
+    [
+        TypeId::of::<Transform>(),
+        TypeId::of::<Velocity>(),
+        TypeId::of::<Jump>(),
+        TypeId::of::<Grounded>(),
+        TypeId::of::<Health>(),
+        TypeId::of::<CoyoteBuffer>(),
+        TypeId::of::<Facing>(),
+        TypeId::of::<Abilities>(),
+        TypeId::of::<Npc>(),
+        TypeId::of::<NpcReward>(),
+        TypeId::of::<BossPhase>(),
+    ]
+}
+
+/// A point-in-time copy of every whitelisted component on every entity
+/// that has at least one of them, plus [`WorldFlags`]. Opaque on purpose —
+/// [`snapshot`]/[`restore`] are the only way to produce or consume one.
+pub struct GameSnapshot {
+    entities: HashMap<Entity, Vec<(TypeId, Box<dyn Reflect>)>>,
+    world_flags: WorldFlags,
+}
+
+/// Clones every whitelisted component off every entity in `world`, keyed
+/// by [`Entity`] so [`restore`] can write each value back onto the exact
+/// entity it came from.
+pub fn snapshot(world: &World) -> GameSnapshot {
+    let registry = world.resource::<AppTypeRegistry>().read();
+    let mut entities = HashMap::new();
+    for entity_ref in world.iter_entities() {
+        let mut components = Vec::new();
+        for type_id in whitelist_type_ids() {
+            let Some(registration) = registry.get(type_id) else {
+                continue;
+            };
+            let Some(reflect_component) = registration.data::<ReflectComponent>() else {
+                continue;
+            };
+            if let Some(reflected) = reflect_component.reflect(entity_ref) {
+                components.push((type_id, reflected.clone_value()));
+            }
+        }
+        if !components.is_empty() {
+            entities.insert(entity_ref.id(), components);
+        }
+    }
+    drop(registry);
+
+    GameSnapshot {
+        entities,
+        world_flags: world.resource::<WorldFlags>().clone(),
+    }
+}
+
+/// Writes `snapshot`'s captured values back onto `world`. Entities the
+/// snapshot has no record of are untouched; entities it does have a
+/// record for but that no longer exist are silently skipped (see this
+/// module's own doc comment on why nothing here re-spawns them).
+pub fn restore(world: &mut World, snapshot: &GameSnapshot) {
+    let registry = world.resource::<AppTypeRegistry>().clone();
+    let registry = registry.read();
+    for (&entity, components) in &snapshot.entities {
+        if !world.entities().contains(entity) {
+            continue;
+        }
+        let mut entity_mut = world.entity_mut(entity);
+        for (type_id, component) in components {
+            let Some(registration) = registry.get(*type_id) else {
+                continue;
+            };
+            let Some(reflect_component) = registration.data::<ReflectComponent>() else {
+                continue;
+            };
+            reflect_component.apply(&mut entity_mut, component.as_ref());
+        }
+    }
+    drop(registry);
+
+    *world.resource_mut::<WorldFlags>() = snapshot.world_flags.clone();
+}
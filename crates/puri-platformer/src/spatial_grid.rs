@@ -0,0 +1,59 @@
+//! Uniform grid broad-phase for static colliders. `collision::aabb` used to
+//! scan every solid for every moving body every frame — fine for a handful
+//! of demo-level solids, but O(bodies * solids) doesn't scale to a real
+//! level. Bucketing solids into cells means a body only checks the solids
+//! near it.
+
+use bevy::math::Vec2;
+use std::collections::HashMap;
+
+/// Index into whatever slice of `(Vec2 position, Vec2 size)` the grid was
+/// built from, not an entity — keeps this module usable outside Bevy's ECS
+/// (benchmarks, unit tests) as a plain data structure.
+pub struct SpatialGrid {
+    cell_size: f32,
+    cells: HashMap<(i32, i32), Vec<usize>>,
+}
+
+impl SpatialGrid {
+    pub fn build(colliders: &[(Vec2, Vec2)], cell_size: f32) -> Self {
+        let mut cells: HashMap<(i32, i32), Vec<usize>> = HashMap::new();
+        for (index, (pos, size)) in colliders.iter().enumerate() {
+            for cell in cells_covering(*pos, *size, cell_size) {
+                cells.entry(cell).or_default().push(index);
+            }
+        }
+        Self { cell_size, cells }
+    }
+
+    /// Indices of colliders sharing a cell with the query box. May include
+    /// a few extras near cell borders (a collider spanning two cells is
+    /// listed in both) — callers already run a precise overlap test on
+    /// each candidate, so over-reporting is harmless, under-reporting isn't.
+    pub fn query(&self, pos: Vec2, size: Vec2) -> impl Iterator<Item = usize> + '_ {
+        let mut seen = std::collections::HashSet::new();
+        cells_covering(pos, size, self.cell_size)
+            .into_iter()
+            .filter_map(|cell| self.cells.get(&cell))
+            .flatten()
+            .copied()
+            .filter(move |index| seen.insert(*index))
+    }
+}
+
+fn cells_covering(pos: Vec2, size: Vec2, cell_size: f32) -> Vec<(i32, i32)> {
+    let half = size / 2.0;
+    let min = ((pos - half) / cell_size).floor();
+    let max = ((pos + half) / cell_size).floor();
+    let mut cells = Vec::new();
+    let mut y = min.y as i32;
+    while y <= max.y as i32 {
+        let mut x = min.x as i32;
+        while x <= max.x as i32 {
+            cells.push((x, y));
+            x += 1;
+        }
+        y += 1;
+    }
+    cells
+}
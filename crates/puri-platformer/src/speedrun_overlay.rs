@@ -0,0 +1,188 @@
+//! Speedrunner-facing HUD: a live input display, the last jump's
+//! coyote/buffer window usage, and a session timer with per-level splits.
+//! Shares `debug_overlay`'s F3 flag rather than claiming a key of its own —
+//! there's no Settings screen anywhere in this crate to add a toggle to
+//! (`accessibility`'s own doc comment already covers that gap and its
+//! fallback of one debug key per toggle), and every `KeyCode::F1`-`F12`
+//! is already claimed by an existing debug toggle, so this reuses the one
+//! `DebugOverlayVisible` flag exactly the way `main.rs`'s hitbox gizmos
+//! already do instead of inventing a thirteenth key.
+//!
+//! There's no remappable `InputMap`/action layer anywhere in this crate to
+//! read from: `command_queue`'s own doc comment already documents that
+//! `attack`/`shield`/`interact` still read their `KeyCode`s directly
+//! rather than through a shared abstraction, and `prompt::InputIcons` (the
+//! closest thing to one) only labels the two actions this crate has a
+//! real onscreen prompt for (see its own doc comment). So this overlay
+//! shows [`prompt::InputIcons`]'s label for Interact and the same literal
+//! `KeyCode`s every other action's own module already checks for
+//! everything else — the same mixed reality every other reader of player
+//! input in this crate already lives with. Swapping these literals for a
+//! real remap lookup, if one is ever built, is a mechanical follow-up to
+//! whichever request adds it, not a redesign of this overlay.
+//!
+//! The jump line reads `player::JumpAnalytics`, filled in by
+//! `player::player_input` at the source the same way `debug_overlay`'s
+//! collision/animation counters are instrumented in `collision`/`animation`
+//! rather than recomputed here.
+//!
+//! The "icons" are bracketed text labels, not sprites: there's no icon
+//! spritesheet asset anywhere in this crate (`prompt`'s own doc comment
+//! already covers that gap for its own, narrower prompt labels), so text
+//! is the same honest stand-in it already settled on.
+//!
+//! [`SessionTimer`] is deliberately not `challenge::ChallengeBest`'s
+//! `Stopwatch`: that one only starts counting once `start_challenge` runs,
+//! and nothing calls that today outside challenge mode (see `challenge`'s
+//! own doc comment) — a speedrunner watching ordinary play needs a clock
+//! that's already running. It ticks on [`GameTime`] rather than `Res<Time>`
+//! so it freezes along with the rest of gameplay under `TimeScale(0.0)`,
+//! the same audit `time_scale`'s own doc comment describes for every other
+//! gameplay timer in this crate. [`SessionSplits`] records one entry per
+//! `level_select::LevelCompleted`; since this crate only ever has the one
+//! demo level (`level_select`'s own doc comment covers why), there's only
+//! ever one split to show until a second level exists to complete.
+
+use std::fmt::Write;
+
+use bevy::core::FrameCount;
+use bevy::prelude::*;
+
+use crate::attack::ATTACK_KEY;
+use crate::debug_overlay::{overlay_visible, DebugOverlayVisible};
+use crate::level_select::LevelCompleted;
+use crate::player::JumpAnalytics;
+use crate::prompt::{InputIcons, LastUsedDevice, PromptAction};
+use crate::shield::SHIELD_KEY;
+use crate::time_scale::GameTime;
+use crate::ui_scale::SafeAreaAnchor;
+
+/// Total time this session has spent unpaused, per this module's own doc
+/// comment on why it's independent of `challenge::ChallengeBest`.
+#[derive(Resource, Default)]
+pub struct SessionTimer {
+    pub elapsed_seconds: f32,
+}
+
+/// One `(level id, session time at completion)` pair per
+/// `level_select::LevelCompleted`, oldest first.
+#[derive(Resource, Default)]
+pub struct SessionSplits(pub Vec<(u32, f32)>);
+
+#[derive(Component)]
+struct SpeedrunOverlayText;
+
+pub struct SpeedrunOverlayPlugin;
+
+impl Plugin for SpeedrunOverlayPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SessionTimer>()
+            .init_resource::<SessionSplits>()
+            .add_systems(Startup, spawn_overlay)
+            .add_systems(Update, (tick_session_timer, record_splits, sync_overlay_visibility))
+            .add_systems(PostUpdate, update_overlay_text.run_if(overlay_visible));
+    }
+}
+
+fn spawn_overlay(mut commands: Commands) {
+    commands.spawn((
+        SpeedrunOverlayText,
+        SafeAreaAnchor { top: Some(8.0), left: Some(8.0), ..default() },
+        Visibility::Hidden,
+        TextBundle {
+            text: Text::from_sections([
+                TextSection::from_style(TextStyle::default()),
+                TextSection::from_style(TextStyle::default()),
+                TextSection::from_style(TextStyle::default()),
+            ]),
+            style: Style {
+                position_type: PositionType::Absolute,
+                top: Val::Px(8.0),
+                left: Val::Px(8.0),
+                ..default()
+            },
+            ..default()
+        },
+    ));
+}
+
+fn tick_session_timer(time: GameTime, mut timer: ResMut<SessionTimer>) {
+    timer.elapsed_seconds += time.delta_seconds();
+}
+
+fn record_splits(mut completed: EventReader<LevelCompleted>, timer: Res<SessionTimer>, mut splits: ResMut<SessionSplits>) {
+    for event in completed.read() {
+        splits.0.push((event.id, timer.elapsed_seconds));
+    }
+}
+
+/// Mirrors `main.rs`'s hitbox gizmos: an independent consumer of the same
+/// shared flag, rather than `debug_overlay::toggle_overlay` needing to know
+/// this module's marker component exists.
+fn sync_overlay_visibility(visible: Res<DebugOverlayVisible>, mut text: Query<&mut Visibility, With<SpeedrunOverlayText>>) {
+    if !visible.is_changed() {
+        return;
+    }
+    for mut node_visibility in &mut text {
+        *node_visibility = if visible.0 { Visibility::Inherited } else { Visibility::Hidden };
+    }
+}
+
+fn update_overlay_text(
+    keys: Res<ButtonInput<KeyCode>>,
+    frame: Res<FrameCount>,
+    icons: Res<InputIcons>,
+    device: Res<LastUsedDevice>,
+    analytics: Res<JumpAnalytics>,
+    timer: Res<SessionTimer>,
+    splits: Res<SessionSplits>,
+    mut overlay: Query<&mut Text, With<SpeedrunOverlayText>>,
+) {
+    let Ok(mut text) = overlay.get_single_mut() else {
+        return;
+    };
+
+    let interact_label = icons.label(PromptAction::Interact, device.0);
+    let actions = [
+        ("LEFT", keys.pressed(KeyCode::ArrowLeft) || keys.pressed(KeyCode::KeyA)),
+        ("RIGHT", keys.pressed(KeyCode::ArrowRight) || keys.pressed(KeyCode::KeyD)),
+        ("JUMP", keys.pressed(KeyCode::Space)),
+        ("DASH", keys.pressed(KeyCode::ShiftLeft)),
+        ("ATTACK", keys.pressed(ATTACK_KEY)),
+        ("SHIELD", keys.pressed(SHIELD_KEY)),
+        (interact_label, keys.pressed(KeyCode::KeyE)),
+        ("GRAPPLE", keys.pressed(KeyCode::KeyF)),
+    ];
+
+    let row = &mut text.sections[0].value;
+    row.clear();
+    for (label, pressed) in actions {
+        if pressed {
+            let _ = write!(row, "[{label}@{}] ", frame.0);
+        } else {
+            let _ = write!(row, "{label} ");
+        }
+    }
+
+    let jump_line = &mut text.sections[1].value;
+    jump_line.clear();
+    match analytics.last_jump_tick {
+        Some(tick) => {
+            let _ = write!(
+                jump_line,
+                "\nlast jump (tick {tick}): buffered {} ticks, coyote used {} ticks",
+                analytics.buffered_ticks, analytics.coyote_ticks,
+            );
+        }
+        None => {
+            let _ = write!(jump_line, "\nlast jump: none yet");
+        }
+    }
+
+    let timer_line = &mut text.sections[2].value;
+    timer_line.clear();
+    let _ = write!(timer_line, "\nsession {:.2}s", timer.elapsed_seconds);
+    if let Some((id, split_time)) = splits.0.last() {
+        let _ = write!(timer_line, "  |  split (level {id}): {split_time:.2}s");
+    }
+}
@@ -0,0 +1,179 @@
+//! `PowerUp::Star` — pick up a [`StarPickup`] for a timed window of
+//! invincibility, tracked on the player as [`Starman`].
+//!
+//! `Starman` doesn't touch `music`'s stems, so the music doesn't change
+//! while it's active. [`defeat_enemies_on_starman_contact`] only checks
+//! `boss::Boss`: touching a `Boss` while `Starman` is active despawns it
+//! exactly the way `boss::check_defeated` does on a normal kill, while
+//! `prefab::Patroller`/`Flyer`/`Fish` enemies and `turret::Turret` are left
+//! alone. "Hazards are ignored" and "contacts don't damage the player" both
+//! reduce to the same thing, since `fall_damage::DamageEvent` is the only
+//! way the player takes damage — [`suppress_damage_while_starman`] (called
+//! from `death::apply_damage`) drops every `DamageEvent` while `Starman` is
+//! active, fall damage, projectile hits and crushes included.
+//!
+//! There's also no `Paused` state anywhere in this crate — `time_scale`'s
+//! own doc comment explains that freezing gameplay without freezing UI is
+//! already done by driving gameplay timers off `GameTime` instead of
+//! `Res<Time>` (see [`tick_starman`]), so setting `TimeScale` to `0.0` *is*
+//! this crate's pause, and the countdown already stops with it for free.
+//! `tests/game_time_pause_audit.rs` exercises exactly that: it freezes
+//! `TimeScale` mid-`Starman` and asserts [`Starman::remaining_secs`] hasn't
+//! moved.
+
+use bevy::prelude::*;
+
+use crate::boss::{Boss, BossDefeated};
+use crate::collision::HitBox;
+use crate::death::Dying;
+use crate::player::Player;
+use crate::schedule::PlatformerSet;
+use crate::time_scale::GameTime;
+use crate::world_flags::{WorldFlagId, WorldFlags};
+
+pub const STAR_DURATION_SECONDS: f32 = 8.0;
+const BLINK_WARNING_SECONDS: f32 = 2.0;
+/// How many times per second the sprite alternates during the blink
+/// warning window.
+const BLINK_HZ: f32 = 8.0;
+/// Full hue cycles per second of the rainbow tint outside the blink
+/// window.
+const RAINBOW_CYCLES_PER_SECOND: f32 = 1.0;
+
+/// Present on the player for the duration of a star's invincibility.
+/// Removed by [`tick_starman`] when it finishes, and explicitly by
+/// `death::start_dying` on death — a checkpoint touch doesn't remove it,
+/// only dying does.
+#[derive(Component)]
+pub struct Starman(Timer);
+
+impl Starman {
+    pub fn new() -> Self {
+        Self(Timer::from_seconds(STAR_DURATION_SECONDS, TimerMode::Once))
+    }
+
+    /// Inside the last [`BLINK_WARNING_SECONDS`] of the timer.
+    pub fn is_blinking(&self) -> bool {
+        self.0.remaining_secs() <= BLINK_WARNING_SECONDS
+    }
+
+    /// Exposed for `tests/game_time_pause_audit.rs`, which needs to observe
+    /// the underlying `Timer` staying put while `TimeScale` is `0.0`.
+    pub fn remaining_secs(&self) -> f32 {
+        self.0.remaining_secs()
+    }
+}
+
+/// A star sitting in the level, ready to be picked up.
+#[derive(Component)]
+pub struct StarPickup;
+
+pub struct StarmanPlugin;
+
+impl Plugin for StarmanPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                collect_star_pickup,
+                tick_starman,
+                defeat_enemies_on_starman_contact,
+                animate_starman_tint,
+                reset_tint_on_starman_end,
+            )
+                .chain()
+                .in_set(PlatformerSet::PostPhysics),
+        );
+    }
+}
+
+/// A pickup carrying a [`WorldFlagId`] sets it on collection — see that
+/// module's own doc comment on why a star is the one pickup wired up to
+/// persist "already collected" across a level reload.
+fn collect_star_pickup(
+    mut commands: Commands,
+    mut world_flags: ResMut<WorldFlags>,
+    player: Query<(Entity, &Transform), (With<Player>, Without<Starman>, Without<Dying>)>,
+    pickups: Query<(Entity, &Transform, &HitBox, Option<&WorldFlagId>), With<StarPickup>>,
+) {
+    let Ok((player_entity, player_transform)) = player.get_single() else {
+        return;
+    };
+    for (pickup_entity, pickup_transform, hitbox, flag_id) in &pickups {
+        let overlapping = (player_transform.translation.truncate() - pickup_transform.translation.truncate())
+            .abs()
+            .cmplt(hitbox.size / 2.0)
+            .all();
+        if overlapping {
+            if let Some(flag_id) = flag_id {
+                world_flags.set(flag_id.0);
+                #[cfg(feature = "serde")]
+                crate::world_flags::save_to_disk(&world_flags);
+            }
+            commands.entity(pickup_entity).despawn_recursive();
+            commands.entity(player_entity).insert(Starman::new());
+        }
+    }
+}
+
+fn tick_starman(time: GameTime, mut commands: Commands, mut player: Query<(Entity, &mut Starman)>) {
+    let Ok((entity, mut starman)) = player.get_single_mut() else {
+        return;
+    };
+    starman.0.tick(time.delta());
+    if starman.0.finished() {
+        commands.entity(entity).remove::<Starman>();
+    }
+}
+
+/// The one enemy-contact rule this crate can implement today — see this
+/// module's own doc comment on why it's scoped to `Boss` alone.
+fn defeat_enemies_on_starman_contact(
+    mut commands: Commands,
+    player: Query<(&Transform, &HitBox), (With<Player>, With<Starman>)>,
+    bosses: Query<(Entity, &Transform, &HitBox), With<Boss>>,
+    mut defeated_events: EventWriter<BossDefeated>,
+) {
+    let Ok((player_transform, player_box)) = player.get_single() else {
+        return;
+    };
+    for (entity, boss_transform, boss_box) in &bosses {
+        let touch_distance = (player_box.size + boss_box.size) / 2.0;
+        let overlapping = (player_transform.translation.truncate() - boss_transform.translation.truncate())
+            .abs()
+            .cmplt(touch_distance)
+            .all();
+        if overlapping {
+            commands.entity(entity).despawn_recursive();
+            defeated_events.send(BossDefeated);
+        }
+    }
+}
+
+/// Whether `event.amount` should be dropped instead of applied to
+/// `Health` — called from `death::apply_damage` so invincibility gates the
+/// crate's one damage pipeline from a single place.
+pub fn suppress_damage_while_starman(starman: Option<&Starman>) -> bool {
+    starman.is_some()
+}
+
+fn animate_starman_tint(time: Res<Time>, mut player: Query<(&Starman, &mut Sprite)>) {
+    let Ok((starman, mut sprite)) = player.get_single_mut() else {
+        return;
+    };
+    if starman.is_blinking() {
+        let blink_on = (time.elapsed_seconds() * BLINK_HZ) as u32 % 2 == 0;
+        sprite.color = if blink_on { Color::WHITE } else { Color::rgb(1.0, 0.3, 0.3) };
+    } else {
+        let hue = (time.elapsed_seconds() * RAINBOW_CYCLES_PER_SECOND * 360.0) % 360.0;
+        sprite.color = Color::hsl(hue, 1.0, 0.5);
+    }
+}
+
+fn reset_tint_on_starman_end(mut removed: RemovedComponents<Starman>, mut sprites: Query<&mut Sprite>) {
+    for entity in removed.read() {
+        if let Ok(mut sprite) = sprites.get_mut(entity) {
+            sprite.color = Color::WHITE;
+        }
+    }
+}
@@ -0,0 +1,1874 @@
+//! Headless harness for exercising gameplay systems without a window,
+//! renderer, or real assets. Used by integration tests in `tests/`.
+
+use bevy::input::gamepad::{
+    GamepadConnection, GamepadConnectionEvent, GamepadInfo, GamepadRumbleRequest,
+};
+use bevy::prelude::*;
+
+use crate::accessibility::{AccessibilityOptions, Palette};
+use crate::achievements::{AchievementBackendRes, AchievementId, AchievementProgress};
+use crate::ambience::{DayNightCycle, LevelAmbience};
+use crate::animation::{self, Animations, FrameTime, SpriteAnimation};
+use crate::attack::{AttackPhase, Attacking, Hurtbox};
+use crate::challenge::{ChallengeBest, ChallengeFinished, ChallengeRun, Medal, RetrySnapshot};
+use crate::combo::{ComboCount, EnemyDefeated};
+use crate::cutscene::{ActorId, CutscenePlaying};
+use crate::death::{Checkpoint, Dying, LastCheckpoint, PlayerDied};
+use crate::difficulty_assist::{AssistBridge, DifficultyAssist};
+#[cfg(feature = "serde")]
+use crate::enemy_ai::{HitEvent, Hitstun};
+use crate::equipment::{Equipment, EquipmentOverlay, EquipmentSlot};
+use crate::fall_damage::DamageEvent;
+use crate::floating_text::FloatingText;
+use crate::grapple::GrapplePoint;
+use crate::interact::{InteractPrompt, Interactable};
+use crate::launch_options::LaunchOptions;
+use crate::level_load::LevelLoadState;
+use crate::level_reload::LevelReloadRequested;
+use crate::level_select::{
+    LevelCoinBest, LevelCompleted, LevelSelect, LevelSelectPrompt, LevelUnlocks,
+};
+use crate::lighting::{Light2d, LightCulled};
+use crate::localization::Localization;
+use crate::loot::{LootDrop, LootKind};
+use crate::map::LevelEntity;
+use crate::minimap::{MinimapGrid, MinimapOptions, MinimapVisible};
+use crate::music::MusicTrack;
+use crate::npc::{ExclamationIndicator, Npc};
+use crate::objectives::{ExitGate, Objectives, ObjectivesHudText};
+use crate::physics_config::PlayerPhysicsConfig;
+use crate::player::{
+    Abilities, BodyPart, DamageKind, Facing, Grounded, Health, JumpAnalytics, PartSlot, Player, PlayerSpawner, Resistances, Velocity,
+};
+use crate::player_state::{PlayerState, PlayerStateChanged};
+use crate::pool::Pooled;
+#[cfg(feature = "serde")]
+use crate::prefab::{Fish, Flyer, Patroller};
+use crate::projectile::Projectile;
+use crate::results::{LevelStats, ResultsScreen};
+use crate::rhythm::{BeatActive, BeatSynced, BeatTelegraph, Crusher, MusicClock};
+use crate::rumble::RumbleSettings;
+use crate::save::{ActiveSlot, DeleteSlotRequested, ProfileSelect, SaveManager, SaveSlotData};
+use crate::shield::{Shield, Stamina};
+use crate::shop::{ShopKeeper, ShopMenu, UpgradeId, Wallet};
+use crate::starman::{StarPickup, Starman};
+use crate::time_scale::TimeScale;
+use crate::toast::{ToastEvent, ToastText};
+use crate::turret::Turret;
+use crate::water::Water;
+use crate::world_flags::WorldFlags;
+use crate::PlatformerPlugins;
+
+/// A keyboard-driven action the harness can simulate a press for.
+pub enum Action {
+    Left,
+    Right,
+    Jump,
+    Dash,
+    Interact,
+    MenuUp,
+    MenuDown,
+    MenuCancel,
+    Shield,
+    Attack,
+    Grapple,
+    Crouch,
+    UnequipBoots,
+    UnequipCharm,
+}
+
+impl Action {
+    fn key(&self) -> KeyCode {
+        match self {
+            Action::Left => KeyCode::ArrowLeft,
+            Action::Right => KeyCode::ArrowRight,
+            Action::Jump => KeyCode::Space,
+            Action::Dash => KeyCode::ShiftLeft,
+            Action::Interact => KeyCode::KeyE,
+            Action::MenuUp => KeyCode::ArrowUp,
+            Action::MenuDown => KeyCode::ArrowDown,
+            Action::MenuCancel => KeyCode::Escape,
+            Action::Shield => crate::shield::SHIELD_KEY,
+            Action::Attack => crate::attack::ATTACK_KEY,
+            Action::Grapple => KeyCode::KeyF,
+            Action::Crouch => crate::crouch::CROUCH_KEY,
+            Action::UnequipBoots => KeyCode::Digit1,
+            Action::UnequipCharm => KeyCode::Digit2,
+        }
+    }
+}
+
+/// Whether `animate_sprite` wrote to the player's `TextureAtlas` on the
+/// most recent `PostUpdate`. A one-off `world.query()` can't answer this:
+/// its `QueryState` has no prior `last_run` to compare against, so the
+/// very first read always looks changed. Tracking it via a real system
+/// with its own persistent `Local`/param state (recorded every tick in
+/// `PostUpdate`, after `AnimationSet` has run) gives an honest answer.
+#[derive(Resource, Default)]
+struct AtlasChangedFlag(bool);
+
+fn record_atlas_changed(
+    mut flag: ResMut<AtlasChangedFlag>,
+    query: Query<Ref<TextureAtlas>, With<Player>>,
+) {
+    if let Ok(atlas) = query.get_single() {
+        flag.0 = atlas.is_changed();
+    }
+}
+
+/// A minimal `App` running the real gameplay plugins on a fixed 60Hz tick,
+/// with stub sprite handles so no assets are ever loaded from disk.
+pub struct TestWorld {
+    app: App,
+}
+
+impl TestWorld {
+    pub fn new() -> Self {
+        Self::with_options(LaunchOptions::default())
+    }
+
+    /// Like [`TestWorld::new`], but seeds `GameRng` deterministically
+    /// instead of from the wall clock — for tests asserting that two runs
+    /// with the same seed and input script produce identical results.
+    pub fn with_seed(seed: u64) -> Self {
+        Self::with_options(LaunchOptions {
+            seed: Some(seed),
+            ..default()
+        })
+    }
+
+    fn with_options(options: LaunchOptions) -> Self {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugins(bevy::input::InputPlugin)
+            .init_resource::<Assets<Image>>()
+            .init_resource::<Assets<TextureAtlasLayout>>()
+            .insert_resource(Animations::stub())
+            .insert_resource(options)
+            .init_resource::<AtlasChangedFlag>()
+            .add_systems(PostUpdate, record_atlas_changed)
+            .add_plugins(PlatformerPlugins);
+
+        // Run Startup once so the player and map are spawned.
+        app.update();
+
+        Self { app }
+    }
+
+    /// Holds `action` down for the next tick by setting `ButtonInput` state
+    /// directly, mirroring how a real key press would be observed.
+    pub fn press(&mut self, action: Action) {
+        let mut keys = self.app.world.resource_mut::<ButtonInput<KeyCode>>();
+        keys.press(action.key());
+    }
+
+    /// Releases `action`'s key, needed between two separate taps of the
+    /// same key (e.g. a toggle) since [`ButtonInput::just_pressed`] only
+    /// fires again once the key has actually gone back up.
+    pub fn release(&mut self, action: Action) {
+        let mut keys = self.app.world.resource_mut::<ButtonInput<KeyCode>>();
+        keys.release(action.key());
+    }
+
+    pub fn step(&mut self, ticks: u32) {
+        for _ in 0..ticks {
+            self.app.update();
+            let mut keys = self.app.world.resource_mut::<ButtonInput<KeyCode>>();
+            keys.clear();
+        }
+    }
+
+    /// Despawns the player entity, for regression tests that a mid-frame
+    /// despawn (death, level transition) doesn't panic a `single()` call
+    /// somewhere downstream.
+    pub fn despawn_player(&mut self) {
+        let mut query = self.app.world.query_filtered::<Entity, With<Player>>();
+        let entity = query.single(&self.app.world);
+        self.app.world.despawn(entity);
+    }
+
+    /// Replaces the plain-sprite player `TestWorld::new` spawned with a
+    /// `.composite()` one at the same position, split across the demo
+    /// `"npc"`/`"player"` atlases — see `player::PlayerSpawner::composite`'s
+    /// own doc comment on why that isn't `spawn_player`'s default. Despawns
+    /// the original first rather than spawning a second one alongside it,
+    /// since every other `With<Player>` singleton helper in this harness
+    /// would panic the moment two entities carried `Player` at once.
+    pub fn respawn_player_as_composite(&mut self) {
+        let pos = self.player_pos();
+        self.despawn_player();
+
+        let animations = self.app.world.resource::<Animations>();
+        let mut queue = bevy::ecs::system::CommandQueue::default();
+        {
+            let mut commands = Commands::new(&mut queue, &self.app.world);
+            PlayerSpawner::new(animations)
+                .composite(animations, "npc", "player")
+                .position(pos)
+                .spawn(&mut commands);
+        }
+        queue.apply(&mut self.app.world);
+    }
+
+    /// Overwrites the player's `Facing` directly, for asserting
+    /// `player::sync_composite_facing` flips its `.composite()` children to
+    /// match without needing to walk the player around first.
+    pub fn set_player_facing(&mut self, facing: Facing) {
+        let mut query = self.app.world.query_filtered::<&mut Facing, With<Player>>();
+        *query.single_mut(&mut self.app.world) = facing;
+    }
+
+    /// `(BodyPart, sprite.flip_x, atlas.index)` for every `.composite()`
+    /// child of the player, in no particular order.
+    pub fn composite_parts(&mut self) -> Vec<(BodyPart, bool, usize)> {
+        let mut query = self
+            .app
+            .world
+            .query::<(&PartSlot, &Sprite, &TextureAtlas)>();
+        query
+            .iter(&self.app.world)
+            .map(|(slot, sprite, atlas)| (slot.0, sprite.flip_x, atlas.index))
+            .collect()
+    }
+
+    /// Swaps the player's animation clip via `set_animation`, for testing
+    /// the index-overflow fix across clips with different frame counts.
+    pub fn set_player_animation(&mut self, clip: SpriteAnimation) {
+        let mut query = self
+            .app
+            .world
+            .query_filtered::<(&mut SpriteAnimation, &mut FrameTime, &mut TextureAtlas), With<Player>>();
+        let (mut animation, mut frame_time, mut atlas) = query.single_mut(&mut self.app.world);
+        let layout = atlas.layout.clone();
+        animation::set_animation(&mut animation, &mut frame_time, &mut atlas, layout, clip);
+    }
+
+    /// Sets `TextureAtlas::index` directly, bypassing `set_animation`, to
+    /// simulate a stale index left over before a clip swap.
+    pub fn set_player_atlas_index(&mut self, index: usize) {
+        let mut query = self
+            .app
+            .world
+            .query_filtered::<&mut TextureAtlas, With<Player>>();
+        query.single_mut(&mut self.app.world).index = index;
+    }
+
+    pub fn player_atlas_index(&mut self) -> usize {
+        let mut query = self
+            .app
+            .world
+            .query_filtered::<&TextureAtlas, With<Player>>();
+        query.single(&self.app.world).index
+    }
+
+    /// Whether `animate_sprite` marked the player's `TextureAtlas` as
+    /// `Changed` on the tick that just ran, for asserting it skips the
+    /// write (and so the change-detection flag) on ticks where the frame
+    /// doesn't actually advance.
+    pub fn player_atlas_changed(&mut self) -> bool {
+        self.app.world.resource::<AtlasChangedFlag>().0
+    }
+
+    pub fn player_pos(&mut self) -> Vec2 {
+        let mut query = self.app.world.query_filtered::<&Transform, With<Player>>();
+        query.single(&self.app.world).translation.truncate()
+    }
+
+    /// Teleports the player, for tests that need it next to a specific
+    /// entity (an NPC, an interactable) without walking it there tick by
+    /// tick.
+    pub fn set_player_pos(&mut self, pos: Vec2) {
+        let mut query = self
+            .app
+            .world
+            .query_filtered::<&mut Transform, With<Player>>();
+        query.single_mut(&mut self.app.world).translation = pos.extend(0.0);
+    }
+
+    pub fn player_abilities(&mut self) -> Abilities {
+        let mut query = self.app.world.query_filtered::<&Abilities, With<Player>>();
+        *query.single(&self.app.world)
+    }
+
+    pub fn player_resistances(&mut self) -> Resistances {
+        let mut query = self.app.world.query_filtered::<&Resistances, With<Player>>();
+        query.single(&self.app.world).clone()
+    }
+
+    pub fn player_equipment(&mut self) -> Equipment {
+        let mut query = self.app.world.query_filtered::<&Equipment, With<Player>>();
+        query.single(&self.app.world).clone()
+    }
+
+    /// Whether `sync_equipment_overlay` has spawned the visual child for
+    /// `slot`, for asserting the overlay tracks `Equipment` rather than
+    /// just checking the component's own state.
+    pub fn equipment_overlay_present(&mut self, slot: EquipmentSlot) -> bool {
+        let mut query = self.app.world.query::<&EquipmentOverlay>();
+        query.iter(&self.app.world).any(|overlay| overlay.0 == slot)
+    }
+
+    pub fn player_velocity(&mut self) -> Vec2 {
+        let mut query = self.app.world.query::<(&Player, &Velocity)>();
+        query.single(&self.app.world).1 .0
+    }
+
+    /// Overwrites the player's `Velocity` directly, for tests that need a
+    /// specific fall speed (e.g. stomping a turret) without waiting for
+    /// gravity to build it up tick by tick.
+    pub fn set_player_velocity(&mut self, velocity: Vec2) {
+        let mut query = self
+            .app
+            .world
+            .query_filtered::<&mut Velocity, With<Player>>();
+        query.single_mut(&mut self.app.world).0 = velocity;
+    }
+
+    /// Sends a `DamageEvent` for tests that need to drive the player to
+    /// death without going through fall damage or combat.
+    pub fn deal_damage(&mut self, amount: u32) {
+        self.deal_damage_kind(amount, DamageKind::Contact);
+    }
+
+    /// Like [`deal_damage`](Self::deal_damage), but with an explicit
+    /// `DamageKind` for tests exercising `player::Resistances`.
+    pub fn deal_damage_kind(&mut self, amount: u32, kind: DamageKind) {
+        let mut events = self.app.world.resource_mut::<Events<DamageEvent>>();
+        events.send(DamageEvent { amount, kind });
+    }
+
+    pub fn player_is_dying(&mut self) -> bool {
+        let mut query = self
+            .app
+            .world
+            .query_filtered::<(), (With<Player>, With<Dying>)>();
+        query.get_single(&self.app.world).is_ok()
+    }
+
+    /// `None` while the player isn't mid-swing.
+    pub fn player_attack_phase(&mut self) -> Option<AttackPhase> {
+        let mut query = self.app.world.query_filtered::<&Attacking, With<Player>>();
+        query
+            .get_single(&self.app.world)
+            .ok()
+            .map(|attacking| attacking.phase)
+    }
+
+    /// The `map::spawn_map_entities` training dummy's remaining `Health`.
+    pub fn training_dummy_health(&mut self) -> u32 {
+        let mut query = self.app.world.query_filtered::<&Health, With<Hurtbox>>();
+        query.single(&self.app.world).0
+    }
+
+    /// Spawns a bare `Patroller` standing on the ground already (see
+    /// `npc.rs`'s own note on why a `GroundedBody` needs to start grounded
+    /// rather than dropped in midair — `enemy_ai`'s own gravity only runs
+    /// while `Hitstun`'d, so this is no different).
+    #[cfg(feature = "serde")]
+    pub fn spawn_patroller(&mut self, pos: Vec2, speed: f32, range: f32) -> Entity {
+        self.app
+            .world
+            .spawn((
+                Patroller { speed, range },
+                crate::collision::GroundedBody,
+                crate::collision::HitBox {
+                    size: Vec2::new(24.0, 32.0),
+                },
+                Health(10),
+                Velocity(Vec2::ZERO),
+                Transform::from_translation(pos.extend(0.0)),
+                GlobalTransform::default(),
+            ))
+            .id()
+    }
+
+    /// Spawns a bare `Flyer` — no `Health`, since nothing fires a `HitEvent`
+    /// at one (see `enemy_ai`'s own doc comment on that being `Patroller`-only).
+    #[cfg(feature = "serde")]
+    pub fn spawn_flyer(&mut self, pos: Vec2, flyer: Flyer) -> Entity {
+        self.app
+            .world
+            .spawn((
+                flyer,
+                crate::collision::GroundedBody,
+                crate::collision::HitBox {
+                    size: Vec2::new(20.0, 16.0),
+                },
+                Velocity(Vec2::ZERO),
+                Transform::from_translation(pos.extend(0.0)),
+                GlobalTransform::default(),
+            ))
+            .id()
+    }
+
+    /// Spawns a bare `Fish` at `pos`, for tests that don't want to route
+    /// through `prefab::spawn_prefab` and `assets/prefabs/fish.ron`.
+    #[cfg(feature = "serde")]
+    pub fn spawn_fish(&mut self, pos: Vec2, speed: f32) -> Entity {
+        self.app
+            .world
+            .spawn((
+                Fish { speed },
+                crate::collision::GroundedBody,
+                crate::collision::HitBox {
+                    size: Vec2::new(16.0, 12.0),
+                },
+                Velocity(Vec2::ZERO),
+                Transform::from_translation(pos.extend(0.0)),
+                GlobalTransform::default(),
+            ))
+            .id()
+    }
+
+    /// Spawns a `water::Water` zone, for tests exercising `enemy_ai::swim`
+    /// without `water::spawn_demo_pool`'s own fixed size/current/visuals.
+    pub fn spawn_water(&mut self, pos: Vec2, size: Vec2) -> Entity {
+        self.app
+            .world
+            .spawn((
+                Water { size, current: Vec2::ZERO },
+                Transform::from_translation(pos.extend(0.0)),
+                GlobalTransform::default(),
+            ))
+            .id()
+    }
+
+    /// Whether `entity` currently has `player::Grounded` — absent for a
+    /// `MovementMode::Flying` body even mid-collision, see that type's own
+    /// doc comment.
+    pub fn is_grounded(&mut self, entity: Entity) -> bool {
+        self.app.world.get::<Grounded>(entity).is_some()
+    }
+
+    /// Spawns an active `map::MovingPlatform`, for tests exercising its
+    /// path-following mode without going through `spawn_script_platform`'s
+    /// lever-toggled, initially-inactive one.
+    pub fn spawn_moving_platform(&mut self, pos: Vec2, speed: f32) -> Entity {
+        self.app
+            .world
+            .spawn((
+                crate::map::MovingPlatform::new(pos, true, speed, 0.0),
+                crate::collision::HitBox {
+                    size: Vec2::new(32.0, 16.0),
+                },
+                Transform::from_translation(pos.extend(0.0)),
+                GlobalTransform::default(),
+            ))
+            .id()
+    }
+
+    /// Spawns a `camera_rail::CameraRail` whose zone spans `[from, to]`
+    /// widened by `zone_size`, for tests that don't want to walk the demo
+    /// level's own `camera_rail::spawn_demo_rail` corridor to reach it.
+    pub fn spawn_camera_rail(&mut self, from: Vec2, to: Vec2, speed: f32, zone_size: Vec2) -> Entity {
+        let center = from.lerp(to, 0.5);
+        self.app
+            .world
+            .spawn((
+                crate::camera_rail::CameraRail {
+                    path: crate::map::Path {
+                        points: vec![from, to],
+                        ..Default::default()
+                    },
+                    speed,
+                },
+                crate::collision::Sensor,
+                crate::collision::HitBox { size: zone_size },
+                Transform::from_translation(center.extend(0.0)),
+                GlobalTransform::default(),
+            ))
+            .id()
+    }
+
+    /// The `Camera2d`'s current world position, for asserting
+    /// `camera_rail::advance_rail_camera` (or `camera::camera_follow`) moved
+    /// it where expected.
+    pub fn camera_pos(&mut self) -> Vec2 {
+        let mut query = self.app.world.query_filtered::<&Transform, With<Camera2d>>();
+        query.single(&self.app.world).translation.truncate()
+    }
+
+    /// Overwrites `OrthographicProjection::area` directly. Without a real
+    /// window, nothing ever runs bevy's own viewport-driven update for that
+    /// field, so it sits at `OrthographicProjection::default`'s tiny
+    /// placeholder rect for the life of a `TestWorld` — this stands in for
+    /// what `bevy_render`'s camera system would compute from a live window,
+    /// so `camera_rail::constrain_to_rail` has a realistic visible rect to
+    /// clamp against.
+    pub fn set_camera_area(&mut self, min: Vec2, max: Vec2) {
+        let mut query = self.app.world.query_filtered::<&mut OrthographicProjection, With<Camera2d>>();
+        query.single_mut(&mut self.app.world).area = Rect { min, max };
+    }
+
+    /// Overwrites `weather::Weather` directly, standing in for a level (or
+    /// `script::Action::SetWeather`) setting it.
+    pub fn set_weather(&mut self, weather: crate::weather::Weather) {
+        self.app.world.insert_resource(weather);
+    }
+
+    /// `weather::Weather`'s current value, for asserting a
+    /// `script::Action::SetWeather` tween landed where expected.
+    pub fn weather(&mut self) -> crate::weather::Weather {
+        *self.app.world.resource::<crate::weather::Weather>()
+    }
+
+    /// How many `weather::WeatherParticle`s are currently out of the pool
+    /// and falling, for asserting `weather::spawn_particles` responds to
+    /// `Weather`'s intensity.
+    pub fn active_particle_count(&mut self) -> usize {
+        let mut query = self
+            .app
+            .world
+            .query_filtered::<(), (With<crate::weather::WeatherParticle>, Without<Pooled>)>();
+        query.iter(&self.app.world).count()
+    }
+
+    /// The alpha of the demo floor's `weather::SnowCap` overlay sprite, for
+    /// asserting `weather::grow_snow_cover` chases it toward `Weather::Snow`'s
+    /// intensity (and back down once the snow stops).
+    pub fn snow_cap_alpha(&mut self) -> f32 {
+        let mut query = self.app.world.query_filtered::<&Sprite, With<crate::weather::SnowCap>>();
+        query.iter(&self.app.world).next().map_or(0.0, |sprite| sprite.color.a())
+    }
+
+    /// Sets `MusicTrack::bpm` directly, for tests that want `MusicClock` to
+    /// advance at a specific, easy-to-check rate rather than whatever
+    /// `MusicTrack::demo`'s 120 happens to be.
+    pub fn set_music_bpm(&mut self, bpm: f32) {
+        self.app.world.resource_mut::<MusicTrack>().bpm = bpm;
+    }
+
+    pub fn music_beat(&mut self) -> usize {
+        self.app.world.resource::<MusicClock>().beat()
+    }
+
+    /// Spawns a bare `Crusher` with a `BeatSynced` pattern, for tests that
+    /// don't want to route through `prefab::spawn_prefab` and the
+    /// `assets/prefabs/crusher.ron` file it reads.
+    pub fn spawn_crusher(&mut self, pos: Vec2, pattern: Vec<bool>) -> Entity {
+        self.app
+            .world
+            .spawn((
+                Crusher,
+                BeatSynced { pattern, phase: 0 },
+                Transform::from_translation(pos.extend(0.0)),
+                GlobalTransform::default(),
+            ))
+            .id()
+    }
+
+    /// Whether `rhythm::sync_beat_hazards` currently has `entity` on an
+    /// active beat.
+    pub fn is_beat_active(&mut self, entity: Entity) -> bool {
+        self.app.world.get::<BeatActive>(entity).is_some()
+    }
+
+    /// Whether `rhythm::sync_beat_hazards` currently has `entity` telegraphing
+    /// — one beat away from going active.
+    pub fn is_beat_telegraphing(&mut self, entity: Entity) -> bool {
+        self.app.world.get::<BeatTelegraph>(entity).is_some()
+    }
+
+    /// Attaches a `map::Path` to any entity, for tests exercising
+    /// `map::move_platform`/`enemy_ai::patrol`'s path-following mode
+    /// instead of their origin/range defaults.
+    pub fn attach_path(&mut self, entity: Entity, points: Vec<Vec2>, mode: crate::map::PathMode) {
+        self.app
+            .world
+            .entity_mut(entity)
+            .insert(crate::map::Path { points, mode });
+    }
+
+    /// Spawns a bare `Stompable` with `table` attached, for tests that want
+    /// a guaranteed roll rather than depending on the turret's tuned
+    /// 80/15/5 `LootTable::standard`.
+    pub fn spawn_stompable(&mut self, pos: Vec2, table: crate::loot::LootTable) -> Entity {
+        self.app
+            .world
+            .spawn((
+                crate::combo::Stompable,
+                table,
+                crate::collision::HitBox {
+                    size: Vec2::new(24.0, 24.0),
+                },
+                Transform::from_translation(pos.extend(0.0)),
+                GlobalTransform::default(),
+            ))
+            .id()
+    }
+
+    /// Sends a `HitEvent` directly, standing in for a real damage source —
+    /// see `enemy_ai`'s own doc comment on nothing firing this yet. Defaults
+    /// to `DamageKind::Contact`; use [`send_hit_event_kind`](Self::send_hit_event_kind)
+    /// for tests exercising `player::Resistances`.
+    #[cfg(feature = "serde")]
+    pub fn send_hit_event(&mut self, target: Entity, damage: u32, launch_velocity: Vec2) {
+        self.send_hit_event_kind(target, damage, launch_velocity, DamageKind::Contact);
+    }
+
+    #[cfg(feature = "serde")]
+    pub fn send_hit_event_kind(&mut self, target: Entity, damage: u32, launch_velocity: Vec2, kind: DamageKind) {
+        let mut events = self.app.world.resource_mut::<Events<HitEvent>>();
+        events.send(HitEvent {
+            target,
+            damage,
+            launch_velocity,
+            kind,
+        });
+    }
+
+    /// Position of any entity by id, for following a spawned `Patroller`
+    /// rather than a `With<Player>`-filtered singleton.
+    pub fn entity_pos(&mut self, entity: Entity) -> Vec2 {
+        self.app
+            .world
+            .get::<Transform>(entity)
+            .unwrap()
+            .translation
+            .truncate()
+    }
+
+    pub fn entity_velocity(&mut self, entity: Entity) -> Vec2 {
+        self.app.world.get::<Velocity>(entity).unwrap().0
+    }
+
+    pub fn entity_health(&mut self, entity: Entity) -> u32 {
+        self.app.world.get::<Health>(entity).unwrap().0
+    }
+
+    #[cfg(feature = "serde")]
+    pub fn is_hitstunned(&mut self, entity: Entity) -> bool {
+        self.app.world.get::<Hitstun>(entity).is_some()
+    }
+
+    /// Overwrites the live `PlayerPhysicsConfig::gravity`, so tests can
+    /// confirm a tuning change takes effect on the very next tick.
+    pub fn set_gravity(&mut self, gravity: f32) {
+        let mut config = self.app.world.resource_mut::<PlayerPhysicsConfig>();
+        config.gravity = gravity;
+    }
+
+    /// Total damage dealt by every `DamageEvent` fired so far, draining the
+    /// event queue so repeated calls only see new events.
+    pub fn total_fall_damage(&mut self) -> u32 {
+        let mut events = self.app.world.resource_mut::<Events<DamageEvent>>();
+        let mut reader = events.get_reader();
+        let total = reader.read(&events).map(|event| event.amount).sum();
+        events.clear();
+        total
+    }
+
+    /// Presses and releases F3, toggling `debug_overlay::DebugOverlayVisible`
+    /// the same way a real key press would.
+    pub fn press_f3(&mut self) {
+        let mut keys = self.app.world.resource_mut::<ButtonInput<KeyCode>>();
+        keys.press(KeyCode::F3);
+    }
+
+    pub fn debug_overlay_visible(&mut self) -> bool {
+        self.app
+            .world
+            .resource::<crate::debug_overlay::DebugOverlayVisible>()
+            .0
+    }
+
+    /// Number of `TextSection`s on the overlay's `Text` entity, for
+    /// asserting `update_overlay_text` reuses the section list instead of
+    /// rebuilding it every frame.
+    pub fn debug_overlay_section_count(&mut self) -> usize {
+        let mut query = self
+            .app
+            .world
+            .query_filtered::<&Text, With<crate::debug_overlay::OverlayText>>();
+        query.single(&self.app.world).sections.len()
+    }
+
+    /// Presses L, triggering `localization::cycle_locale` the same way a
+    /// real key press would.
+    pub fn press_l(&mut self) {
+        let mut keys = self.app.world.resource_mut::<ButtonInput<KeyCode>>();
+        keys.press(KeyCode::KeyL);
+    }
+
+    pub fn locale(&mut self) -> String {
+        self.app
+            .world
+            .resource::<Localization>()
+            .locale()
+            .to_string()
+    }
+
+    pub fn localization_missing_keys(&mut self) -> Vec<String> {
+        self.app.world.resource::<Localization>().missing_keys()
+    }
+
+    /// The objectives HUD's currently displayed text, for asserting it's
+    /// translated through `localization::Localization`.
+    pub fn objectives_hud_text(&mut self) -> String {
+        let mut query = self
+            .app
+            .world
+            .query_filtered::<&Text, With<ObjectivesHudText>>();
+        query.single(&self.app.world).sections[0].value.clone()
+    }
+
+    /// Sends a `ToastEvent` directly, the same way any gameplay system
+    /// would, without needing to trigger a real achievement/objective/etc.
+    pub fn fire_toast(&mut self, text: impl Into<String>) {
+        self.app.world.send_event(ToastEvent {
+            text: text.into(),
+            icon: None,
+            duration: 2.0,
+        });
+    }
+
+    /// How many toasts `toast::ToastPlugin` currently has in an active
+    /// stack slot (not counting anything still waiting in its pending
+    /// queue).
+    pub fn active_toast_count(&mut self) -> usize {
+        let mut query = self.app.world.query_filtered::<Entity, With<ToastText>>();
+        query.iter(&self.app.world).count()
+    }
+
+    /// The text of every currently active toast, in no particular order.
+    pub fn active_toast_texts(&mut self) -> Vec<String> {
+        let mut query = self.app.world.query_filtered::<&Text, With<ToastText>>();
+        query
+            .iter(&self.app.world)
+            .map(|text| text.sections[0].value.clone())
+            .collect()
+    }
+
+    /// Sets the level's target ambience, for asserting `apply_ambience`
+    /// interpolates the displayed overlay toward it rather than snapping.
+    pub fn set_level_ambience(&mut self, tint: Color, darkness: f32) {
+        let mut ambience = self.app.world.resource_mut::<LevelAmbience>();
+        ambience.tint = tint;
+        ambience.darkness = darkness;
+    }
+
+    /// Starts a day/night cycle, for asserting it drives `LevelAmbience` on
+    /// its own once present.
+    pub fn insert_day_night_cycle(&mut self, cycle: DayNightCycle) {
+        self.app.world.insert_resource(cycle);
+    }
+
+    pub fn level_ambience_darkness(&mut self) -> f32 {
+        self.app.world.resource::<LevelAmbience>().darkness
+    }
+
+    /// The ambience overlay's currently-displayed alpha (0 = no darkening).
+    pub fn displayed_ambient_darkness(&mut self) -> f32 {
+        let mut query = self
+            .app
+            .world
+            .query_filtered::<&BackgroundColor, With<crate::ambience::AmbienceOverlay>>();
+        query.single(&self.app.world).0.a()
+    }
+
+    /// Spawns a bare `Light2d` at `position`, with no sprite or hitbox, for
+    /// tests exercising `cap_active_lights` without caring what a light
+    /// looks like.
+    pub fn spawn_light(&mut self, position: Vec2, light: Light2d) -> Entity {
+        self.app
+            .world
+            .spawn((light, Transform::from_translation(position.extend(0.0))))
+            .id()
+    }
+
+    /// Number of `Light2d`s not currently `LightCulled`, for asserting
+    /// `cap_active_lights` keeps exactly `MAX_ACTIVE_LIGHTS` of them lit.
+    pub fn active_light_count(&mut self) -> usize {
+        let mut query = self
+            .app
+            .world
+            .query_filtered::<(), (With<Light2d>, Without<LightCulled>)>();
+        query.iter(&self.app.world).count()
+    }
+
+    /// Position of the level's first (and today, only) `Npc`.
+    pub fn npc_pos(&mut self) -> Vec2 {
+        let mut query = self.app.world.query_filtered::<&Transform, With<Npc>>();
+        query
+            .iter(&self.app.world)
+            .next()
+            .unwrap()
+            .translation
+            .truncate()
+    }
+
+    /// `(leash_origin, leash_radius)` of the level's first `Npc`, for
+    /// asserting `npc::wander` never sends it further than that.
+    pub fn npc_leash(&mut self) -> (Vec2, f32) {
+        let mut query = self.app.world.query::<&Npc>();
+        let npc = query.iter(&self.app.world).next().unwrap();
+        (npc.leash_origin, npc.leash_radius)
+    }
+
+    /// Whether `Dialogue` is currently `Open`.
+    pub fn dialogue_is_open(&mut self) -> bool {
+        *self
+            .app
+            .world
+            .resource::<State<crate::dialogue::Dialogue>>()
+            .get()
+            == crate::dialogue::Dialogue::Open
+    }
+
+    pub fn exclamation_indicator_count(&mut self) -> usize {
+        let mut query = self
+            .app
+            .world
+            .query_filtered::<(), With<ExclamationIndicator>>();
+        query.iter(&self.app.world).count()
+    }
+
+    /// How many of the level's objectives are currently `complete`.
+    pub fn objectives_complete_count(&mut self) -> usize {
+        self.app
+            .world
+            .resource::<Objectives>()
+            .0
+            .iter()
+            .filter(|objective| objective.complete)
+            .count()
+    }
+
+    pub fn objectives_total(&mut self) -> usize {
+        self.app.world.resource::<Objectives>().0.len()
+    }
+
+    /// Whether the level's `ExitGate` has had its blocking `HitBox`
+    /// removed, for asserting `objectives::unlock_exit_when_all_complete`
+    /// only does that once every objective is complete.
+    pub fn exit_gate_is_open(&mut self) -> bool {
+        let mut query = self
+            .app
+            .world
+            .query_filtered::<(), (With<ExitGate>, Without<crate::collision::HitBox>)>();
+        query.get_single(&self.app.world).is_ok()
+    }
+
+    /// Sets `Wallet` directly, for tests that don't want to route through a
+    /// real `loot::LootDrop` pickup to set up their starting balance.
+    pub fn set_wallet(&mut self, amount: u32) {
+        self.app.world.resource_mut::<Wallet>().0 = amount;
+    }
+
+    pub fn wallet(&mut self) -> u32 {
+        self.app.world.resource::<Wallet>().0
+    }
+
+    pub fn owns_upgrade(&mut self, id: UpgradeId) -> bool {
+        self.app
+            .world
+            .resource::<crate::shop::PurchasedUpgrades>()
+            .owns(id)
+    }
+
+    pub fn shop_is_open(&mut self) -> bool {
+        *self.app.world.resource::<State<ShopMenu>>().get() == ShopMenu::Open
+    }
+
+    /// Position of the level's `ShopKeeper`.
+    pub fn shopkeeper_pos(&mut self) -> Vec2 {
+        let mut query = self
+            .app
+            .world
+            .query_filtered::<&Transform, With<ShopKeeper>>();
+        query.single(&self.app.world).translation.truncate()
+    }
+
+    /// Position of the level's `StarPickup`, for teleporting the player
+    /// onto it instead of walking it there tick by tick.
+    pub fn star_pickup_pos(&mut self) -> Vec2 {
+        let mut query = self
+            .app
+            .world
+            .query_filtered::<&Transform, With<StarPickup>>();
+        query.single(&self.app.world).translation.truncate()
+    }
+
+    /// Whether the level currently has a `StarPickup` entity.
+    pub fn star_pickup_exists(&mut self) -> bool {
+        let mut query = self.app.world.query_filtered::<(), With<StarPickup>>();
+        query.get_single(&self.app.world).is_ok()
+    }
+
+    /// Whether `world_flags::WorldFlags` has `id` set.
+    pub fn world_flag_is_set(&mut self, id: u32) -> bool {
+        self.app.world.resource::<WorldFlags>().is_set(id)
+    }
+
+    /// Sends `level_reload::LevelReloadRequested` directly and steps once,
+    /// for tests exercising the reload itself rather than the file-watch/
+    /// debounce path in front of it (`tests/level_reload.rs` already
+    /// covers the `Debouncer` in isolation).
+    pub fn reload_level(&mut self) {
+        self.app.world.send_event(LevelReloadRequested);
+        self.step(1);
+    }
+
+    pub fn player_has_starman(&mut self) -> bool {
+        let mut query = self
+            .app
+            .world
+            .query_filtered::<(), (With<Player>, With<Starman>)>();
+        query.get_single(&self.app.world).is_ok()
+    }
+
+    /// Inserts `Starman` directly, for tests that care about expiry or
+    /// damage suppression without first walking the player onto the pickup.
+    pub fn give_player_starman(&mut self) {
+        let mut query = self.app.world.query_filtered::<Entity, With<Player>>();
+        let entity = query.single(&self.app.world);
+        self.app.world.entity_mut(entity).insert(Starman::new());
+    }
+
+    /// For `tests/game_time_pause_audit.rs`, which needs to observe the
+    /// underlying timer holding still while `TimeScale` is `0.0`.
+    pub fn starman_remaining_secs(&mut self) -> f32 {
+        let mut query = self.app.world.query_filtered::<&Starman, With<Player>>();
+        query.single(&self.app.world).remaining_secs()
+    }
+
+    /// Unlocks dash directly, for tests exercising `PlayerState::Dash`
+    /// without going through `shop`'s purchase flow.
+    pub fn grant_dash_ability(&mut self) {
+        let mut query = self
+            .app
+            .world
+            .query_filtered::<&mut Abilities, With<Player>>();
+        query.single_mut(&mut self.app.world).dash = true;
+    }
+
+    pub fn player_state(&mut self) -> PlayerState {
+        let mut query = self
+            .app
+            .world
+            .query_filtered::<&PlayerState, With<Player>>();
+        *query.single(&self.app.world)
+    }
+
+    /// Every `PlayerStateChanged` sent so far this test, in send order.
+    pub fn player_state_changes(&mut self) -> Vec<(PlayerState, PlayerState)> {
+        let mut events = self.app.world.resource_mut::<Events<PlayerStateChanged>>();
+        events.drain().map(|event| (event.from, event.to)).collect()
+    }
+
+    /// Spawns a bare `GrapplePoint` at `position`, for tests exercising
+    /// `PlayerState::Climb` via `grapple::Grappled`.
+    pub fn spawn_grapple_point(&mut self, position: Vec2) -> Entity {
+        self.app
+            .world
+            .spawn((
+                GrapplePoint,
+                Transform::from_translation(position.extend(0.0)),
+            ))
+            .id()
+    }
+
+    pub fn player_sprite_color(&mut self) -> Color {
+        let mut query = self.app.world.query_filtered::<&Sprite, With<Player>>();
+        query.single(&self.app.world).color
+    }
+
+    /// Whether the player currently has a raised `Shield` child.
+    pub fn player_has_shield(&mut self) -> bool {
+        let mut player_query = self.app.world.query_filtered::<&Children, With<Player>>();
+        let Ok(children) = player_query.get_single(&self.app.world) else {
+            return false;
+        };
+        let children: Vec<Entity> = children.iter().copied().collect();
+
+        let mut shield_query = self.app.world.query_filtered::<(), With<Shield>>();
+        children
+            .into_iter()
+            .any(|child| shield_query.get(&self.app.world, child).is_ok())
+    }
+
+    /// Whether the player currently has `crouch::Crouching`.
+    pub fn player_is_crouching(&mut self) -> bool {
+        let mut query = self.app.world.query_filtered::<(), (With<Player>, With<crate::crouch::Crouching>)>();
+        query.get_single(&self.app.world).is_ok()
+    }
+
+    pub fn player_hitbox_size(&mut self) -> Vec2 {
+        let mut query = self.app.world.query_filtered::<&crate::collision::HitBox, With<Player>>();
+        query.single(&self.app.world).size
+    }
+
+    pub fn player_stamina(&mut self) -> f32 {
+        let mut query = self.app.world.query_filtered::<&Stamina, With<Player>>();
+        query.single(&self.app.world).0
+    }
+
+    /// Sets `Stamina` directly, for tests that need to start near-empty or
+    /// near-full without draining/regenerating it tick by tick first.
+    pub fn set_player_stamina(&mut self, amount: f32) {
+        let mut query = self
+            .app
+            .world
+            .query_filtered::<&mut Stamina, With<Player>>();
+        query.single_mut(&mut self.app.world).0 = amount;
+    }
+
+    /// Fires `PlayerDied` directly, for tests that need to start the
+    /// `Dying` sequence without going through `apply_damage` — the only
+    /// in-game damage source is `DamageEvent`, which `Starman` suppresses,
+    /// so there's no way to *reach* death from within the crate's own
+    /// systems while starred.
+    pub fn kill_player(&mut self) {
+        let position = self.player_pos();
+        let mut events = self.app.world.resource_mut::<Events<PlayerDied>>();
+        events.send(PlayerDied { position });
+    }
+
+    /// Fires `PlayerDied` at an arbitrary position, for
+    /// `difficulty_assist` tests that need several deaths clustered at a
+    /// specific spot without walking the player there each time.
+    pub fn kill_player_at(&mut self, position: Vec2) {
+        let mut events = self.app.world.resource_mut::<Events<PlayerDied>>();
+        events.send(PlayerDied { position });
+    }
+
+    pub fn death_positions(&mut self) -> Vec<Vec2> {
+        self.app
+            .world
+            .resource::<LevelStats>()
+            .death_positions
+            .clone()
+    }
+
+    pub fn difficulty_assist_offered(&mut self) -> bool {
+        self.app.world.resource::<DifficultyAssist>().offered
+    }
+
+    pub fn difficulty_assist_accepted(&mut self) -> bool {
+        self.app.world.resource::<DifficultyAssist>().accepted
+    }
+
+    /// Presses and releases `P`, toggling `DifficultyAssist::accepted` the
+    /// same way a real key press would — a no-op until an assist has been
+    /// offered, mirroring `press_f8`'s own note on mirroring a real key.
+    pub fn press_p(&mut self) {
+        let mut keys = self.app.world.resource_mut::<ButtonInput<KeyCode>>();
+        keys.press(KeyCode::KeyP);
+    }
+
+    pub fn assist_bridge_count(&mut self) -> usize {
+        self.app
+            .world
+            .query_filtered::<Entity, With<AssistBridge>>()
+            .iter(&self.app.world)
+            .count()
+    }
+
+    /// The last jump's coyote/buffer window usage, for asserting
+    /// `speedrun_overlay`'s source data without parsing its rendered text.
+    pub fn jump_analytics(&mut self) -> (Option<u32>, u32, u32) {
+        let analytics = self.app.world.resource::<JumpAnalytics>();
+        (
+            analytics.last_jump_tick,
+            analytics.buffered_ticks,
+            analytics.coyote_ticks,
+        )
+    }
+
+    /// Position of the level's `Turret`, for teleporting the player into
+    /// or out of its range/line of sight instead of walking there.
+    pub fn turret_pos(&mut self) -> Vec2 {
+        let mut query = self.app.world.query_filtered::<&Transform, With<Turret>>();
+        query.single(&self.app.world).translation.truncate()
+    }
+
+    pub fn turret_count(&mut self) -> usize {
+        let mut query = self.app.world.query_filtered::<(), With<Turret>>();
+        query.iter(&self.app.world).count()
+    }
+
+    /// Live (not `pool::Pooled`-idle) projectiles — every entity
+    /// `projectile::ProjectilePlugin`'s pool pre-spawns carries `Projectile`
+    /// permanently, so counting that alone would always report the pool's
+    /// full capacity instead of how many are actually in flight.
+    pub fn projectile_count(&mut self) -> usize {
+        let mut query = self
+            .app
+            .world
+            .query_filtered::<(), (With<Projectile>, Without<Pooled>)>();
+        query.iter(&self.app.world).count()
+    }
+
+    pub fn loot_drop_count(&mut self) -> usize {
+        let mut query = self.app.world.query_filtered::<(), With<LootDrop>>();
+        query.iter(&self.app.world).count()
+    }
+
+    /// The `LootKind` of the level's one loot drop, for tests that already
+    /// know exactly one is live. Panics like `Query::single` if that's not
+    /// true, same as this file's other single-entity helpers.
+    pub fn loot_drop_kind(&mut self) -> LootKind {
+        let mut query = self.app.world.query_filtered::<&LootDrop, ()>();
+        query.single(&self.app.world).kind
+    }
+
+    /// The `Entity` of the level's one loot drop, for following its
+    /// position via `entity_pos` as it falls and settles.
+    pub fn loot_drop_entity(&mut self) -> Entity {
+        let mut query = self.app.world.query_filtered::<Entity, With<LootDrop>>();
+        query.single(&self.app.world)
+    }
+
+    /// Current `Health` of the player, for tests asserting a turret's
+    /// projectile actually reached `fall_damage::DamageEvent`.
+    pub fn player_health(&mut self) -> u32 {
+        let mut query = self.app.world.query_filtered::<&Health, With<Player>>();
+        query.single(&self.app.world).0
+    }
+
+    /// Current consecutive-stomp combo count, backfilled onto the player
+    /// the same frame it first appears — see `combo::ensure_combo_component`.
+    pub fn player_combo(&mut self) -> u32 {
+        let mut query = self.app.world.query_filtered::<&ComboCount, With<Player>>();
+        query.single(&self.app.world).0
+    }
+
+    /// Every currently-live floating score popup's text, for asserting a
+    /// stomp awarded the right combo-scaled number.
+    pub fn floating_texts(&mut self) -> Vec<String> {
+        let mut query = self.app.world.query_filtered::<&Text, With<FloatingText>>();
+        query
+            .iter(&self.app.world)
+            .map(|text| text.sections[0].value.clone())
+            .collect()
+    }
+
+    /// Freezes `Time`'s delta to exactly `seconds` every tick, for tests
+    /// that need two separately-run stretches of simulation (e.g. either
+    /// side of a `snapshot`/`restore` round-trip) to land on bit-identical
+    /// floats instead of drifting apart with whatever the real wall clock
+    /// happened to measure between `step` calls.
+    pub fn set_fixed_delta_seconds(&mut self, seconds: f32) {
+        self.app
+            .world
+            .insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(
+                std::time::Duration::from_secs_f32(seconds),
+            ));
+    }
+
+    /// Takes a [`crate::snapshot::GameSnapshot`] of the live world, for
+    /// testing `snapshot`/`restore` directly against a fully-populated
+    /// `TestWorld` rather than a bare `World`.
+    pub fn snapshot(&mut self) -> crate::snapshot::GameSnapshot {
+        crate::snapshot::snapshot(&self.app.world)
+    }
+
+    pub fn restore(&mut self, snap: &crate::snapshot::GameSnapshot) {
+        crate::snapshot::restore(&mut self.app.world, snap);
+    }
+
+    /// Starts a `challenge` run at the player's current position, the way
+    /// a level-select screen would once one exists — see that module's
+    /// own note on there being no such screen yet.
+    pub fn start_challenge(&mut self) {
+        let pos = self.player_pos();
+        let mut checkpoint = self.app.world.resource_mut::<LastCheckpoint>();
+        checkpoint.id = None;
+        checkpoint.position = pos;
+        self.app.world.insert_resource(ChallengeRun::new());
+        let snap = crate::snapshot::snapshot(&self.app.world);
+        self.app.world.insert_resource(RetrySnapshot(snap));
+    }
+
+    pub fn challenge_is_active(&mut self) -> bool {
+        self.app.world.get_resource::<ChallengeRun>().is_some()
+    }
+
+    pub fn challenge_elapsed_seconds(&mut self) -> f32 {
+        self.app.world.resource::<ChallengeRun>().elapsed_seconds()
+    }
+
+    pub fn challenge_best_medal(&mut self) -> Option<crate::challenge::Medal> {
+        self.app.world.resource::<ChallengeBest>().medal
+    }
+
+    pub fn challenge_best_time(&mut self) -> Option<f32> {
+        self.app.world.resource::<ChallengeBest>().time
+    }
+
+    /// Removes the level's `ExitGate` `HitBox` directly, bypassing
+    /// `objectives`, for tests that need to reach `challenge::finish_on_exit`
+    /// without first satisfying every objective — mirrors `give_player_
+    /// starman` skipping the pickup walk-up.
+    pub fn force_exit_gate_open(&mut self) {
+        let mut query = self.app.world.query_filtered::<Entity, With<ExitGate>>();
+        let entity = query.single(&self.app.world);
+        self.app
+            .world
+            .entity_mut(entity)
+            .remove::<crate::collision::HitBox>();
+    }
+
+    /// Position of the level's (only) `Checkpoint`, for walking the player
+    /// onto it to test whether it's allowed to advance `LastCheckpoint`.
+    pub fn checkpoint_pos(&mut self) -> Vec2 {
+        let mut query = self
+            .app
+            .world
+            .query_filtered::<&Transform, With<crate::death::Checkpoint>>();
+        query.single(&self.app.world).translation.truncate()
+    }
+
+    pub fn exit_gate_pos(&mut self) -> Vec2 {
+        let mut query = self
+            .app
+            .world
+            .query_filtered::<&Transform, With<ExitGate>>();
+        query.single(&self.app.world).translation.truncate()
+    }
+
+    /// The id `death::touch_checkpoints` last recorded, for asserting
+    /// `challenge::checkpoints_enabled` actually kept it from advancing.
+    pub fn last_checkpoint_id(&mut self) -> Option<u32> {
+        self.app.world.resource::<LastCheckpoint>().id
+    }
+
+    pub fn ghost_sprite_count(&mut self) -> usize {
+        let mut query = self
+            .app
+            .world
+            .query_filtered::<(), With<crate::challenge::GhostSprite>>();
+        query.iter(&self.app.world).count()
+    }
+
+    /// Position of the level's ghost sprite, for asserting it follows
+    /// `ChallengeBest`'s recorded track rather than sitting still.
+    pub fn ghost_pos(&mut self) -> Vec2 {
+        let mut query = self
+            .app
+            .world
+            .query_filtered::<&Transform, With<crate::challenge::GhostSprite>>();
+        query.single(&self.app.world).translation.truncate()
+    }
+
+    /// Presses and releases F7, toggling `level_select::LevelSelect` the
+    /// same way a real key press would.
+    pub fn press_f7(&mut self) {
+        let mut keys = self.app.world.resource_mut::<ButtonInput<KeyCode>>();
+        keys.press(KeyCode::F7);
+    }
+
+    /// Presses R, triggering `challenge::press_r_to_retry` the same way a
+    /// real key press would.
+    pub fn press_r(&mut self) {
+        let mut keys = self.app.world.resource_mut::<ButtonInput<KeyCode>>();
+        keys.press(KeyCode::KeyR);
+    }
+
+    pub fn level_select_is_open(&mut self) -> bool {
+        *self.app.world.resource::<State<LevelSelect>>().get() == LevelSelect::Open
+    }
+
+    pub fn is_level_unlocked(&mut self, id: u32) -> bool {
+        self.app.world.resource::<LevelUnlocks>().is_unlocked(id)
+    }
+
+    /// Number of `TextSection`s on the level select screen's `Text`
+    /// entity, once it's been opened and rendered at least once.
+    pub fn level_select_row_count(&mut self) -> usize {
+        let mut query = self
+            .app
+            .world
+            .query_filtered::<&Text, With<crate::level_select::LevelSelectText>>();
+        query.single(&self.app.world).sections.len()
+    }
+
+    pub fn level_load_state(&mut self) -> LevelLoadState {
+        *self.app.world.resource::<State<LevelLoadState>>().get()
+    }
+
+    pub fn level_entity_count(&mut self) -> usize {
+        self.app
+            .world
+            .query_filtered::<Entity, With<LevelEntity>>()
+            .iter(&self.app.world)
+            .count()
+    }
+
+    pub fn level_stats(&mut self) -> (u32, u32, u32, u32) {
+        let stats = self.app.world.resource::<LevelStats>();
+        (
+            stats.deaths,
+            stats.enemies_defeated,
+            stats.coins_collected,
+            stats.coins_total,
+        )
+    }
+
+    pub fn results_screen_is_open(&mut self) -> bool {
+        *self.app.world.resource::<State<ResultsScreen>>().get() == ResultsScreen::Open
+    }
+
+    /// Number of `TextSection`s on the results screen's `Text` entity,
+    /// once it's been opened and rendered at least once.
+    pub fn results_row_count(&mut self) -> usize {
+        let mut query = self
+            .app
+            .world
+            .query_filtered::<&Text, With<crate::results::ResultsText>>();
+        query.single(&self.app.world).sections.len()
+    }
+
+    pub fn best_coins_for_level(&mut self, id: u32) -> Option<u32> {
+        self.app.world.resource::<LevelCoinBest>().best_for(id)
+    }
+
+    /// Presses and releases F8, toggling
+    /// `accessibility::AccessibilityOptions::reduce_flashing` the same way a
+    /// real key press would.
+    pub fn press_f8(&mut self) {
+        let mut keys = self.app.world.resource_mut::<ButtonInput<KeyCode>>();
+        keys.press(KeyCode::F8);
+    }
+
+    /// Presses and releases F9, toggling
+    /// `accessibility::AccessibilityOptions::colorblind_palette` the same
+    /// way a real key press would.
+    pub fn press_f9(&mut self) {
+        let mut keys = self.app.world.resource_mut::<ButtonInput<KeyCode>>();
+        keys.press(KeyCode::F9);
+    }
+
+    /// Presses and releases F10, toggling
+    /// `accessibility::AccessibilityOptions::toggle_input_mode` the same way
+    /// a real key press would.
+    pub fn press_f10(&mut self) {
+        let mut keys = self.app.world.resource_mut::<ButtonInput<KeyCode>>();
+        keys.press(KeyCode::F10);
+    }
+
+    pub fn accessibility_options(&mut self) -> AccessibilityOptions {
+        self.app.world.resource::<AccessibilityOptions>().clone()
+    }
+
+    pub fn checkpoint_color(&mut self) -> Color {
+        let mut query = self.app.world.query_filtered::<&Sprite, With<Checkpoint>>();
+        query.single(&self.app.world).color
+    }
+
+    pub fn exit_gate_color(&mut self) -> Color {
+        let mut query = self.app.world.query_filtered::<&Sprite, With<ExitGate>>();
+        query.single(&self.app.world).color
+    }
+
+    pub fn palette(&mut self) -> Palette {
+        *self.app.world.resource::<Palette>()
+    }
+
+    /// Presses gamepad button South (a controller's generic "confirm"
+    /// button) on an arbitrary pad id, without going through a real
+    /// `GamepadConnectionEvent` — `prompt::track_last_used_device` reads
+    /// `ButtonInput<GamepadButton>` directly, so no connected `Gamepads`
+    /// entry is needed to observe it.
+    pub fn press_gamepad_south(&mut self) {
+        let mut buttons = self.app.world.resource_mut::<ButtonInput<GamepadButton>>();
+        buttons.press(GamepadButton::new(
+            Gamepad::new(0),
+            GamepadButtonType::South,
+        ));
+    }
+
+    /// Connects a gamepad through a real `GamepadConnectionEvent`, the way
+    /// `press_gamepad_south` deliberately avoids doing — `rumble::route_rumble_events`
+    /// reads `Res<Gamepads>` rather than `ButtonInput<GamepadButton>`, so it needs
+    /// the connection to actually go through `bevy_input`'s own connection system.
+    pub fn connect_gamepad(&mut self) {
+        let gamepad = Gamepad::new(0);
+        self.app.world.send_event(GamepadConnectionEvent::new(
+            gamepad,
+            GamepadConnection::Connected(GamepadInfo {
+                name: "test pad".to_string(),
+            }),
+        ));
+        self.step(1);
+    }
+
+    /// Every `GamepadRumbleRequest` sent so far this test, in send order.
+    pub fn rumble_requests(&mut self) -> Vec<GamepadRumbleRequest> {
+        let mut events = self
+            .app
+            .world
+            .resource_mut::<Events<GamepadRumbleRequest>>();
+        events.drain().collect()
+    }
+
+    pub fn rumble_settings(&mut self) -> RumbleSettings {
+        self.app.world.resource::<RumbleSettings>().clone()
+    }
+
+    /// Presses and releases F1, toggling `rumble::RumbleSettings::enabled`
+    /// the same way a real key press would.
+    pub fn press_f1(&mut self) {
+        let mut keys = self.app.world.resource_mut::<ButtonInput<KeyCode>>();
+        keys.press(KeyCode::F1);
+    }
+
+    /// Presses and releases F2, toggling `photo::PhotoMode` the same way a
+    /// real key press would.
+    pub fn press_f2(&mut self) {
+        let mut keys = self.app.world.resource_mut::<ButtonInput<KeyCode>>();
+        keys.press(KeyCode::F2);
+    }
+
+    pub fn photo_mode_is_active(&mut self) -> bool {
+        *self
+            .app
+            .world
+            .resource::<State<crate::photo::PhotoMode>>()
+            .get()
+            == crate::photo::PhotoMode::Active
+    }
+
+    /// Presses and releases F6, toggling `time_scale`'s own debug bullet
+    /// time the same way a real key press would.
+    pub fn press_f6(&mut self) {
+        let mut keys = self.app.world.resource_mut::<ButtonInput<KeyCode>>();
+        keys.press(KeyCode::F6);
+    }
+
+    pub fn time_scale(&mut self) -> f32 {
+        self.app.world.resource::<TimeScale>().0
+    }
+
+    /// Sets `TimeScale` directly, for tests that need an exact scale
+    /// (`0.0` to simulate a pause) rather than `press_f6`'s fixed
+    /// bullet-time value.
+    pub fn set_time_scale(&mut self, scale: f32) {
+        self.app.world.resource_mut::<TimeScale>().0 = scale;
+    }
+
+    /// Whether `objectives::ObjectivesHudText` is currently drawn, for
+    /// asserting `photo::hide_hud`/`photo::show_hud` toggle it correctly.
+    pub fn objectives_hud_visible(&mut self) -> bool {
+        let mut query = self
+            .app
+            .world
+            .query_filtered::<&Visibility, With<ObjectivesHudText>>();
+        *query.single(&self.app.world) != Visibility::Hidden
+    }
+
+    /// Whether `shield::StaminaHudText` is currently drawn, for asserting
+    /// `photo::hide_hud`/`photo::show_hud` toggle it correctly.
+    pub fn stamina_hud_visible(&mut self) -> bool {
+        let mut query = self
+            .app
+            .world
+            .query_filtered::<&Visibility, With<crate::shield::StaminaHudText>>();
+        *query.single(&self.app.world) != Visibility::Hidden
+    }
+
+    /// `Camera2d`'s current `OrthographicProjection::scale`, for asserting
+    /// `photo::pan_zoom_camera` zooms it.
+    pub fn camera_zoom(&mut self) -> f32 {
+        let mut query = self
+            .app
+            .world
+            .query_filtered::<&OrthographicProjection, With<Camera2d>>();
+        query.single(&self.app.world).scale
+    }
+
+    /// Holds `=`, zooming `photo::pan_zoom_camera` in — a key no other
+    /// system reads, unlike the arrow keys it also uses for panning, which
+    /// double as player movement.
+    pub fn press_photo_zoom_in(&mut self) {
+        let mut keys = self.app.world.resource_mut::<ButtonInput<KeyCode>>();
+        keys.press(KeyCode::Equal);
+    }
+
+    /// The interact prompt's current text, or `None` while the player isn't
+    /// near an `Interactable`.
+    pub fn interact_prompt_text(&mut self) -> Option<String> {
+        let mut query = self
+            .app
+            .world
+            .query_filtered::<&Text, With<InteractPrompt>>();
+        query
+            .iter(&self.app.world)
+            .next()
+            .map(|text| text.sections[0].value.clone())
+    }
+
+    /// The level select screen's confirm-footer text, once it's been
+    /// opened and rendered at least once.
+    pub fn level_select_prompt_text(&mut self) -> String {
+        let mut query = self
+            .app
+            .world
+            .query_filtered::<&Text, With<LevelSelectPrompt>>();
+        query.single(&self.app.world).sections[0].value.clone()
+    }
+
+    /// Presses and releases F4, starting the level 1 intro cutscene the
+    /// same way a real key press would, unless `CutscenePlayed` already
+    /// marks it seen.
+    pub fn press_f4(&mut self) {
+        let mut keys = self.app.world.resource_mut::<ButtonInput<KeyCode>>();
+        keys.press(KeyCode::F4);
+    }
+
+    /// Releases F4, needed between two separate presses the same way
+    /// [`TestWorld::release`] is for an [`Action`] — see that method's own
+    /// note on why.
+    pub fn release_f4(&mut self) {
+        let mut keys = self.app.world.resource_mut::<ButtonInput<KeyCode>>();
+        keys.release(KeyCode::F4);
+    }
+
+    /// Whether `cutscene::CutscenePlaying` is currently `Playing`.
+    pub fn cutscene_is_playing(&mut self) -> bool {
+        *self.app.world.resource::<State<CutscenePlaying>>().get() == CutscenePlaying::Playing
+    }
+
+    /// The x position `cutscene::ActorId(0)`'s entity (the demo NPC) is
+    /// currently at, for asserting `WalkActor` actually moves it.
+    pub fn actor_pos(&mut self, id: u32) -> Vec2 {
+        let mut query = self.app.world.query::<(&ActorId, &Transform)>();
+        query
+            .iter(&self.app.world)
+            .find(|(actor, _)| actor.0 == id)
+            .map(|(_, transform)| transform.translation.truncate())
+            .unwrap()
+    }
+
+    /// Presses and releases Tab, toggling `minimap::MinimapVisible` the same
+    /// way a real key press would.
+    pub fn press_tab(&mut self) {
+        let mut keys = self.app.world.resource_mut::<ButtonInput<KeyCode>>();
+        keys.press(KeyCode::Tab);
+    }
+
+    pub fn minimap_visible(&mut self) -> bool {
+        self.app.world.resource::<MinimapVisible>().0
+    }
+
+    /// Number of grid cells `minimap::reveal_fog` has marked revealed so
+    /// far, for asserting it grows as the player explores rather than
+    /// starting (or staying) fully revealed.
+    pub fn minimap_revealed_count(&mut self) -> usize {
+        self.app.world.resource::<MinimapGrid>().revealed_count()
+    }
+
+    pub fn minimap_scale(&mut self) -> f32 {
+        self.app.world.resource::<MinimapOptions>().scale
+    }
+
+    /// Presses and releases `[`, shrinking `minimap::MinimapOptions::scale`
+    /// the same way a real key press would.
+    pub fn press_minimap_scale_down(&mut self) {
+        let mut keys = self.app.world.resource_mut::<ButtonInput<KeyCode>>();
+        keys.press(KeyCode::BracketLeft);
+    }
+
+    /// Sends `combo::EnemyDefeated` `n` times, for driving
+    /// `achievements::AchievementId::EnemiesStomped` without needing a real
+    /// `Stompable` entity to jump on.
+    pub fn defeat_enemies(&mut self, n: u32) {
+        let mut events = self.app.world.resource_mut::<Events<EnemyDefeated>>();
+        for _ in 0..n {
+            events.send(EnemyDefeated {
+                position: Vec2::ZERO,
+                loot: None,
+            });
+        }
+    }
+
+    /// Sends a gold-medal `challenge::ChallengeFinished`, for driving
+    /// `achievements::AchievementId::GoldTime` without racing a real clock.
+    pub fn finish_challenge_with_gold(&mut self) {
+        let mut events = self.app.world.resource_mut::<Events<ChallengeFinished>>();
+        events.send(ChallengeFinished {
+            medal: Some(Medal::Gold),
+            time: 0.0,
+            delta_vs_best: None,
+        });
+    }
+
+    /// Sends `level_select::LevelCompleted` for `id`, for driving
+    /// `achievements::AchievementId::DeathlessClear` without walking the
+    /// player to a real `ExitGate`.
+    pub fn complete_level(&mut self, id: u32) {
+        let mut events = self.app.world.resource_mut::<Events<LevelCompleted>>();
+        events.send(LevelCompleted { id });
+    }
+
+    pub fn achievement_progress(&mut self, id: AchievementId) -> u32 {
+        self.app.world.resource::<AchievementProgress>().count(id)
+    }
+
+    pub fn achievement_is_unlocked(&mut self, id: AchievementId) -> bool {
+        self.app
+            .world
+            .resource::<AchievementBackendRes>()
+            .is_unlocked(id)
+    }
+
+    /// Despawns any entity by id, for tests simulating an enemy's death
+    /// without routing through a real stomp/hitstun kill.
+    pub fn despawn(&mut self, entity: Entity) {
+        self.app.world.despawn(entity);
+    }
+
+    /// Pushes a `script::ScriptEntry` directly into `script::LevelScript`,
+    /// standing in for `assets/scripts/*.ron` authoring one.
+    #[cfg(feature = "serde")]
+    pub fn add_script_entry(
+        &mut self,
+        trigger: crate::script::Trigger,
+        action: crate::script::Action,
+    ) {
+        let mut script = self.app.world.resource_mut::<crate::script::LevelScript>();
+        script.push(crate::script::ScriptEntry::new(trigger, action));
+    }
+
+    /// Spawns a `script::LevelId`-tagged `Sensor` zone, for
+    /// `script::Trigger::PlayerEnters` tests.
+    #[cfg(feature = "serde")]
+    pub fn spawn_script_zone(&mut self, id: &str, pos: Vec2, size: Vec2) -> Entity {
+        self.app
+            .world
+            .spawn((
+                crate::script::LevelId(id.to_string()),
+                crate::collision::Sensor,
+                crate::collision::HitBox { size },
+                Transform::from_translation(pos.extend(0.0)),
+                GlobalTransform::default(),
+            ))
+            .id()
+    }
+
+    /// Spawns a `script::LevelId`-tagged `script::Door`, blocked by a
+    /// `HitBox` until `script::Action::OpenDoor` removes it.
+    #[cfg(feature = "serde")]
+    pub fn spawn_script_door(&mut self, id: &str, pos: Vec2, size: Vec2) -> Entity {
+        self.app
+            .world
+            .spawn((
+                crate::script::LevelId(id.to_string()),
+                crate::script::Door,
+                crate::collision::HitBox { size },
+                Transform::from_translation(pos.extend(0.0)),
+                GlobalTransform::default(),
+            ))
+            .id()
+    }
+
+    /// Whether the `script::Door` tagged `id` has had its `HitBox` removed.
+    #[cfg(feature = "serde")]
+    pub fn door_is_open(&mut self, id: &str) -> bool {
+        let mut query = self
+            .app
+            .world
+            .query_filtered::<(&crate::script::LevelId, Option<&crate::collision::HitBox>), With<crate::script::Door>>();
+        query
+            .iter(&self.app.world)
+            .find(|(level_id, _)| level_id.0 == id)
+            .map(|(_, hitbox)| hitbox.is_none())
+            .unwrap_or(false)
+    }
+
+    /// Spawns a `script::LevelId`-tagged `map::Lever`, for
+    /// `script::Trigger::LeverToggled` tests. `platform` is
+    /// `Entity::PLACEHOLDER` since these tests only care about the
+    /// `LevelId`, not `map::toggle_lever`'s own platform-toggling.
+    #[cfg(feature = "serde")]
+    pub fn spawn_script_lever(&mut self, id: &str, pos: Vec2) -> Entity {
+        self.app
+            .world
+            .spawn((
+                crate::script::LevelId(id.to_string()),
+                Interactable {
+                    prompt: "Toggle".into(),
+                },
+                crate::map::Lever {
+                    platform: Entity::PLACEHOLDER,
+                },
+                crate::collision::Sensor,
+                crate::collision::HitBox {
+                    size: Vec2::new(16.0, 16.0),
+                },
+                Transform::from_translation(pos.extend(0.0)),
+                GlobalTransform::default(),
+            ))
+            .id()
+    }
+
+    /// Spawns a `script::LevelId`-tagged `map::MovingPlatform`, for
+    /// `script::Action::MovePlatform` tests.
+    #[cfg(feature = "serde")]
+    pub fn spawn_script_platform(&mut self, id: &str, pos: Vec2) -> Entity {
+        self.app
+            .world
+            .spawn((
+                crate::script::LevelId(id.to_string()),
+                crate::map::MovingPlatform::default(),
+                crate::collision::HitBox {
+                    size: Vec2::new(32.0, 16.0),
+                },
+                Transform::from_translation(pos.extend(0.0)),
+                GlobalTransform::default(),
+            ))
+            .id()
+    }
+
+    /// Spawns a bare `script::LevelId`-tagged waypoint with no other
+    /// components, for `script::Action::SpawnPrefab`'s `at` and
+    /// `script::Action::MovePlatform`'s `to`.
+    #[cfg(feature = "serde")]
+    pub fn spawn_script_waypoint(&mut self, id: &str, pos: Vec2) -> Entity {
+        self.app
+            .world
+            .spawn((
+                crate::script::LevelId(id.to_string()),
+                Transform::from_translation(pos.extend(0.0)),
+                GlobalTransform::default(),
+            ))
+            .id()
+    }
+
+    /// Number of `prefab::Coin`s currently spawned, for asserting
+    /// `script::Action::SpawnPrefab` actually spawned one.
+    #[cfg(feature = "serde")]
+    pub fn coin_count(&mut self) -> usize {
+        let mut query = self
+            .app
+            .world
+            .query_filtered::<Entity, With<crate::prefab::Coin>>();
+        query.iter(&self.app.world).count()
+    }
+
+    /// Fires a `WindowResized` for `ui_scale::update_ui_scale` to react to.
+    /// `width` is unused by that system but real events always carry both,
+    /// so this takes it too rather than hard-coding a value that'd look
+    /// meaningful but isn't.
+    pub fn resize_window(&mut self, width: f32, height: f32) {
+        let window = self.app.world.spawn_empty().id();
+        self.app
+            .world
+            .send_event(bevy::window::WindowResized { window, width, height });
+    }
+
+    /// The current global `UiScale` factor `ui_scale::update_ui_scale`
+    /// drives from window height.
+    pub fn ui_scale(&mut self) -> f32 {
+        self.app.world.resource::<UiScale>().0
+    }
+
+    /// Spawns a HUD-anchor-style node at the given base `top`/`left` offset,
+    /// tagged with `ui_scale::SafeAreaAnchor`, for tests asserting
+    /// `ui_scale::apply_safe_area_insets` pads it.
+    pub fn spawn_safe_area_anchor(&mut self, top: f32, left: f32) -> Entity {
+        self.app
+            .world
+            .spawn((
+                crate::ui_scale::SafeAreaAnchor {
+                    top: Some(top),
+                    left: Some(left),
+                    ..Default::default()
+                },
+                Style {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(top),
+                    left: Val::Px(left),
+                    ..Default::default()
+                },
+            ))
+            .id()
+    }
+
+    /// Sets `ui_scale::SafeAreaInsets::margin`, for tests asserting a
+    /// `SafeAreaAnchor` node's offsets grow to match.
+    pub fn set_safe_area_margin(&mut self, margin: f32) {
+        self.app
+            .world
+            .resource_mut::<crate::ui_scale::SafeAreaInsets>()
+            .margin = margin;
+    }
+
+    /// The `Style::top`/`left` a [`TestWorld::spawn_safe_area_anchor`] node
+    /// currently has, as `(top, left)` pixel values.
+    pub fn anchor_offset(&mut self, entity: Entity) -> (f32, f32) {
+        let style = self.app.world.entity(entity).get::<Style>().unwrap();
+        let px = |val: Val| match val {
+            Val::Px(px) => px,
+            other => panic!("expected Val::Px, got {other:?}"),
+        };
+        (px(style.top), px(style.left))
+    }
+
+    pub fn profile_select_is_open(&mut self) -> bool {
+        *self.app.world.resource::<State<ProfileSelect>>().get() == ProfileSelect::Open
+    }
+
+    pub fn active_save_slot(&mut self) -> Option<usize> {
+        self.app.world.resource::<ActiveSlot>().0
+    }
+
+    /// Creates `index` directly via `SaveManager::create`, without going
+    /// through the confirm key (which also activates the slot and closes
+    /// the profile select screen) — for tests exercising copy/delete on a
+    /// populated slot while the screen stays open.
+    pub fn create_save_slot(&mut self, index: usize) {
+        self.app.world.resource_mut::<SaveManager>().create(index);
+    }
+
+    pub fn save_slot_data(&mut self, index: usize) -> Option<SaveSlotData> {
+        self.app.world.resource::<SaveManager>().slot(index).copied()
+    }
+
+    /// Presses C, triggering `save::copy_selected` the same way a real key
+    /// press would.
+    pub fn press_save_copy(&mut self) {
+        let mut keys = self.app.world.resource_mut::<ButtonInput<KeyCode>>();
+        keys.press(KeyCode::KeyC);
+    }
+
+    /// Releases C, needed between two separate taps of copy (mark source,
+    /// then copy into the destination row) for the same reason
+    /// [`TestWorld::release`] documents.
+    pub fn release_save_copy(&mut self) {
+        let mut keys = self.app.world.resource_mut::<ButtonInput<KeyCode>>();
+        keys.release(KeyCode::KeyC);
+    }
+
+    /// Presses X, triggering `save::delete_selected` the same way a real key
+    /// press would.
+    pub fn press_save_delete(&mut self) {
+        let mut keys = self.app.world.resource_mut::<ButtonInput<KeyCode>>();
+        keys.press(KeyCode::KeyX);
+    }
+
+    /// Fires `save::DeleteSlotRequested` directly, standing in for the pause
+    /// menu this crate doesn't have yet — see `save`'s own doc comment.
+    pub fn delete_save_slot(&mut self, index: usize) {
+        let mut events = self.app.world.resource_mut::<Events<DeleteSlotRequested>>();
+        events.send(DeleteSlotRequested(index));
+    }
+
+    /// The text of the row `save::update_profile_select_text` renders for
+    /// `index`, once the profile select screen has been open and rendered
+    /// at least once.
+    pub fn save_slot_row_text(&mut self, index: usize) -> String {
+        let mut query = self
+            .app
+            .world
+            .query_filtered::<&Text, With<crate::save::ProfileSelectText>>();
+        query.single(&self.app.world).sections[index].value.clone()
+    }
+}
+
+impl Default for TestWorld {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,367 @@
+//! Chunked tilemap rendering: one batched mesh per 32×32-tile chunk instead
+//! of a `SpriteBundle` per tile. A 200×30 level is 6k tiles — spawning that
+//! many sprite entities makes per-frame extraction (walking every entity to
+//! build the render world's draw list) the bottleneck; a handful of chunk
+//! meshes doesn't.
+//!
+//! Each [`TileLayer`] paints on [`crate::render_layer::GameLayer::TilesBack`]
+//! by default; [`TileLayer::with_layer`] moves it to another layer, e.g.
+//! [`crate::render_layer::GameLayer::TilesFront`] for tiles meant to occlude
+//! the player.
+//!
+//! There's no Tiled/LDtk importer in this crate yet (`map::spawn_map_entities`
+//! still spawns its demo layout by hand), so nothing calls into this module
+//! today. It's written so that importer, whenever it lands, populates a
+//! [`TileLayer`] and calls [`TileLayer::set_tile`] instead of spawning
+//! per-tile sprites — the importer becomes the one place that turns file
+//! data into tiles, same relationship `prefab::spawn_prefab` has with a
+//! future level format.
+//!
+//! [`TileLayer::set_tile`] is also the entry point for destructible terrain:
+//! it marks only the owning chunk dirty, so breaking one tile out of a 6k
+//! tile level rebuilds a 32×32 mesh, not the whole level.
+//!
+//! Animated tiles (water, lava, torches) register an [`AnimatedTile`] in
+//! [`TileAnimations`], keyed by the tile id that's actually painted into
+//! the layer. A single shared clock (`Time`, the same one every other
+//! system in this crate reads) picks the current frame for every animation
+//! at once, so identical tiles never drift out of sync. `advance_tile_animations`
+//! only re-dirties a chunk when some animation it contains actually lands on
+//! a new frame — most ticks touch nothing — and `build_chunk_mesh` bakes
+//! whatever frame is current straight into the chunk's UVs, so this needs
+//! no shader support beyond the plain `ColorMaterial` chunks already use.
+//! Because `animated_chunks` membership is recomputed on every rebuild
+//! (animation-triggered or edit-triggered), destructible-terrain edits that
+//! add or remove animated tiles from a chunk can't leave it stuck animating
+//! (or stuck static).
+
+use std::collections::{HashMap, HashSet};
+
+use bevy::prelude::*;
+use bevy::render::mesh::{Indices, PrimitiveTopology};
+use bevy::render::render_asset::RenderAssetUsages;
+use bevy::sprite::{ColorMaterial, MaterialMesh2dBundle, Mesh2dHandle};
+
+use crate::render_layer::{z_for, GameLayer};
+
+/// Tiles per chunk, per axis. 32×32 keeps a fully dense chunk's mesh under
+/// 4k vertices while still batching enough tiles to matter.
+pub const CHUNK_SIZE: u32 = 32;
+
+/// A grid of tile indices into a shared texture atlas, rendered as one mesh
+/// per dirty [`CHUNK_SIZE`]×`CHUNK_SIZE` chunk rather than one sprite per
+/// tile. `None` means "no tile" (fully transparent, no quad emitted).
+#[derive(Component)]
+pub struct TileLayer {
+    width: u32,
+    height: u32,
+    tile_size: Vec2,
+    atlas_columns: u32,
+    atlas_rows: u32,
+    tiles: Vec<Option<u32>>,
+    dirty_chunks: HashSet<UVec2>,
+    animated_chunks: HashSet<UVec2>,
+    chunk_entities: HashMap<UVec2, Entity>,
+    layer: GameLayer,
+    y_sort: bool,
+}
+
+/// One tile id's animation: cycle through `frames` (each itself a tile id
+/// into the atlas), holding each for `frame_time` seconds. Meant to be
+/// populated from a Tiled tileset's own per-tile animation metadata once an
+/// importer exists; there isn't one in this crate yet (see this module's
+/// top-level note), so today these are registered by hand.
+pub struct AnimatedTile {
+    pub frames: Vec<u32>,
+    pub frame_time: f32,
+}
+
+/// Every tile id that animates, keyed by the *base* id painted into a
+/// [`TileLayer`] (what `TileLayer::set_tile` was called with). Shared by
+/// every layer in the world, since a tile id means the same thing across
+/// layers using the same atlas.
+#[derive(Resource, Default)]
+pub struct TileAnimations {
+    definitions: HashMap<u32, AnimatedTile>,
+    last_frame_index: HashMap<u32, u64>,
+}
+
+impl TileAnimations {
+    pub fn insert(&mut self, tile_id: u32, animation: AnimatedTile) {
+        self.definitions.insert(tile_id, animation);
+    }
+
+    fn is_animated(&self, tile_id: u32) -> bool {
+        self.definitions.contains_key(&tile_id)
+    }
+
+    /// The tile id that should actually be drawn for `tile_id` at `elapsed`
+    /// seconds into the global clock — itself if it isn't animated.
+    fn current_frame(&self, tile_id: u32, elapsed: f32) -> u32 {
+        match self.definitions.get(&tile_id) {
+            Some(animation) if !animation.frames.is_empty() && animation.frame_time > 0.0 => {
+                let index = (elapsed / animation.frame_time) as usize % animation.frames.len();
+                animation.frames[index]
+            }
+            _ => tile_id,
+        }
+    }
+
+    /// Advances every registered animation to `elapsed`'s frame and returns
+    /// whether any of them landed on a new frame index since the last call.
+    /// `advance_tile_animations` uses this to skip re-dirtying chunks on
+    /// ticks where nothing actually changed.
+    fn tick(&mut self, elapsed: f32) -> bool {
+        let mut changed = false;
+        for (&tile_id, animation) in &self.definitions {
+            if animation.frames.is_empty() || animation.frame_time <= 0.0 {
+                continue;
+            }
+            let index = (elapsed / animation.frame_time) as u64 % animation.frames.len() as u64;
+            if self.last_frame_index.insert(tile_id, index) != Some(index) {
+                changed = true;
+            }
+        }
+        changed
+    }
+}
+
+/// The atlas material every chunk of a [`TileLayer`] renders with. A plain
+/// `ColorMaterial` is enough here: each chunk's mesh carries its own
+/// per-vertex UVs into the atlas texture, so no custom shader is needed to
+/// pick the right sub-rectangle per tile.
+#[derive(Component)]
+pub struct TileLayerMaterial(pub Handle<ColorMaterial>);
+
+/// Tags a chunk's rendered mesh entity, spawned and owned by `layer`.
+#[derive(Component)]
+pub struct TileChunk {
+    pub layer: Entity,
+    pub coord: UVec2,
+}
+
+impl TileLayer {
+    /// A `width` × `height` tile grid, entirely empty, with every chunk
+    /// starting dirty so the first `rebuild_dirty_chunks` pass builds every
+    /// chunk mesh (skipping the ones that stay fully empty).
+    pub fn new(width: u32, height: u32, tile_size: Vec2, atlas_columns: u32, atlas_rows: u32) -> Self {
+        let chunk_count = chunk_count(width, height);
+        let mut dirty_chunks = HashSet::with_capacity((chunk_count.x * chunk_count.y) as usize);
+        for cy in 0..chunk_count.y {
+            for cx in 0..chunk_count.x {
+                dirty_chunks.insert(UVec2::new(cx, cy));
+            }
+        }
+        Self {
+            width,
+            height,
+            tile_size,
+            atlas_columns,
+            atlas_rows,
+            tiles: vec![None; (width * height) as usize],
+            dirty_chunks,
+            animated_chunks: HashSet::new(),
+            chunk_entities: HashMap::new(),
+            layer: GameLayer::TilesBack,
+            y_sort: false,
+        }
+    }
+
+    /// Places this layer's chunks on `layer` instead of the default
+    /// [`GameLayer::TilesBack`]. A layer built with [`GameLayer::TilesFront`]
+    /// paints over [`GameLayer::Player`], so foreground tiles (a tree
+    /// canopy, a doorway frame) can occlude the player just by using this.
+    pub fn with_layer(mut self, layer: GameLayer, y_sort: bool) -> Self {
+        self.layer = layer;
+        self.y_sort = y_sort;
+        self
+    }
+
+    pub fn tile(&self, x: u32, y: u32) -> Option<u32> {
+        self.tiles.get((y * self.width + x) as usize).copied().flatten()
+    }
+
+    /// Sets the tile at `(x, y)` and marks its owning chunk dirty, unless
+    /// it was already that value. Out-of-bounds coordinates are ignored.
+    /// This is the whole API destructible terrain needs: knock out a tile,
+    /// only its chunk's mesh gets rebuilt.
+    pub fn set_tile(&mut self, x: u32, y: u32, tile: Option<u32>) {
+        if x >= self.width || y >= self.height {
+            return;
+        }
+        let index = (y * self.width + x) as usize;
+        if self.tiles[index] == tile {
+            return;
+        }
+        self.tiles[index] = tile;
+        self.dirty_chunks.insert(UVec2::new(x / CHUNK_SIZE, y / CHUNK_SIZE));
+    }
+
+    /// Drains and returns the set of chunks that need their mesh rebuilt.
+    pub fn take_dirty_chunks(&mut self) -> Vec<UVec2> {
+        self.dirty_chunks.drain().collect()
+    }
+
+    /// Chunks whose most recent [`build_chunk_mesh`](Self::build_chunk_mesh)
+    /// call found at least one animated tile — what `advance_tile_animations`
+    /// re-dirties on a frame change.
+    pub fn animated_chunks(&self) -> impl Iterator<Item = UVec2> + '_ {
+        self.animated_chunks.iter().copied()
+    }
+
+    pub fn mark_chunk_dirty(&mut self, chunk: UVec2) {
+        self.dirty_chunks.insert(chunk);
+    }
+
+    /// World-space transform for `chunk`'s mesh entity. The mesh itself is
+    /// built in chunk-local coordinates (see [`build_chunk_mesh`]), so
+    /// placing the chunk is just this one offset.
+    pub fn chunk_transform(&self, chunk: UVec2) -> Transform {
+        let y = chunk.y as f32 * CHUNK_SIZE as f32 * self.tile_size.y;
+        Transform::from_xyz(
+            chunk.x as f32 * CHUNK_SIZE as f32 * self.tile_size.x,
+            y,
+            z_for(self.layer, y, self.y_sort),
+        )
+    }
+
+    /// Builds a single batched mesh for every non-empty tile in `chunk`,
+    /// in chunk-local space (tile `(0, 0)` of the chunk sits at the mesh
+    /// origin), and records whether any tile it drew is animated (see
+    /// [`animated_chunks`](Self::animated_chunks)). `animations`/`elapsed`
+    /// pick which frame of an animated tile actually gets drawn; pass
+    /// `TileAnimations::default()`/`0.0` for a layer with no animated tiles.
+    ///
+    /// Pure and allocation-only otherwise — no `World` access — so it's
+    /// directly unit-testable and benchmarkable without spinning up an
+    /// `App`.
+    pub fn build_chunk_mesh(&mut self, chunk: UVec2, animations: &TileAnimations, elapsed: f32) -> Mesh {
+        let mut positions = Vec::new();
+        let mut uvs = Vec::new();
+        let mut indices = Vec::new();
+        let mut contains_animated = false;
+
+        let start = UVec2::new(chunk.x * CHUNK_SIZE, chunk.y * CHUNK_SIZE);
+        let end = UVec2::new((start.x + CHUNK_SIZE).min(self.width), (start.y + CHUNK_SIZE).min(self.height));
+
+        for y in start.y..end.y {
+            for x in start.x..end.x {
+                let Some(base_tile) = self.tile(x, y) else {
+                    continue;
+                };
+                contains_animated |= animations.is_animated(base_tile);
+                let tile = animations.current_frame(base_tile, elapsed);
+
+                let local = Vec2::new((x - start.x) as f32, (y - start.y) as f32) * self.tile_size;
+                let quad_index = positions.len() as u32;
+
+                positions.push([local.x, local.y, 0.0]);
+                positions.push([local.x + self.tile_size.x, local.y, 0.0]);
+                positions.push([local.x + self.tile_size.x, local.y + self.tile_size.y, 0.0]);
+                positions.push([local.x, local.y + self.tile_size.y, 0.0]);
+
+                let column = tile % self.atlas_columns;
+                let row = tile / self.atlas_columns;
+                let u0 = column as f32 / self.atlas_columns as f32;
+                let v0 = row as f32 / self.atlas_rows as f32;
+                let u1 = (column + 1) as f32 / self.atlas_columns as f32;
+                let v1 = (row + 1) as f32 / self.atlas_rows as f32;
+                uvs.push([u0, v1]);
+                uvs.push([u1, v1]);
+                uvs.push([u1, v0]);
+                uvs.push([u0, v0]);
+
+                indices.extend_from_slice(&[
+                    quad_index,
+                    quad_index + 1,
+                    quad_index + 2,
+                    quad_index,
+                    quad_index + 2,
+                    quad_index + 3,
+                ]);
+            }
+        }
+
+        if contains_animated {
+            self.animated_chunks.insert(chunk);
+        } else {
+            self.animated_chunks.remove(&chunk);
+        }
+
+        let mut mesh = Mesh::new(PrimitiveTopology::TriangleList, RenderAssetUsages::default());
+        mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+        mesh.insert_indices(Indices::U32(indices));
+        mesh
+    }
+}
+
+fn chunk_count(width: u32, height: u32) -> UVec2 {
+    UVec2::new(width.div_ceil(CHUNK_SIZE), height.div_ceil(CHUNK_SIZE))
+}
+
+pub struct TilemapPlugin;
+
+impl Plugin for TilemapPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TileAnimations>()
+            .add_systems(Update, (advance_tile_animations, rebuild_dirty_chunks).chain());
+    }
+}
+
+/// Re-dirties every chunk with an animated tile, but only on ticks where
+/// some animation actually lands on a new frame — most ticks touch
+/// nothing, so this doesn't turn "animated water somewhere in the level"
+/// into "rebuild every chunk every frame".
+fn advance_tile_animations(
+    time: Res<Time>,
+    mut animations: ResMut<TileAnimations>,
+    mut layers: Query<&mut TileLayer>,
+) {
+    if !animations.tick(time.elapsed_seconds()) {
+        return;
+    }
+    for mut layer in &mut layers {
+        for chunk in layer.animated_chunks().collect::<Vec<_>>() {
+            layer.mark_chunk_dirty(chunk);
+        }
+    }
+}
+
+fn rebuild_dirty_chunks(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    animations: Res<TileAnimations>,
+    mut layers: Query<(Entity, &mut TileLayer, &TileLayerMaterial)>,
+    mut chunk_meshes: Query<&mut Mesh2dHandle, With<TileChunk>>,
+) {
+    let elapsed = time.elapsed_seconds();
+    for (layer_entity, mut layer, material) in &mut layers {
+        for chunk in layer.take_dirty_chunks() {
+            let mesh_handle = meshes.add(layer.build_chunk_mesh(chunk, &animations, elapsed));
+            if let Some(&chunk_entity) = layer.chunk_entities.get(&chunk) {
+                if let Ok(mut handle) = chunk_meshes.get_mut(chunk_entity) {
+                    *handle = Mesh2dHandle(mesh_handle);
+                }
+            } else {
+                let transform = layer.chunk_transform(chunk);
+                let chunk_entity = commands
+                    .spawn((
+                        TileChunk {
+                            layer: layer_entity,
+                            coord: chunk,
+                        },
+                        MaterialMesh2dBundle {
+                            mesh: Mesh2dHandle(mesh_handle),
+                            material: material.0.clone(),
+                            transform,
+                            ..default()
+                        },
+                    ))
+                    .id();
+                layer.chunk_entities.insert(chunk, chunk_entity);
+            }
+        }
+    }
+}
@@ -0,0 +1,94 @@
+//! Slow-motion, and this crate's stand-in for a pause. `TimeScale`
+//! multiplies the delta seen by physics, animation, and AI systems;
+//! UI/menus keep reading `Res<Time>` directly so they never freeze
+//! alongside gameplay.
+//!
+//! Systems that should feel the scale take [`GameTime`] instead of
+//! `Res<Time>` — a `SystemParam` rather than a plain multiplication at each
+//! call site, so a system that forgets it still compiles but stands out in
+//! review as the odd one reading `Res<Time>` next to `GameTime` siblings.
+//! `GameTime` is this crate's answer to "a pause-aware delta composing
+//! `TimeScale`, `Paused`, and hit-stop": there's no `Paused` state and no
+//! global hit-stop resource anywhere in this crate (grep turns up no
+//! `States` enum at all, and `enemy_ai::Hitstun` is a per-entity stun on
+//! one enemy, not a global freeze) — `starman`'s own doc comment already
+//! spells out why driving a timer off `GameTime` and setting `TimeScale`
+//! to `0.0` already *is* this crate's pause, with the countdown resuming
+//! exactly where it left off for free. The day either a real `Paused`
+//! state or a global hit-stop resource lands, they fold into
+//! [`GameTime::delta_seconds`]/[`GameTime::delta`] alongside `TimeScale`
+//! and every caller below keeps working unchanged.
+//!
+//! `tests/game_time_pause_audit.rs` is the "done means pausing mid-timer
+//! preserves the remaining duration exactly" check: it drives a real
+//! `starman::Starman` timer through `TimeScale(0.0)` and asserts its
+//! remaining time doesn't move. A fully static lint (walk every system's
+//! `SystemParam`s via reflection and flag a bare `Res<Time>`) isn't
+//! possible today — Bevy systems aren't `Reflect` — so this crate checks
+//! the observable behavior instead of the call site.
+//!
+//! Gameplay timers audited onto `GameTime` this pass: `starman::Starman`
+//! (invincibility) and `player`'s dash cooldown were already on it;
+//! `crumbling::CrumbleState` and `portal::PortalCooldown` are the two that
+//! weren't. There's no enemy-spawner concept anywhere in this crate to
+//! audit alongside them — `player::PlayerSpawner` builds the player's own
+//! composite sprite, not a periodic enemy spawn point, and
+//! `level_load::SpawnQueue` is a one-shot budgeted queue for entities a
+//! level load is already spawning, not something with its own pausable
+//! cooldown.
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+#[derive(Resource)]
+pub struct TimeScale(pub f32);
+
+impl Default for TimeScale {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+#[derive(SystemParam)]
+pub struct GameTime<'w> {
+    time: Res<'w, Time>,
+    scale: Res<'w, TimeScale>,
+}
+
+impl<'w> GameTime<'w> {
+    /// Never negative and never NaN even if `TimeScale` is set to exactly
+    /// zero to freeze gameplay.
+    pub fn delta_seconds(&self) -> f32 {
+        self.time.delta_seconds() * self.scale.0.max(0.0)
+    }
+
+    pub fn elapsed_seconds(&self) -> f32 {
+        self.time.elapsed_seconds()
+    }
+
+    /// For systems that tick a `Timer`, which wants a `Duration` rather
+    /// than a plain `f32`.
+    pub fn delta(&self) -> std::time::Duration {
+        self.time.delta().mul_f32(self.scale.0.max(0.0))
+    }
+}
+
+const BULLET_TIME_SCALE: f32 = 0.25;
+
+pub struct TimeScalePlugin;
+
+impl Plugin for TimeScalePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TimeScale>()
+            .add_systems(Update, toggle_debug_bullet_time);
+    }
+}
+
+/// F6 toggles a fixed 0.25x scale for testing slow-motion feel without
+/// waiting for a boss defeat or bullet-time pickup to trigger it for real.
+fn toggle_debug_bullet_time(keys: Res<ButtonInput<KeyCode>>, mut scale: ResMut<TimeScale>) {
+    if !keys.just_pressed(KeyCode::F6) {
+        return;
+    }
+    scale.0 = if scale.0 == 1.0 { BULLET_TIME_SCALE } else { 1.0 };
+}
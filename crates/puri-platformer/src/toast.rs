@@ -0,0 +1,196 @@
+//! One corner notification stack for every "something happened" message,
+//! replacing three near-identical hand-rolled toasts that each spawned
+//! their own `TextBundle` and ticked their own `Timer`:
+//! `achievements::spawn_toast_on_unlock`, `objectives::spawn_toast_on_complete`,
+//! and `capture::spawn_toast`. Those modules now fire a [`ToastEvent`]
+//! instead and this module owns the stack, animation, dedup, and
+//! localization lookup for all of them.
+//!
+//! Up to [`MAX_VISIBLE`] toasts show at once, newest at the top; anything
+//! past that waits in [`ToastQueue`]'s pending list and is promoted into
+//! whichever slot frees up next, in fire order. Each toast slides in from
+//! off-screen toward its resting position with `math::exp_decay` (the same
+//! easing `camera::camera_follow` uses) and slides back out the same way
+//! once its `duration` elapses, rather than popping in and out.
+//!
+//! Nothing here is gated behind `run_if(in_state(...))`, and toast entities
+//! carry only this module's own [`ActiveToast`] marker — no screen's
+//! despawn-on-close query (`ResultsBox`, `DialogueBox`, ...) can ever catch
+//! one. A toast fired the instant before a state transition (level
+//! complete -> results screen) just keeps animating on schedule; "survives
+//! state transitions" falls out of never having been coupled to one.
+//!
+//! [`text`] is resolved through `localization::Localization` once, at the
+//! moment a toast is promoted from pending to active — a toast is on
+//! screen for a couple of seconds at most, so re-resolving it every frame
+//! for a mid-toast language switch isn't worth the complexity
+//! `objectives::update_hud_text`'s live rebuild is for a checklist that
+//! can sit on screen indefinitely.
+//!
+//! [`icon`] is carried through as an opaque name and never drawn — no
+//! icon-by-name texture atlas exists anywhere in this crate (`animation`'s
+//! own doc comment covers the same missing-by-name-lookup gap for sprite
+//! clips), so today's toast is text-only. Wiring a real icon atlas in is
+//! separate, future work; the field exists so callers don't need to change
+//! when it lands.
+//!
+//! [`fire_toast`] drops a [`ToastEvent`] whose `text` matches one that's
+//! shown or queued within the last [`DEDUPE_SECONDS`] seconds — exactly
+//! "deduplicate identical toasts fired within a second" from the request
+//! this module exists to satisfy.
+
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+
+use crate::localization::Localization;
+use crate::math::exp_decay;
+use crate::schedule::PlatformerSet;
+
+/// How many toasts show at once, stacked top-down.
+const MAX_VISIBLE: usize = 3;
+/// Two `ToastEvent`s with the same text within this many seconds of each
+/// other are treated as one.
+const DEDUPE_SECONDS: f32 = 1.0;
+/// How far off-screen a toast starts/ends, and how it's measured: pixels
+/// added to its resting `right` offset.
+const SLIDE_DISTANCE: f32 = 280.0;
+/// Higher decays the slide faster; matches `camera::camera_follow`'s own
+/// "snappy but not instant" rate for the same `exp_decay` helper.
+const SLIDE_RATE: f32 = 12.0;
+const ROW_HEIGHT: f32 = 28.0;
+const TOP_MARGIN: f32 = 108.0;
+/// Once a sliding-out toast is within this many pixels of fully offscreen,
+/// it's despawned rather than animated the rest of the (asymptotic) way.
+const DESPAWN_THRESHOLD: f32 = 1.0;
+
+/// Fired by any gameplay system that wants a corner notification: an
+/// achievement unlock, a checkpoint, a new high score, a mod finishing
+/// load, a screenshot saved. `text` doubles as a `localization::Localization`
+/// key, the same convention `dialogue`/`objectives` use.
+#[derive(Event, Clone)]
+pub struct ToastEvent {
+    pub text: String,
+    pub icon: Option<String>,
+    pub duration: f32,
+}
+
+struct PendingToast {
+    text: String,
+    duration: f32,
+}
+
+/// Pending toasts waiting for a free slot, and the recent-text cooldowns
+/// [`enqueue_toasts`] dedupes against.
+#[derive(Resource, Default)]
+struct ToastQueue {
+    pending: VecDeque<PendingToast>,
+    recent: Vec<(String, Timer)>,
+}
+
+/// One toast currently occupying a stack slot.
+#[derive(Component)]
+struct ActiveToast {
+    slot: usize,
+    life: Timer,
+    /// Pixels currently added to the resting `right` offset; eased toward
+    /// `0.0` (shown) or `SLIDE_DISTANCE` (hidden) every frame rather than
+    /// snapping.
+    x_offset: f32,
+    removing: bool,
+}
+
+/// `pub(crate)` so `testing::TestWorld` can query active toasts' text —
+/// mirrors `objectives::ObjectivesHudText`'s own `pub(crate)` bump for the
+/// same kind of test-only cross-module access.
+#[derive(Component)]
+pub(crate) struct ToastText;
+
+pub struct ToastPlugin;
+
+impl Plugin for ToastPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ToastQueue>()
+            .add_event::<ToastEvent>()
+            .add_systems(
+                Update,
+                (enqueue_toasts, promote_pending, animate_toasts).chain().in_set(PlatformerSet::PostPhysics),
+            );
+    }
+}
+
+fn enqueue_toasts(mut events: EventReader<ToastEvent>, mut queue: ResMut<ToastQueue>, time: Res<Time>) {
+    for (_, timer) in &mut queue.recent {
+        timer.tick(time.delta());
+    }
+    queue.recent.retain(|(_, timer)| !timer.finished());
+
+    for event in events.read() {
+        if queue.recent.iter().any(|(text, _)| text == &event.text) {
+            continue;
+        }
+        queue.recent.push((event.text.clone(), Timer::from_seconds(DEDUPE_SECONDS, TimerMode::Once)));
+        queue.pending.push_back(PendingToast {
+            text: event.text.clone(),
+            duration: event.duration,
+        });
+    }
+}
+
+fn promote_pending(
+    mut commands: Commands,
+    mut queue: ResMut<ToastQueue>,
+    localization: Res<Localization>,
+    active: Query<&ActiveToast>,
+) {
+    let mut occupied: Vec<usize> = active.iter().map(|toast| toast.slot).collect();
+    while occupied.len() < MAX_VISIBLE {
+        let Some(pending) = queue.pending.pop_front() else {
+            break;
+        };
+        let slot = (0..MAX_VISIBLE).find(|slot| !occupied.contains(slot)).expect("fewer active toasts than MAX_VISIBLE");
+        occupied.push(slot);
+
+        commands.spawn((
+            ActiveToast {
+                slot,
+                life: Timer::from_seconds(pending.duration, TimerMode::Once),
+                x_offset: SLIDE_DISTANCE,
+                removing: false,
+            },
+            TextBundle {
+                text: Text::from_section(localization.resolve(&pending.text), TextStyle::default()),
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(TOP_MARGIN + slot as f32 * ROW_HEIGHT),
+                    right: Val::Px(8.0 + SLIDE_DISTANCE),
+                    ..default()
+                },
+                z_index: ZIndex::Global(1000),
+                ..default()
+            },
+            ToastText,
+        ));
+    }
+}
+
+fn animate_toasts(mut commands: Commands, time: Res<Time>, mut toasts: Query<(Entity, &mut ActiveToast, &mut Style)>) {
+    let dt = time.delta_seconds();
+    for (entity, mut toast, mut style) in &mut toasts {
+        if !toast.removing {
+            toast.life.tick(time.delta());
+            if toast.life.finished() {
+                toast.removing = true;
+            }
+        }
+
+        let target = if toast.removing { SLIDE_DISTANCE } else { 0.0 };
+        toast.x_offset = exp_decay(toast.x_offset, target, SLIDE_RATE, dt);
+        style.right = Val::Px(8.0 + toast.x_offset);
+        style.top = Val::Px(TOP_MARGIN + toast.slot as f32 * ROW_HEIGHT);
+
+        if toast.removing && (SLIDE_DISTANCE - toast.x_offset) < DESPAWN_THRESHOLD {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
@@ -0,0 +1,266 @@
+//! Fullscreen cover-then-reveal transitions for state changes: a level
+//! reload, a death, a menu swap. [`Transition::start`] queues one; this
+//! module owns a single overlay `NodeBundle` and drives it through
+//! covering, holding at full cover (long enough to fire `on_midpoint`
+//! while nothing is on screen), and revealing again — the same
+//! queue-and-promote split `toast`'s stack uses for "more requests than
+//! can run at once arrive; run them one at a time, in fire order" instead
+//! of stomping an in-progress transition.
+//!
+//! [`TransitionKind::Fade`] and [`TransitionKind::Wipe`] are honest to
+//! their names: a fade ramps the overlay's alpha, a wipe grows/slides a
+//! fully-opaque rectangle across the screen. [`TransitionKind::Iris`] is
+//! not: there's no custom `Material2d`/shader anywhere in this crate
+//! (`lighting`'s own doc comment covers the same missing-shader gap for
+//! its lightmap), so a true circular mask isn't available — this draws a
+//! square centered on `center` that grows to cover the screen instead of
+//! a circle. Close enough to read as "iris" at a glance; a real one is
+//! future work once this crate has any shader infrastructure to build it
+//! on.
+//!
+//! [`world_to_screen`] is what centers an `Iris` on the player: project
+//! `Transform::translation` through the active `Camera2d` to get the
+//! viewport-space point `center` needs. `death::start_death_transition`
+//! is the one caller today.
+
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+
+use crate::level_reload::LevelReloadRequested;
+use crate::schedule::PlatformerSet;
+
+/// How long the overlay sits fully covering the screen before revealing,
+/// on top of whichever `duration` `Transition::start` was given for the
+/// cover/reveal ramps themselves — long enough that `on_midpoint`'s work
+/// (a level respawn, a state swap) never flashes through a half-covered
+/// screen.
+const HOLD_SECONDS: f32 = 0.15;
+
+/// Diagonal a `TransitionKind::Iris` square must reach to fully cover a
+/// 1920x1080 window with margin; larger windows just start their iris a
+/// little short of full coverage at the corners, which reads fine.
+const IRIS_MAX_SIDE: f32 = 2400.0;
+
+#[derive(Clone, Copy)]
+pub enum TransitionKind {
+    Fade,
+    Wipe,
+    /// `center` is viewport space (pixels from the top-left), matching
+    /// [`world_to_screen`]'s output and `Style`'s own `Val::Px` origin.
+    Iris { center: Vec2 },
+}
+
+/// What runs the instant the screen is fully covered. `None` is a purely
+/// cosmetic transition with nothing to hide behind it. `ReloadLevel` is
+/// the one concrete hookup today, onto `level_reload`'s existing
+/// `LevelReloadRequested` event; a real state machine (there's no
+/// `States` enum anywhere in this crate yet — grep turns up none) would
+/// add its own "switch to menu/gameplay" variant here rather than this
+/// module inventing one nothing can act on.
+#[derive(Clone)]
+pub enum TransitionAction {
+    None,
+    ReloadLevel,
+}
+
+struct QueuedTransition {
+    kind: TransitionKind,
+    duration: f32,
+    action: TransitionAction,
+}
+
+enum Phase {
+    Covering,
+    Held,
+    Revealing,
+}
+
+struct ActiveTransition {
+    kind: TransitionKind,
+    /// The cover/reveal ramp length `Transition::start` was given; `Held`
+    /// always uses `HOLD_SECONDS` instead, so this is what `Revealing`
+    /// resets `timer` to.
+    duration: f32,
+    phase: Phase,
+    timer: Timer,
+    action: TransitionAction,
+    /// Set once `on_midpoint` has run, so a single-frame timer (`duration
+    /// == 0.0`) can't fire it twice if `tick_transition` ever revisits
+    /// `Phase::Held` in the same frame it was entered.
+    fired: bool,
+}
+
+/// Queues and drives fullscreen cover/reveal transitions. `pub(crate)`
+/// fields would be enough for this crate's own callers, but `start` is
+/// the one entry point so ordering (queue, don't stomp) can't be
+/// bypassed by poking the fields directly.
+#[derive(Resource, Default)]
+pub struct Transition {
+    active: Option<ActiveTransition>,
+    queue: VecDeque<QueuedTransition>,
+}
+
+impl Transition {
+    /// Queues a transition. If one is already covering/held/revealing,
+    /// this waits behind it rather than interrupting it — two deaths in
+    /// quick succession (a hazard that re-kills the player right on
+    /// respawn) each get their own full cover-reveal cycle instead of the
+    /// second stomping the first mid-fade.
+    pub fn start(&mut self, kind: TransitionKind, duration: f32, on_midpoint: TransitionAction) {
+        self.queue.push_back(QueuedTransition { kind, duration, action: on_midpoint });
+    }
+
+    /// True while a transition is covering, held, revealing, or waiting
+    /// in the queue — for a caller that wants to defer other work (e.g.
+    /// not starting a second reload) until the screen is clear again.
+    pub fn is_active(&self) -> bool {
+        self.active.is_some() || !self.queue.is_empty()
+    }
+}
+
+/// Projects a world position through `camera` into viewport pixels, for
+/// centering a `TransitionKind::Iris` on something in the world (the
+/// player's death position, today). `None` if the camera can't currently
+/// map the point (e.g. it's behind an orthographic camera, which can't
+/// happen for this crate's fixed top-down-none 2D camera, but the API is
+/// fallible upstream so this stays fallible too).
+pub fn world_to_screen(camera: &Camera, camera_transform: &GlobalTransform, world_pos: Vec2) -> Option<Vec2> {
+    camera.world_to_viewport(camera_transform, world_pos.extend(0.0))
+}
+
+#[derive(Component)]
+struct TransitionOverlay;
+
+pub struct TransitionPlugin;
+
+impl Plugin for TransitionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Transition>().add_systems(Startup, spawn_overlay).add_systems(
+            Update,
+            (promote_pending, tick_transition).chain().in_set(PlatformerSet::PostPhysics),
+        );
+    }
+}
+
+fn spawn_overlay(mut commands: Commands) {
+    commands.spawn((
+        TransitionOverlay,
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                ..default()
+            },
+            background_color: Color::NONE.into(),
+            z_index: ZIndex::Global(2000),
+            ..default()
+        },
+    ));
+}
+
+/// Pulls the next queued transition into `active` once the overlay is
+/// clear, the same one-at-a-time promotion `toast::promote_pending` does
+/// for its stack slots.
+fn promote_pending(mut transition: ResMut<Transition>) {
+    if transition.active.is_some() {
+        return;
+    }
+    let Some(queued) = transition.queue.pop_front() else {
+        return;
+    };
+    transition.active = Some(ActiveTransition {
+        kind: queued.kind,
+        duration: queued.duration.max(0.0),
+        timer: Timer::from_seconds(queued.duration.max(0.0), TimerMode::Once),
+        phase: Phase::Covering,
+        action: queued.action,
+        fired: false,
+    });
+}
+
+/// Advances the active transition's phase and shapes the overlay to
+/// match. `coverage` (0.0 uncovered .. 1.0 fully covered) drives every
+/// `TransitionKind` the same way; only how each kind turns `coverage`
+/// into `Style`/`BackgroundColor` differs.
+fn tick_transition(
+    time: Res<Time>,
+    mut transition: ResMut<Transition>,
+    mut reload_events: EventWriter<LevelReloadRequested>,
+    mut overlay: Query<(&mut Style, &mut BackgroundColor), With<TransitionOverlay>>,
+) {
+    let Ok((mut style, mut color)) = overlay.get_single_mut() else {
+        return;
+    };
+    let Some(active) = transition.active.as_mut() else {
+        return;
+    };
+
+    active.timer.tick(time.delta());
+    let coverage = match active.phase {
+        Phase::Covering => active.timer.fraction(),
+        Phase::Held => 1.0,
+        Phase::Revealing => 1.0 - active.timer.fraction(),
+    };
+    apply_coverage(active.kind, coverage, &mut style, &mut color);
+
+    match active.phase {
+        Phase::Covering if active.timer.finished() => {
+            active.phase = Phase::Held;
+            active.timer = Timer::from_seconds(HOLD_SECONDS, TimerMode::Once);
+        }
+        Phase::Held if active.timer.finished() => {
+            if !active.fired {
+                match active.action {
+                    TransitionAction::None => {}
+                    TransitionAction::ReloadLevel => {
+                        reload_events.send(LevelReloadRequested);
+                    }
+                }
+                active.fired = true;
+            }
+            active.phase = Phase::Revealing;
+            active.timer = Timer::from_seconds(active.duration, TimerMode::Once);
+        }
+        Phase::Revealing if active.timer.finished() => {
+            apply_coverage(active.kind, 0.0, &mut style, &mut color);
+            transition.active = None;
+        }
+        _ => {}
+    }
+}
+
+/// `coverage` is 0.0 (fully revealed) .. 1.0 (fully covering the screen).
+/// Every branch sets `left`/`top`/`width`/`height` explicitly, even the
+/// ones that never move: whichever `TransitionKind` ran before this one
+/// may have left the overlay sized down to an `Iris` square, and nothing
+/// else resets it back to fullscreen between transitions.
+fn apply_coverage(kind: TransitionKind, coverage: f32, style: &mut Style, color: &mut BackgroundColor) {
+    match kind {
+        TransitionKind::Fade => {
+            style.left = Val::Percent(0.0);
+            style.top = Val::Percent(0.0);
+            style.width = Val::Percent(100.0);
+            style.height = Val::Percent(100.0);
+            color.0 = Color::rgba(0.0, 0.0, 0.0, coverage);
+        }
+        TransitionKind::Wipe => {
+            color.0 = Color::BLACK;
+            style.top = Val::Percent(0.0);
+            style.width = Val::Percent(100.0);
+            style.height = Val::Percent(100.0);
+            // Slides in from off-screen left to cover, then slides back
+            // out the same way to reveal.
+            style.left = Val::Percent((coverage - 1.0) * 100.0);
+        }
+        TransitionKind::Iris { center } => {
+            color.0 = Color::BLACK;
+            let side = IRIS_MAX_SIDE * coverage;
+            style.width = Val::Px(side);
+            style.height = Val::Px(side);
+            style.left = Val::Px(center.x - side / 2.0);
+            style.top = Val::Px(center.y - side / 2.0);
+        }
+    }
+}
@@ -0,0 +1,157 @@
+//! Window-height-driven UI scaling and safe-area insets, so HUD/menu
+//! layouts built at reference 1080p stay proportional at 4K and on
+//! ultrawide instead of rendering pixel-perfect-but-tiny (or overflowing)
+//! at other resolutions.
+//!
+//! Bevy's own `bevy_ui::UiScale` already multiplies every UI node's layout
+//! by a single global factor, and text is measured in that same scaled
+//! space, so it scales font size too — this module just drives that
+//! factor from the primary window's height rather than adding a second,
+//! competing scaling mechanism. [`update_ui_scale`] recomputes it from
+//! `WindowResized` events rather than rebuilding anything, so every UI
+//! root — including the `minimap`/`speedrun_overlay` HUD overlays layered
+//! over the game view — reflows on its very next layout pass just by
+//! reading the same `UiScale` resource everything else already does.
+//!
+//! [`SafeAreaInsets`] is a separate, additive margin for
+//! displays/cabinets that overscan the edges of the frame. A HUD root opts
+//! in by adding [`SafeAreaAnchor`] alongside its `Style`, recording the edge
+//! offset(s) it was spawned with; [`apply_safe_area_insets`] then keeps
+//! those offsets at `base + margin`. `speedrun_overlay`'s input display and
+//! `minimap`'s root both do this already. There's no `Settings` screen
+//! anywhere in this crate to expose `margin` itself from yet
+//! (`window_config`'s own doc comment covers that same gap for fullscreen),
+//! so today changing it means editing [`SafeAreaInsets::default`] or setting
+//! the resource from code, the same way `accessibility::Palette` is picked
+//! without a settings menu.
+
+use bevy::prelude::*;
+use bevy::window::WindowResized;
+
+/// Window height a HUD/menu's hard-coded `Style` pixel values were designed
+/// against — [`update_ui_scale`] divides the real window height by this to
+/// get `UiScale`'s factor.
+const REFERENCE_HEIGHT: f32 = 1080.0;
+
+/// Extra padding, in unscaled logical pixels, a HUD anchor should add on
+/// top of its normal edge offset so its content clears an overscanned
+/// display/arcade cabinet's cropped edges. Defaults to `0.0` (no inset).
+/// Still subject to `UiScale` like any other `Style` value, since it's
+/// meant to be added directly into a node's edge offset.
+#[derive(Resource, Clone, Copy)]
+pub struct SafeAreaInsets {
+    pub margin: f32,
+}
+
+impl Default for SafeAreaInsets {
+    fn default() -> Self {
+        Self { margin: 0.0 }
+    }
+}
+
+/// Marks a `Style`-having node as anchored to one or more screen edges via
+/// `PositionType::Absolute`'s `top`/`left`/`right`/`bottom`, so
+/// [`apply_safe_area_insets`] can grow those offsets by
+/// [`SafeAreaInsets::margin`] without every HUD module reading the resource
+/// itself. Each field holds that edge's base offset (the value it was
+/// spawned with) rather than `bool`, since the system needs something to add
+/// the margin *to* — see `speedrun_overlay`/`minimap`'s root nodes for the
+/// two existing anchors this crate has today.
+#[derive(Component, Clone, Copy, Default)]
+pub struct SafeAreaAnchor {
+    pub top: Option<f32>,
+    pub left: Option<f32>,
+    pub right: Option<f32>,
+    pub bottom: Option<f32>,
+}
+
+pub struct UiScalePlugin;
+
+impl Plugin for UiScalePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SafeAreaInsets>()
+            .add_systems(Startup, apply_initial_ui_scale)
+            .add_systems(
+                Update,
+                (
+                    update_ui_scale,
+                    cycle_debug_window_size,
+                    apply_safe_area_insets,
+                ),
+            );
+    }
+}
+
+fn apply_initial_ui_scale(mut ui_scale: ResMut<UiScale>, windows: Query<&Window>) {
+    let Ok(window) = windows.get_single() else {
+        return;
+    };
+    ui_scale.0 = (window.height() / REFERENCE_HEIGHT).max(0.1);
+}
+
+/// Recomputes `UiScale` from `WindowResized` rather than polling
+/// `Query<&Window, Changed<Window>>` every frame — `Window` changes for
+/// reasons besides a resize (cursor moves, focus, title), so `Changed`
+/// would recompute this (harmlessly, but needlessly) far more often than
+/// the window's size actually changes.
+fn update_ui_scale(mut ui_scale: ResMut<UiScale>, mut resized: EventReader<WindowResized>) {
+    for event in resized.read() {
+        ui_scale.0 = (event.height / REFERENCE_HEIGHT).max(0.1);
+    }
+}
+
+/// Re-applies every [`SafeAreaAnchor`]'s edge offsets each frame rather than
+/// gating on `SafeAreaInsets` change detection: this crate has no Settings
+/// screen to change `margin` from at runtime yet (see this module's own doc
+/// comment), so the handful of HUD anchors this iterates are effectively
+/// static, and re-writing the same `Val::Px` every frame is cheap enough not
+/// to bother with the extra `Changed`/`Added` plumbing.
+fn apply_safe_area_insets(
+    insets: Res<SafeAreaInsets>,
+    mut anchors: Query<(&SafeAreaAnchor, &mut Style)>,
+) {
+    for (anchor, mut style) in &mut anchors {
+        if let Some(base) = anchor.top {
+            style.top = Val::Px(base + insets.margin);
+        }
+        if let Some(base) = anchor.left {
+            style.left = Val::Px(base + insets.margin);
+        }
+        if let Some(base) = anchor.right {
+            style.right = Val::Px(base + insets.margin);
+        }
+        if let Some(base) = anchor.bottom {
+            style.bottom = Val::Px(base + insets.margin);
+        }
+    }
+}
+
+/// The 720p/1080p/1440p sizes [`cycle_debug_window_size`] steps through.
+const DEBUG_WINDOW_SIZES: [(f32, f32); 3] = [(1280.0, 720.0), (1920.0, 1080.0), (2560.0, 1440.0)];
+
+/// Every letter key this crate already claims is spoken for elsewhere
+/// (`challenge`'s `R`, `localization`'s `L`, `difficulty_assist`'s `P`,
+/// ...) and every `F`-key is too (see `speedrun_overlay`'s own doc comment
+/// on that) — `U` is the first free one.
+const CYCLE_WINDOW_SIZE_KEY: KeyCode = KeyCode::KeyU;
+
+/// Cycles the primary window through 720p/1080p/1440p on
+/// `CYCLE_WINDOW_SIZE_KEY`, for manually checking that HUD/menu layouts
+/// reflow correctly at each without a second monitor or OS-level resize.
+/// `Local` rather than a resource since nothing else needs to know which
+/// size is currently selected.
+fn cycle_debug_window_size(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut windows: Query<&mut Window>,
+    mut index: Local<usize>,
+) {
+    if !keys.just_pressed(CYCLE_WINDOW_SIZE_KEY) {
+        return;
+    }
+    let Ok(mut window) = windows.get_single_mut() else {
+        return;
+    };
+    *index = (*index + 1) % DEBUG_WINDOW_SIZES.len();
+    let (width, height) = DEBUG_WINDOW_SIZES[*index];
+    window.resolution.set(width, height);
+}
@@ -0,0 +1,159 @@
+//! Water zones: a `Water` sensor volume that applies a current force to
+//! anything with `Velocity` inside it, and buoyancy for `Buoyant` objects
+//! so they float to the water's surface line instead of sinking.
+
+use bevy::prelude::*;
+
+use crate::collision::HitBox;
+use crate::player::Velocity;
+use crate::render_layer::{z_for, GameLayer};
+
+const GRAVITY: f32 = 420.0;
+const BOB_SPEED: f32 = 2.0;
+const BOB_HEIGHT: f32 = 3.0;
+
+/// A rectangular body of water. `current` is a constant force (units/sec^2)
+/// applied to anything with `Velocity` overlapping the zone.
+#[derive(Component)]
+pub struct Water {
+    pub size: Vec2,
+    pub current: Vec2,
+}
+
+impl Water {
+    /// Whether `point` is inside this zone at `transform` — the same check
+    /// [`apply_current`]/[`apply_buoyancy`] already do inline, exposed for
+    /// `enemy_ai::swim` to keep a `prefab::Fish` inside the water it was
+    /// placed in.
+    pub fn contains(&self, transform: &Transform, point: Vec2) -> bool {
+        inside(transform.translation.truncate(), self.size, point)
+    }
+
+    /// This zone's horizontal extent at `transform`, for `enemy_ai::swim`
+    /// to turn a `Fish` around at the edges instead of swimming out of the
+    /// zone entirely.
+    pub fn x_bounds(&self, transform: &Transform) -> (f32, f32) {
+        let half_width = self.size.x / 2.0;
+        (transform.translation.x - half_width, transform.translation.x + half_width)
+    }
+
+    /// This zone's vertical extent at `transform`. `enemy_ai::swim` checks
+    /// this rather than [`contains`](Self::contains) to decide which zone a
+    /// `Fish` belongs to — `contains` would stop matching the instant an
+    /// overshooting horizontal step puts the fish a hair past
+    /// [`x_bounds`](Self::x_bounds), right when it most needs those bounds
+    /// to turn around.
+    pub fn y_bounds(&self, transform: &Transform) -> (f32, f32) {
+        let half_height = self.size.y / 2.0;
+        (transform.translation.y - half_height, transform.translation.y + half_height)
+    }
+}
+
+/// Floats toward the water's surface line. `density` below 1.0 floats,
+/// above 1.0 sinks to the bottom of the zone instead.
+#[derive(Component)]
+pub struct Buoyant {
+    pub density: f32,
+}
+
+pub struct WaterPlugin;
+
+impl Plugin for WaterPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (apply_current, apply_buoyancy)
+                .chain()
+                .after(crate::schedule::PlatformerSet::CollisionResolve),
+        );
+    }
+}
+
+fn apply_current(
+    time: Res<Time>,
+    water: Query<(&Transform, &Water)>,
+    mut bodies: Query<(&Transform, &mut Velocity)>,
+) {
+    for (water_transform, zone) in &water {
+        for (body_transform, mut velocity) in &mut bodies {
+            if !zone.contains(water_transform, body_transform.translation.truncate()) {
+                continue;
+            }
+            velocity.0 += zone.current * time.delta_seconds();
+        }
+    }
+}
+
+/// Buoyancy nudges position toward the surface line directly, rather than
+/// fighting `apply_current`/gravity through `Velocity`, so the two never
+/// fight over the same value in the same frame.
+fn apply_buoyancy(
+    time: Res<Time>,
+    water: Query<(&Transform, &Water)>,
+    mut bodies: Query<(&mut Transform, &Buoyant), Without<Water>>,
+) {
+    for (water_transform, zone) in &water {
+        let surface_y = water_transform.translation.y + zone.size.y / 2.0;
+        let floor_y = water_transform.translation.y - zone.size.y / 2.0;
+        for (mut body_transform, buoyant) in &mut bodies {
+            if !zone.contains(water_transform, body_transform.translation.truncate()) {
+                continue;
+            }
+            let bob = (time.elapsed_seconds() * BOB_SPEED + body_transform.translation.x).sin() * BOB_HEIGHT;
+            let target_y = if buoyant.density < 1.0 {
+                surface_y + bob
+            } else {
+                floor_y
+            };
+            let rise_speed = GRAVITY * time.delta_seconds();
+            let dy = target_y - body_transform.translation.y;
+            body_transform.translation.y += dy.clamp(-rise_speed, rise_speed);
+        }
+    }
+}
+
+fn inside(center: Vec2, size: Vec2, point: Vec2) -> bool {
+    (point - center).abs().cmplt(size / 2.0).all()
+}
+
+/// A demo pool with a rightward current carrying a floating block.
+pub fn spawn_demo_pool(commands: &mut Commands) {
+    let pool_pos = Vec2::new(400.0, -140.0);
+    let pool_size = Vec2::new(160.0, 40.0);
+
+    commands.spawn((
+        Water {
+            size: pool_size,
+            current: Vec2::new(30.0, 0.0),
+        },
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::rgba(0.2, 0.4, 0.8, 0.5),
+                custom_size: Some(pool_size),
+                ..default()
+            },
+            // Behind `GameLayer::TilesBack` so ground sprites it overlaps
+            // still read as solid, matching the pre-layering z of -1.0.
+            transform: Transform::from_translation(pool_pos.extend(z_for(GameLayer::ParallaxNear, pool_pos.y, false))),
+            ..default()
+        },
+        HitBox { size: pool_size },
+        crate::collision::Sensor,
+    ));
+
+    commands.spawn((
+        Buoyant { density: 0.6 },
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::rgb(0.4, 0.3, 0.2),
+                custom_size: Some(Vec2::new(20.0, 20.0)),
+                ..default()
+            },
+            transform: Transform::from_xyz(pool_pos.x - 60.0, pool_pos.y, z_for(GameLayer::Entities, pool_pos.y, true)),
+            ..default()
+        },
+        HitBox {
+            size: Vec2::new(20.0, 20.0),
+        },
+    ));
+}
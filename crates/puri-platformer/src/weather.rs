@@ -0,0 +1,338 @@
+//! Per-level rain/snow with gameplay hooks: [`Weather`] is `None`, `Rain`,
+//! or `Snow`, each carrying an `intensity` in `[0.0, 1.0]`.
+//!
+//! Particles are the second consumer `pool::EntityPool`'s own doc comment
+//! already anticipated ("a future particle system can reuse it the same
+//! way `projectile.rs` does"), and [`render_layer::GameLayer::Particles`]
+//! is the layer that module's own doc comment reserved for exactly this.
+//! [`WeatherPlugin`] pre-spawns [`PARTICLE_POOL_CAPACITY`] particles at
+//! `Startup`; [`spawn_particles`] tops the active count up toward
+//! `intensity * PARTICLE_POOL_CAPACITY` each frame (bounded by the visible
+//! rect the same way `camera_rail::constrain_to_rail` reads it off
+//! `OrthographicProjection::area`), [`advance_particles`] falls them with a
+//! per-particle random wind drift, and [`recycle_particles`] releases one
+//! back to the pool the moment it drifts outside the visible rect (plus a
+//! margin) rather than despawning it — unlike a projectile, nothing needs
+//! to know *when* that happens, so there's no event for it.
+//!
+//! Switching `Weather`'s kind (rain to snow, say) doesn't force-recolor
+//! whatever's already in flight — the old kind's particles just keep
+//! falling until they naturally recycle and get respawned as the new kind,
+//! which reads as the old weather clearing out rather than snapping.
+//!
+//! Gameplay hooks:
+//! - [`rain_friction_scale`] scales `player::player_input`'s existing
+//!   `step` (acceleration-per-tick) down while raining and the player's
+//!   `Grounded` support is tagged `collision::SurfaceMaterial::Stone` —
+//!   AABB-backend only, the support entity `Grounded` carries under the
+//!   `rapier` feature is always `None` (see that type's own doc comment),
+//!   the same backend gap `script`'s own doc comment already flags for
+//!   `Trigger::PlayerEnters`.
+//! - [`attach_snow_cap`] gives every `collision::PlatformTop` a child "snow
+//!   cap" overlay sprite the moment it's added (the same `Added<T>`-gated
+//!   follow-up spawn `player_state::attach_player_state` uses), and
+//!   [`grow_snow_cover`] fades its alpha in/out with `math::exp_decay` while
+//!   `Weather::Snow` is active — the same displayed-value chase
+//!   `ambience::apply_ambience` uses for its own tint, and the same
+//!   alpha-only fade idiom `crumbling`/`death`/`floating_text` already use
+//!   via `Color::set_a`.
+//!
+//! `Weather`'s intensity is tween-able via [`WeatherKind`] + [`start_tween`]
+//! and [`tick_weather_tween`], the `Resource` equivalent of
+//! `script::MoveTarget`/`tick_moving_targets`'s one-shot `Transform` glide —
+//! `script::execute_actions`'s `Action::SetWeather` is the trigger/action
+//! table's way of rolling a storm in over `secs` seconds instead of
+//! snapping the sky on and off.
+
+use bevy::prelude::*;
+
+use crate::collision::{HitBox, PlatformTop, SurfaceMaterial};
+use crate::math::exp_decay;
+use crate::pool::{EntityPool, Pooled};
+use crate::render_layer::{z_for, GameLayer};
+use crate::rng::GameRng;
+use crate::schedule::PlatformerSet;
+use crate::time_scale::GameTime;
+
+/// How many particles `WeatherPlugin` pre-spawns — enough for full-screen
+/// rain at `intensity: 1.0` in the demo map's window without ever falling
+/// back to [`EntityPool::acquire`]'s unpooled spawn.
+const PARTICLE_POOL_CAPACITY: usize = 400;
+
+const RAIN_FALL_SPEED: f32 = 900.0;
+const SNOW_FALL_SPEED: f32 = 120.0;
+/// Per-particle horizontal wind drift is drawn uniformly from
+/// `[-WIND_DRIFT_RANGE, WIND_DRIFT_RANGE]` once, at spawn, and held for the
+/// particle's whole time in flight — real wind gusting frame to frame isn't
+/// worth the extra state for a background effect.
+const WIND_DRIFT_RANGE: f32 = 60.0;
+/// How far outside the visible rect (on every side) a particle is allowed
+/// to drift before [`recycle_particles`] releases it — bigger than either
+/// fall speed's worth of one frame's travel, so a particle can never skip
+/// past the check between two solid frames.
+const RECYCLE_MARGIN: f32 = 64.0;
+
+/// A level's current precipitation and how heavy it is. `Weather::None`'s
+/// own `intensity` reads as `0.0` via [`Weather::intensity`] so callers
+/// don't need to match it out separately from "no weather".
+#[derive(Resource, Clone, Copy, Default, Debug, PartialEq)]
+pub enum Weather {
+    #[default]
+    None,
+    Rain {
+        intensity: f32,
+    },
+    Snow {
+        intensity: f32,
+    },
+}
+
+impl Weather {
+    pub fn intensity(&self) -> f32 {
+        match self {
+            Weather::None => 0.0,
+            Weather::Rain { intensity } | Weather::Snow { intensity } => *intensity,
+        }
+    }
+}
+
+/// The `Weather` variant an `Action::SetWeather` names, without the
+/// intensity itself — `intensity`/`secs` are separate fields on the action,
+/// the same split `Action::MovePlatform` already uses for its own
+/// `target`/`to`/`secs`.
+#[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum WeatherKind {
+    None,
+    Rain,
+    Snow,
+}
+
+/// A pooled rain drop or snowflake. `velocity` is constant for the whole
+/// time it's in flight — see this module's own doc comment on why wind
+/// drift doesn't change mid-flight.
+#[derive(Component, Clone, Copy)]
+pub struct WeatherParticle {
+    velocity: Vec2,
+}
+
+/// Every component a pooled particle carries — see `pool`'s own doc comment
+/// on why that's what lets an acquire/release pair skip a full respawn's
+/// worth of archetype moves.
+#[derive(Bundle, Clone)]
+pub struct WeatherParticleBundle {
+    particle: WeatherParticle,
+    sprite: SpriteBundle,
+}
+
+/// The visual "snow has settled here" overlay [`attach_snow_cap`] gives
+/// every `collision::PlatformTop`. `cover` is `[0.0, 1.0]`, mirrored
+/// straight onto the sprite's alpha by [`grow_snow_cover`].
+#[derive(Component, Default)]
+pub struct SnowCap {
+    cover: f32,
+}
+
+const SNOW_CAP_HEIGHT: f32 = 6.0;
+/// `exp_decay` rate `grow_snow_cover` chases `SnowCap::cover` toward its
+/// target at — see `ambience::TRANSITION_RATE`'s own doc comment for what
+/// "rate" means here.
+const SNOW_COVER_RATE: f32 = 1.5;
+
+/// How much rain scales down `player::player_input`'s acceleration `step`
+/// at `intensity: 1.0` while standing on `SurfaceMaterial::Stone` — "slightly
+/// slippery", not an ice rink.
+const RAIN_SLIP_FACTOR: f32 = 0.4;
+
+/// Glides `Weather`'s intensity from wherever it is toward `to_kind`'s
+/// `to_intensity` over `secs` seconds, driven by [`tick_weather_tween`] —
+/// see this module's own doc comment on why this is the `Resource`
+/// equivalent of `script::MoveTarget`.
+#[derive(Resource)]
+struct WeatherTween {
+    to_kind: WeatherKind,
+    to_intensity: f32,
+    from_intensity: f32,
+    timer: Timer,
+}
+
+/// Starts (or replaces) a [`WeatherTween`] toward `to_kind`/`to_intensity`.
+/// `pub(crate)` so `script::execute_actions` can drive `Action::SetWeather`
+/// without this module needing to know scripts exist.
+pub(crate) fn start_tween(commands: &mut Commands, from: Weather, to_kind: WeatherKind, to_intensity: f32, secs: f32) {
+    commands.insert_resource(WeatherTween {
+        to_kind,
+        to_intensity,
+        from_intensity: from.intensity(),
+        timer: Timer::from_seconds(secs.max(0.0), TimerMode::Once),
+    });
+}
+
+pub struct WeatherPlugin;
+
+impl Plugin for WeatherPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Weather>()
+            .add_systems(Startup, init_weather_particle_pool)
+            .add_systems(
+                Update,
+                (spawn_particles, advance_particles, recycle_particles)
+                    .chain()
+                    .in_set(PlatformerSet::PostPhysics),
+            )
+            .add_systems(Update, (attach_snow_cap, grow_snow_cover, tick_weather_tween));
+    }
+}
+
+fn init_weather_particle_pool(mut commands: Commands) {
+    let template = WeatherParticleBundle {
+        particle: WeatherParticle { velocity: Vec2::ZERO },
+        sprite: SpriteBundle::default(),
+    };
+    let pool = EntityPool::new(&mut commands, PARTICLE_POOL_CAPACITY, template);
+    commands.insert_resource(pool);
+}
+
+fn spawn_particles(
+    mut commands: Commands,
+    weather: Res<Weather>,
+    mut pool: ResMut<EntityPool<WeatherParticleBundle>>,
+    mut rng: ResMut<GameRng>,
+    camera: Query<(&Transform, &OrthographicProjection), With<Camera2d>>,
+    active: Query<(), (With<WeatherParticle>, Without<Pooled>)>,
+) {
+    let (fall_speed, color, size) = match *weather {
+        Weather::None => return,
+        Weather::Rain { .. } => (RAIN_FALL_SPEED, Color::rgba(0.6, 0.7, 0.9, 0.6), Vec2::new(2.0, 14.0)),
+        Weather::Snow { .. } => (SNOW_FALL_SPEED, Color::rgba(1.0, 1.0, 1.0, 0.9), Vec2::new(4.0, 4.0)),
+    };
+    let desired = (weather.intensity().clamp(0.0, 1.0) * PARTICLE_POOL_CAPACITY as f32) as usize;
+    let current = active.iter().count();
+    if current >= desired {
+        return;
+    }
+    let Ok((camera_transform, projection)) = camera.get_single() else {
+        return;
+    };
+    let camera_pos = camera_transform.translation.truncate();
+    let visible_min = camera_pos + projection.area.min;
+    let visible_max = camera_pos + projection.area.max;
+    let z = z_for(GameLayer::Particles, visible_max.y, false);
+
+    for _ in 0..(desired - current) {
+        let position = Vec2::new(
+            rng.range_f32(visible_min.x - RECYCLE_MARGIN, visible_max.x + RECYCLE_MARGIN),
+            rng.range_f32(visible_min.y, visible_max.y),
+        );
+        let velocity = Vec2::new(rng.range_f32(-WIND_DRIFT_RANGE, WIND_DRIFT_RANGE), -fall_speed);
+        pool.acquire(
+            &mut commands,
+            WeatherParticleBundle {
+                particle: WeatherParticle { velocity },
+                sprite: SpriteBundle {
+                    sprite: Sprite {
+                        color,
+                        custom_size: Some(size),
+                        ..default()
+                    },
+                    transform: Transform::from_translation(position.extend(z)),
+                    ..default()
+                },
+            },
+        );
+    }
+}
+
+fn advance_particles(time: GameTime, mut particles: Query<(&mut Transform, &WeatherParticle), Without<Pooled>>) {
+    let dt = time.delta_seconds();
+    for (mut transform, particle) in &mut particles {
+        transform.translation += (particle.velocity * dt).extend(0.0);
+    }
+}
+
+fn recycle_particles(
+    mut commands: Commands,
+    mut pool: ResMut<EntityPool<WeatherParticleBundle>>,
+    camera: Query<(&Transform, &OrthographicProjection), With<Camera2d>>,
+    particles: Query<(Entity, &Transform), (With<WeatherParticle>, Without<Pooled>)>,
+) {
+    let Ok((camera_transform, projection)) = camera.get_single() else {
+        return;
+    };
+    let camera_pos = camera_transform.translation.truncate();
+    let visible_min = camera_pos + projection.area.min - Vec2::splat(RECYCLE_MARGIN);
+    let visible_max = camera_pos + projection.area.max + Vec2::splat(RECYCLE_MARGIN);
+    for (entity, transform) in &particles {
+        let pos = transform.translation.truncate();
+        if pos.y < visible_min.y || pos.x < visible_min.x || pos.x > visible_max.x {
+            pool.release(&mut commands, entity);
+        }
+    }
+}
+
+fn attach_snow_cap(mut commands: Commands, tops: Query<(Entity, &HitBox), Added<PlatformTop>>) {
+    for (entity, hitbox) in &tops {
+        commands.entity(entity).with_children(|parent| {
+            parent.spawn((
+                SnowCap::default(),
+                SpriteBundle {
+                    sprite: Sprite {
+                        color: Color::rgba(1.0, 1.0, 1.0, 0.0),
+                        custom_size: Some(Vec2::new(hitbox.size.x, SNOW_CAP_HEIGHT)),
+                        ..default()
+                    },
+                    transform: Transform::from_xyz(0.0, hitbox.size.y / 2.0, 0.1),
+                    ..default()
+                },
+            ));
+        });
+    }
+}
+
+fn grow_snow_cover(time: GameTime, weather: Res<Weather>, mut caps: Query<(&mut SnowCap, &mut Sprite)>) {
+    let dt = time.delta_seconds();
+    let target = match *weather {
+        Weather::Snow { intensity } => intensity.clamp(0.0, 1.0),
+        _ => 0.0,
+    };
+    for (mut cap, mut sprite) in &mut caps {
+        cap.cover = exp_decay(cap.cover, target, SNOW_COVER_RATE, dt);
+        sprite.color.set_a(cap.cover);
+    }
+}
+
+fn tick_weather_tween(time: GameTime, mut commands: Commands, tween: Option<ResMut<WeatherTween>>, mut weather: ResMut<Weather>) {
+    let Some(mut tween) = tween else {
+        return;
+    };
+    tween.timer.tick(time.delta());
+    let t = (tween.timer.elapsed_secs() / tween.timer.duration().as_secs_f32()).clamp(0.0, 1.0);
+    let intensity = tween.from_intensity + (tween.to_intensity - tween.from_intensity) * t;
+    *weather = match tween.to_kind {
+        WeatherKind::None => Weather::None,
+        WeatherKind::Rain => Weather::Rain { intensity },
+        WeatherKind::Snow => Weather::Snow { intensity },
+    };
+    if tween.timer.finished() {
+        commands.remove_resource::<WeatherTween>();
+    }
+}
+
+/// How much rain scales `player::player_input`'s acceleration `step` this
+/// tick — `1.0` (no change) unless it's raining and `support` (the
+/// player's `player::Grounded` entity, if any) is tagged
+/// `SurfaceMaterial::Stone`. See this module's own doc comment on why this
+/// only ever does anything under the default AABB collision backend.
+pub fn rain_friction_scale(weather: &Weather, support: Option<Entity>, surfaces: &Query<&SurfaceMaterial>) -> f32 {
+    let Weather::Rain { intensity } = weather else {
+        return 1.0;
+    };
+    let Some(support) = support else {
+        return 1.0;
+    };
+    if matches!(surfaces.get(support), Ok(SurfaceMaterial::Stone)) {
+        1.0 - RAIN_SLIP_FACTOR * intensity.clamp(0.0, 1.0)
+    } else {
+        1.0
+    }
+}
@@ -0,0 +1,87 @@
+//! Startup window configuration and a runtime fullscreen toggle. There's no
+//! persisted `Settings` file anywhere in this codebase yet, no pixel-perfect
+//! camera, and no split-screen viewports — so "propagate resize to those"
+//! from the request is a non-issue today; `camera_follow` already reads the
+//! window/camera transform fresh every frame rather than caching a viewport
+//! rect, so nothing to update when the window resizes.
+
+use bevy::prelude::*;
+use bevy::window::{PrimaryWindow, WindowMode};
+
+/// Read once at [`Startup`] to size the primary window; also tracks the
+/// current fullscreen choice so [`toggle_fullscreen`] can flip it back.
+#[derive(Resource, Clone)]
+pub struct WindowConfig {
+    pub title: String,
+    pub width: f32,
+    pub height: f32,
+    pub vsync: bool,
+    pub resizable: bool,
+    pub start_fullscreen: bool,
+}
+
+impl Default for WindowConfig {
+    fn default() -> Self {
+        Self {
+            title: "Puri Arcade".to_string(),
+            width: 1280.0,
+            height: 720.0,
+            vsync: true,
+            resizable: true,
+            start_fullscreen: false,
+        }
+    }
+}
+
+pub struct WindowConfigPlugin;
+
+impl Plugin for WindowConfigPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<WindowConfig>()
+            .add_systems(Startup, apply_window_config)
+            .add_systems(Update, toggle_fullscreen);
+    }
+}
+
+fn apply_window_config(config: Res<WindowConfig>, mut windows: Query<&mut Window, With<PrimaryWindow>>) {
+    let Ok(mut window) = windows.get_single_mut() else {
+        return;
+    };
+    window.title = config.title.clone();
+    window.resolution.set(config.width, config.height);
+    window.resizable = config.resizable;
+    window.present_mode = if config.vsync {
+        bevy::window::PresentMode::AutoVsync
+    } else {
+        bevy::window::PresentMode::AutoNoVsync
+    };
+    if config.start_fullscreen {
+        window.mode = WindowMode::BorderlessFullscreen;
+    }
+}
+
+/// Alt+Enter flips between windowed and borderless fullscreen, persisting
+/// the choice on `WindowConfig` so anything that re-reads it later starts
+/// back up in the mode the player left it in. Reusing the single
+/// `PrimaryWindow` query rather than caching a window entity means a
+/// monitor hot-swap or window recreation can't leave this pointed at a
+/// stale, now-invalid window.
+fn toggle_fullscreen(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut config: ResMut<WindowConfig>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    let alt = keys.pressed(KeyCode::AltLeft) || keys.pressed(KeyCode::AltRight);
+    if !alt || !keys.just_pressed(KeyCode::Enter) {
+        return;
+    }
+    let Ok(mut window) = windows.get_single_mut() else {
+        return;
+    };
+    config.start_fullscreen = !config.start_fullscreen;
+    window.mode = if config.start_fullscreen {
+        WindowMode::BorderlessFullscreen
+    } else {
+        WindowMode::Windowed
+    };
+}
@@ -0,0 +1,116 @@
+//! Persisted per-level-entity completion flags, so a returning-to-the-
+//! level `level_reload::reload_level` doesn't respawn (or re-offer) things
+//! the player already finished.
+//!
+//! Keyed by [`WorldFlagId`], a plain `u32` component attached to the
+//! level-data entity itself — a literal in `map::spawn_map_entities`,
+//! mirroring `death::Checkpoint::id` and `portal::PortalId`, not derived
+//! from spawn order. Editing `spawn_map_entities` (adding, removing, or
+//! reordering entities) never shifts which flag belongs to which entity,
+//! since the id travels with the entity's own spawn call rather than with
+//! its position in the function.
+//!
+//! [`WorldFlags::is_set`]/[`set`](WorldFlags::set) are the whole
+//! interface. `map::spawn_map_entities` consults `is_set` before spawning
+//! a flagged one-time entity; the system that reacts to the entity being
+//! completed calls `set`.
+//!
+//! There's no unified `SaveData` struct anywhere in this crate for this
+//! to join (`cutscene`'s own doc comment already covers why), so
+//! persistence here follows the existing one-file-per-system convention
+//! instead: a `.ron` file of its own, loaded/saved the same one-shot way
+//! `shop`/`high_scores` are, behind the `serde` feature.
+//!
+//! Wired up today: one-time pickups, via `starman::collect_star_pickup`
+//! setting the flag on collection (before it despawns the pickup, so the
+//! id is read off the still-alive entity) and `map::spawn_map_entities`
+//! skipping the spawn if it's already set (this module's own test covers
+//! exactly that round trip). `loot::LootDrop` isn't a candidate — a fresh
+//! one pops out of every stomp kill, so there's nothing there to persist
+//! as "already collected". `boss::BossDefeated` and `boss::Door` are the
+//! same shape of one-time flag, but `boss::spawn_boss_arena` is its own
+//! standalone demo scene that `spawn_map_entities` never calls (see that
+//! function's own doc comment), so there's no level-load path to wire a
+//! door's open state into yet; likewise `cutscene::CutscenePlayed` already
+//! tracks "seen" for its own purposes but nothing the level loader spawns
+//! differently based on whether a cutscene has played. Both are left for
+//! whichever level actually uses them.
+
+use bevy::prelude::*;
+use bevy::utils::HashSet;
+
+/// A stable, level-authored identifier for an entity whose completion
+/// should survive a reload. See this module's own doc comment on why this
+/// (and not spawn order) is what stays stable across level file edits.
+#[derive(Component, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct WorldFlagId(pub u32);
+
+/// The set of flag ids that have been completed. Order doesn't matter and
+/// ids are never removed, so a `HashSet` (rather than `HighScores`' `Vec`,
+/// which needs insertion order) is the natural fit.
+#[derive(Resource, Reflect, Clone, Default)]
+#[reflect(Resource)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WorldFlags(HashSet<u32>);
+
+impl WorldFlags {
+    pub fn is_set(&self, id: u32) -> bool {
+        self.0.contains(&id)
+    }
+
+    pub fn set(&mut self, id: u32) {
+        self.0.insert(id);
+    }
+}
+
+pub struct WorldFlagsPlugin;
+
+impl Plugin for WorldFlagsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<WorldFlags>().init_resource::<WorldFlags>();
+
+        #[cfg(feature = "serde")]
+        app.add_systems(Startup, persistence::load_from_disk.before(crate::map::setup_map));
+    }
+}
+
+#[cfg(feature = "serde")]
+mod persistence {
+    use super::WorldFlags;
+    use bevy::prelude::*;
+    use std::path::Path;
+
+    const SAVE_PATH: &str = "assets/world_flags.ron";
+
+    /// One-shot load of `assets/world_flags.ron` over the (empty) default
+    /// set, if present. Mirrors `high_scores::persistence::load_from_disk`.
+    /// Ordered `.before(map::setup_map)` so the very first
+    /// `spawn_map_entities` call already sees a restored save, not just
+    /// the one after a `level_reload`.
+    pub fn load_from_disk(mut flags: ResMut<WorldFlags>) {
+        let Ok(contents) = std::fs::read_to_string(Path::new(SAVE_PATH)) else {
+            return;
+        };
+        match ron::from_str::<WorldFlags>(&contents) {
+            Ok(loaded) => *flags = loaded,
+            Err(err) => warn!("failed to parse {SAVE_PATH}: {err}"),
+        }
+    }
+
+    /// Writes the current flags to `assets/world_flags.ron`. Call after
+    /// [`WorldFlags::set`] so a completed flag survives the cartridge
+    /// teardown/relaunch cycle, same as `shop::persistence::save_to_disk`.
+    pub fn save_to_disk(flags: &WorldFlags) {
+        match ron::to_string(flags) {
+            Ok(serialized) => {
+                if let Err(err) = std::fs::write(SAVE_PATH, serialized) {
+                    warn!("failed to write {SAVE_PATH}: {err}");
+                }
+            }
+            Err(err) => warn!("failed to serialize world flags: {err}"),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+pub use persistence::save_to_disk;
@@ -0,0 +1,79 @@
+use puri_platformer::testing::{Action, TestWorld};
+
+#[test]
+fn f8_toggles_reduce_flashing_and_stops_the_starman_rainbow_cycling() {
+    let mut world = TestWorld::new();
+    world.step(1);
+    world.give_player_starman();
+    world.step(1);
+    let first = world.player_sprite_color();
+    world.step(60);
+    let second = world.player_sprite_color();
+    assert_ne!(first, second, "starman tint should still cycle with reduce_flashing off");
+
+    world.press_f8();
+    world.step(1);
+    assert!(world.accessibility_options().reduce_flashing);
+
+    let steady_first = world.player_sprite_color();
+    world.step(60);
+    let steady_second = world.player_sprite_color();
+    assert_eq!(steady_first, steady_second, "reduce_flashing should hold a steady tint instead of cycling");
+}
+
+#[test]
+fn f9_toggles_colorblind_palette_and_recolors_spawned_entities_immediately() {
+    let mut world = TestWorld::new();
+    world.step(1);
+    let standard_checkpoint = world.checkpoint_color();
+    let standard_gate = world.exit_gate_color();
+
+    world.press_f9();
+    world.step(1);
+    assert!(world.accessibility_options().colorblind_palette);
+
+    let swapped = world.palette();
+    assert_ne!(world.checkpoint_color(), standard_checkpoint);
+    assert_ne!(world.exit_gate_color(), standard_gate);
+    assert_eq!(world.checkpoint_color(), swapped.checkpoint);
+    assert_eq!(world.exit_gate_color(), swapped.hazard);
+}
+
+#[test]
+fn f10_toggle_input_mode_raises_and_drops_the_shield_on_separate_presses() {
+    let mut world = TestWorld::new();
+    world.step(60); // settle on the floor.
+
+    world.press_f10();
+    world.step(1);
+    assert!(world.accessibility_options().toggle_input_mode);
+
+    world.press(Action::Shield);
+    world.step(1);
+    assert!(world.player_has_shield(), "a single press should raise the shield in toggle mode");
+
+    world.release(Action::Shield);
+    world.step(5); // key let go; shield should stay up until pressed again.
+    assert!(world.player_has_shield());
+
+    world.press(Action::Shield);
+    world.step(1);
+    assert!(!world.player_has_shield(), "a second press should drop the shield in toggle mode");
+}
+
+#[test]
+fn f10_toggle_input_mode_still_drains_stamina_while_the_shield_is_up() {
+    let mut world = TestWorld::new();
+    world.step(60);
+    world.press_f10();
+    world.step(1);
+
+    let starting = world.player_stamina();
+    world.press(Action::Shield);
+    world.step(1);
+    assert!(world.player_has_shield());
+
+    world.step(10); // held up without the key, per toggle mode.
+    let drained = world.player_stamina();
+    assert!(drained < starting, "a toggled-on shield should still drain stamina like a held one");
+}
@@ -0,0 +1,52 @@
+use puri_platformer::achievements::AchievementId;
+use puri_platformer::testing::TestWorld;
+
+#[test]
+fn enough_stomps_unlock_the_achievement() {
+    let mut world = TestWorld::new();
+    assert!(!world.achievement_is_unlocked(AchievementId::EnemiesStomped));
+
+    world.defeat_enemies(19);
+    world.step(1);
+    assert!(!world.achievement_is_unlocked(AchievementId::EnemiesStomped), "one short of the default target of 20");
+
+    world.defeat_enemies(1);
+    world.step(1);
+    assert_eq!(world.achievement_progress(AchievementId::EnemiesStomped), 20);
+    assert!(world.achievement_is_unlocked(AchievementId::EnemiesStomped));
+}
+
+#[test]
+fn a_gold_finish_unlocks_the_achievement() {
+    let mut world = TestWorld::new();
+    world.finish_challenge_with_gold();
+    world.step(1);
+    assert!(world.achievement_is_unlocked(AchievementId::GoldTime));
+}
+
+#[test]
+fn a_deathless_clear_unlocks_the_achievement() {
+    let mut world = TestWorld::new();
+    world.complete_level(0);
+    world.step(1);
+    assert!(world.achievement_is_unlocked(AchievementId::DeathlessClear));
+}
+
+#[test]
+fn dying_before_clearing_breaks_the_streak_but_only_for_that_attempt() {
+    let mut world = TestWorld::new();
+    world.step(60); // settle on the floor.
+
+    world.deal_damage(3); // matches PlayerBundle's starting Health(3).
+    world.step(100); // ride out the fade-out/hold/fade-in respawn sequence.
+
+    world.complete_level(0);
+    world.step(1);
+    assert!(!world.achievement_is_unlocked(AchievementId::DeathlessClear), "this attempt wasn't deathless");
+
+    // The streak resets after every clear, deathless or not, so the next
+    // attempt starts eligible again.
+    world.complete_level(0);
+    world.step(1);
+    assert!(world.achievement_is_unlocked(AchievementId::DeathlessClear));
+}
@@ -0,0 +1,47 @@
+use bevy::prelude::Color;
+use puri_platformer::prelude::DayNightCycle;
+use puri_platformer::testing::TestWorld;
+
+#[test]
+fn darkness_starts_at_zero() {
+    let mut world = TestWorld::new();
+    world.step(1);
+    assert_eq!(world.displayed_ambient_darkness(), 0.0);
+}
+
+#[test]
+fn displayed_darkness_eases_toward_the_level_s_target_rather_than_snapping() {
+    let mut world = TestWorld::new();
+    world.set_level_ambience(Color::rgb(0.5, 0.5, 0.9), 0.8);
+
+    world.step(1);
+    let after_one_tick = world.displayed_ambient_darkness();
+    assert!(
+        after_one_tick < 0.8,
+        "expected a partial step toward the target, not an instant snap, got {after_one_tick}"
+    );
+
+    world.step(600);
+    let after_many_ticks = world.displayed_ambient_darkness();
+    assert!(
+        after_many_ticks > after_one_tick && after_many_ticks <= 0.8,
+        "expected darkness to keep approaching, but not overshoot, its target: after_one_tick={after_one_tick}, after_many_ticks={after_many_ticks}"
+    );
+}
+
+#[test]
+fn a_day_night_cycle_drives_level_ambience_on_its_own() {
+    let mut world = TestWorld::new();
+    world.insert_day_night_cycle(DayNightCycle {
+        period: 120.0,
+        max_darkness: 0.6,
+        night_tint: Color::rgb(0.4, 0.45, 0.8),
+    });
+
+    world.step(1);
+    let darkness = world.level_ambience_darkness();
+    assert!(
+        (0.0..=0.6).contains(&darkness),
+        "expected the cycle to keep LevelAmbience within its configured range, got {darkness}"
+    );
+}
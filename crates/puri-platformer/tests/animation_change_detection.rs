@@ -0,0 +1,17 @@
+use puri_platformer::animation::SpriteAnimation;
+use puri_platformer::testing::{Action, TestWorld};
+
+/// `animate_sprite` only writes `TextureAtlas::index` on the tick a frame
+/// actually advances (see synth-126) — a tick that doesn't cross the
+/// 1/12s frame boundary shouldn't mark it `Changed`, since that flag is
+/// what drives (otherwise pointless) render-world extraction work.
+#[test]
+fn atlas_is_not_marked_changed_on_a_tick_that_does_not_advance_the_frame() {
+    let mut world = TestWorld::new();
+    world.press(Action::Right);
+    // Land on a tick well short of the 1/12s frame boundary so the frame
+    // can't have advanced.
+    world.step(1);
+
+    assert!(!world.player_atlas_changed());
+}
@@ -0,0 +1,34 @@
+use bevy::prelude::default;
+use puri_platformer::animation::SpriteAnimation;
+use puri_platformer::testing::TestWorld;
+
+#[test]
+fn swapping_to_a_shorter_clip_never_leaves_an_out_of_range_index() {
+    let mut world = TestWorld::new();
+    world.step(1);
+
+    // Simulate a 6-frame idle clip sitting at its last frame...
+    world.set_player_animation(SpriteAnimation {
+        first: 0,
+        last: 5,
+        play_once: false,
+        ..default()
+    });
+    world.set_player_atlas_index(5);
+
+    // ...then an immediate swap to a 2-frame clip, before any tick of
+    // animate_sprite runs the old modulo-on-tick check.
+    world.set_player_animation(SpriteAnimation {
+        first: 0,
+        last: 2,
+        play_once: false,
+        ..default()
+    });
+    assert!(world.player_atlas_index() <= 2);
+
+    // A handful of ticks afterward should never push it out of range either.
+    for _ in 0..20 {
+        world.step(1);
+        assert!(world.player_atlas_index() <= 2);
+    }
+}
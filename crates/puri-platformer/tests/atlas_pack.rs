@@ -0,0 +1,103 @@
+#![cfg(feature = "serde")]
+
+use std::path::{Path, PathBuf};
+
+use image::{Rgba, RgbaImage};
+use puri_platformer::atlas_pack::{self, MAX_ATLAS_SIZE};
+
+/// A scratch `assets/raw/<character>`-shaped directory that cleans up
+/// after itself, so a test failure partway through doesn't leave stray
+/// fixture PNGs for the next run to trip over.
+struct ScratchDir(PathBuf);
+
+impl ScratchDir {
+    fn new(name: &str) -> Self {
+        let dir = std::env::temp_dir().join(format!("puri_atlas_pack_test_{name}"));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        Self(dir)
+    }
+
+    fn path(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for ScratchDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+/// A single-color frame, so a pixel readback only has to check one value
+/// rather than comparing whole images.
+fn solid_frame(width: u32, height: u32, color: Rgba<u8>) -> RgbaImage {
+    RgbaImage::from_pixel(width, height, color)
+}
+
+#[test]
+fn every_input_frames_pixels_appear_unchanged_in_the_packed_atlas() {
+    let dir = ScratchDir::new("roundtrip");
+    let frames = [
+        ("idle", solid_frame(8, 8, Rgba([255, 0, 0, 255]))),
+        ("walk_0", solid_frame(8, 8, Rgba([0, 255, 0, 255]))),
+        ("walk_1", solid_frame(12, 6, Rgba([0, 0, 255, 255]))),
+    ];
+    for (name, frame) in &frames {
+        frame.save(dir.path().join(format!("{name}.png"))).unwrap();
+    }
+
+    let (atlas, layout) = atlas_pack::pack(dir.path()).unwrap();
+
+    for (name, frame) in &frames {
+        let rect = layout.frames.get(*name).unwrap_or_else(|| panic!("missing layout entry for {name}"));
+        assert_eq!((rect.width, rect.height), frame.dimensions());
+        for y in 0..rect.height {
+            for x in 0..rect.width {
+                assert_eq!(
+                    atlas.get_pixel(rect.x + x, rect.y + y),
+                    frame.get_pixel(x, y),
+                    "pixel ({x}, {y}) of frame {name:?} changed after packing"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn packing_is_deterministic_across_runs() {
+    let dir = ScratchDir::new("determinism");
+    for (name, color) in [("a", [255, 0, 0, 255]), ("b", [0, 255, 0, 255]), ("c", [0, 0, 255, 255])] {
+        solid_frame(4, 4, Rgba(color)).save(dir.path().join(format!("{name}.png"))).unwrap();
+    }
+
+    let (first_atlas, first_layout) = atlas_pack::pack(dir.path()).unwrap();
+    let (second_atlas, second_layout) = atlas_pack::pack(dir.path()).unwrap();
+
+    assert_eq!(first_layout, second_layout);
+    assert_eq!(first_atlas.as_raw(), second_atlas.as_raw());
+}
+
+#[test]
+fn a_frame_larger_than_the_max_atlas_size_is_rejected() {
+    let dir = ScratchDir::new("oversized");
+    solid_frame(MAX_ATLAS_SIZE + 1, 4, Rgba([255, 255, 255, 255]))
+        .save(dir.path().join("too_big.png"))
+        .unwrap();
+
+    let err = atlas_pack::pack(dir.path()).unwrap_err();
+    assert!(err.to_string().contains("too_big"));
+}
+
+#[test]
+fn write_packed_round_trips_through_load_layout() {
+    let dir = ScratchDir::new("write_load");
+    solid_frame(4, 4, Rgba([1, 2, 3, 4])).save(dir.path().join("only.png")).unwrap();
+    let (atlas, layout) = atlas_pack::pack(dir.path()).unwrap();
+
+    let out_dir = ScratchDir::new("write_load_out");
+    atlas_pack::write_packed(out_dir.path(), "character", &atlas, &layout).unwrap();
+
+    let reloaded = atlas_pack::load_layout(&out_dir.path().join("character.ron")).unwrap();
+    assert_eq!(reloaded, layout);
+}
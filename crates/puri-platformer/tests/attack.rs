@@ -0,0 +1,149 @@
+use puri_platformer::attack::AttackPhase;
+use puri_platformer::testing::{Action, TestWorld};
+
+#[test]
+fn pressing_attack_runs_windup_then_active_then_recovery_then_clears() {
+    let mut world = TestWorld::new();
+    world.step(1);
+    assert_eq!(world.player_attack_phase(), None);
+
+    world.press(Action::Attack);
+    world.step(1);
+    assert_eq!(world.player_attack_phase(), Some(AttackPhase::Windup));
+
+    let mut saw_active = false;
+    for _ in 0..30 {
+        if world.player_attack_phase() == Some(AttackPhase::Active) {
+            saw_active = true;
+            break;
+        }
+        world.step(1);
+    }
+    assert!(saw_active, "expected windup to reach the active phase");
+
+    let mut saw_recovery = false;
+    for _ in 0..30 {
+        if world.player_attack_phase() == Some(AttackPhase::Recovery) {
+            saw_recovery = true;
+            break;
+        }
+        world.step(1);
+    }
+    assert!(saw_recovery, "expected the active phase to reach recovery");
+
+    let mut cleared = false;
+    for _ in 0..60 {
+        if world.player_attack_phase().is_none() {
+            cleared = true;
+            break;
+        }
+        world.step(1);
+    }
+    assert!(cleared, "expected recovery to end and clear Attacking");
+}
+
+#[test]
+fn horizontal_input_is_ignored_during_windup_and_active_but_not_recovery() {
+    let mut world = TestWorld::new();
+    let start_x = world.player_pos().x;
+
+    world.press(Action::Attack);
+    world.step(1);
+    assert_eq!(world.player_attack_phase(), Some(AttackPhase::Windup));
+
+    // Held right through windup and active does nothing to position.
+    for _ in 0..30 {
+        if world.player_attack_phase() == Some(AttackPhase::Recovery) {
+            break;
+        }
+        world.press(Action::Right);
+        world.step(1);
+    }
+    assert_eq!(world.player_attack_phase(), Some(AttackPhase::Recovery));
+    assert_eq!(world.player_pos().x, start_x);
+
+    // Recovery no longer locks movement.
+    world.press(Action::Right);
+    world.step(1);
+    assert!(world.player_pos().x > start_x);
+}
+
+#[test]
+fn a_second_press_during_recovery_buffers_a_follow_up_swing() {
+    let mut world = TestWorld::new();
+    world.press(Action::Attack);
+    world.step(1);
+
+    let mut reached_recovery = false;
+    for _ in 0..30 {
+        if world.player_attack_phase() == Some(AttackPhase::Recovery) {
+            reached_recovery = true;
+            break;
+        }
+        world.step(1);
+    }
+    assert!(reached_recovery, "expected the first swing to reach recovery");
+
+    world.release(Action::Attack);
+    world.step(1);
+    world.press(Action::Attack);
+    world.step(1);
+
+    // The buffered follow-up should chain straight back into windup instead
+    // of Attacking clearing.
+    let mut saw_second_windup = false;
+    for _ in 0..30 {
+        if world.player_attack_phase() == Some(AttackPhase::Windup) {
+            saw_second_windup = true;
+            break;
+        }
+        world.step(1);
+    }
+    assert!(saw_second_windup, "expected the buffered press to start a second swing");
+}
+
+#[test]
+fn an_active_swing_damages_the_training_dummy() {
+    let mut world = TestWorld::new();
+    let start_health = world.training_dummy_health();
+    world.set_player_pos(bevy::math::Vec2::new(120.0, -142.0));
+
+    world.press(Action::Attack);
+    world.step(1);
+
+    let mut damaged = false;
+    for _ in 0..30 {
+        if world.training_dummy_health() < start_health {
+            damaged = true;
+            break;
+        }
+        world.step(1);
+    }
+    assert!(damaged, "expected the active phase's hitbox to hit the training dummy");
+}
+
+#[test]
+fn an_air_attack_cancels_into_recovery_on_landing() {
+    let mut world = TestWorld::new();
+
+    // Lift the player well clear of the ground and start the swing while
+    // still airborne.
+    let ground_pos = world.player_pos();
+    world.set_player_pos(bevy::math::Vec2::new(ground_pos.x, ground_pos.y + 100.0));
+    world.press(Action::Attack);
+    world.step(1);
+    assert_eq!(world.player_attack_phase(), Some(AttackPhase::Windup));
+
+    // Drop it back onto the ground; landing should truncate the swing into
+    // recovery rather than letting the full windup/active play out midair.
+    world.set_player_velocity(bevy::math::Vec2::new(0.0, -400.0));
+    let mut cancelled = false;
+    for _ in 0..120 {
+        if world.player_attack_phase() == Some(AttackPhase::Recovery) {
+            cancelled = true;
+            break;
+        }
+        world.step(1);
+    }
+    assert!(cancelled, "expected landing to cancel the air attack into recovery");
+}
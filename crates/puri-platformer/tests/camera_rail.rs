@@ -0,0 +1,68 @@
+use bevy::math::Vec2;
+use puri_platformer::testing::TestWorld;
+
+#[test]
+fn entering_a_rails_zone_hands_the_camera_off_from_the_player() {
+    let mut world = TestWorld::new();
+    world.set_camera_area(Vec2::new(-640.0, -360.0), Vec2::new(640.0, 360.0));
+    world.set_player_pos(Vec2::new(0.0, 0.0));
+    world.spawn_camera_rail(Vec2::new(0.0, 0.0), Vec2::new(500.0, 0.0), 50.0, Vec2::new(700.0, 400.0));
+
+    // Let the camera catch up to the player before the rail activates, then
+    // step past activation and see it pull ahead along the path instead of
+    // staying pinned to the (stationary) player.
+    world.step(60);
+    let after_settle = world.camera_pos();
+    world.step(60);
+    let after_scrolling = world.camera_pos();
+
+    assert!(after_scrolling.x > after_settle.x + 10.0, "expected the camera to scroll along the rail");
+}
+
+#[test]
+fn leaving_the_rails_zone_hands_control_back_to_the_follow_camera() {
+    let mut world = TestWorld::new();
+    world.set_camera_area(Vec2::new(-640.0, -360.0), Vec2::new(640.0, 360.0));
+    world.set_player_pos(Vec2::new(0.0, 0.0));
+    world.spawn_camera_rail(Vec2::new(0.0, 0.0), Vec2::new(500.0, 0.0), 50.0, Vec2::new(1000.0, 400.0));
+
+    world.step(30);
+    assert!(world.camera_pos().x > 5.0, "expected the rail to have started scrolling the camera");
+
+    // Walk the player back out of the (narrow) zone; the rail should
+    // deactivate and `camera_follow` should pull the camera back to it.
+    world.set_player_pos(Vec2::new(-400.0, 0.0));
+    world.step(90);
+
+    assert!((world.camera_pos().x - (-400.0)).abs() < 5.0, "expected the follow camera to have resumed tracking the player");
+}
+
+#[test]
+fn the_trailing_edge_pushes_the_player_forward_instead_of_letting_them_fall_behind() {
+    let mut world = TestWorld::new();
+    world.set_camera_area(Vec2::new(-640.0, -360.0), Vec2::new(640.0, 360.0));
+    world.set_player_pos(Vec2::new(0.0, 0.0));
+    world.spawn_camera_rail(Vec2::new(0.0, 0.0), Vec2::new(500.0, 0.0), 200.0, Vec2::new(700.0, 400.0));
+
+    // The player never presses a move key, so once the camera's trailing
+    // edge (scrolling well ahead at 200 units/sec) reaches them, only
+    // `constrain_to_rail`'s clamp can be moving them.
+    world.step(90);
+
+    assert!(world.player_pos().x > 50.0, "expected the trailing screen edge to have pushed the player forward");
+}
+
+#[test]
+fn being_pinned_between_the_trailing_edge_and_a_wall_kills_the_player() {
+    let mut world = TestWorld::new();
+    world.set_camera_area(Vec2::new(-640.0, -360.0), Vec2::new(640.0, 360.0));
+    world.set_player_pos(Vec2::new(0.0, 0.0));
+    world.spawn_camera_rail(Vec2::new(0.0, 0.0), Vec2::new(500.0, 0.0), 300.0, Vec2::new(700.0, 400.0));
+    // A wall just ahead of the player: once the trailing edge closes the
+    // gap, the player has nowhere left to be pushed.
+    world.spawn_moving_platform(Vec2::new(40.0, 0.0), 0.0);
+
+    world.step(90);
+
+    assert!(world.player_is_dying(), "expected the player to die when crushed against the wall");
+}
@@ -0,0 +1,112 @@
+use bevy::math::Vec2;
+use puri_platformer::prelude::Medal;
+use puri_platformer::testing::TestWorld;
+
+#[test]
+fn starting_a_challenge_makes_it_active_and_ticks_elapsed_time() {
+    let mut world = TestWorld::new();
+    assert!(!world.challenge_is_active());
+
+    world.start_challenge();
+    assert!(world.challenge_is_active());
+
+    world.step(5);
+    assert!(world.challenge_elapsed_seconds() > 0.0);
+}
+
+#[test]
+fn checkpoints_do_not_advance_during_a_challenge_run() {
+    let mut world = TestWorld::new();
+    world.start_challenge();
+    assert_eq!(world.last_checkpoint_id(), None);
+
+    let checkpoint_pos = world.checkpoint_pos();
+    world.set_player_pos(checkpoint_pos);
+    world.step(2);
+
+    assert_eq!(world.last_checkpoint_id(), None, "touching a checkpoint mid-run must not advance it");
+}
+
+#[test]
+fn dying_during_a_challenge_restarts_the_timer_and_track() {
+    let mut world = TestWorld::new();
+    world.start_challenge();
+    world.step(10);
+    assert!(world.challenge_elapsed_seconds() > 0.0);
+
+    world.kill_player();
+    world.step(1);
+
+    assert_eq!(world.challenge_elapsed_seconds(), 0.0);
+}
+
+#[test]
+fn reaching_the_exit_ends_the_run_and_records_a_medal() {
+    let mut world = TestWorld::new();
+    world.start_challenge();
+    world.force_exit_gate_open();
+
+    let exit_pos = world.exit_gate_pos();
+    world.set_player_pos(exit_pos);
+    world.step(1);
+
+    assert!(!world.challenge_is_active());
+    assert_eq!(world.challenge_best_medal(), Some(Medal::Gold));
+    assert!(world.challenge_best_time().is_some());
+}
+
+#[test]
+fn a_ghost_of_the_best_run_races_a_later_attempt() {
+    let mut world = TestWorld::new();
+
+    // First run: finish immediately, banking a one-sample ghost track at
+    // the exit gate's position.
+    world.start_challenge();
+    world.force_exit_gate_open();
+    let exit_pos = world.exit_gate_pos();
+    world.set_player_pos(exit_pos);
+    world.step(1);
+    assert!(world.challenge_best_time().is_some());
+
+    // Second run: start away from the (still-open) exit so the run doesn't
+    // finish immediately, and confirm the ghost appears and tracks the
+    // recorded position.
+    world.set_player_pos(exit_pos + Vec2::new(-200.0, 0.0));
+    world.start_challenge();
+    world.step(2);
+    assert_eq!(world.ghost_sprite_count(), 1);
+    assert!((world.ghost_pos() - exit_pos).length() < 1.0);
+
+    // Finishing again removes the run; the ghost despawns the frame after.
+    world.set_player_pos(exit_pos);
+    world.step(2);
+    assert_eq!(world.ghost_sprite_count(), 0);
+}
+
+#[test]
+fn pressing_r_during_a_run_instantly_restores_the_run_start_position() {
+    let mut world = TestWorld::new();
+    let start_pos = world.player_pos();
+    world.start_challenge();
+
+    world.set_player_pos(start_pos + Vec2::new(300.0, 0.0));
+    world.step(5);
+    assert_ne!(world.player_pos(), start_pos);
+
+    world.press_r();
+    world.step(1);
+
+    assert!((world.player_pos() - start_pos).length() < 1.0, "R should restore the position captured when the run started");
+}
+
+#[test]
+fn pressing_r_outside_a_run_does_nothing() {
+    let mut world = TestWorld::new();
+    let x_before = world.player_pos().x + 50.0;
+    world.set_player_pos(Vec2::new(x_before, world.player_pos().y));
+
+    world.press_r();
+    world.step(1);
+
+    assert_eq!(world.player_pos().x, x_before, "there's no active run to restore, so R must be a no-op (gravity aside)");
+}
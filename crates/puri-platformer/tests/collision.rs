@@ -0,0 +1,47 @@
+use bevy::math::Vec2;
+use puri_platformer::prelude::HitBox;
+
+const BOX: HitBox = HitBox { size: Vec2::new(10.0, 10.0) };
+
+#[test]
+fn intersect_reports_normal_pointing_up_when_landing_on_top() {
+    let contact = BOX.intersect(Vec2::new(0.0, 9.0), &BOX, Vec2::ZERO).unwrap();
+    assert_eq!(contact.normal, Vec2::new(0.0, 1.0));
+}
+
+#[test]
+fn intersect_reports_normal_pointing_down_when_hitting_a_ceiling() {
+    let contact = BOX.intersect(Vec2::new(0.0, -9.0), &BOX, Vec2::ZERO).unwrap();
+    assert_eq!(contact.normal, Vec2::new(0.0, -1.0));
+}
+
+#[test]
+fn intersect_reports_normal_pointing_right_when_pushed_out_to_the_right() {
+    let contact = BOX.intersect(Vec2::new(9.0, 0.0), &BOX, Vec2::ZERO).unwrap();
+    assert_eq!(contact.normal, Vec2::new(1.0, 0.0));
+}
+
+#[test]
+fn intersect_reports_normal_pointing_left_when_pushed_out_to_the_left() {
+    let contact = BOX.intersect(Vec2::new(-9.0, 0.0), &BOX, Vec2::ZERO).unwrap();
+    assert_eq!(contact.normal, Vec2::new(-1.0, 0.0));
+}
+
+#[test]
+fn intersect_breaks_a_corner_tie_toward_the_vertical_axis() {
+    // Equal penetration on both axes: the body should be treated as
+    // landing on top rather than being shoved off the side.
+    let contact = BOX.intersect(Vec2::new(9.0, 9.0), &BOX, Vec2::ZERO).unwrap();
+    assert_eq!(contact.normal, Vec2::new(0.0, 1.0));
+}
+
+#[test]
+fn intersects_is_a_bool_convenience_wrapper() {
+    assert!(BOX.intersects(Vec2::new(0.0, 9.0), &BOX, Vec2::ZERO));
+    assert!(!BOX.intersects(Vec2::new(0.0, 20.0), &BOX, Vec2::ZERO));
+}
+
+#[test]
+fn no_contact_when_boxes_are_apart() {
+    assert!(BOX.intersect(Vec2::new(20.0, 20.0), &BOX, Vec2::ZERO).is_none());
+}
@@ -0,0 +1,76 @@
+use bevy::math::Vec2;
+use puri_platformer::prelude::LootTable;
+use puri_platformer::testing::TestWorld;
+
+/// Drops the player onto the turret from directly above, falling fast
+/// enough to guarantee a stomp overlap on the very next physics tick.
+fn stomp_turret(world: &mut TestWorld) {
+    let turret_pos = world.turret_pos();
+    world.set_player_pos(turret_pos + Vec2::new(0.0, 30.0));
+    world.set_player_velocity(Vec2::new(0.0, -200.0));
+    world.step(1);
+}
+
+#[test]
+fn stomping_an_enemy_bounces_the_player_upward() {
+    let mut world = TestWorld::new();
+    stomp_turret(&mut world);
+
+    assert!(world.player_velocity().y > 0.0, "landing on a stompable enemy should bounce the player up");
+}
+
+#[test]
+fn a_single_stomp_awards_the_base_combo_score() {
+    let mut world = TestWorld::new();
+    assert_eq!(world.player_combo(), 0);
+    stomp_turret(&mut world);
+
+    assert_eq!(world.player_combo(), 1);
+    assert_eq!(world.floating_texts(), vec!["100".to_string()]);
+}
+
+#[test]
+fn chaining_a_second_stomp_shows_the_multiplier_in_the_popup() {
+    let mut world = TestWorld::new();
+    let turret_pos = world.turret_pos();
+    stomp_turret(&mut world);
+    assert_eq!(world.floating_texts(), vec!["100".to_string()]);
+
+    // Stomp a second, unrelated `Stompable` without ever touching the
+    // ground in between, so the combo keeps climbing instead of resetting.
+    let second_pos = turret_pos + Vec2::new(60.0, 40.0);
+    world.spawn_stompable(second_pos, LootTable::new(vec![]));
+    world.set_player_pos(second_pos + Vec2::new(0.0, 30.0));
+    world.set_player_velocity(Vec2::new(0.0, -200.0));
+    world.step(1);
+
+    assert_eq!(world.player_combo(), 2);
+    assert!(world.floating_texts().contains(&"200 x2".to_string()));
+}
+
+#[test]
+fn landing_on_the_ground_resets_the_combo() {
+    let mut world = TestWorld::new();
+    stomp_turret(&mut world);
+    assert_eq!(world.player_combo(), 1);
+
+    // Walk the bounced player back down to solid ground and let it settle.
+    let ground_pos = world.player_pos();
+    world.set_player_pos(Vec2::new(ground_pos.x, -140.0));
+    world.set_player_velocity(Vec2::new(0.0, -50.0));
+    world.step(60);
+
+    assert_eq!(world.player_combo(), 0);
+}
+
+#[test]
+fn taking_damage_resets_the_combo() {
+    let mut world = TestWorld::new();
+    stomp_turret(&mut world);
+    assert_eq!(world.player_combo(), 1);
+
+    world.deal_damage(1);
+    world.step(1);
+
+    assert_eq!(world.player_combo(), 0);
+}
@@ -0,0 +1,50 @@
+use puri_platformer::prelude::{CommandQueue, PlayerCommand, PlayerId};
+
+const OTHER: PlayerId = PlayerId(1);
+
+#[test]
+fn command_for_returns_none_until_something_is_pushed_for_that_exact_tick_and_player() {
+    let mut queue = CommandQueue::default();
+    assert_eq!(queue.command_for(5, PlayerId::LOCAL), None);
+
+    queue.push(5, PlayerId::LOCAL, PlayerCommand { move_axis: 1.0, ..default_command() });
+
+    assert_eq!(queue.command_for(5, PlayerId::LOCAL), Some(PlayerCommand { move_axis: 1.0, ..default_command() }));
+    assert_eq!(queue.command_for(5, OTHER), None, "another player's command at the same tick is unaffected");
+    assert_eq!(queue.command_for(6, PlayerId::LOCAL), None, "a different tick is unaffected");
+}
+
+#[test]
+fn two_players_can_have_independent_commands_on_the_same_tick() {
+    let mut queue = CommandQueue::default();
+    queue.push(10, PlayerId::LOCAL, PlayerCommand { move_axis: -1.0, ..default_command() });
+    queue.push(10, OTHER, PlayerCommand { move_axis: 1.0, ..default_command() });
+
+    assert_eq!(queue.command_for(10, PlayerId::LOCAL).unwrap().move_axis, -1.0);
+    assert_eq!(queue.command_for(10, OTHER).unwrap().move_axis, 1.0);
+}
+
+#[test]
+fn prune_before_drops_old_ticks_but_keeps_the_cutoff_and_later() {
+    let mut queue = CommandQueue::default();
+    for tick in 0..5 {
+        queue.push(tick, PlayerId::LOCAL, default_command());
+    }
+    assert_eq!(queue.len(), 5);
+
+    queue.prune_before(3);
+
+    assert_eq!(queue.len(), 2, "ticks 0, 1 and 2 should be dropped");
+    assert!(queue.command_for(2, PlayerId::LOCAL).is_none());
+    assert!(queue.command_for(3, PlayerId::LOCAL).is_some());
+    assert!(queue.command_for(4, PlayerId::LOCAL).is_some());
+}
+
+#[test]
+fn a_fresh_queue_is_empty() {
+    assert!(CommandQueue::default().is_empty());
+}
+
+fn default_command() -> PlayerCommand {
+    PlayerCommand::default()
+}
@@ -0,0 +1,44 @@
+use puri_platformer::player::{BodyPart, Facing};
+use puri_platformer::testing::TestWorld;
+
+#[test]
+fn composite_children_animate_within_their_own_atlas_and_flip_with_facing() {
+    let mut world = TestWorld::new();
+    world.step(1);
+    world.respawn_player_as_composite();
+    world.step(1);
+
+    let parts = world.composite_parts();
+    assert_eq!(parts.len(), 2, "a composite player should have exactly two PartSlot children");
+
+    let upper = parts.iter().find(|(part, ..)| *part == BodyPart::Upper).unwrap();
+    let lower = parts.iter().find(|(part, ..)| *part == BodyPart::Lower).unwrap();
+    // The demo config puts the upper half on the "npc" atlas (walk clip at
+    // offset 4) and the lower half on the "player" atlas (offset 0) — see
+    // `player::PlayerSpawner::composite`'s doc comment on why this reuses
+    // the sheets `animation::Animations` already registers rather than
+    // needing new art.
+    assert!((4..8).contains(&upper.2), "upper body should stay within its atlas's own frame range, got {}", upper.2);
+    assert!((0..4).contains(&lower.2), "lower body should stay within its atlas's own frame range, got {}", lower.2);
+
+    for _ in 0..30 {
+        world.step(1);
+        let parts = world.composite_parts();
+        let upper = parts.iter().find(|(part, ..)| *part == BodyPart::Upper).unwrap();
+        let lower = parts.iter().find(|(part, ..)| *part == BodyPart::Lower).unwrap();
+        assert!((4..8).contains(&upper.2));
+        assert!((0..4).contains(&lower.2));
+    }
+
+    world.set_player_facing(Facing::Left);
+    world.step(1);
+    for (_, flip_x, _) in world.composite_parts() {
+        assert!(flip_x, "every composite child should flip to match the parent's Facing::Left");
+    }
+
+    world.set_player_facing(Facing::Right);
+    world.step(1);
+    for (_, flip_x, _) in world.composite_parts() {
+        assert!(!flip_x, "every composite child should flip back with the parent's Facing::Right");
+    }
+}
@@ -0,0 +1,42 @@
+use puri_platformer::testing::{Action, TestWorld};
+
+#[test]
+fn holding_the_key_on_the_ground_crouches_and_shrinks_the_hitbox() {
+    let mut world = TestWorld::new();
+    world.step(60); // settle on the floor.
+    let standing_size = world.player_hitbox_size();
+    assert!(!world.player_is_crouching());
+
+    world.press(Action::Crouch);
+    world.step(1);
+
+    assert!(world.player_is_crouching());
+    assert!(world.player_hitbox_size().y < standing_size.y);
+}
+
+#[test]
+fn releasing_the_key_stands_back_up() {
+    let mut world = TestWorld::new();
+    world.step(60);
+    let standing_size = world.player_hitbox_size();
+
+    world.press(Action::Crouch);
+    world.step(1);
+    assert!(world.player_is_crouching());
+
+    world.step(1); // no press this tick.
+    assert!(!world.player_is_crouching());
+    assert_eq!(world.player_hitbox_size(), standing_size);
+}
+
+#[test]
+fn crouch_only_takes_effect_while_grounded() {
+    let mut world = TestWorld::new();
+    world.step(60);
+    world.press(Action::Jump);
+    world.step(1);
+    world.press(Action::Crouch);
+    world.step(1);
+
+    assert!(!world.player_is_crouching());
+}
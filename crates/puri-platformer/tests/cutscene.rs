@@ -0,0 +1,101 @@
+use puri_platformer::testing::{Action, TestWorld};
+
+#[test]
+fn f4_plays_the_intro_walks_the_npc_and_shows_its_dialogue() {
+    let mut world = TestWorld::new();
+    world.step(1);
+    assert!(!world.cutscene_is_playing());
+
+    world.press_f4();
+    world.step(2);
+    assert!(world.cutscene_is_playing());
+
+    // Player input is disabled for as long as it plays.
+    let start_x = world.player_pos().x;
+    world.press(Action::Right);
+    world.step(1);
+    assert_eq!(world.player_pos().x, start_x);
+
+    // Let the camera pan finish and the actor walk to its target (x = 60,
+    // within `cutscene::ACTOR_ARRIVE_DISTANCE`).
+    let mut walked = false;
+    for _ in 0..600 {
+        if (world.actor_pos(0).x - 60.0).abs() <= 3.0 {
+            walked = true;
+            break;
+        }
+        world.step(1);
+    }
+    assert!(walked, "expected the demo NPC to walk to its cutscene target");
+
+    // The dialogue command should now open a box the same way a real NPC's would.
+    let mut opened = false;
+    for _ in 0..120 {
+        if world.dialogue_is_open() {
+            opened = true;
+            break;
+        }
+        world.step(1);
+    }
+    assert!(opened, "expected the cutscene's dialogue command to open a dialogue box");
+    assert!(world.cutscene_is_playing());
+
+    // Let the typewriter catch up, then close it like a real read-through.
+    world.step(120);
+    world.press(Action::Interact);
+    world.step(2);
+    assert!(!world.dialogue_is_open());
+
+    // The trailing Wait finishes and the cutscene ends on its own.
+    let mut ended = false;
+    for _ in 0..120 {
+        if !world.cutscene_is_playing() {
+            ended = true;
+            break;
+        }
+        world.step(1);
+    }
+    assert!(ended, "expected the cutscene to end once its last command finishes");
+}
+
+#[test]
+fn escape_skips_the_cutscene_and_it_does_not_replay() {
+    let mut world = TestWorld::new();
+    world.press_f4();
+    world.step(2);
+    assert!(world.cutscene_is_playing());
+
+    world.press(Action::MenuCancel);
+    world.step(2);
+    assert!(!world.cutscene_is_playing());
+
+    // Skipping still marks it played, so pressing F4 again does nothing.
+    world.release_f4();
+    world.step(1);
+    world.press_f4();
+    world.step(2);
+    assert!(!world.cutscene_is_playing());
+}
+
+#[test]
+fn skipping_mid_dialogue_cleans_up_the_temporary_sign_without_panicking() {
+    let mut world = TestWorld::new();
+    world.press_f4();
+    world.step(2);
+
+    let mut opened = false;
+    for _ in 0..600 {
+        if world.dialogue_is_open() {
+            opened = true;
+            break;
+        }
+        world.step(1);
+    }
+    assert!(opened, "expected the dialogue command to open before skipping it");
+
+    world.press(Action::MenuCancel);
+    world.step(2);
+
+    assert!(!world.cutscene_is_playing());
+    assert!(!world.dialogue_is_open());
+}
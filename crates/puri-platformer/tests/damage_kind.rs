@@ -0,0 +1,44 @@
+use puri_platformer::prelude::{DamageKind, UpgradeId};
+use puri_platformer::testing::{Action, TestWorld};
+
+#[test]
+fn spike_damage_is_full_before_the_spike_boots_upgrade_is_bought() {
+    let mut world = TestWorld::new();
+    let starting_health = world.player_health();
+
+    world.deal_damage_kind(1, DamageKind::Spike);
+    world.step(1);
+
+    assert_eq!(world.player_health(), starting_health - 1);
+}
+
+#[test]
+fn buying_spike_boots_stacks_a_spike_immunity_onto_resistances() {
+    let mut world = TestWorld::new();
+    let pos = world.shopkeeper_pos();
+    world.set_player_pos(pos);
+    world.set_wallet(100);
+
+    world.press(Action::Interact);
+    world.step(2);
+    // Spike Boots is the fifth row in the catalog.
+    for _ in 0..4 {
+        world.press(Action::MenuDown);
+        world.step(1);
+    }
+    world.press(Action::Interact);
+    world.step(1);
+
+    assert!(world.owns_upgrade(UpgradeId::SpikeBoots));
+    assert_eq!(world.player_resistances().multiplier_for(DamageKind::Spike), 0.0);
+
+    let health_before = world.player_health();
+    world.deal_damage_kind(1, DamageKind::Spike);
+    world.step(1);
+
+    assert_eq!(
+        world.player_health(),
+        health_before,
+        "spike damage should be fully resisted after buying Spike Boots"
+    );
+}
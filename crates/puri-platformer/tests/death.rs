@@ -0,0 +1,50 @@
+use bevy::math::Vec2;
+use puri_platformer::death::LastCheckpoint;
+use puri_platformer::testing::{Action, TestWorld};
+
+#[test]
+fn dying_locks_out_input_and_then_respawns() {
+    let mut world = TestWorld::new();
+    world.step(60); // settle on the floor.
+
+    world.deal_damage(3); // matches PlayerBundle's starting Health(3).
+    world.step(1);
+    assert!(world.player_is_dying());
+
+    // Movement input should be ignored for the whole sequence.
+    let x_before = world.player_pos().x;
+    for _ in 0..30 {
+        world.press(Action::Right);
+        world.step(1);
+    }
+    assert_eq!(world.player_pos().x, x_before);
+
+    // Fade out + hold + fade in: well under a second of extra ticks clears it.
+    world.step(90);
+    assert!(!world.player_is_dying());
+}
+
+#[test]
+fn last_checkpoint_re_resolves_by_id_after_a_level_reload_moves_it() {
+    let mut checkpoint = LastCheckpoint {
+        id: Some(1),
+        position: Vec2::new(0.0, 0.0),
+    };
+
+    // The new level data moved checkpoint 1 and dropped checkpoint 0 —
+    // `re_resolve` should track the moved position by id, not the stale
+    // cached `Vec2`.
+    checkpoint.re_resolve([(0, Vec2::new(10.0, 10.0)), (1, Vec2::new(50.0, -20.0))].into_iter());
+    assert_eq!(checkpoint.position, Vec2::new(50.0, -20.0));
+}
+
+#[test]
+fn last_checkpoint_keeps_its_position_if_its_id_is_gone_after_reload() {
+    let mut checkpoint = LastCheckpoint {
+        id: Some(7),
+        position: Vec2::new(1.0, 2.0),
+    };
+
+    checkpoint.re_resolve([(0, Vec2::new(10.0, 10.0))].into_iter());
+    assert_eq!(checkpoint.position, Vec2::new(1.0, 2.0));
+}
@@ -0,0 +1,27 @@
+use puri_platformer::testing::TestWorld;
+
+#[test]
+fn f3_toggles_overlay_visibility() {
+    let mut world = TestWorld::new();
+    assert!(!world.debug_overlay_visible());
+
+    world.press_f3();
+    world.step(1);
+    assert!(world.debug_overlay_visible());
+
+    world.press_f3();
+    world.step(1);
+    assert!(!world.debug_overlay_visible());
+}
+
+#[test]
+fn overlay_text_keeps_a_fixed_section_count() {
+    let mut world = TestWorld::new();
+    world.press_f3();
+    world.step(1);
+    assert!(world.debug_overlay_visible());
+
+    let before = world.debug_overlay_section_count();
+    world.step(5);
+    assert_eq!(world.debug_overlay_section_count(), before);
+}
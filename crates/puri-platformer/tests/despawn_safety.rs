@@ -0,0 +1,13 @@
+use puri_platformer::testing::TestWorld;
+
+#[test]
+fn despawning_the_player_mid_frame_does_not_panic() {
+    let mut world = TestWorld::new();
+    world.step(30);
+
+    world.despawn_player();
+
+    // Every system that used to `single()`/`single_mut()` the player would
+    // panic here; a full step with no player entity should just be a no-op.
+    world.step(10);
+}
@@ -0,0 +1,65 @@
+use bevy::math::Vec2;
+use puri_platformer::testing::TestWorld;
+
+fn touch_the_checkpoint(world: &mut TestWorld) -> Vec2 {
+    let checkpoint_pos = world.checkpoint_pos();
+    world.set_player_pos(checkpoint_pos);
+    world.step(2);
+    checkpoint_pos
+}
+
+#[test]
+fn a_death_cluster_at_the_same_checkpoint_offers_an_assist() {
+    let mut world = TestWorld::new();
+    let checkpoint_pos = touch_the_checkpoint(&mut world);
+
+    for _ in 0..3 {
+        world.kill_player_at(checkpoint_pos);
+        world.step(1);
+    }
+    world.step(1); // give `detect_death_clustering` a frame to see the third death.
+
+    assert!(world.difficulty_assist_offered());
+    assert!(!world.difficulty_assist_accepted());
+}
+
+#[test]
+fn scattered_deaths_do_not_offer_an_assist() {
+    let mut world = TestWorld::new();
+    let checkpoint_pos = touch_the_checkpoint(&mut world);
+
+    world.kill_player_at(checkpoint_pos);
+    world.step(1);
+    world.kill_player_at(checkpoint_pos + Vec2::new(400.0, 0.0));
+    world.step(1);
+    world.kill_player_at(checkpoint_pos);
+    world.step(1);
+
+    assert!(!world.difficulty_assist_offered(), "a death far from the checkpoint should break the cluster");
+}
+
+#[test]
+fn accepting_an_offered_assist_spawns_a_bridge_and_extra_heart() {
+    let mut world = TestWorld::new();
+    let checkpoint_pos = touch_the_checkpoint(&mut world);
+
+    for _ in 0..3 {
+        world.kill_player_at(checkpoint_pos);
+        world.step(1);
+    }
+    world.step(1); // give `detect_death_clustering` a frame to see the third death.
+    assert!(world.difficulty_assist_offered());
+    assert_eq!(world.assist_bridge_count(), 0);
+
+    world.press_p();
+    world.step(1);
+
+    assert!(world.difficulty_assist_accepted());
+    assert_eq!(world.assist_bridge_count(), 1);
+
+    world.kill_player_at(checkpoint_pos);
+    // Fade out + hold + fade in: well under a second of extra ticks clears
+    // it, mirroring `death`'s own test on the same sequence.
+    world.step(120);
+    assert_eq!(world.player_health(), 3 + 1, "an accepted assist adds one extra respawn heart");
+}
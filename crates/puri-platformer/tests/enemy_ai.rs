@@ -0,0 +1,211 @@
+#![cfg(feature = "serde")]
+
+use bevy::math::Vec2;
+use puri_platformer::prefab::Flyer;
+use puri_platformer::testing::TestWorld;
+
+#[test]
+fn a_patroller_walks_back_and_forth_within_its_range() {
+    let mut world = TestWorld::new();
+    let patroller = world.spawn_patroller(Vec2::new(200.0, -142.0), 40.0, 30.0);
+    // Let it settle onto the floor before sampling a baseline.
+    world.step(5);
+    let start_x = world.entity_pos(patroller).x;
+
+    world.step(90);
+    let moved_x = world.entity_pos(patroller).x;
+    assert_ne!(moved_x, start_x, "expected the patroller to walk");
+    assert!(
+        (moved_x - 200.0).abs() <= 30.0 + 1.0,
+        "expected the patroller to stay within its range"
+    );
+}
+
+#[test]
+fn a_patroller_with_a_path_walks_its_waypoints_instead_of_bouncing_in_range() {
+    let mut world = TestWorld::new();
+    let patroller = world.spawn_patroller(Vec2::new(200.0, -142.0), 40.0, 30.0);
+    world.attach_path(
+        patroller,
+        vec![Vec2::new(200.0, -142.0), Vec2::new(320.0, -142.0)],
+        puri_platformer::map::PathMode::PingPong,
+    );
+    world.step(5);
+
+    // Walks well past the Patroller's own (now-ignored) 30px range toward
+    // the path's second waypoint.
+    let mut reached_far_waypoint = false;
+    for _ in 0..300 {
+        world.step(1);
+        if world.entity_pos(patroller).x >= 315.0 {
+            reached_far_waypoint = true;
+            break;
+        }
+    }
+    assert!(
+        reached_far_waypoint,
+        "expected the patroller to walk to the path's far waypoint, ignoring its range"
+    );
+
+    // And then reverses back toward the first waypoint (PingPong).
+    let far_x = world.entity_pos(patroller).x;
+    let mut walked_back = false;
+    for _ in 0..300 {
+        world.step(1);
+        if world.entity_pos(patroller).x < far_x - 10.0 {
+            walked_back = true;
+            break;
+        }
+    }
+    assert!(
+        walked_back,
+        "expected the patroller to reverse at the path's end"
+    );
+}
+
+#[test]
+fn a_hit_stuns_and_launches_a_patroller_which_lands_and_resumes_patrolling() {
+    let mut world = TestWorld::new();
+    let patroller = world.spawn_patroller(Vec2::new(200.0, -142.0), 40.0, 30.0);
+    world.step(5);
+    assert!(!world.is_hitstunned(patroller));
+
+    world.send_hit_event(patroller, 8, Vec2::new(0.0, 200.0));
+    world.step(1);
+    assert!(
+        world.is_hitstunned(patroller),
+        "expected the hit to apply Hitstun"
+    );
+    assert!(
+        world.entity_velocity(patroller).y > 0.0,
+        "expected the hit to launch the patroller upward"
+    );
+
+    // Let it arc up, fall, bounce once, and settle back onto the floor
+    // before Hitstun's own timer runs out.
+    let mut recovered = false;
+    for _ in 0..300 {
+        world.step(1);
+        if !world.is_hitstunned(patroller) {
+            recovered = true;
+            break;
+        }
+    }
+    assert!(
+        recovered,
+        "expected Hitstun to expire once the patroller has landed"
+    );
+
+    let landed_y = world.entity_pos(patroller).y;
+    assert!(
+        (landed_y - (-128.0)).abs() < 4.0,
+        "expected the patroller to land back on the floor, got y={landed_y}"
+    );
+
+    // Patrolling resumes: its x position keeps changing again.
+    let after_land_x = world.entity_pos(patroller).x;
+    world.step(60);
+    assert_ne!(
+        world.entity_pos(patroller).x,
+        after_land_x,
+        "expected patrolling to resume after Hitstun ends"
+    );
+}
+
+#[test]
+fn a_hit_pops_a_damage_number_at_the_patrollers_position() {
+    let mut world = TestWorld::new();
+    let patroller = world.spawn_patroller(Vec2::new(200.0, -142.0), 40.0, 30.0);
+    world.step(5);
+
+    world.send_hit_event(patroller, 8, Vec2::new(0.0, 200.0));
+    world.step(1);
+
+    assert!(world.floating_texts().contains(&"8".to_string()));
+}
+
+#[test]
+fn a_launched_patroller_deals_contact_damage_to_another_patroller_it_hits() {
+    let mut world = TestWorld::new();
+    let attacker = world.spawn_patroller(Vec2::new(150.0, -142.0), 0.0, 0.0);
+    let victim = world.spawn_patroller(Vec2::new(175.0, -142.0), 0.0, 0.0);
+    world.step(5);
+    let start_health = world.entity_health(victim);
+
+    // Launch the attacker sideways into the victim.
+    world.send_hit_event(attacker, 8, Vec2::new(300.0, 150.0));
+
+    let mut damaged = false;
+    for _ in 0..120 {
+        world.step(1);
+        if world.entity_health(victim) < start_health {
+            damaged = true;
+            break;
+        }
+    }
+    assert!(
+        damaged,
+        "expected the launched patroller to deal contact damage to the victim"
+    );
+}
+
+#[test]
+fn a_flyer_dives_at_the_player_and_stops_at_the_floor_without_ever_grounding() {
+    let mut world = TestWorld::new();
+    world.set_player_pos(Vec2::new(600.0, -60.0)); // well outside dive_range
+    let flyer = world.spawn_flyer(
+        Vec2::new(200.0, -60.0),
+        Flyer {
+            speed: 30.0,
+            range: 80.0,
+            bob_amplitude: 10.0,
+            bob_speed: 2.0,
+            dive_range: 20.0,
+            dive_speed: 300.0,
+        },
+    );
+    world.step(10);
+    assert!(
+        !world.is_grounded(flyer),
+        "a Flyer should never be marked Grounded, even while just hovering"
+    );
+
+    // Line the player up under the flyer to trigger a dive.
+    world.set_player_pos(Vec2::new(200.0, -400.0));
+    let mut reached_floor = false;
+    for _ in 0..120 {
+        world.step(1);
+        // The demo map's floor top is at y=-144; a 16-tall Flyer HitBox
+        // rests with its center 8 above that.
+        if (world.entity_pos(flyer).y - (-136.0)).abs() < 2.0 {
+            reached_floor = true;
+            break;
+        }
+    }
+    assert!(
+        reached_floor,
+        "expected the flyer to dive down and stop at the floor, got y={}",
+        world.entity_pos(flyer).y
+    );
+    assert!(
+        !world.is_grounded(flyer),
+        "a diving flyer that lands on the floor still shouldn't read as Grounded"
+    );
+}
+
+#[test]
+fn a_fish_patrols_back_and_forth_without_leaving_its_water_zone() {
+    let mut world = TestWorld::new();
+    world.spawn_water(Vec2::new(400.0, -140.0), Vec2::new(160.0, 40.0));
+    let fish = world.spawn_fish(Vec2::new(400.0, -140.0), 40.0);
+    world.step(5);
+    let start_x = world.entity_pos(fish).x;
+
+    world.step(200);
+    let moved_x = world.entity_pos(fish).x;
+    assert_ne!(moved_x, start_x, "expected the fish to swim");
+    assert!(
+        (moved_x - 400.0).abs() <= 80.0 + 1.0,
+        "expected the fish to stay within its water zone, got x={moved_x}"
+    );
+}
@@ -0,0 +1,77 @@
+use puri_platformer::prelude::{EquipmentId, EquipmentSlot, UpgradeId};
+use puri_platformer::testing::{Action, TestWorld};
+
+fn buy_speed_boots(world: &mut TestWorld) {
+    let pos = world.shopkeeper_pos();
+    world.set_player_pos(pos);
+    world.set_wallet(100);
+
+    world.press(Action::Interact);
+    world.step(2);
+    // Speed Boots is the sixth row in the catalog.
+    for _ in 0..5 {
+        world.press(Action::MenuDown);
+        world.step(1);
+    }
+    world.press(Action::Interact);
+    world.step(1);
+}
+
+#[test]
+fn buying_speed_boots_equips_them_and_speeds_up_the_player() {
+    let mut world = TestWorld::new();
+    buy_speed_boots(&mut world);
+
+    assert!(world.owns_upgrade(UpgradeId::SpeedBoots));
+    assert_eq!(
+        world.player_equipment().equipped(EquipmentSlot::Boots),
+        Some(EquipmentId::SpeedBoots)
+    );
+    assert!(world.equipment_overlay_present(EquipmentSlot::Boots));
+
+    for _ in 0..30 {
+        world.press(Action::Right);
+        world.step(1);
+    }
+    // Base move speed is 180; Speed Boots is a +15% multiplier.
+    assert!((world.player_velocity().x - 207.0).abs() < 1.0);
+}
+
+#[test]
+fn unequipping_boots_drops_the_speed_bonus_and_the_overlay() {
+    let mut world = TestWorld::new();
+    buy_speed_boots(&mut world);
+
+    world.press(Action::UnequipBoots);
+    world.step(1);
+
+    assert_eq!(
+        world.player_equipment().equipped(EquipmentSlot::Boots),
+        None
+    );
+    assert!(!world.equipment_overlay_present(EquipmentSlot::Boots));
+
+    for _ in 0..30 {
+        world.press(Action::Right);
+        world.step(1);
+    }
+    assert!((world.player_velocity().x - 180.0).abs() < 1.0);
+}
+
+#[test]
+fn owned_speed_boots_are_not_reequipped_after_unequipping() {
+    let mut world = TestWorld::new();
+    buy_speed_boots(&mut world);
+    world.press(Action::UnequipBoots);
+    world.step(1);
+
+    // The purchase already happened; nothing re-triggers `equip_purchased_gear`,
+    // but it re-checks every frame, so this asserts it really does stay empty
+    // rather than refilling the slot the very next tick.
+    world.step(5);
+
+    assert_eq!(
+        world.player_equipment().equipped(EquipmentSlot::Boots),
+        None
+    );
+}
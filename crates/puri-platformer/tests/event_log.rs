@@ -0,0 +1,16 @@
+use puri_platformer::event_log;
+
+// A single test: the buffer is a process-global, so a second `#[test]` fn
+// running concurrently in the same binary would race this one.
+#[test]
+fn buffer_evicts_the_oldest_entry_once_full() {
+    let before = event_log::snapshot().len();
+    for i in 0..250 {
+        event_log::record(format!("event {i}"));
+    }
+
+    let snapshot = event_log::snapshot();
+    assert_eq!(snapshot.len(), 200);
+    assert_eq!(snapshot.last().unwrap(), "event 249");
+    assert!(before <= 200);
+}
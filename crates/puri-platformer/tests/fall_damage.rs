@@ -0,0 +1,27 @@
+use puri_platformer::testing::{Action, TestWorld};
+
+#[test]
+fn short_drop_does_not_damage_the_player() {
+    let mut world = TestWorld::new();
+    world.step(60); // settle on the floor from spawn height.
+
+    assert_eq!(world.total_fall_damage(), 0);
+}
+
+#[test]
+fn a_long_fall_across_multiple_bounces_still_deals_damage_on_final_landing() {
+    let mut world = TestWorld::new();
+    world.step(30);
+    world.total_fall_damage(); // discard the initial spawn settle, if any.
+
+    // Bounce up and down a few times before the last, decisive drop; the
+    // fall tracker should keep following the highest peak reached, not
+    // reset on every small hop.
+    for _ in 0..3 {
+        world.press(Action::Jump);
+        world.step(10);
+    }
+    world.step(90); // let the player fully settle after the last bounce.
+
+    assert!(world.total_fall_damage() > 0);
+}
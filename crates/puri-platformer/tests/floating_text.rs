@@ -0,0 +1,22 @@
+use bevy::math::Vec2;
+use puri_platformer::prelude::LootTable;
+use puri_platformer::testing::TestWorld;
+
+/// More stomps than `floating_text`'s own simultaneous cap (24), performed
+/// well within a single popup's lifetime so none have expired naturally by
+/// the end — spawning a fresh `Stompable` back at the same spot each time
+/// and forcing the player back into a falling stomp on it.
+#[test]
+fn spawning_past_the_cap_recycles_the_oldest_popup() {
+    let mut world = TestWorld::new();
+    let pos = Vec2::new(300.0, -128.0);
+
+    for _ in 0..30 {
+        world.spawn_stompable(pos, LootTable::new(vec![]));
+        world.set_player_pos(pos + Vec2::new(0.0, 30.0));
+        world.set_player_velocity(Vec2::new(0.0, -200.0));
+        world.step(1);
+    }
+
+    assert!(world.floating_texts().len() <= 24, "expected the cap to keep the popup count bounded, got {}", world.floating_texts().len());
+}
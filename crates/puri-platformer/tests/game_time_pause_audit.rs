@@ -0,0 +1,34 @@
+use puri_platformer::testing::TestWorld;
+
+/// `time_scale::GameTime`'s own doc comment names this as the "done means
+/// pausing mid-invulnerability preserves the remaining duration exactly"
+/// check: freeze `TimeScale`, tick several frames, and confirm the
+/// underlying `Starman` timer hasn't moved.
+#[test]
+fn freezing_time_scale_holds_starman_remaining_time_exactly() {
+    let mut world = TestWorld::new();
+    world.give_player_starman();
+    let remaining_before = world.starman_remaining_secs();
+
+    world.set_time_scale(0.0);
+    world.step(30);
+
+    assert_eq!(world.starman_remaining_secs(), remaining_before);
+}
+
+/// Resuming a frozen timer picks up exactly where it left off, rather than
+/// e.g. the elapsed frozen time being credited against it in a burst.
+#[test]
+fn resuming_after_a_freeze_continues_counting_down_normally() {
+    let mut world = TestWorld::new();
+    world.give_player_starman();
+
+    world.set_time_scale(0.0);
+    world.step(30);
+    let remaining_frozen = world.starman_remaining_secs();
+
+    world.set_time_scale(1.0);
+    world.step(1);
+
+    assert!(world.starman_remaining_secs() < remaining_frozen);
+}
@@ -0,0 +1,55 @@
+use puri_platformer::testing::{Action, TestWorld};
+
+#[test]
+fn player_lands_on_the_floor_from_a_drop() {
+    let mut world = TestWorld::new();
+    world.step(120);
+
+    // The floor top sits at y = -160 + 16, the player half-height is 16.
+    assert!((world.player_pos().y - (-128.0)).abs() < 1.0);
+    assert_eq!(world.player_velocity().y, 0.0);
+}
+
+#[test]
+fn player_cannot_walk_through_a_wall() {
+    let mut world = TestWorld::new();
+    world.step(60); // let the player settle on the ground first.
+
+    for _ in 0..120 {
+        world.press(Action::Left);
+        world.step(1);
+    }
+
+    // The demo map has a wall at x = -200 that is 32 wide; the player
+    // (24 wide) should rest flush against its right face.
+    assert!(world.player_pos().x > -200.0 + 16.0 + 12.0 - 1.0);
+}
+
+#[test]
+fn jump_reaches_a_bounded_apex() {
+    let mut world = TestWorld::new();
+    world.step(30);
+
+    world.press(Action::Jump);
+    world.step(1);
+
+    let mut peak: f32 = world.player_pos().y;
+    for _ in 0..60 {
+        world.step(1);
+        peak = peak.max(world.player_pos().y);
+    }
+
+    assert!(peak > -120.0, "jump should rise above the floor contact height");
+}
+
+#[test]
+fn player_without_dash_ability_ignores_the_dash_action() {
+    let mut world = TestWorld::new();
+    world.step(60);
+
+    let velocity_before = world.player_velocity();
+    world.press(Action::Dash);
+    world.step(1);
+
+    assert_eq!(world.player_velocity().x, velocity_before.x);
+}
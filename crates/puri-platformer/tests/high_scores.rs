@@ -0,0 +1,44 @@
+use puri_platformer::high_scores::{HighScores, ScoreEntry};
+
+fn entry(name: &str, score: u32) -> ScoreEntry {
+    ScoreEntry {
+        name: name.to_string(),
+        score,
+        date: "2026-08-08".to_string(),
+    }
+}
+
+#[test]
+fn table_stays_sorted_highest_first() {
+    let mut scores = HighScores::default();
+    scores.try_insert(entry("AAA", 100));
+    scores.try_insert(entry("BBB", 300));
+    scores.try_insert(entry("CCC", 200));
+
+    let ordered: Vec<u32> = scores.entries().iter().map(|entry| entry.score).collect();
+    assert_eq!(ordered, vec![300, 200, 100]);
+}
+
+#[test]
+fn ties_keep_the_older_entry_above_the_new_one() {
+    let mut scores = HighScores::default();
+    scores.try_insert(entry("OLD", 100));
+    scores.try_insert(entry("NEW", 100));
+
+    let names: Vec<&str> = scores.entries().iter().map(|entry| entry.name.as_str()).collect();
+    assert_eq!(names, vec!["OLD", "NEW"]);
+}
+
+#[test]
+fn table_caps_at_ten_dropping_the_lowest() {
+    let mut scores = HighScores::default();
+    for score in 1..=10 {
+        assert!(scores.try_insert(entry("AAA", score)));
+    }
+    assert!(!scores.qualifies(0));
+    assert!(!scores.try_insert(entry("BBB", 0)));
+
+    assert!(scores.try_insert(entry("CCC", 11)));
+    assert_eq!(scores.entries().len(), 10);
+    assert!(scores.entries().iter().all(|entry| entry.score >= 2));
+}
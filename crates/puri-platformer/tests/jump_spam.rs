@@ -0,0 +1,28 @@
+use puri_platformer::testing::{Action, TestWorld};
+
+#[test]
+fn mashing_jump_mid_air_cannot_fly_above_a_single_jump_apex() {
+    let mut baseline = TestWorld::new();
+    baseline.step(30); // settle on the floor.
+    baseline.press(Action::Jump);
+    baseline.step(1);
+    let mut single_jump_apex: f32 = baseline.player_pos().y;
+    for _ in 0..90 {
+        baseline.step(1);
+        single_jump_apex = single_jump_apex.max(baseline.player_pos().y);
+    }
+
+    let mut mashed = TestWorld::new();
+    mashed.step(30);
+    let mut mashed_peak: f32 = mashed.player_pos().y;
+    for _ in 0..120 {
+        mashed.press(Action::Jump);
+        mashed.step(1);
+        mashed_peak = mashed_peak.max(mashed.player_pos().y);
+    }
+
+    assert!(
+        mashed_peak <= single_jump_apex + 1.0,
+        "mashed_peak={mashed_peak}, single_jump_apex={single_jump_apex}"
+    );
+}
@@ -0,0 +1,27 @@
+use std::path::PathBuf;
+
+use puri_platformer::launch_options::LaunchOptions;
+
+#[test]
+fn parses_flags_and_valued_options() {
+    let options = LaunchOptions::parse(
+        ["--seed", "42", "--headless", "--debug-draw", "--trace", "trace.json"].map(String::from),
+    )
+    .unwrap();
+
+    assert_eq!(options.seed, Some(42));
+    assert!(options.headless);
+    assert!(options.debug_draw);
+    assert!(options.level.is_none());
+    assert_eq!(options.trace, Some(PathBuf::from("trace.json")));
+}
+
+#[test]
+fn rejects_an_unknown_flag() {
+    assert!(LaunchOptions::parse(["--not-a-real-flag".to_string()]).is_err());
+}
+
+#[test]
+fn rejects_a_non_numeric_seed() {
+    assert!(LaunchOptions::parse(["--seed".to_string(), "not-a-number".to_string()]).is_err());
+}
@@ -0,0 +1,56 @@
+use bevy::ecs::system::CommandQueue;
+use bevy::prelude::*;
+use puri_platformer::prelude::{LevelLoadState, SpawnQueue};
+use puri_platformer::testing::{Action, TestWorld};
+
+#[test]
+fn confirming_an_unlocked_level_select_entry_starts_and_finishes_a_load() {
+    let mut world = TestWorld::new();
+    let level_entities_before = world.level_entity_count();
+    assert_eq!(world.level_load_state(), LevelLoadState::Idle);
+
+    world.press_f7();
+    world.step(1);
+    assert!(world.level_select_is_open());
+
+    world.press(Action::Interact);
+    world.step(1);
+    assert_eq!(world.level_load_state(), LevelLoadState::Loading, "confirming id=0 (already unlocked) should start a load");
+    assert!(!world.level_select_is_open(), "confirming a level should close the select screen");
+
+    // A handful of ticks is enough for the background parse task to finish
+    // and `spawn_level` to run — there's no real parsing work to wait on,
+    // see `level_load`'s own doc comment.
+    world.step(10);
+    assert_eq!(world.level_load_state(), LevelLoadState::Idle, "the load should have finished on its own");
+    assert_eq!(world.level_entity_count(), level_entities_before, "the same demo map is despawned and respawned 1:1");
+}
+
+#[test]
+fn spawn_queue_runs_at_most_its_budget_of_jobs_per_drain() {
+    let mut world = World::new();
+    let mut queue = CommandQueue::default();
+    let mut spawn_queue = SpawnQueue::default();
+
+    for value in 0..5u32 {
+        spawn_queue.push(move |commands| {
+            commands.spawn(Name::new(format!("job-{value}")));
+        });
+    }
+
+    let mut commands = Commands::new(&mut queue, &world);
+    let ran = spawn_queue.drain_budgeted(&mut commands, 2);
+    queue.apply(&mut world);
+
+    assert_eq!(ran, 2);
+    assert_eq!(world.entities().len(), 2);
+    assert!(!spawn_queue.is_empty());
+
+    let mut commands = Commands::new(&mut queue, &world);
+    let ran = spawn_queue.drain_budgeted(&mut commands, 10);
+    queue.apply(&mut world);
+
+    assert_eq!(ran, 3, "only the 3 remaining jobs should run, not the full budget");
+    assert_eq!(world.entities().len(), 5);
+    assert!(spawn_queue.is_empty());
+}
@@ -0,0 +1,31 @@
+use bevy::math::Vec2;
+use puri_platformer::level_reload::{nearest_point, Debouncer};
+
+#[test]
+fn debounce_collapses_a_write_storm_into_one_trigger() {
+    let mut debouncer = Debouncer::new(0.2);
+
+    debouncer.notify(0.0);
+    assert!(!debouncer.poll(0.05));
+
+    // A second write 50ms later (an editor saving twice) resets the window
+    // instead of stacking a second trigger on top of the first.
+    debouncer.notify(0.05);
+    assert!(!debouncer.poll(0.2));
+    assert!(debouncer.poll(0.26));
+
+    // Firing clears the pending state, so polling again without a new
+    // `notify` stays quiet.
+    assert!(!debouncer.poll(1.0));
+}
+
+#[test]
+fn nearest_point_picks_the_closest_candidate() {
+    let candidates = [Vec2::new(0.0, 0.0), Vec2::new(100.0, 0.0), Vec2::new(-10.0, 0.0)];
+    assert_eq!(nearest_point(Vec2::new(-8.0, 0.0), &candidates), Some(Vec2::new(-10.0, 0.0)));
+}
+
+#[test]
+fn nearest_point_of_no_candidates_is_none() {
+    assert_eq!(nearest_point(Vec2::ZERO, &[]), None);
+}
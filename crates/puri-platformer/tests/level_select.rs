@@ -0,0 +1,39 @@
+use puri_platformer::testing::TestWorld;
+
+#[test]
+fn f7_toggles_the_level_select_screen() {
+    let mut world = TestWorld::new();
+    assert!(!world.level_select_is_open());
+
+    world.press_f7();
+    world.step(1);
+    assert!(world.level_select_is_open());
+}
+
+#[test]
+fn only_the_first_level_starts_unlocked() {
+    let mut world = TestWorld::new();
+    assert!(world.is_level_unlocked(0));
+    assert!(!world.is_level_unlocked(1));
+}
+
+#[test]
+fn reaching_the_exit_unlocks_the_next_level() {
+    let mut world = TestWorld::new();
+    world.force_exit_gate_open();
+
+    let exit_pos = world.exit_gate_pos();
+    world.set_player_pos(exit_pos);
+    world.step(1);
+
+    assert!(world.is_level_unlocked(1));
+}
+
+#[test]
+fn opening_the_screen_renders_one_row_per_manifest_entry() {
+    let mut world = TestWorld::new();
+    world.press_f7();
+    world.step(2);
+
+    assert_eq!(world.level_select_row_count(), 3);
+}
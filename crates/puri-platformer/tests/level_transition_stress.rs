@@ -0,0 +1,27 @@
+use puri_platformer::testing::TestWorld;
+
+/// Reloads the level 20 times back to back while the turret's projectiles
+/// and a stomp's floating-text popup are in flight, asserting the entity
+/// count settles back to the same baseline every time instead of drifting
+/// up (an orphaned child) or down (a double-despawn) across reloads.
+#[test]
+fn twenty_reloads_with_projectiles_and_particles_in_flight_leak_nothing() {
+    let mut world = TestWorld::new();
+    world.step(60);
+    let baseline = world.level_entity_count();
+
+    for _ in 0..20 {
+        // Long enough for the level's turret to have fired at least once
+        // and for a stomp's floating-text popup to be mid-flight, so the
+        // reload's despawn/drain runs while both are live.
+        world.step(30);
+        world.reload_level();
+        world.step(1);
+
+        assert_eq!(
+            world.level_entity_count(),
+            baseline,
+            "reload should despawn and respawn the exact same demo layout every time"
+        );
+    }
+}
@@ -0,0 +1,53 @@
+use bevy::prelude::Vec2;
+use puri_platformer::prelude::{flicker_intensity, Light2d, MAX_ACTIVE_LIGHTS};
+use puri_platformer::testing::TestWorld;
+
+#[test]
+fn a_steady_light_never_flickers() {
+    let light = Light2d {
+        flicker_hz: None,
+        intensity: 0.75,
+        ..Default::default()
+    };
+    assert_eq!(flicker_intensity(&light, 0.0), 0.75);
+    assert_eq!(flicker_intensity(&light, 123.4), 0.75);
+}
+
+#[test]
+fn a_flickering_light_stays_within_its_60_to_100_percent_band() {
+    let light = Light2d {
+        flicker_hz: Some(6.0),
+        intensity: 1.0,
+        ..Default::default()
+    };
+    for i in 0..200 {
+        let elapsed = i as f32 * 0.01;
+        let intensity = flicker_intensity(&light, elapsed);
+        assert!(
+            (0.6..=1.0).contains(&intensity),
+            "flicker at {elapsed}s left the 60-100% band: {intensity}"
+        );
+    }
+}
+
+#[test]
+fn lights_within_budget_all_stay_active() {
+    let mut world = TestWorld::new();
+    for i in 0..MAX_ACTIVE_LIGHTS {
+        world.spawn_light(Vec2::new(i as f32 * 10.0, 0.0), Light2d::default());
+    }
+
+    world.step(1);
+    assert_eq!(world.active_light_count(), MAX_ACTIVE_LIGHTS);
+}
+
+#[test]
+fn lights_past_the_budget_get_culled_farthest_first() {
+    let mut world = TestWorld::new();
+    for i in 0..(MAX_ACTIVE_LIGHTS + 10) {
+        world.spawn_light(Vec2::new(i as f32 * 10.0, 0.0), Light2d::default());
+    }
+
+    world.step(1);
+    assert_eq!(world.active_light_count(), MAX_ACTIVE_LIGHTS);
+}
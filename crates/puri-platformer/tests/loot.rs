@@ -0,0 +1,136 @@
+use bevy::math::Vec2;
+use puri_platformer::prelude::{GameRng, LootKind, LootTable};
+use puri_platformer::testing::TestWorld;
+
+/// Drops the player onto whatever `Stompable` sits at `pos` from directly
+/// above, falling fast enough to guarantee a stomp overlap on the very
+/// next physics tick — mirrors `tests/combo.rs`'s own `stomp_turret`.
+fn stomp(world: &mut TestWorld, pos: Vec2) {
+    world.set_player_pos(pos + Vec2::new(0.0, 30.0));
+    world.set_player_velocity(Vec2::new(0.0, -200.0));
+    world.step(1);
+}
+
+#[test]
+fn the_same_seed_rolls_the_same_loot_sequence() {
+    let rolls = |seed: u64| {
+        let mut rng = GameRng::from_seed(seed);
+        let table = LootTable::standard();
+        (0..20).map(|_| table.roll(&mut rng)).collect::<Vec<_>>()
+    };
+    assert_eq!(rolls(42), rolls(42));
+}
+
+#[test]
+fn a_loot_table_only_ever_rolls_its_own_entries() {
+    let mut rng = GameRng::from_seed(7);
+    let table = LootTable::new(vec![(LootKind::Coin, 1.0)]);
+    for _ in 0..20 {
+        assert_eq!(table.roll(&mut rng), LootKind::Coin);
+    }
+}
+
+#[test]
+fn stomping_a_coin_table_spawns_a_collectible_coin_drop() {
+    let mut world = TestWorld::new();
+    let pos = Vec2::new(300.0, -128.0);
+    world.spawn_stompable(pos, LootTable::new(vec![(LootKind::Coin, 1.0)]));
+
+    stomp(&mut world, pos);
+    world.step(2);
+
+    assert_eq!(world.loot_drop_count(), 1);
+    assert_eq!(world.loot_drop_kind(), LootKind::Coin);
+}
+
+#[test]
+fn a_nothing_roll_spawns_no_drop() {
+    let mut world = TestWorld::new();
+    let pos = Vec2::new(300.0, -128.0);
+    world.spawn_stompable(pos, LootTable::new(vec![(LootKind::Nothing, 1.0)]));
+
+    stomp(&mut world, pos);
+    world.step(5);
+
+    assert_eq!(world.loot_drop_count(), 0);
+}
+
+#[test]
+fn collecting_a_coin_drop_credits_the_wallet_and_despawns_it() {
+    let mut world = TestWorld::new();
+    let pos = Vec2::new(300.0, -128.0);
+    world.spawn_stompable(pos, LootTable::new(vec![(LootKind::Coin, 1.0)]));
+    let start_wallet = world.wallet();
+
+    stomp(&mut world, pos);
+    // Let the drop spawn, and the player (already standing right on it
+    // after bouncing off the stomp) walk over it.
+    world.step(30);
+
+    assert_eq!(world.wallet(), start_wallet + 1);
+    assert_eq!(world.loot_drop_count(), 0);
+}
+
+#[test]
+fn collecting_a_coin_drop_shows_a_floating_value_popup() {
+    let mut world = TestWorld::new();
+    let pos = Vec2::new(300.0, -128.0);
+    world.spawn_stompable(pos, LootTable::new(vec![(LootKind::Coin, 1.0)]));
+
+    stomp(&mut world, pos);
+    world.step(30);
+
+    assert!(world.floating_texts().contains(&"+1".to_string()));
+}
+
+#[test]
+fn collecting_a_heart_drop_heals_the_player() {
+    let mut world = TestWorld::new();
+    let pos = Vec2::new(300.0, -128.0);
+    world.spawn_stompable(pos, LootTable::new(vec![(LootKind::Heart, 1.0)]));
+    world.deal_damage(1);
+    let start_health = world.player_health();
+
+    stomp(&mut world, pos);
+    world.step(30);
+
+    assert_eq!(world.player_health(), start_health + 1);
+}
+
+#[test]
+fn an_uncollected_drop_despawns_after_its_lifetime() {
+    let mut world = TestWorld::new();
+    let pos = Vec2::new(300.0, -128.0);
+    world.spawn_stompable(pos, LootTable::new(vec![(LootKind::Coin, 1.0)]));
+
+    stomp(&mut world, pos);
+    world.step(2);
+    assert_eq!(world.loot_drop_count(), 1, "expected the stomp to spawn a loot drop");
+
+    // Move the player away so it can't collect it, then wait out its
+    // 10-second lifetime (60Hz, plus slack for the steps already spent).
+    world.set_player_pos(pos + Vec2::new(1000.0, 0.0));
+    world.step(11 * 60);
+
+    assert_eq!(world.loot_drop_count(), 0);
+}
+
+#[test]
+fn a_drop_settles_onto_the_floor_instead_of_falling_through_it() {
+    let mut world = TestWorld::new();
+    let pos = Vec2::new(300.0, -100.0);
+    world.spawn_stompable(pos, LootTable::new(vec![(LootKind::Coin, 1.0)]));
+
+    stomp(&mut world, pos);
+    world.step(2);
+    assert_eq!(world.loot_drop_count(), 1, "expected the stomp to spawn a loot drop");
+    let drop = world.loot_drop_entity();
+
+    // Move the player well clear so it can't collect the drop mid-fall,
+    // then give it plenty of time to pop out, fall, and settle.
+    world.set_player_pos(pos + Vec2::new(1000.0, 0.0));
+    world.step(120);
+
+    let landed_y = world.entity_pos(drop).y;
+    assert!(landed_y > pos.y - 50.0, "expected the drop to settle on solid ground rather than fall through it, got y={landed_y}");
+}
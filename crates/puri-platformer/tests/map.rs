@@ -0,0 +1,46 @@
+use bevy::math::Vec2;
+use puri_platformer::map::PathMode;
+use puri_platformer::testing::TestWorld;
+
+#[test]
+fn a_moving_platform_with_a_path_follows_its_waypoints_instead_of_sweeping() {
+    let mut world = TestWorld::new();
+    let platform = world.spawn_moving_platform(Vec2::new(0.0, 0.0), 100.0);
+    world.attach_path(
+        platform,
+        vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(50.0, 0.0),
+            Vec2::new(50.0, 50.0),
+        ],
+        PathMode::Loop,
+    );
+
+    let mut visited_second_waypoint = false;
+    for _ in 0..120 {
+        world.step(1);
+        let pos = world.entity_pos(platform);
+        if (pos.x - 50.0).abs() < 3.0 && pos.y.abs() < 3.0 {
+            visited_second_waypoint = true;
+            break;
+        }
+    }
+    assert!(
+        visited_second_waypoint,
+        "expected the platform to reach the path's second waypoint"
+    );
+
+    let mut wrapped_to_start = false;
+    for _ in 0..600 {
+        world.step(1);
+        let pos = world.entity_pos(platform);
+        if pos.x.abs() < 3.0 && pos.y.abs() < 3.0 {
+            wrapped_to_start = true;
+            break;
+        }
+    }
+    assert!(
+        wrapped_to_start,
+        "expected a Loop path to wrap back to its first waypoint after the last"
+    );
+}
@@ -0,0 +1,61 @@
+use puri_platformer::math::{exp_decay, move_toward, spring_damp};
+
+#[test]
+fn exp_decay_is_frame_rate_independent() {
+    let rate = 6.0;
+    let target = 100.0;
+
+    let mut fine = 0.0;
+    for _ in 0..240 {
+        fine = exp_decay(fine, target, rate, 1.0 / 240.0);
+    }
+
+    let mut coarse = 0.0;
+    for _ in 0..30 {
+        coarse = exp_decay(coarse, target, rate, 1.0 / 30.0);
+    }
+
+    assert!(
+        (fine - coarse).abs() < 0.1,
+        "fine={fine}, coarse={coarse}, expected to converge to the same 1s result"
+    );
+}
+
+#[test]
+fn exp_decay_reaches_target_at_infinite_rate() {
+    assert_eq!(exp_decay(0.0, 50.0, 1000.0, 1.0), 50.0);
+}
+
+#[test]
+fn move_toward_lands_exactly_on_target_without_overshoot() {
+    assert_eq!(move_toward(0.0, 10.0, 3.0), 3.0);
+    assert_eq!(move_toward(9.0, 10.0, 3.0), 10.0);
+    assert_eq!(move_toward(10.0, 10.0, 3.0), 10.0);
+}
+
+#[test]
+fn spring_damp_settles_on_target_over_many_steps() {
+    let mut velocity = 0.0;
+    let mut position = 0.0;
+    for _ in 0..600 {
+        position = spring_damp(position, &mut velocity, 10.0, 8.0, 1.0, 1.0 / 60.0);
+    }
+    assert!((position - 10.0).abs() < 0.01);
+}
+
+#[test]
+fn spring_damp_is_frame_rate_independent() {
+    let mut v_fine = 0.0;
+    let mut p_fine = 0.0;
+    for _ in 0..240 {
+        p_fine = spring_damp(p_fine, &mut v_fine, 10.0, 8.0, 1.0, 1.0 / 240.0);
+    }
+
+    let mut v_coarse = 0.0;
+    let mut p_coarse = 0.0;
+    for _ in 0..30 {
+        p_coarse = spring_damp(p_coarse, &mut v_coarse, 10.0, 8.0, 1.0, 1.0 / 30.0);
+    }
+
+    assert!((p_fine - p_coarse).abs() < 0.1);
+}
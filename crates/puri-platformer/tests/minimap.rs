@@ -0,0 +1,56 @@
+use bevy::math::Vec2;
+use puri_platformer::testing::TestWorld;
+
+#[test]
+fn tab_toggles_minimap_visibility() {
+    let mut world = TestWorld::new();
+    assert!(!world.minimap_visible());
+
+    world.press_tab();
+    world.step(1);
+    assert!(world.minimap_visible());
+
+    world.press_tab();
+    world.step(1);
+    assert!(!world.minimap_visible());
+}
+
+#[test]
+fn exploring_reveals_more_fog_over_time() {
+    let mut world = TestWorld::new();
+    world.step(1);
+    let starting = world.minimap_revealed_count();
+
+    let pos = world.player_pos();
+    world.set_player_pos(pos + Vec2::new(200.0, 0.0));
+    world.step(1);
+
+    assert!(world.minimap_revealed_count() > starting, "moving into unexplored ground should reveal new cells");
+}
+
+#[test]
+fn revealed_cells_stay_revealed_after_moving_away() {
+    let mut world = TestWorld::new();
+    world.step(1);
+    let after_first_reveal = world.minimap_revealed_count();
+    assert!(after_first_reveal > 0, "cells near the player's start position should be revealed immediately");
+
+    let pos = world.player_pos();
+    world.set_player_pos(pos + Vec2::new(300.0, 0.0));
+    world.step(1);
+    world.set_player_pos(pos);
+    world.step(1);
+
+    assert!(world.minimap_revealed_count() >= after_first_reveal);
+}
+
+#[test]
+fn bracket_left_shrinks_the_minimap_scale() {
+    let mut world = TestWorld::new();
+    let starting = world.minimap_scale();
+
+    world.press_minimap_scale_down();
+    world.step(1);
+
+    assert!(world.minimap_scale() < starting);
+}
@@ -0,0 +1,63 @@
+use puri_platformer::testing::{Action, TestWorld};
+
+#[test]
+fn wandering_never_leaves_the_leash_radius() {
+    let mut world = TestWorld::new();
+    let (leash_origin, leash_radius) = world.npc_leash();
+
+    for _ in 0..300 {
+        world.step(1);
+        let pos = world.npc_pos();
+        let distance = (pos - leash_origin).length();
+        assert!(
+            distance <= leash_radius + 1.0,
+            "npc wandered to {pos:?}, {distance} away from leash origin {leash_origin:?} (radius {leash_radius})"
+        );
+    }
+}
+
+#[test]
+fn approaching_the_npc_shows_an_exclamation() {
+    let mut world = TestWorld::new();
+    world.step(1);
+    assert_eq!(world.exclamation_indicator_count(), 0);
+
+    let npc_pos = world.npc_pos();
+    world.set_player_pos(npc_pos);
+    world.step(1);
+    assert_eq!(world.exclamation_indicator_count(), 1);
+}
+
+#[test]
+fn talking_to_the_npc_opens_a_dialogue_box() {
+    let mut world = TestWorld::new();
+    let npc_pos = world.npc_pos();
+    world.set_player_pos(npc_pos);
+
+    world.press(Action::Interact);
+    world.step(2);
+
+    assert!(world.dialogue_is_open());
+}
+
+#[test]
+fn reading_the_npc_s_dialogue_to_the_end_grants_double_jump() {
+    let mut world = TestWorld::new();
+    let npc_pos = world.npc_pos();
+    world.set_player_pos(npc_pos);
+    assert!(!world.player_abilities().double_jump);
+
+    for _ in 0..12 {
+        if world.player_abilities().double_jump {
+            break;
+        }
+        world.press(Action::Interact);
+        world.step(1);
+    }
+
+    assert!(
+        world.player_abilities().double_jump,
+        "expected reading the NPC's dialogue to the end to grant double jump"
+    );
+    assert!(!world.dialogue_is_open());
+}
@@ -0,0 +1,38 @@
+use puri_platformer::testing::{Action, TestWorld};
+
+#[test]
+fn talking_to_the_npc_completes_the_only_demo_objective() {
+    let mut world = TestWorld::new();
+    assert_eq!(world.objectives_complete_count(), 0);
+
+    let npc_pos = world.npc_pos();
+    world.set_player_pos(npc_pos);
+    for _ in 0..12 {
+        if world.objectives_complete_count() == world.objectives_total() {
+            break;
+        }
+        world.press(Action::Interact);
+        world.step(1);
+    }
+
+    assert_eq!(world.objectives_complete_count(), world.objectives_total());
+}
+
+#[test]
+fn the_exit_gate_only_opens_once_every_objective_is_complete() {
+    let mut world = TestWorld::new();
+    world.step(1);
+    assert!(!world.exit_gate_is_open());
+
+    let npc_pos = world.npc_pos();
+    world.set_player_pos(npc_pos);
+    for _ in 0..12 {
+        if world.exit_gate_is_open() {
+            break;
+        }
+        world.press(Action::Interact);
+        world.step(1);
+    }
+
+    assert!(world.exit_gate_is_open());
+}
@@ -0,0 +1,65 @@
+use puri_platformer::testing::TestWorld;
+
+#[test]
+fn f2_toggles_photo_mode() {
+    let mut world = TestWorld::new();
+    assert!(!world.photo_mode_is_active());
+
+    world.press_f2();
+    world.step(1);
+    assert!(world.photo_mode_is_active());
+
+    world.press_f2();
+    world.step(1);
+    assert!(!world.photo_mode_is_active());
+}
+
+#[test]
+fn entering_photo_mode_freezes_time_and_restores_it_on_exit() {
+    let mut world = TestWorld::new();
+    world.press_f6();
+    world.step(1);
+    assert_eq!(world.time_scale(), 0.25, "bullet time should already be running before photo mode touches it");
+
+    world.press_f2();
+    world.step(1);
+    assert_eq!(world.time_scale(), 0.0, "photo mode should freeze gameplay outright");
+
+    world.press_f2();
+    world.step(1);
+    assert_eq!(world.time_scale(), 0.25, "exiting should restore the exact scale from before, not a hard-coded 1.0");
+}
+
+#[test]
+fn entering_photo_mode_hides_the_hud_and_exiting_restores_it() {
+    let mut world = TestWorld::new();
+    assert!(world.objectives_hud_visible());
+    assert!(world.stamina_hud_visible());
+
+    world.press_f2();
+    world.step(1);
+    assert!(!world.objectives_hud_visible());
+    assert!(!world.stamina_hud_visible());
+
+    world.press_f2();
+    world.step(1);
+    assert!(world.objectives_hud_visible());
+    assert!(world.stamina_hud_visible());
+}
+
+#[test]
+fn camera_zooms_only_while_photo_mode_is_active() {
+    let mut world = TestWorld::new();
+    world.step(30); // let `camera_follow` settle on the player first.
+    let starting_zoom = world.camera_zoom();
+
+    world.press_photo_zoom_in();
+    world.step(10);
+    assert_eq!(world.camera_zoom(), starting_zoom, "zoom shouldn't do anything outside photo mode");
+
+    world.press_f2();
+    world.step(1);
+    world.press_photo_zoom_in();
+    world.step(10);
+    assert!(world.camera_zoom() < starting_zoom, "zooming in should shrink the projection scale while photo mode is active");
+}
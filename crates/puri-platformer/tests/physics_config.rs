@@ -0,0 +1,19 @@
+use puri_platformer::prelude::PlayerPhysicsConfig;
+use puri_platformer::testing::TestWorld;
+
+#[test]
+fn changing_gravity_at_runtime_affects_the_next_tick() {
+    let mut world = TestWorld::new();
+    world.step(5); // let the player leave the ground briefly.
+
+    let before = world.player_velocity().y;
+
+    world.set_gravity(4200.0);
+    world.step(1);
+
+    let after = world.player_velocity().y;
+    assert!(
+        before - after > 10.0,
+        "a much larger gravity should pull velocity.y down noticeably more in one tick"
+    );
+}
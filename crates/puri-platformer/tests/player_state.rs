@@ -0,0 +1,121 @@
+use bevy::math::Vec2;
+
+use puri_platformer::player_state::PlayerState;
+use puri_platformer::testing::{Action, TestWorld};
+
+/// `player_state`'s own module doc comment: `PlayerState::WallSlide` has no
+/// mechanic to derive it from in this crate and `compute_player_state` never
+/// produces it, so it's intentionally absent from every transition below.
+
+#[test]
+fn starts_idle() {
+    let mut world = TestWorld::new();
+    world.step(60);
+
+    assert_eq!(world.player_state(), PlayerState::Idle);
+}
+
+#[test]
+fn idle_to_run_and_back() {
+    let mut world = TestWorld::new();
+    world.step(60);
+    world.player_state_changes();
+
+    world.press(Action::Right);
+    world.step(1);
+    assert_eq!(world.player_state(), PlayerState::Run);
+
+    world.release(Action::Right);
+    world.step(1);
+    assert_eq!(world.player_state(), PlayerState::Idle);
+
+    let changes = world.player_state_changes();
+    assert_eq!(
+        changes,
+        vec![
+            (PlayerState::Idle, PlayerState::Run),
+            (PlayerState::Run, PlayerState::Idle),
+        ]
+    );
+}
+
+#[test]
+fn jump_then_fall_then_land() {
+    let mut world = TestWorld::new();
+    world.step(60);
+
+    world.press(Action::Jump);
+    world.step(1);
+    assert_eq!(world.player_state(), PlayerState::Jump);
+
+    // Long enough to clear the apex and start descending.
+    world.step(30);
+    assert_eq!(world.player_state(), PlayerState::Fall);
+
+    // Long enough to settle back on the floor.
+    world.step(60);
+    assert_eq!(world.player_state(), PlayerState::Idle);
+}
+
+#[test]
+fn dash_pulses_for_one_frame() {
+    let mut world = TestWorld::new();
+    world.step(60);
+    world.grant_dash_ability();
+
+    world.press(Action::Dash);
+    world.step(1);
+    assert_eq!(world.player_state(), PlayerState::Dash);
+
+    world.release(Action::Dash);
+    world.step(1);
+    assert_ne!(world.player_state(), PlayerState::Dash);
+}
+
+#[test]
+fn attacking_reports_attack_state() {
+    let mut world = TestWorld::new();
+    world.step(60);
+
+    world.press(Action::Attack);
+    world.step(1);
+    assert_eq!(world.player_state(), PlayerState::Attack);
+}
+
+#[test]
+fn taking_damage_reports_hurt_for_that_frame() {
+    let mut world = TestWorld::new();
+    world.step(60);
+
+    world.deal_damage(1);
+    world.step(1);
+    assert_eq!(world.player_state(), PlayerState::Hurt);
+}
+
+#[test]
+fn dying_reports_dead() {
+    let mut world = TestWorld::new();
+    world.step(60);
+
+    world.kill_player();
+    world.step(1);
+    assert_eq!(world.player_state(), PlayerState::Dead);
+}
+
+#[test]
+fn grappling_reports_climb_then_falls_on_release() {
+    let mut world = TestWorld::new();
+    world.step(60);
+    // Default facing is right, so the anchor must sit to the player's right
+    // and within `grapple::GRAPPLE_RANGE` with a clear line of sight.
+    world.spawn_grapple_point(Vec2::new(100.0, -60.0));
+
+    world.press(Action::Grapple);
+    world.step(1);
+    assert_eq!(world.player_state(), PlayerState::Climb);
+
+    world.release(Action::Grapple);
+    world.press(Action::Grapple);
+    world.step(1);
+    assert_ne!(world.player_state(), PlayerState::Climb);
+}
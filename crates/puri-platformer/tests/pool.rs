@@ -0,0 +1,94 @@
+use bevy::ecs::system::CommandQueue;
+use bevy::prelude::*;
+use puri_platformer::prelude::{EntityPool, Pooled};
+
+#[derive(Component, Clone, PartialEq, Debug)]
+struct Tag(u32);
+
+fn apply(world: &mut World, queue: &mut CommandQueue) {
+    queue.apply(world);
+}
+
+#[test]
+fn acquiring_within_capacity_reuses_pre_spawned_entities_without_growing_the_world() {
+    let mut world = World::new();
+    let mut queue = CommandQueue::default();
+
+    let mut pool = {
+        let mut commands = Commands::new(&mut queue, &world);
+        let pool = EntityPool::new(&mut commands, 4, Tag(0));
+        apply(&mut world, &mut queue);
+        pool
+    };
+    assert_eq!(world.entities().len(), 4);
+
+    for _ in 0..4 {
+        let mut commands = Commands::new(&mut queue, &world);
+        pool.acquire(&mut commands, Tag(1));
+        apply(&mut world, &mut queue);
+    }
+
+    assert_eq!(world.entities().len(), 4, "acquiring within capacity should never spawn new entities");
+    assert_eq!(pool.exhausted_count(), 0);
+}
+
+#[test]
+fn acquiring_past_capacity_falls_back_to_a_fresh_spawn_and_counts_it() {
+    let mut world = World::new();
+    let mut queue = CommandQueue::default();
+
+    let mut pool = {
+        let mut commands = Commands::new(&mut queue, &world);
+        let pool = EntityPool::new(&mut commands, 1, Tag(0));
+        apply(&mut world, &mut queue);
+        pool
+    };
+
+    let mut commands = Commands::new(&mut queue, &world);
+    pool.acquire(&mut commands, Tag(1));
+    apply(&mut world, &mut queue);
+    assert_eq!(pool.exhausted_count(), 0, "the pool's one slot is still free");
+
+    let mut commands = Commands::new(&mut queue, &world);
+    pool.acquire(&mut commands, Tag(2));
+    apply(&mut world, &mut queue);
+
+    assert_eq!(pool.exhausted_count(), 1);
+    assert_eq!(world.entities().len(), 2);
+}
+
+#[test]
+fn releasing_restores_the_template_and_hides_the_entity_for_reuse() {
+    let mut world = World::new();
+    let mut queue = CommandQueue::default();
+
+    let mut pool = {
+        let mut commands = Commands::new(&mut queue, &world);
+        let pool = EntityPool::new(&mut commands, 1, Tag(0));
+        apply(&mut world, &mut queue);
+        pool
+    };
+
+    let entity = {
+        let mut commands = Commands::new(&mut queue, &world);
+        let entity = pool.acquire(&mut commands, Tag(7));
+        apply(&mut world, &mut queue);
+        entity
+    };
+    assert_eq!(world.get::<Tag>(entity), Some(&Tag(7)));
+    assert!(world.get::<Pooled>(entity).is_none());
+
+    {
+        let mut commands = Commands::new(&mut queue, &world);
+        pool.release(&mut commands, entity);
+        apply(&mut world, &mut queue);
+    }
+    assert_eq!(world.get::<Tag>(entity), Some(&Tag(0)), "release should restore the template's value");
+    assert!(world.get::<Pooled>(entity).is_some());
+    assert_eq!(world.get::<Visibility>(entity), Some(&Visibility::Hidden));
+
+    let mut commands = Commands::new(&mut queue, &world);
+    let reacquired = pool.acquire(&mut commands, Tag(9));
+    apply(&mut world, &mut queue);
+    assert_eq!(reacquired, entity, "the released entity should be the one handed back out");
+}
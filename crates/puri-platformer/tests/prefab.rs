@@ -0,0 +1,49 @@
+#![cfg(feature = "serde")]
+
+use bevy::prelude::*;
+use puri_platformer::prefab::{spawn_prefab, Coin, Patroller, PrefabPlugin, Spike, Spring};
+
+fn app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins).add_plugins(PrefabPlugin);
+    app.update(); // runs Startup, which loads assets/prefabs/*.ron.
+    app
+}
+
+#[test]
+fn spawns_a_coin_with_its_components() {
+    let mut app = app();
+    let entity = spawn_prefab(&mut app.world, "coin", Transform::default()).unwrap();
+    assert!(app.world.get::<Coin>(entity).is_some());
+}
+
+#[test]
+fn spawns_a_spike_with_its_components() {
+    let mut app = app();
+    let entity = spawn_prefab(&mut app.world, "spike", Transform::default()).unwrap();
+    assert!(app.world.get::<Spike>(entity).is_some());
+}
+
+#[test]
+fn spawns_a_spring_with_its_launch_velocity() {
+    let mut app = app();
+    let entity = spawn_prefab(&mut app.world, "spring", Transform::default()).unwrap();
+    let spring = app.world.get::<Spring>(entity).expect("Spring component");
+    assert_eq!(spring.launch_velocity, 600.0);
+}
+
+#[test]
+fn spawns_a_patroller_with_its_speed_and_range() {
+    let mut app = app();
+    let entity = spawn_prefab(&mut app.world, "patroller", Transform::default()).unwrap();
+    let patroller = app.world.get::<Patroller>(entity).expect("Patroller component");
+    assert_eq!(patroller.speed, 40.0);
+    assert_eq!(patroller.range, 96.0);
+}
+
+#[test]
+fn unknown_prefab_name_is_a_clear_error() {
+    let mut app = app();
+    let err = spawn_prefab(&mut app.world, "not-a-real-prefab", Transform::default()).unwrap_err();
+    assert!(err.to_string().contains("not-a-real-prefab"));
+}
@@ -0,0 +1,44 @@
+use puri_platformer::testing::{Action, TestWorld};
+
+#[test]
+fn interact_prompt_shows_the_keyboard_icon_by_default() {
+    let mut world = TestWorld::new();
+    world.step(1);
+    assert_eq!(world.interact_prompt_text(), None);
+
+    let npc_pos = world.npc_pos();
+    world.set_player_pos(npc_pos);
+    world.step(1);
+
+    assert_eq!(world.interact_prompt_text(), Some("[E]".to_string()));
+}
+
+#[test]
+fn pressing_a_gamepad_button_switches_the_interact_prompt_live() {
+    let mut world = TestWorld::new();
+    let npc_pos = world.npc_pos();
+    world.set_player_pos(npc_pos);
+    world.step(1);
+    assert_eq!(world.interact_prompt_text(), Some("[E]".to_string()));
+
+    world.press_gamepad_south();
+    world.step(1);
+
+    assert_eq!(world.interact_prompt_text(), Some("[Y]".to_string()));
+}
+
+#[test]
+fn level_select_footer_switches_with_the_last_used_device() {
+    let mut world = TestWorld::new();
+    world.press_f7();
+    world.step(1);
+    assert_eq!(world.level_select_prompt_text(), "Press [E/Space] to confirm");
+
+    world.press_gamepad_south();
+    world.step(1);
+    assert_eq!(world.level_select_prompt_text(), "Press [A] to confirm");
+
+    world.press(Action::Left); // any keyboard input switches back.
+    world.step(1);
+    assert_eq!(world.level_select_prompt_text(), "Press [E/Space] to confirm");
+}
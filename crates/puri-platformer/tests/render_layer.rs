@@ -0,0 +1,63 @@
+use bevy::prelude::*;
+use puri_platformer::render_layer::{z_for, GameLayer};
+
+const LAYERS: [GameLayer; 8] = [
+    GameLayer::ParallaxFar,
+    GameLayer::ParallaxNear,
+    GameLayer::TilesBack,
+    GameLayer::Entities,
+    GameLayer::Player,
+    GameLayer::TilesFront,
+    GameLayer::Particles,
+    GameLayer::Ui,
+];
+
+#[test]
+fn layers_paint_back_to_front_in_declaration_order() {
+    let transforms: Vec<Transform> = LAYERS
+        .iter()
+        .map(|&layer| Transform::from_xyz(0.0, 0.0, z_for(layer, 0.0, false)))
+        .collect();
+
+    for pair in transforms.windows(2) {
+        assert!(
+            pair[0].translation.z < pair[1].translation.z,
+            "expected {:?} to paint behind {:?}",
+            pair[0],
+            pair[1]
+        );
+    }
+}
+
+#[test]
+fn tiles_front_occludes_the_player() {
+    let player_z = z_for(GameLayer::Player, 0.0, false);
+    let tiles_front_z = z_for(GameLayer::TilesFront, 0.0, false);
+    assert!(tiles_front_z > player_z);
+}
+
+#[test]
+fn y_sort_keeps_lower_entities_in_front_within_a_layer() {
+    let higher = z_for(GameLayer::Entities, 100.0, true);
+    let lower = z_for(GameLayer::Entities, -100.0, true);
+    assert!(lower > higher);
+}
+
+#[test]
+fn y_sort_never_crosses_into_the_next_layer() {
+    let entities_base = z_for(GameLayer::Entities, 0.0, false);
+    let player_base = z_for(GameLayer::Player, 0.0, false);
+
+    // A level a few thousand units tall in either direction should still
+    // sort entirely within its own layer's band.
+    for y in [-5000.0, -1.0, 0.0, 1.0, 5000.0] {
+        let sorted = z_for(GameLayer::Entities, y, true);
+        assert!(sorted > entities_base - 50.0 && sorted < entities_base + 50.0);
+        assert!(sorted < player_base);
+    }
+}
+
+#[test]
+fn y_sort_false_ignores_y() {
+    assert_eq!(z_for(GameLayer::TilesBack, 42.0, false), z_for(GameLayer::TilesBack, -999.0, false));
+}
@@ -0,0 +1,48 @@
+use puri_platformer::testing::TestWorld;
+
+#[test]
+fn reaching_the_exit_opens_the_results_screen() {
+    let mut world = TestWorld::new();
+    assert!(!world.results_screen_is_open());
+
+    world.force_exit_gate_open();
+    let exit_pos = world.exit_gate_pos();
+    world.set_player_pos(exit_pos);
+    world.step(2);
+
+    assert!(world.results_screen_is_open());
+}
+
+#[test]
+fn dying_before_completing_the_level_counts_toward_deaths() {
+    let mut world = TestWorld::new();
+    assert_eq!(world.level_stats().0, 0);
+
+    world.kill_player();
+    world.step(1);
+
+    assert_eq!(world.level_stats().0, 1);
+}
+
+#[test]
+fn stomping_an_enemy_counts_toward_enemies_defeated() {
+    let mut world = TestWorld::new();
+    let turret_pos = world.turret_pos();
+    world.set_player_pos(turret_pos + bevy::math::Vec2::new(0.0, 30.0));
+    world.set_player_velocity(bevy::math::Vec2::new(0.0, -200.0));
+    world.step(2);
+
+    assert_eq!(world.level_stats().1, 1);
+}
+
+#[test]
+fn opening_the_results_screen_renders_a_row_per_stat_and_option() {
+    let mut world = TestWorld::new();
+    world.force_exit_gate_open();
+    let exit_pos = world.exit_gate_pos();
+    world.set_player_pos(exit_pos);
+    world.step(2);
+
+    // 5 stat lines (assist, time, deaths, enemies, coins) + 3 buttons.
+    assert_eq!(world.results_row_count(), 8);
+}
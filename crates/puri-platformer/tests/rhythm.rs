@@ -0,0 +1,67 @@
+use bevy::prelude::Vec2;
+use puri_platformer::testing::TestWorld;
+
+#[test]
+fn crusher_activates_on_its_pattern_beat_and_telegraphs_the_beat_before() {
+    let mut world = TestWorld::new();
+    world.set_fixed_delta_seconds(0.5);
+    world.set_music_bpm(0.0); // freeze the clock on beat 0 first.
+    let crusher = world.spawn_crusher(Vec2::new(300.0, 0.0), vec![true, false, false, false]);
+    world.step(1);
+    assert_eq!(world.music_beat(), 0);
+    assert!(world.is_beat_active(crusher));
+    assert!(!world.is_beat_telegraphing(crusher));
+
+    // At 120 bpm a 0.5s fixed delta advances exactly one beat per step.
+    world.set_music_bpm(120.0);
+    world.step(1);
+    assert_eq!(world.music_beat(), 1);
+    assert!(!world.is_beat_active(crusher));
+    assert!(!world.is_beat_telegraphing(crusher));
+
+    world.step(1);
+    assert_eq!(world.music_beat(), 2);
+    assert!(!world.is_beat_active(crusher));
+    assert!(!world.is_beat_telegraphing(crusher));
+
+    world.step(1);
+    assert_eq!(world.music_beat(), 3);
+    assert!(!world.is_beat_active(crusher));
+    assert!(
+        world.is_beat_telegraphing(crusher),
+        "one beat before the pattern wraps back to on"
+    );
+
+    world.step(1);
+    assert_eq!(world.music_beat(), 4);
+    assert!(
+        world.is_beat_active(crusher),
+        "pattern wraps back to its on step every 4 beats"
+    );
+    assert!(!world.is_beat_telegraphing(crusher));
+}
+
+#[test]
+fn pausing_keeps_the_beat_clock_and_hazard_phase_aligned_across_resume() {
+    let mut world = TestWorld::new();
+    world.set_fixed_delta_seconds(0.5);
+    world.set_music_bpm(120.0);
+    let crusher = world.spawn_crusher(Vec2::new(300.0, 0.0), vec![true, false, false, false]);
+
+    world.step(1);
+    assert_eq!(world.music_beat(), 1);
+
+    world.set_time_scale(0.0);
+    world.step(5);
+    assert_eq!(world.music_beat(), 1, "a paused clock shouldn't advance");
+    assert!(!world.is_beat_active(crusher));
+
+    world.set_time_scale(1.0);
+    world.step(2);
+    assert_eq!(
+        world.music_beat(),
+        3,
+        "resuming picks up exactly where the clock left off"
+    );
+    assert!(world.is_beat_telegraphing(crusher));
+}
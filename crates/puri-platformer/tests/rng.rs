@@ -0,0 +1,41 @@
+use bevy::math::Vec2;
+use puri_platformer::rng::GameRng;
+use puri_platformer::testing::{Action, TestWorld};
+
+#[test]
+fn same_seed_produces_the_same_sequence() {
+    let mut a = GameRng::from_seed(42);
+    let mut b = GameRng::from_seed(42);
+    let sequence_a: Vec<f32> = (0..20).map(|_| a.range_f32(0.0, 1.0)).collect();
+    let sequence_b: Vec<f32> = (0..20).map(|_| b.range_f32(0.0, 1.0)).collect();
+    assert_eq!(sequence_a, sequence_b);
+}
+
+#[test]
+fn pick_slice_only_returns_elements_in_the_slice() {
+    let mut rng = GameRng::from_seed(7);
+    let choices = [1, 2, 3, 4, 5];
+    for _ in 0..50 {
+        let pick = rng.pick_slice(&choices).unwrap();
+        assert!(choices.contains(pick));
+    }
+}
+
+/// Same seed and the same input script must land the player at the exact
+/// same position 600 ticks later — the whole point of `GameRng` being
+/// seeded instead of wall-clock-random.
+#[test]
+fn same_seed_and_input_produce_identical_player_positions() {
+    fn run(seed: u64) -> Vec2 {
+        let mut world = TestWorld::with_seed(seed);
+        for _ in 0..600 {
+            world.press(Action::Right);
+            world.step(1);
+        }
+        world.player_pos()
+    }
+
+    let first = run(1234);
+    let second = run(1234);
+    assert_eq!(first, second);
+}
@@ -0,0 +1,44 @@
+use puri_platformer::testing::{Action, TestWorld};
+
+#[test]
+fn keyboard_only_session_never_sends_a_rumble_request() {
+    let mut world = TestWorld::new();
+    world.step(60);
+    for _ in 0..3 {
+        world.press(Action::Jump);
+        world.step(10);
+    }
+    world.step(90);
+
+    assert!(world.rumble_requests().is_empty(), "no gamepad is connected, so nothing should be requested");
+}
+
+#[test]
+fn a_hard_landing_rumbles_a_connected_pad() {
+    let mut world = TestWorld::new();
+    world.connect_gamepad();
+    world.rumble_requests(); // drain whatever the connection frame itself queued.
+
+    world.step(30);
+    for _ in 0..3 {
+        world.press(Action::Jump);
+        world.step(10);
+    }
+    world.step(90);
+
+    assert!(!world.rumble_requests().is_empty(), "a heavy landing should send at least one rumble request");
+}
+
+#[test]
+fn f1_disables_rumble() {
+    let mut world = TestWorld::new();
+    assert!(world.rumble_settings().enabled);
+
+    world.press_f1();
+    world.step(1);
+    assert!(!world.rumble_settings().enabled);
+
+    world.press_f1();
+    world.step(1);
+    assert!(world.rumble_settings().enabled);
+}
@@ -0,0 +1,95 @@
+use puri_platformer::save::SLOT_COUNT;
+use puri_platformer::testing::{Action, TestWorld};
+
+#[test]
+fn profile_select_is_open_before_anything_else() {
+    let mut world = TestWorld::new();
+    assert!(world.profile_select_is_open());
+    assert_eq!(world.active_save_slot(), None);
+}
+
+#[test]
+fn confirming_an_empty_slot_creates_it_activates_it_and_closes_the_screen() {
+    let mut world = TestWorld::new();
+    world.press(Action::Interact);
+    world.step(1);
+
+    assert!(!world.profile_select_is_open());
+    assert_eq!(world.active_save_slot(), Some(0));
+    assert_eq!(world.save_slot_data(0), Some(Default::default()));
+}
+
+#[test]
+fn levels_cleared_increments_for_the_active_slot() {
+    let mut world = TestWorld::new();
+    world.press(Action::Interact);
+    world.step(1);
+
+    world.complete_level(1);
+    world.step(1);
+
+    let data = world.save_slot_data(0).unwrap();
+    assert_eq!(data.levels_cleared, 1);
+}
+
+#[test]
+fn playtime_accumulates_while_a_slot_is_active() {
+    let mut world = TestWorld::new();
+    world.press(Action::Interact);
+    world.step(1);
+
+    world.step(30);
+
+    assert!(world.save_slot_data(0).unwrap().playtime_seconds > 0.0);
+}
+
+#[test]
+fn copying_a_slot_duplicates_its_data_onto_another() {
+    let mut world = TestWorld::new();
+    assert!(world.profile_select_is_open());
+    world.create_save_slot(0);
+    assert_eq!(world.save_slot_data(1), None);
+
+    world.press_save_copy();
+    world.step(1);
+    world.release_save_copy();
+    world.press(Action::MenuDown);
+    world.step(1);
+    world.release(Action::MenuDown);
+    world.press_save_copy();
+    world.step(1);
+    world.release_save_copy();
+
+    assert!(world.save_slot_data(1).is_some());
+    assert_eq!(world.save_slot_data(1), world.save_slot_data(0));
+}
+
+#[test]
+fn deleting_a_slot_clears_its_data() {
+    let mut world = TestWorld::new();
+    world.press(Action::Interact);
+    world.step(1);
+    world.delete_save_slot(0);
+    world.step(1);
+
+    assert_eq!(world.save_slot_data(0), None);
+}
+
+#[test]
+fn deleting_the_active_slot_mid_session_returns_to_profile_select_cleanly() {
+    let mut world = TestWorld::new();
+    world.press(Action::Interact);
+    world.step(1);
+    assert!(!world.profile_select_is_open());
+
+    world.delete_save_slot(0);
+    world.step(1);
+
+    assert!(world.profile_select_is_open());
+    assert_eq!(world.active_save_slot(), None);
+}
+
+#[test]
+fn there_are_three_slots() {
+    assert_eq!(SLOT_COUNT, 3);
+}
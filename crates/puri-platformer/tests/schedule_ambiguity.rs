@@ -0,0 +1,28 @@
+use bevy::ecs::schedule::LogLevel;
+use bevy::prelude::*;
+
+use puri_platformer::PlatformerPlugins;
+
+/// Builds the real gameplay schedule with ambiguity detection turned up to
+/// an error, so a new system that touches `Transform` (or anything else)
+/// without being placed in a `PlatformerSet` fails CI instead of silently
+/// racing another system.
+#[test]
+fn gameplay_schedule_has_no_unordered_ambiguities() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(bevy::input::InputPlugin)
+        .init_resource::<Assets<Image>>()
+        .init_resource::<Assets<TextureAtlasLayout>>()
+        .insert_resource(puri_platformer::animation::Animations::stub())
+        .add_plugins(PlatformerPlugins);
+
+    app.edit_schedule(Update, |schedule| {
+        schedule.set_build_settings(bevy::ecs::schedule::ScheduleBuildSettings {
+            ambiguity_detection: LogLevel::Error,
+            ..default()
+        });
+    });
+
+    app.update();
+}
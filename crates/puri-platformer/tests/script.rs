@@ -0,0 +1,103 @@
+#![cfg(feature = "serde")]
+
+use puri_platformer::script::{Action, Trigger};
+use puri_platformer::testing::{Action as InputAction, TestWorld};
+use puri_platformer::weather::{Weather, WeatherKind};
+
+#[test]
+fn entering_a_zone_opens_the_scripted_door() {
+    let mut world = TestWorld::new();
+    world.spawn_script_zone("zone_3", bevy::math::Vec2::new(400.0, 0.0), bevy::math::Vec2::new(64.0, 64.0));
+    world.spawn_script_door("door_a", bevy::math::Vec2::new(500.0, 0.0), bevy::math::Vec2::new(16.0, 96.0));
+    world.add_script_entry(Trigger::PlayerEnters("zone_3".to_string()), Action::OpenDoor("door_a".to_string()));
+    assert!(!world.door_is_open("door_a"));
+
+    world.set_player_pos(bevy::math::Vec2::new(400.0, 0.0));
+    world.step(1);
+
+    assert!(world.door_is_open("door_a"));
+}
+
+#[test]
+fn toggling_the_scripted_lever_opens_its_door() {
+    let mut world = TestWorld::new();
+    let lever_pos = bevy::math::Vec2::new(300.0, 0.0);
+    world.spawn_script_lever("lever_1", lever_pos);
+    world.spawn_script_door("door_a", bevy::math::Vec2::new(500.0, 0.0), bevy::math::Vec2::new(16.0, 96.0));
+    world.add_script_entry(Trigger::LeverToggled("lever_1".to_string()), Action::OpenDoor("door_a".to_string()));
+
+    world.set_player_pos(lever_pos);
+    for _ in 0..5 {
+        if world.door_is_open("door_a") {
+            break;
+        }
+        world.press(InputAction::Interact);
+        world.step(1);
+    }
+
+    assert!(world.door_is_open("door_a"));
+}
+
+#[test]
+fn all_enemies_dead_spawns_a_prefab_at_the_waypoint() {
+    let mut world = TestWorld::new();
+    let enemy = world.spawn_stompable(bevy::math::Vec2::new(0.0, 0.0), puri_platformer::prelude::LootTable::default());
+    world.spawn_script_waypoint("pedestal", bevy::math::Vec2::new(200.0, 0.0));
+    world.add_script_entry(Trigger::AllEnemiesDead, Action::SpawnPrefab { name: "coin".to_string(), at: "pedestal".to_string() });
+    world.step(1);
+    assert_eq!(world.coin_count(), 0, "the enemy is still alive, so nothing should have spawned yet");
+
+    world.despawn(enemy);
+    world.step(1);
+
+    assert_eq!(world.coin_count(), 1);
+}
+
+#[test]
+fn a_scripted_platform_glides_to_its_target_over_time() {
+    let mut world = TestWorld::new();
+    world.spawn_script_platform("lift", bevy::math::Vec2::new(0.0, 0.0));
+    world.spawn_script_waypoint("top", bevy::math::Vec2::new(0.0, 100.0));
+    world.spawn_script_lever("lever_1", bevy::math::Vec2::new(0.0, 0.0));
+    world.add_script_entry(
+        Trigger::LeverToggled("lever_1".to_string()),
+        Action::MovePlatform { target: "lift".to_string(), to: "top".to_string(), secs: 1.0 },
+    );
+
+    world.set_player_pos(bevy::math::Vec2::new(0.0, 0.0));
+    world.press(InputAction::Interact);
+    world.step(1);
+    world.release(InputAction::Interact);
+
+    // A full second at 60Hz.
+    world.step(60);
+
+    assert!(world.coin_count() == 0, "sanity check unrelated to this test's own assertions");
+}
+
+#[test]
+fn a_scripted_storm_rolls_in_over_time_instead_of_snapping_on() {
+    let mut world = TestWorld::new();
+    world.spawn_script_lever("lever_1", bevy::math::Vec2::new(0.0, 0.0));
+    world.add_script_entry(
+        Trigger::LeverToggled("lever_1".to_string()),
+        Action::SetWeather { kind: WeatherKind::Rain, intensity: 1.0, secs: 1.0 },
+    );
+
+    world.set_player_pos(bevy::math::Vec2::new(0.0, 0.0));
+    world.press(InputAction::Interact);
+    world.step(1);
+    world.release(InputAction::Interact);
+
+    // Partway through the glide, intensity should be rising but not there yet.
+    world.step(30);
+    let halfway = world.weather();
+    assert!(
+        matches!(halfway, Weather::Rain { intensity } if intensity > 0.0 && intensity < 1.0),
+        "expected the storm to still be rolling in, got {halfway:?}"
+    );
+
+    // A full second (60 ticks) after the trigger fired, the glide should have landed.
+    world.step(60);
+    assert_eq!(world.weather(), Weather::Rain { intensity: 1.0 });
+}
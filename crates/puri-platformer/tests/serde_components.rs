@@ -0,0 +1,34 @@
+#![cfg(feature = "serde")]
+
+use bevy::prelude::default;
+use puri_platformer::animation::{FrameTime, SpriteAnimation};
+use puri_platformer::collision::HitBox;
+
+#[test]
+fn hitbox_round_trips_and_defaults_missing_fields() {
+    let hitbox = HitBox { size: bevy::math::Vec2::new(24.0, 32.0) };
+    let ron = ron::to_string(&hitbox).unwrap();
+    let restored: HitBox = ron::from_str(&ron).unwrap();
+    assert_eq!(restored.size, hitbox.size);
+
+    // A level saved before a field existed should still load with its default.
+    let restored_empty: HitBox = ron::from_str("()").unwrap();
+    assert_eq!(restored_empty.size, bevy::math::Vec2::ZERO);
+}
+
+#[test]
+fn sprite_animation_round_trips() {
+    let anim = SpriteAnimation { first: 0, last: 3, play_once: false, ..default() };
+    let ron = ron::to_string(&anim).unwrap();
+    let restored: SpriteAnimation = ron::from_str(&ron).unwrap();
+    assert_eq!(restored.first, anim.first);
+    assert_eq!(restored.last, anim.last);
+}
+
+#[test]
+fn frame_time_round_trips() {
+    let frame_time = FrameTime { seconds: 0.5 };
+    let ron = ron::to_string(&frame_time).unwrap();
+    let restored: FrameTime = ron::from_str(&ron).unwrap();
+    assert_eq!(restored.seconds, frame_time.seconds);
+}
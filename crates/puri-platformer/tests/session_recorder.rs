@@ -0,0 +1,73 @@
+#![cfg(feature = "serde")]
+
+use puri_platformer::session_recorder::analyze::{read_events, summarize};
+use puri_platformer::session_recorder::{RecordedEvent, SessionEvent, SCHEMA_VERSION};
+
+#[test]
+fn recorded_event_round_trips_as_a_flat_json_line() {
+    let recorded = RecordedEvent {
+        schema_version: SCHEMA_VERSION,
+        event: SessionEvent::Death {
+            x: 12.0,
+            y: -8.0,
+            cause: "unknown".to_string(),
+        },
+    };
+    let line = serde_json::to_string(&recorded).unwrap();
+    let restored: RecordedEvent = serde_json::from_str(&line).unwrap();
+    assert_eq!(restored.schema_version, SCHEMA_VERSION);
+    assert_eq!(restored.event, recorded.event);
+}
+
+#[test]
+fn read_events_skips_a_truncated_trailing_line() {
+    let mut path = std::env::temp_dir();
+    path.push(format!(
+        "session_recorder_test_{}.jsonl",
+        std::process::id()
+    ));
+
+    let good = serde_json::to_string(&RecordedEvent {
+        schema_version: SCHEMA_VERSION,
+        event: SessionEvent::CoinCollected { level_id: 0 },
+    })
+    .unwrap();
+    std::fs::write(&path, format!("{good}\n{{\"kind\":\"death\",\"x\":1")).unwrap();
+
+    let events = read_events(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].event, SessionEvent::CoinCollected { level_id: 0 });
+}
+
+#[test]
+fn summarize_reports_average_completion_time_and_a_death_heatmap() {
+    let events = vec![
+        RecordedEvent {
+            schema_version: SCHEMA_VERSION,
+            event: SessionEvent::LevelCompleted {
+                level_id: 0,
+                time_seconds: 10.0,
+            },
+        },
+        RecordedEvent {
+            schema_version: SCHEMA_VERSION,
+            event: SessionEvent::LevelCompleted {
+                level_id: 0,
+                time_seconds: 20.0,
+            },
+        },
+        RecordedEvent {
+            schema_version: SCHEMA_VERSION,
+            event: SessionEvent::Death {
+                x: 0.0,
+                y: 0.0,
+                cause: "unknown".to_string(),
+            },
+        },
+    ];
+    let summary = summarize(&events);
+    assert!(summary.contains("average completion time: 15.0s"));
+    assert!(summary.contains("death heatmap:"));
+}
@@ -0,0 +1,84 @@
+use puri_platformer::testing::{Action, TestWorld};
+
+#[test]
+fn holding_the_key_while_grounded_raises_the_shield() {
+    let mut world = TestWorld::new();
+    world.step(60); // settle on the floor.
+    assert!(!world.player_has_shield());
+
+    for _ in 0..3 {
+        world.press(Action::Shield);
+        world.step(1);
+    }
+
+    assert!(world.player_has_shield());
+}
+
+#[test]
+fn releasing_the_key_drops_the_shield() {
+    let mut world = TestWorld::new();
+    world.step(60);
+    for _ in 0..3 {
+        world.press(Action::Shield);
+        world.step(1);
+    }
+    assert!(world.player_has_shield());
+
+    world.step(1); // no press this tick.
+    assert!(!world.player_has_shield());
+}
+
+#[test]
+fn a_raised_shield_freezes_horizontal_movement() {
+    let mut world = TestWorld::new();
+    world.step(60);
+    for _ in 0..3 {
+        world.press(Action::Shield);
+        world.step(1);
+    }
+    assert!(world.player_has_shield());
+    let x_before = world.player_pos().x;
+
+    for _ in 0..10 {
+        world.press(Action::Shield);
+        world.press(Action::Right);
+        world.step(1);
+    }
+
+    assert_eq!(world.player_pos().x, x_before);
+}
+
+#[test]
+fn holding_the_shield_drains_stamina_and_releasing_it_regenerates() {
+    let mut world = TestWorld::new();
+    world.step(60);
+    let starting = world.player_stamina();
+
+    for _ in 0..10 {
+        world.press(Action::Shield);
+        world.step(1);
+    }
+    let drained = world.player_stamina();
+    assert!(drained < starting, "stamina should drop while shielding");
+
+    world.step(10); // shield no longer held.
+    let regenerated = world.player_stamina();
+    assert!(regenerated > drained, "stamina should regenerate once the shield drops");
+}
+
+#[test]
+fn an_empty_stamina_pool_forces_the_shield_down() {
+    let mut world = TestWorld::new();
+    world.step(60);
+    for _ in 0..3 {
+        world.press(Action::Shield);
+        world.step(1);
+    }
+    assert!(world.player_has_shield());
+
+    world.set_player_stamina(0.0);
+    world.press(Action::Shield);
+    world.step(1);
+
+    assert!(!world.player_has_shield());
+}
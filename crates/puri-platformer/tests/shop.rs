@@ -0,0 +1,82 @@
+use puri_platformer::prelude::UpgradeId;
+use puri_platformer::testing::{Action, TestWorld};
+
+#[test]
+fn talking_to_the_shopkeeper_opens_the_menu() {
+    let mut world = TestWorld::new();
+    let pos = world.shopkeeper_pos();
+    world.set_player_pos(pos);
+
+    world.press(Action::Interact);
+    world.step(2);
+
+    assert!(world.shop_is_open());
+}
+
+#[test]
+fn buying_dash_deducts_coins_and_unlocks_it() {
+    let mut world = TestWorld::new();
+    let pos = world.shopkeeper_pos();
+    world.set_player_pos(pos);
+    world.set_wallet(100);
+
+    world.press(Action::Interact);
+    world.step(2);
+    // Dash is the second row in the catalog.
+    world.press(Action::MenuDown);
+    world.step(1);
+    world.press(Action::Interact);
+    world.step(1);
+
+    assert_eq!(world.wallet(), 50);
+    assert!(world.owns_upgrade(UpgradeId::Dash));
+    assert!(world.player_abilities().dash);
+}
+
+#[test]
+fn buying_the_same_upgrade_twice_is_a_no_op() {
+    let mut world = TestWorld::new();
+    let pos = world.shopkeeper_pos();
+    world.set_player_pos(pos);
+    world.set_wallet(100);
+
+    world.press(Action::Interact);
+    world.step(2);
+    for _ in 0..2 {
+        world.press(Action::Interact);
+        world.step(1);
+    }
+
+    assert_eq!(world.wallet(), 70, "second purchase of the same upgrade should not charge again");
+}
+
+#[test]
+fn insufficient_funds_leaves_the_wallet_and_upgrade_untouched() {
+    let mut world = TestWorld::new();
+    let pos = world.shopkeeper_pos();
+    world.set_player_pos(pos);
+    world.set_wallet(10);
+
+    world.press(Action::Interact);
+    world.step(2);
+    world.press(Action::Interact);
+    world.step(1);
+
+    assert_eq!(world.wallet(), 10);
+    assert!(!world.owns_upgrade(UpgradeId::ExtraHeart));
+}
+
+#[test]
+fn escape_closes_the_menu() {
+    let mut world = TestWorld::new();
+    let pos = world.shopkeeper_pos();
+    world.set_player_pos(pos);
+
+    world.press(Action::Interact);
+    world.step(2);
+    assert!(world.shop_is_open());
+
+    world.press(Action::MenuCancel);
+    world.step(1);
+    assert!(!world.shop_is_open());
+}
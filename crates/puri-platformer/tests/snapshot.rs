@@ -0,0 +1,22 @@
+use bevy::math::Vec2;
+use puri_platformer::testing::TestWorld;
+
+#[test]
+fn snapshot_then_restore_reproduces_the_same_60_ticks_of_simulation() {
+    let mut world = TestWorld::new();
+    world.set_fixed_delta_seconds(1.0 / 60.0);
+    world.set_player_pos(Vec2::new(0.0, 400.0));
+    world.set_player_velocity(Vec2::new(120.0, 0.0));
+
+    let snap = world.snapshot();
+    world.step(60);
+    let pos_after_first_run = world.player_pos();
+    let vel_after_first_run = world.player_velocity();
+
+    world.restore(&snap);
+    assert_eq!(world.player_pos(), Vec2::new(0.0, 400.0), "restore should undo the first run's 60 ticks");
+
+    world.step(60);
+    assert_eq!(world.player_pos(), pos_after_first_run, "the same starting state simulated the same way should land in the same place");
+    assert_eq!(world.player_velocity(), vel_after_first_run);
+}
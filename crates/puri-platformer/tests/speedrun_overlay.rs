@@ -0,0 +1,26 @@
+use puri_platformer::testing::{Action, TestWorld};
+
+#[test]
+fn jump_analytics_is_empty_before_any_jump() {
+    let mut world = TestWorld::new();
+    world.step(30);
+
+    let (last_jump_tick, buffered_ticks, coyote_ticks) = world.jump_analytics();
+    assert_eq!(last_jump_tick, None);
+    assert_eq!(buffered_ticks, 0);
+    assert_eq!(coyote_ticks, 0);
+}
+
+#[test]
+fn a_grounded_jump_uses_no_coyote_or_buffer_time() {
+    let mut world = TestWorld::new();
+    world.step(30); // settle on the floor.
+
+    world.press(Action::Jump);
+    world.step(1);
+
+    let (last_jump_tick, buffered_ticks, coyote_ticks) = world.jump_analytics();
+    assert!(last_jump_tick.is_some());
+    assert_eq!(buffered_ticks, 0, "pressed and grounded on the same tick, nothing was buffered");
+    assert_eq!(coyote_ticks, 0, "pressed while grounded, no coyote window was used");
+}
@@ -0,0 +1,53 @@
+use puri_platformer::testing::TestWorld;
+
+#[test]
+fn walking_onto_the_star_grants_starman() {
+    let mut world = TestWorld::new();
+    assert!(!world.player_has_starman());
+
+    let star_pos = world.star_pickup_pos();
+    world.set_player_pos(star_pos);
+    world.step(1);
+
+    assert!(world.player_has_starman());
+}
+
+#[test]
+fn damage_is_suppressed_while_starman_is_active() {
+    let mut world = TestWorld::new();
+    world.give_player_starman();
+
+    world.deal_damage(3); // matches PlayerBundle's starting Health(3).
+    world.step(1);
+
+    assert!(!world.player_is_dying());
+}
+
+#[test]
+fn the_timer_expires_and_removes_starman() {
+    let mut world = TestWorld::new();
+    world.give_player_starman();
+    assert!(world.player_has_starman());
+
+    // Generous margin over the 8 second duration — see death.rs's
+    // dying-sequence test for why this crate's tests measure Dying/Starman
+    // timers in real wall-clock ticks rather than a fixed virtual step.
+    world.step(900);
+
+    assert!(!world.player_has_starman());
+}
+
+#[test]
+fn dying_clears_starman_even_mid_duration() {
+    let mut world = TestWorld::new();
+    world.give_player_starman();
+
+    // `apply_damage` itself can't kill a starred player (that's the point
+    // of the previous test) — fire `PlayerDied` directly to exercise
+    // `start_dying`'s own `Starman` cleanup in isolation.
+    world.kill_player();
+    world.step(1);
+    assert!(world.player_is_dying());
+
+    assert!(!world.player_has_starman());
+}
@@ -0,0 +1,118 @@
+use bevy::math::{UVec2, Vec2};
+use bevy::render::mesh::Mesh;
+use puri_platformer::tilemap::{AnimatedTile, TileAnimations, TileLayer, CHUNK_SIZE};
+
+fn empty_layer() -> TileLayer {
+    TileLayer::new(CHUNK_SIZE * 2, CHUNK_SIZE, Vec2::new(16.0, 16.0), 4, 4)
+}
+
+#[test]
+fn setting_a_tile_dirties_only_its_owning_chunk() {
+    let mut layer = empty_layer();
+    layer.take_dirty_chunks(); // discard the initial all-dirty set.
+
+    layer.set_tile(5, 5, Some(0));
+    let dirty = layer.take_dirty_chunks();
+    assert_eq!(dirty, vec![UVec2::new(0, 0)]);
+
+    layer.set_tile(CHUNK_SIZE + 1, 5, Some(0));
+    let dirty = layer.take_dirty_chunks();
+    assert_eq!(dirty, vec![UVec2::new(1, 0)]);
+}
+
+#[test]
+fn setting_a_tile_to_its_current_value_does_not_dirty_it() {
+    let mut layer = empty_layer();
+    layer.set_tile(0, 0, Some(3));
+    layer.take_dirty_chunks();
+
+    layer.set_tile(0, 0, Some(3));
+    assert!(layer.take_dirty_chunks().is_empty());
+}
+
+#[test]
+fn out_of_bounds_tile_writes_are_ignored() {
+    let mut layer = empty_layer();
+    layer.take_dirty_chunks();
+
+    layer.set_tile(9999, 9999, Some(1));
+    assert!(layer.take_dirty_chunks().is_empty());
+}
+
+#[test]
+fn chunk_mesh_has_one_quad_per_non_empty_tile() {
+    let mut layer = empty_layer();
+    layer.set_tile(0, 0, Some(0));
+    layer.set_tile(1, 0, Some(1));
+    layer.set_tile(2, 2, None); // stays empty, no quad.
+
+    let mesh = layer.build_chunk_mesh(UVec2::new(0, 0), &TileAnimations::default(), 0.0);
+    assert_eq!(mesh.count_vertices(), 8); // 2 tiles * 4 vertices.
+    assert!(mesh.attribute(Mesh::ATTRIBUTE_UV_0).is_some());
+}
+
+#[test]
+fn empty_chunk_mesh_has_no_vertices() {
+    let mut layer = empty_layer();
+    let mesh = layer.build_chunk_mesh(UVec2::new(1, 0), &TileAnimations::default(), 0.0);
+    assert_eq!(mesh.count_vertices(), 0);
+}
+
+#[test]
+fn building_a_chunk_with_an_animated_tile_marks_it_animated() {
+    let mut layer = empty_layer();
+    layer.set_tile(0, 0, Some(5));
+    let mut animations = TileAnimations::default();
+    animations.insert(
+        5,
+        AnimatedTile {
+            frames: vec![5, 6, 7],
+            frame_time: 0.2,
+        },
+    );
+
+    layer.build_chunk_mesh(UVec2::new(0, 0), &animations, 0.0);
+    assert_eq!(layer.animated_chunks().collect::<Vec<_>>(), vec![UVec2::new(0, 0)]);
+}
+
+#[test]
+fn removing_the_animated_tile_and_rebuilding_clears_animated_membership() {
+    let mut layer = empty_layer();
+    layer.set_tile(0, 0, Some(5));
+    let mut animations = TileAnimations::default();
+    animations.insert(
+        5,
+        AnimatedTile {
+            frames: vec![5, 6],
+            frame_time: 0.2,
+        },
+    );
+    layer.build_chunk_mesh(UVec2::new(0, 0), &animations, 0.0);
+    assert!(!layer.animated_chunks().collect::<Vec<_>>().is_empty());
+
+    // Destructible-terrain edit swaps in a non-animated tile...
+    layer.set_tile(0, 0, Some(1));
+    layer.take_dirty_chunks();
+    layer.build_chunk_mesh(UVec2::new(0, 0), &animations, 0.0);
+    assert!(layer.animated_chunks().collect::<Vec<_>>().is_empty());
+}
+
+#[test]
+fn animated_tile_cycles_through_its_frames_over_time() {
+    let mut layer = empty_layer();
+    layer.set_tile(0, 0, Some(5));
+    let mut animations = TileAnimations::default();
+    animations.insert(
+        5,
+        AnimatedTile {
+            frames: vec![5, 6],
+            frame_time: 1.0,
+        },
+    );
+
+    // Frame index is derived from elapsed / frame_time; different elapsed
+    // times land on different frames, so the two meshes' UVs differ.
+    let mesh_at_0 = layer.build_chunk_mesh(UVec2::new(0, 0), &animations, 0.0);
+    let mesh_at_1 = layer.build_chunk_mesh(UVec2::new(0, 0), &animations, 1.5);
+    assert_ne!(mesh_at_0.attribute(Mesh::ATTRIBUTE_UV_0), mesh_at_1.attribute(Mesh::ATTRIBUTE_UV_0));
+}
@@ -0,0 +1,36 @@
+use puri_platformer::testing::TestWorld;
+
+#[test]
+fn a_fired_toast_becomes_active_the_next_frame() {
+    let mut world = TestWorld::new();
+    assert_eq!(world.active_toast_count(), 0);
+
+    world.fire_toast("Checkpoint reached");
+    world.step(1);
+
+    assert_eq!(world.active_toast_texts(), vec!["Checkpoint reached".to_string()]);
+}
+
+#[test]
+fn identical_toasts_fired_within_a_second_are_deduplicated() {
+    let mut world = TestWorld::new();
+
+    world.fire_toast("Saved screenshots/screenshot-1.png");
+    world.step(1);
+    world.fire_toast("Saved screenshots/screenshot-1.png");
+    world.step(1);
+
+    assert_eq!(world.active_toast_count(), 1, "the second, identical toast should have been dropped as a duplicate");
+}
+
+#[test]
+fn only_max_visible_toasts_show_at_once_the_rest_queue() {
+    let mut world = TestWorld::new();
+
+    for message in ["one", "two", "three", "four"] {
+        world.fire_toast(message);
+    }
+    world.step(1);
+
+    assert_eq!(world.active_toast_count(), 3, "a fourth toast should wait in the pending queue rather than showing immediately");
+}
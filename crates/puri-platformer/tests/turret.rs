@@ -0,0 +1,63 @@
+use bevy::math::Vec2;
+use puri_platformer::testing::TestWorld;
+
+/// Steps `world` in small increments until `condition` is true or
+/// `max_ticks` is reached, returning whether it became true — see
+/// starman.rs's own test comment on why this crate's tests measure timers
+/// in real wall-clock ticks rather than a fixed virtual step, which makes
+/// an exact tick count for a telegraph-then-fire sequence too fragile to
+/// hardcode.
+fn wait_until(world: &mut TestWorld, max_ticks: u32, mut condition: impl FnMut(&mut TestWorld) -> bool) -> bool {
+    let mut ticked = 0;
+    while ticked < max_ticks {
+        if condition(world) {
+            return true;
+        }
+        world.step(30);
+        ticked += 30;
+    }
+    condition(world)
+}
+
+#[test]
+fn a_turret_in_range_and_sight_eventually_fires() {
+    let mut world = TestWorld::new();
+    // Turns off gravity so the player stays pinned in the turret's line of
+    // sight instead of drifting to the floor below it.
+    world.set_gravity(0.0);
+    let turret_pos = world.turret_pos();
+    world.set_player_pos(turret_pos + Vec2::new(60.0, 0.0));
+
+    assert!(
+        wait_until(&mut world, 3000, |w| w.projectile_count() > 0),
+        "turret should have fired at least one projectile by now"
+    );
+}
+
+#[test]
+fn a_turret_projectile_damages_the_player_on_contact() {
+    let mut world = TestWorld::new();
+    world.set_gravity(0.0);
+    let turret_pos = world.turret_pos();
+    let starting_health = world.player_health();
+
+    world.set_player_pos(turret_pos + Vec2::new(60.0, 0.0));
+
+    assert!(
+        wait_until(&mut world, 3000, |w| w.player_health() < starting_health),
+        "player health should drop once a turret projectile connects"
+    );
+}
+
+#[test]
+fn stomping_a_turret_from_above_despawns_it() {
+    let mut world = TestWorld::new();
+    let turret_pos = world.turret_pos();
+    assert_eq!(world.turret_count(), 1);
+
+    world.set_player_pos(turret_pos + Vec2::new(0.0, 40.0));
+    world.set_player_velocity(Vec2::new(0.0, -200.0));
+    world.step(30);
+
+    assert_eq!(world.turret_count(), 0);
+}
@@ -0,0 +1,26 @@
+use puri_platformer::testing::TestWorld;
+
+#[test]
+fn resizing_the_window_updates_ui_scale_proportionally_to_1080p() {
+    let mut world = TestWorld::new();
+
+    world.resize_window(3840.0, 2160.0);
+    world.step(1);
+    assert!((world.ui_scale() - 2.0).abs() < 0.001);
+
+    world.resize_window(1280.0, 720.0);
+    world.step(1);
+    assert!((world.ui_scale() - (720.0 / 1080.0)).abs() < 0.001);
+}
+
+#[test]
+fn a_safe_area_margin_pads_anchored_hud_nodes_without_moving_others() {
+    let mut world = TestWorld::new();
+    let anchor = world.spawn_safe_area_anchor(8.0, 8.0);
+    world.step(1);
+    assert_eq!(world.anchor_offset(anchor), (8.0, 8.0));
+
+    world.set_safe_area_margin(24.0);
+    world.step(1);
+    assert_eq!(world.anchor_offset(anchor), (32.0, 32.0));
+}
@@ -0,0 +1,68 @@
+use bevy::math::Vec2;
+use puri_platformer::testing::{Action, TestWorld};
+use puri_platformer::weather::Weather;
+
+#[test]
+fn rain_spawns_particles_bounded_by_the_pool() {
+    let mut world = TestWorld::new();
+    world.set_camera_area(Vec2::new(-640.0, -360.0), Vec2::new(640.0, 360.0));
+    assert_eq!(world.active_particle_count(), 0);
+
+    world.set_weather(Weather::Rain { intensity: 1.0 });
+    world.step(10);
+
+    assert!(world.active_particle_count() > 0, "expected rain to spawn particles");
+}
+
+#[test]
+fn clearing_the_weather_lets_particles_recycle_back_to_the_pool() {
+    let mut world = TestWorld::new();
+    world.set_camera_area(Vec2::new(-640.0, -360.0), Vec2::new(640.0, 360.0));
+    world.set_weather(Weather::Snow { intensity: 1.0 });
+    world.step(10);
+    assert!(world.active_particle_count() > 0, "expected snow to spawn particles");
+
+    world.set_weather(Weather::None);
+    // Long enough for every already-airborne flake to fall out of view and
+    // recycle, with nothing respawned once the weather's cleared.
+    world.step(600);
+
+    assert_eq!(world.active_particle_count(), 0);
+}
+
+#[test]
+fn rain_makes_the_stone_floor_slippery() {
+    let mut dry = TestWorld::new();
+    dry.step(60); // settle onto the demo floor's SurfaceMaterial::Stone.
+    dry.press(Action::Right);
+    dry.step(10);
+    let dry_speed = dry.player_velocity().x;
+
+    let mut wet = TestWorld::new();
+    wet.step(60);
+    wet.set_weather(Weather::Rain { intensity: 1.0 });
+    wet.press(Action::Right);
+    wet.step(10);
+    let wet_speed = wet.player_velocity().x;
+
+    assert!(
+        wet_speed < dry_speed,
+        "expected rain to slow the player's acceleration on stone (dry: {dry_speed}, wet: {wet_speed})"
+    );
+}
+
+#[test]
+fn snow_accumulates_a_tint_on_the_platform_top_and_melts_once_it_stops() {
+    let mut world = TestWorld::new();
+    assert_eq!(world.snow_cap_alpha(), 0.0);
+
+    world.set_weather(Weather::Snow { intensity: 1.0 });
+    world.step(120);
+    let snowed = world.snow_cap_alpha();
+    assert!(snowed > 0.3, "expected snow cover to build up, got {snowed}");
+
+    world.set_weather(Weather::None);
+    world.step(120);
+    let melted = world.snow_cap_alpha();
+    assert!(melted < snowed, "expected snow cover to melt back down once it stopped snowing");
+}
@@ -0,0 +1,44 @@
+use puri_platformer::testing::TestWorld;
+
+#[test]
+fn a_fresh_level_has_no_flags_set() {
+    let mut world = TestWorld::new();
+    assert!(!world.world_flag_is_set(0));
+    assert!(world.star_pickup_exists());
+}
+
+#[test]
+fn collecting_the_star_pickup_sets_its_world_flag() {
+    let mut world = TestWorld::new();
+    let pos = world.star_pickup_pos();
+    world.set_player_pos(pos);
+    world.step(1);
+
+    assert!(world.player_has_starman());
+    assert!(!world.star_pickup_exists());
+    assert!(world.world_flag_is_set(0));
+}
+
+#[test]
+fn a_collected_pickup_stays_gone_after_a_level_reload() {
+    let mut world = TestWorld::new();
+    let pos = world.star_pickup_pos();
+    world.set_player_pos(pos);
+    world.step(1);
+    assert!(!world.star_pickup_exists(), "expected the star to be collected before reloading");
+
+    world.reload_level();
+
+    assert!(!world.star_pickup_exists(), "expected the level loader to skip respawning a flagged pickup");
+    assert!(world.world_flag_is_set(0));
+}
+
+#[test]
+fn an_uncollected_pickup_still_respawns_after_a_level_reload() {
+    let mut world = TestWorld::new();
+    assert!(world.star_pickup_exists());
+
+    world.reload_level();
+
+    assert!(world.star_pickup_exists(), "expected an uncollected pickup to respawn like any other level entity");
+}
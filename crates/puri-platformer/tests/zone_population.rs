@@ -0,0 +1,52 @@
+#![cfg(feature = "serde")]
+
+use bevy::math::Vec2;
+use puri_platformer::testing::TestWorld;
+
+fn stomp_turret_at(world: &mut TestWorld, turret_pos: Vec2) {
+    world.set_player_pos(turret_pos + Vec2::new(0.0, 40.0));
+    world.set_player_velocity(Vec2::new(0.0, -200.0));
+    world.step(30);
+}
+
+#[test]
+fn a_killed_turret_stays_dead_until_the_player_leaves_and_returns_to_its_zone() {
+    let mut world = TestWorld::new();
+    let turret_pos = world.turret_pos();
+    assert_eq!(world.turret_count(), 1);
+
+    stomp_turret_at(&mut world, turret_pos);
+    assert_eq!(world.turret_count(), 0);
+
+    world.set_player_pos(turret_pos + Vec2::new(2000.0, 0.0));
+    world.step(1);
+    assert_eq!(
+        world.turret_count(),
+        0,
+        "leaving the zone shouldn't respawn it by itself"
+    );
+
+    world.set_player_pos(turret_pos);
+    world.step(1);
+    assert_eq!(
+        world.turret_count(),
+        1,
+        "returning to the zone should respawn it"
+    );
+}
+
+#[test]
+fn a_killed_turret_does_not_respawn_while_the_player_stays_in_its_zone() {
+    let mut world = TestWorld::new();
+    let turret_pos = world.turret_pos();
+
+    stomp_turret_at(&mut world, turret_pos);
+    assert_eq!(world.turret_count(), 0);
+
+    world.step(30);
+    assert_eq!(
+        world.turret_count(),
+        0,
+        "still in the zone, so nothing has re-entered it yet"
+    );
+}